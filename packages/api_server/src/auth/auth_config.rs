@@ -4,8 +4,8 @@ use std::str::FromStr;
 #[derive(PartialEq, Eq)]
 pub enum AuthConfig {
     NoAuth,
-    BasicAuth,    // todo: support this kind of http auth
-    BearerAuth,   // todo: support this kind of http auth
+    BasicAuth,
+    BearerAuth,
     KerberosAuth, // todo: support this kind of http auth
 }
 
@@ -0,0 +1,173 @@
+use axum::http::{header, HeaderMap, StatusCode};
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use super::{auth_config::AuthConfig, principal::Principal, role::Role, user::UserListConfig};
+use crate::handler::error::ApiError;
+
+// Tells who sent a request from its `Authorization` header
+pub struct Authenticator {
+    auth_config: AuthConfig,
+    user_list_config: UserListConfig,
+}
+
+// Compares every byte so that the time taken does not tell how much of a secret was guessed
+fn is_secret_matched(expected: &str, actual: &str) -> bool {
+    expected.len() == actual.len()
+        && expected
+            .bytes()
+            .zip(actual.bytes())
+            .fold(0u8, |difference, (left, right)| difference | (left ^ right))
+            == 0
+}
+
+fn unauthenticated(message: &str) -> ApiError {
+    ApiError::new(
+        StatusCode::UNAUTHORIZED,
+        "UNAUTHENTICATED",
+        message.to_owned(),
+    )
+}
+
+impl Authenticator {
+    // Everyone is an admin when there is no auth, like before roles existed
+    pub const ANONYMOUS_USERNAME: &'static str = "anonymous";
+
+    pub fn new(auth_config: AuthConfig, user_list_config: UserListConfig) -> Self {
+        Authenticator {
+            auth_config,
+            user_list_config,
+        }
+    }
+
+    pub fn authenticate(&self, header_map: &HeaderMap) -> Result<Principal, ApiError> {
+        if self.auth_config == AuthConfig::NoAuth {
+            return Result::Ok(Principal {
+                username: Self::ANONYMOUS_USERNAME.to_owned(),
+                role: Role::Admin,
+            });
+        }
+
+        let authorization = header_map
+            .get(header::AUTHORIZATION)
+            .and_then(|authorization| authorization.to_str().ok())
+            .ok_or(unauthenticated("No Authorization header"))?;
+        let user_config = match self.auth_config {
+            AuthConfig::BasicAuth => {
+                let credential = authorization
+                    .strip_prefix("Basic ")
+                    .and_then(|encoded| STANDARD.decode(encoded).ok())
+                    .and_then(|decoded| String::from_utf8(decoded).ok())
+                    .ok_or(unauthenticated("Malformed basic auth credential"))?;
+                let (username, password) = credential
+                    .split_once(':')
+                    .ok_or(unauthenticated("Malformed basic auth credential"))?;
+                self.user_list_config.user_list.iter().find(|user_config| {
+                    user_config.username == username
+                        && user_config
+                            .password
+                            .as_ref()
+                            .is_some_and(|expected| is_secret_matched(expected, password))
+                })
+            }
+            AuthConfig::BearerAuth => {
+                let token = authorization
+                    .strip_prefix("Bearer ")
+                    .ok_or(unauthenticated("Malformed bearer auth credential"))?;
+                self.user_list_config.user_list.iter().find(|user_config| {
+                    user_config
+                        .token
+                        .as_ref()
+                        .is_some_and(|expected| is_secret_matched(expected, token))
+                })
+            }
+            _ => Option::None,
+        }
+        .ok_or(unauthenticated("Unknown credential"))?;
+
+        Result::Ok(Principal {
+            username: user_config.username.clone(),
+            role: user_config.role,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_authenticator {
+    use axum::http::{header, HeaderMap, HeaderValue};
+
+    use super::Authenticator;
+    use crate::auth::{auth_config::AuthConfig, role::Role, user::UserListConfig};
+
+    const USER_LIST: &str = r#"
+user_list:
+  - username: desk
+    role: viewer
+    password: desk_password
+  - username: trader
+    role: admin
+    token: trader_token
+"#;
+
+    fn get_header_map(authorization: &str) -> HeaderMap {
+        HeaderMap::from_iter([(
+            header::AUTHORIZATION,
+            HeaderValue::from_str(authorization).unwrap(),
+        )])
+    }
+
+    #[test]
+    fn test_authenticate() {
+        let user_list_config = UserListConfig::parse(USER_LIST, |_| Option::None).unwrap();
+
+        let authenticator = Authenticator::new(AuthConfig::BasicAuth, user_list_config.clone());
+        // desk:desk_password
+        let principal = authenticator
+            .authenticate(&get_header_map("Basic ZGVzazpkZXNrX3Bhc3N3b3Jk"))
+            .unwrap();
+        assert_eq!("desk", principal.username);
+        assert_eq!(Role::Viewer, principal.role);
+        // desk:wrong
+        assert!(authenticator
+            .authenticate(&get_header_map("Basic ZGVzazp3cm9uZw=="))
+            .is_err());
+        assert!(authenticator.authenticate(&HeaderMap::new()).is_err());
+
+        let authenticator = Authenticator::new(AuthConfig::BearerAuth, user_list_config);
+        let principal = authenticator
+            .authenticate(&get_header_map("Bearer trader_token"))
+            .unwrap();
+        assert_eq!("trader", principal.username);
+        assert_eq!(Role::Admin, principal.role);
+        assert!(authenticator
+            .authenticate(&get_header_map("Bearer desk_password"))
+            .is_err());
+
+        let authenticator = Authenticator::new(AuthConfig::NoAuth, UserListConfig::default());
+        let principal = authenticator.authenticate(&HeaderMap::new()).unwrap();
+        assert_eq!(Role::Admin, principal.role);
+    }
+
+    #[test]
+    fn test_parse_user_list_with_duplicated_username() {
+        let err = UserListConfig::parse(
+            "user_list: [{username: a, role: viewer}, {username: a, role: admin}]",
+            |_| Option::None,
+        )
+        .err()
+        .unwrap();
+        assert!(err.to_string().contains("ILLEGAL_VALUE"));
+    }
+
+    #[test]
+    fn test_parse_user_list_with_env_var() {
+        let user_list_config = UserListConfig::parse(
+            "user_list: [{username: desk, role: viewer, token: '${DESK_TOKEN}'}]",
+            |name| (name == "DESK_TOKEN").then(|| "a'b: #c".to_owned()),
+        )
+        .unwrap();
+        assert_eq!(
+            Option::Some("a'b: #c".to_owned()),
+            user_list_config.user_list[0].token
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::sync::Arc;
+
+use super::authenticator::Authenticator;
+
+// Rejects requests of unknown users, the others carry their `Principal` to the handlers
+pub async fn auth_middleware(
+    State(authenticator): State<Arc<Authenticator>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    match authenticator.authenticate(request.headers()) {
+        Result::Ok(principal) => {
+            request.extensions_mut().insert(principal);
+            next.run(request).await
+        }
+        Result::Err(err) => {
+            log::warn!(
+                "Rejected {} {}, {}",
+                request.method(),
+                request.uri().path(),
+                err.message
+            );
+            err.into_response()
+        }
+    }
+}
@@ -1 +1,6 @@
 pub mod auth_config;
+pub mod authenticator;
+pub mod middleware;
+pub mod principal;
+pub mod role;
+pub mod user;
@@ -0,0 +1,68 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts, http::StatusCode};
+
+use super::role::Role;
+use crate::handler::error::ApiError;
+
+// The authenticated user of a request, put into the request extensions by the auth middleware
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Principal {
+    pub username: String,
+    pub role: Role,
+}
+
+impl Principal {
+    pub fn require(&self, role: Role) -> Result<(), ApiError> {
+        match self.role >= role {
+            true => Result::Ok(()),
+            false => Result::Err(ApiError::new(
+                StatusCode::FORBIDDEN,
+                "PERMISSION_DENIED",
+                format!(
+                    "User {} is {:?}, {:?} is required",
+                    self.username, self.role, role
+                ),
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for Principal
+where
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<Principal>()
+            .cloned()
+            .ok_or(ApiError::new(
+                StatusCode::UNAUTHORIZED,
+                "UNAUTHENTICATED",
+                "No authenticated user".to_owned(),
+            ))
+    }
+}
+
+#[cfg(test)]
+mod test_principal {
+    use axum::http::StatusCode;
+
+    use super::Principal;
+    use crate::auth::role::Role;
+
+    #[test]
+    fn test_require() {
+        let principal = Principal {
+            username: "desk".to_owned(),
+            role: Role::Operator,
+        };
+        assert!(principal.require(Role::Viewer).is_ok());
+        assert!(principal.require(Role::Operator).is_ok());
+        let err = principal.require(Role::Admin).err().unwrap();
+        assert_eq!(StatusCode::FORBIDDEN, err.status);
+        assert_eq!("PERMISSION_DENIED", err.code);
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+// Each role is granted everything the previous ones are
+#[derive(
+    Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    // lists and inspects pods, their events and their brokers
+    Viewer,
+    // starts and stops pods
+    Operator,
+    // places manual orders and reads the audit log
+    Admin,
+}
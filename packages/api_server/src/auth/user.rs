@@ -0,0 +1,56 @@
+use anyhow::{anyhow, Context, Error};
+use rabbit_trading_core::model::config::loader::interpolate_env_var_in_value;
+use serde::Deserialize;
+use serde_json::Value;
+use std::{collections::HashSet, env, fs, path::Path};
+
+use super::role::Role;
+
+// A user allowed to call the API server, `password` is checked by basic auth and `token` by
+// bearer auth
+#[derive(Clone, Debug, Deserialize)]
+pub struct UserConfig {
+    pub username: String,
+    pub role: Role,
+    #[serde(default)]
+    pub password: Option<String>,
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct UserListConfig {
+    pub user_list: Vec<UserConfig>,
+}
+
+impl UserListConfig {
+    // Env vars are interpolated in the parsed values, so a secret needs no YAML escaping
+    pub fn parse(
+        content: &str,
+        get_env_var: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, Error> {
+        let mut value: Value = serde_yaml_ng::from_str(content)
+            .context("PARSING_ERROR Error when parsing the user list")?;
+        interpolate_env_var_in_value(&mut value, get_env_var)?;
+        let user_list_config: UserListConfig = serde_json::from_value(value)
+            .context("PARSING_ERROR Error when parsing the user list")?;
+
+        let mut username_set = HashSet::new();
+        for user_config in &user_list_config.user_list {
+            if !username_set.insert(user_config.username.as_str()) {
+                return Result::Err(anyhow!(
+                    "ILLEGAL_VALUE duplicated username {}",
+                    user_config.username
+                ));
+            }
+        }
+        Result::Ok(user_list_config)
+    }
+
+    // A YAML file whose secrets are better kept in env vars, e.g. `token: ${DESK_TOKEN}`
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Error when reading the user list {}", path.display()))?;
+        Self::parse(&content, |name| env::var(name).ok())
+    }
+}
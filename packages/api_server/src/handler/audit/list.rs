@@ -0,0 +1,40 @@
+use axum::{extract::State, http::StatusCode, Json};
+
+use crate::{
+    auth::{principal::Principal, role::Role},
+    handler::{error::ApiError, extractor::ApiQuery, state::AppState},
+    model::audit::list::{ListAuditRecordQuery, ListAuditRecordResponse},
+};
+
+#[utoipa::path(
+    get,
+    path = "/audit",
+    operation_id = "list_audit_record",
+    tag = "audit",
+    params(ListAuditRecordQuery),
+    responses(
+        (status = 200, body = ListAuditRecordResponse),
+        (status = 403, body = ErrorResponse),
+        (status = 500, body = ErrorResponse),
+    )
+)]
+pub(super) async fn list_handler(
+    State(state): State<AppState>,
+    principal: Principal,
+    ApiQuery(query): ApiQuery<ListAuditRecordQuery>,
+) -> Result<Json<ListAuditRecordResponse>, ApiError> {
+    principal.require(Role::Admin)?;
+    let (record_list, next_offset) = state.audit_log.list(&query).await.map_err(|err| {
+        log::error!("Error when reading the audit log, {:#}", err);
+        ApiError::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "AUDIT_LOG_ERROR",
+            format!("{:#}", err),
+        )
+    })?;
+
+    Result::Ok(axum::Json(ListAuditRecordResponse {
+        record_list,
+        next_offset,
+    }))
+}
@@ -0,0 +1,23 @@
+use axum::{routing::get, Router};
+use utoipa::OpenApi;
+
+use super::list::{__path_list_handler, list_handler};
+use crate::{
+    auth::role::Role,
+    handler::state::AppState,
+    model::audit::{
+        list::ListAuditRecordResponse,
+        record::{AuditAction, AuditRecord},
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(list_handler),
+    components(schemas(AuditAction, AuditRecord, ListAuditRecordResponse, Role))
+)]
+pub struct AuditApiDoc;
+
+pub fn initialize_audit_router(router: Router<AppState>) -> Router<AppState> {
+    router.route("/audit", get(list_handler))
+}
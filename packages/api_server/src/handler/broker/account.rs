@@ -0,0 +1,76 @@
+use axum::{extract::State, Json};
+
+use super::common::{get_transaction, to_broker_error};
+use crate::{
+    auth::{principal::Principal, role::Role},
+    handler::{
+        error::ApiError,
+        extractor::{ApiPath, ApiQuery},
+        state::AppState,
+    },
+    model::broker::{
+        account::{QueryBalanceResponse, QueryPositionsResponse},
+        target::BrokerQuery,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/pod/{pod_id}/broker/balance",
+    operation_id = "query_broker_balance",
+    tag = "broker",
+    params(("pod_id" = String, Path, description = "Id of the pod"), BrokerQuery),
+    responses(
+        (status = 200, body = QueryBalanceResponse),
+        (status = 404, body = ErrorResponse),
+        (status = 409, body = ErrorResponse),
+        (status = 502, body = ErrorResponse),
+    )
+)]
+pub(super) async fn balance_handler(
+    State(state): State<AppState>,
+    principal: Principal,
+    ApiPath(pod_id): ApiPath<String>,
+    ApiQuery(query): ApiQuery<BrokerQuery>,
+) -> Result<Json<QueryBalanceResponse>, ApiError> {
+    principal.require(Role::Viewer)?;
+    let transaction = get_transaction(&state, &pod_id, query.broker_id.as_deref())
+        .await?
+        .lock_owned()
+        .await;
+    let balance = transaction
+        .account_balance()
+        .await
+        .map_err(to_broker_error)?;
+
+    Result::Ok(axum::Json(QueryBalanceResponse { balance }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/pod/{pod_id}/broker/positions",
+    operation_id = "query_broker_positions",
+    tag = "broker",
+    params(("pod_id" = String, Path, description = "Id of the pod"), BrokerQuery),
+    responses(
+        (status = 200, body = QueryPositionsResponse),
+        (status = 404, body = ErrorResponse),
+        (status = 409, body = ErrorResponse),
+        (status = 502, body = ErrorResponse),
+    )
+)]
+pub(super) async fn positions_handler(
+    State(state): State<AppState>,
+    principal: Principal,
+    ApiPath(pod_id): ApiPath<String>,
+    ApiQuery(query): ApiQuery<BrokerQuery>,
+) -> Result<Json<QueryPositionsResponse>, ApiError> {
+    principal.require(Role::Viewer)?;
+    let transaction = get_transaction(&state, &pod_id, query.broker_id.as_deref())
+        .await?
+        .lock_owned()
+        .await;
+    let position_list = transaction.positions().await.map_err(to_broker_error)?;
+
+    Result::Ok(axum::Json(QueryPositionsResponse { position_list }))
+}
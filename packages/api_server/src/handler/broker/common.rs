@@ -0,0 +1,220 @@
+use anyhow::Error;
+use axum::http::StatusCode;
+use rabbit_trading_core::{
+    broker::common::broker::BrokerTrait,
+    pod::{state::PodState, Pod, SharedTransaction},
+};
+use std::sync::Arc;
+
+use crate::handler::{error::ApiError, state::AppState};
+
+// Only a running pod lends its brokers, the broker goes through the interceptors of the pod so
+// that manual requests show up in its events and metrics like the ones of the strategy
+pub(super) async fn create_broker(
+    state: &AppState,
+    pod_id: &str,
+    broker_id: Option<&str>,
+) -> Result<Box<dyn BrokerTrait>, ApiError> {
+    let pod = get_running_pod(state, pod_id).await?;
+    pod.create_broker(broker_id).map_err(|err| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "ILLEGAL_BROKER_ID",
+            err.to_string(),
+        )
+    })
+}
+
+async fn get_running_pod(state: &AppState, pod_id: &str) -> Result<Arc<Pod>, ApiError> {
+    let pod = {
+        let readable_pod_store = state.pod_store.read().await;
+        match readable_pod_store.get(pod_id) {
+            Some(pod_instance) => pod_instance.instance.clone(),
+            None => return Result::Err(ApiError::pod_not_found(pod_id)),
+        }
+    };
+    let pod_state = pod.get_status().state;
+    if pod_state != PodState::Running {
+        return Result::Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "ILLEGAL_POD_STATE",
+            format!("Pod {} is not running, state: {:?}", pod_id, pod_state),
+        ));
+    }
+    Result::Ok(pod)
+}
+
+// The transaction kept by the running pod, so that the orders emulated for a broker are found by
+// the next request. Brokers only meant for market data cannot create a transaction.
+pub(super) async fn get_transaction(
+    state: &AppState,
+    pod_id: &str,
+    broker_id: Option<&str>,
+) -> Result<SharedTransaction, ApiError> {
+    let pod = get_running_pod(state, pod_id).await?;
+    pod.get_transaction(broker_id).map_err(|err| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "ILLEGAL_BROKER_ID",
+            format!("{:#}", err),
+        )
+    })
+}
+
+pub(super) fn to_broker_error(err: Error) -> ApiError {
+    log::error!("Error when calling the broker, {:#}", err);
+    ApiError::new(
+        StatusCode::BAD_GATEWAY,
+        "BROKER_ERROR",
+        format!("{:#}", err),
+    )
+}
+
+#[cfg(test)]
+mod test_broker_handler {
+    use axum::{extract::State, http::StatusCode};
+    use rabbit_trading_core::model::trading::transaction::{
+        Direction, Expire, OrderStatus, Price, RegularTradingTime, SubmitOrderRequest,
+    };
+    use tempfile::TempDir;
+
+    use crate::{
+        auth::{principal::Principal, role::Role},
+        handler::{
+            broker::{
+                account::positions_handler,
+                order::{inspect_order_handler, list_order_handler, submit_order_handler},
+            },
+            extractor::{ApiJson, ApiPath, ApiQuery},
+            state::{AppState, PodStoreInstance},
+            test_util::{create_app_state, get_pod_config, get_symbol, write_quote_real_time_info},
+        },
+        model::broker::{order::SubmitBrokerOrderRequest, target::BrokerQuery},
+    };
+
+    const POD_ID: &str = "1";
+
+    fn get_principal(role: Role) -> Principal {
+        Principal {
+            username: "trader".to_owned(),
+            role,
+        }
+    }
+
+    fn get_submit_request(broker_id: Option<&str>) -> SubmitBrokerOrderRequest {
+        SubmitBrokerOrderRequest {
+            broker_id: broker_id.map(str::to_owned),
+            order: SubmitOrderRequest {
+                symbol: get_symbol(),
+                quantity: 10u64.into(),
+                direction: Direction::Buy,
+                regular_trading_time: RegularTradingTime::AllTime,
+                expire: Expire::Day,
+                price: Price::MarketOrder,
+            },
+        }
+    }
+
+    async fn submit_order(
+        app_state: &AppState,
+        role: Role,
+        broker_id: Option<&str>,
+    ) -> Result<String, (StatusCode, &'static str)> {
+        submit_order_handler(
+            State(app_state.clone()),
+            get_principal(role),
+            ApiPath(POD_ID.to_owned()),
+            ApiJson(get_submit_request(broker_id)),
+        )
+        .await
+        .map(|(_, response)| response.0.response.order_id)
+        .map_err(|err| (err.status, err.code))
+    }
+
+    #[tokio::test]
+    async fn test_broker_handler() {
+        let temp_dir = TempDir::new().unwrap();
+        write_quote_real_time_info(&temp_dir);
+        let app_state = create_app_state(1).await;
+        app_state.pod_store.write().await.insert(
+            POD_ID.to_owned(),
+            PodStoreInstance::create(
+                POD_ID.to_owned(),
+                get_pod_config(&temp_dir, Default::default()),
+            )
+            .unwrap(),
+        );
+
+        // the brokers of a pod are only lent while it runs
+        assert_eq!(
+            Result::Err((StatusCode::CONFLICT, "ILLEGAL_POD_STATE")),
+            submit_order(&app_state, Role::Admin, Option::None).await
+        );
+        let pod = app_state.pod_store.read().await[POD_ID].instance.clone();
+        pod.start().await.unwrap();
+
+        assert_eq!(
+            Result::Err((StatusCode::FORBIDDEN, "PERMISSION_DENIED")),
+            submit_order(&app_state, Role::Viewer, Option::None).await
+        );
+        // a market data broker cannot trade
+        assert_eq!(
+            Result::Err((StatusCode::BAD_REQUEST, "ILLEGAL_BROKER_ID")),
+            submit_order(&app_state, Role::Admin, Option::Some("yahoo_finance")).await
+        );
+        assert_eq!(
+            Result::Err((StatusCode::BAD_REQUEST, "ILLEGAL_BROKER_ID")),
+            submit_order(&app_state, Role::Admin, Option::Some("unknown")).await
+        );
+
+        // the order is kept by the transaction of the pod for the next requests
+        let order_id = submit_order(&app_state, Role::Admin, Option::Some("replay"))
+            .await
+            .unwrap();
+        let order = inspect_order_handler(
+            State(app_state.clone()),
+            get_principal(Role::Viewer),
+            ApiPath((POD_ID.to_owned(), order_id.clone())),
+            ApiQuery(BrokerQuery {
+                broker_id: Option::None,
+            }),
+        )
+        .await
+        .unwrap()
+        .0
+        .order;
+        assert_eq!(OrderStatus::Filled, order.status);
+        assert_eq!("100", order.executed_price.unwrap().to_string());
+
+        let order_list = list_order_handler(
+            State(app_state.clone()),
+            get_principal(Role::Viewer),
+            ApiPath(POD_ID.to_owned()),
+            ApiQuery(BrokerQuery {
+                broker_id: Option::None,
+            }),
+        )
+        .await
+        .unwrap()
+        .0
+        .order_list;
+        assert!(order_list.is_empty());
+
+        let position_list = positions_handler(
+            State(app_state.clone()),
+            get_principal(Role::Viewer),
+            ApiPath(POD_ID.to_owned()),
+            ApiQuery(BrokerQuery {
+                broker_id: Option::Some("replay".to_owned()),
+            }),
+        )
+        .await
+        .unwrap()
+        .0
+        .position_list;
+        assert_eq!(1, position_list.len());
+        assert_eq!("10", position_list[0].quantity.to_string());
+
+        pod.stop().await.unwrap();
+    }
+}
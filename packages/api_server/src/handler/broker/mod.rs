@@ -0,0 +1,5 @@
+pub mod account;
+pub mod common;
+pub mod order;
+pub mod quote;
+pub mod router;
@@ -0,0 +1,269 @@
+use axum::{extract::State, http::StatusCode, Json};
+use rabbit_trading_core::model::trading::transaction::{CancelOrderRequest, OrderDetailRequest};
+use serde_json::json;
+
+use super::common::{get_transaction, to_broker_error};
+use crate::{
+    auth::{principal::Principal, role::Role},
+    handler::{
+        error::ApiError,
+        extractor::{ApiJson, ApiPath, ApiQuery},
+        state::AppState,
+    },
+    model::{
+        audit::record::AuditAction,
+        broker::{
+            order::{
+                CancelBrokerOrderResponse, EditBrokerOrderRequest, EditBrokerOrderResponse,
+                InspectOrderResponse, ListOrderResponse, SubmitBrokerOrderRequest,
+                SubmitBrokerOrderResponse,
+            },
+            target::BrokerQuery,
+        },
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/pod/{pod_id}/broker/order",
+    operation_id = "list_broker_order",
+    tag = "broker",
+    params(("pod_id" = String, Path, description = "Id of the pod"), BrokerQuery),
+    responses(
+        (status = 200, body = ListOrderResponse),
+        (status = 404, body = ErrorResponse),
+        (status = 409, body = ErrorResponse),
+        (status = 502, body = ErrorResponse),
+    )
+)]
+pub(super) async fn list_order_handler(
+    State(state): State<AppState>,
+    principal: Principal,
+    ApiPath(pod_id): ApiPath<String>,
+    ApiQuery(query): ApiQuery<BrokerQuery>,
+) -> Result<Json<ListOrderResponse>, ApiError> {
+    principal.require(Role::Viewer)?;
+    let transaction = get_transaction(&state, &pod_id, query.broker_id.as_deref())
+        .await?
+        .lock_owned()
+        .await;
+    let order_list = transaction
+        .list_open_orders()
+        .await
+        .map_err(to_broker_error)?;
+
+    Result::Ok(axum::Json(ListOrderResponse { order_list }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/pod/{pod_id}/broker/order/{order_id}",
+    operation_id = "inspect_broker_order",
+    tag = "broker",
+    params(("pod_id" = String, Path, description = "Id of the pod"), ("order_id" = String, Path, description = "Id of the order at the broker"), BrokerQuery),
+    responses(
+        (status = 200, body = InspectOrderResponse),
+        (status = 404, body = ErrorResponse),
+        (status = 409, body = ErrorResponse),
+        (status = 502, body = ErrorResponse),
+    )
+)]
+pub(super) async fn inspect_order_handler(
+    State(state): State<AppState>,
+    principal: Principal,
+    ApiPath((pod_id, order_id)): ApiPath<(String, String)>,
+    ApiQuery(query): ApiQuery<BrokerQuery>,
+) -> Result<Json<InspectOrderResponse>, ApiError> {
+    principal.require(Role::Viewer)?;
+    let transaction = get_transaction(&state, &pod_id, query.broker_id.as_deref())
+        .await?
+        .lock_owned()
+        .await;
+    let order = transaction
+        .order_detail(OrderDetailRequest { order_id })
+        .await
+        .map_err(to_broker_error)?;
+
+    Result::Ok(axum::Json(InspectOrderResponse { order }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/pod/{pod_id}/broker/order",
+    operation_id = "submit_broker_order",
+    tag = "broker",
+    params(("pod_id" = String, Path, description = "Id of the pod")),
+    request_body = SubmitBrokerOrderRequest,
+    responses(
+        (status = 201, body = SubmitBrokerOrderResponse),
+        (status = 403, body = ErrorResponse),
+        (status = 404, body = ErrorResponse),
+        (status = 409, body = ErrorResponse),
+        (status = 502, body = ErrorResponse),
+    )
+)]
+pub(super) async fn submit_order_handler(
+    State(state): State<AppState>,
+    principal: Principal,
+    ApiPath(pod_id): ApiPath<String>,
+    ApiJson(request): ApiJson<SubmitBrokerOrderRequest>,
+) -> Result<(StatusCode, Json<SubmitBrokerOrderResponse>), ApiError> {
+    let detail = json!({ "broker_id": request.broker_id, "order": request.order });
+    let result = async {
+        principal.require(Role::Admin)?;
+        let mut transaction = get_transaction(&state, &pod_id, request.broker_id.as_deref())
+            .await?
+            .lock_owned()
+            .await;
+        log::warn!(
+            "Manual order submitted to pod {} by {}, {:?}",
+            pod_id,
+            principal.username,
+            request.order
+        );
+        transaction
+            .submit_order(request.order)
+            .await
+            .map_err(to_broker_error)
+    }
+    .await;
+    state
+        .audit(
+            &principal,
+            AuditAction::SubmitOrder,
+            Option::Some(pod_id),
+            detail,
+            &result,
+        )
+        .await;
+
+    result.map(|response| {
+        (
+            StatusCode::CREATED,
+            axum::Json(SubmitBrokerOrderResponse { response }),
+        )
+    })
+}
+
+#[utoipa::path(
+    put,
+    path = "/pod/{pod_id}/broker/order/{order_id}",
+    operation_id = "edit_broker_order",
+    tag = "broker",
+    params(
+        ("pod_id" = String, Path, description = "Id of the pod"),
+        ("order_id" = String, Path, description = "Id of the order at the broker")
+    ),
+    request_body = EditBrokerOrderRequest,
+    responses(
+        (status = 200, body = EditBrokerOrderResponse),
+        (status = 400, body = ErrorResponse),
+        (status = 403, body = ErrorResponse),
+        (status = 404, body = ErrorResponse),
+        (status = 409, body = ErrorResponse),
+        (status = 502, body = ErrorResponse),
+    )
+)]
+pub(super) async fn edit_order_handler(
+    State(state): State<AppState>,
+    principal: Principal,
+    ApiPath((pod_id, order_id)): ApiPath<(String, String)>,
+    ApiJson(request): ApiJson<EditBrokerOrderRequest>,
+) -> Result<Json<EditBrokerOrderResponse>, ApiError> {
+    let detail = json!({ "broker_id": request.broker_id, "order": request.order });
+    let result = async {
+        principal.require(Role::Admin)?;
+        if request.order.order_id != order_id {
+            return Result::Err(ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "ILLEGAL_VALUE",
+                format!(
+                    "order_id {} of the body does not match {} of the path",
+                    request.order.order_id, order_id
+                ),
+            ));
+        }
+        let mut transaction = get_transaction(&state, &pod_id, request.broker_id.as_deref())
+            .await?
+            .lock_owned()
+            .await;
+        log::warn!(
+            "Manual order edited in pod {} by {}, {:?}",
+            pod_id,
+            principal.username,
+            request.order
+        );
+        transaction
+            .edit_order(request.order)
+            .await
+            .map_err(to_broker_error)
+    }
+    .await;
+    state
+        .audit(
+            &principal,
+            AuditAction::EditOrder,
+            Option::Some(pod_id),
+            detail,
+            &result,
+        )
+        .await;
+
+    result.map(|response| axum::Json(EditBrokerOrderResponse { response }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/pod/{pod_id}/broker/order/{order_id}",
+    operation_id = "cancel_broker_order",
+    tag = "broker",
+    params(
+        ("pod_id" = String, Path, description = "Id of the pod"),
+        ("order_id" = String, Path, description = "Id of the order at the broker"),
+        BrokerQuery
+    ),
+    responses(
+        (status = 200, body = CancelBrokerOrderResponse),
+        (status = 403, body = ErrorResponse),
+        (status = 404, body = ErrorResponse),
+        (status = 409, body = ErrorResponse),
+        (status = 502, body = ErrorResponse),
+    )
+)]
+pub(super) async fn cancel_order_handler(
+    State(state): State<AppState>,
+    principal: Principal,
+    ApiPath((pod_id, order_id)): ApiPath<(String, String)>,
+    ApiQuery(query): ApiQuery<BrokerQuery>,
+) -> Result<Json<CancelBrokerOrderResponse>, ApiError> {
+    let detail = json!({ "broker_id": query.broker_id, "order_id": order_id });
+    let result = async {
+        principal.require(Role::Admin)?;
+        let mut transaction = get_transaction(&state, &pod_id, query.broker_id.as_deref())
+            .await?
+            .lock_owned()
+            .await;
+        log::warn!(
+            "Manual order {} cancelled in pod {} by {}",
+            order_id,
+            pod_id,
+            principal.username
+        );
+        transaction
+            .cancel_order(CancelOrderRequest { order_id })
+            .await
+            .map_err(to_broker_error)
+    }
+    .await;
+    state
+        .audit(
+            &principal,
+            AuditAction::CancelOrder,
+            Option::Some(pod_id),
+            detail,
+            &result,
+        )
+        .await;
+
+    result.map(|response| axum::Json(CancelBrokerOrderResponse { response }))
+}
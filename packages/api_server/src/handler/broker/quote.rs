@@ -0,0 +1,87 @@
+use axum::{extract::State, http::StatusCode, Json};
+use rabbit_trading_core::model::trading::{
+    quote::{QueryInfoRequest, QuoteKind},
+    symbol::Symbol,
+};
+use std::str::FromStr;
+
+use super::common::{create_broker, to_broker_error};
+use crate::{
+    auth::{principal::Principal, role::Role},
+    handler::{
+        error::ApiError,
+        extractor::{ApiPath, ApiQuery},
+        state::AppState,
+    },
+    model::broker::quote::{QueryDepthResponse, QueryQuoteResponse, QuoteQuery},
+};
+
+fn to_query_info_request(query: &QuoteQuery) -> Result<QueryInfoRequest, ApiError> {
+    let symbol = Symbol::from_str(&query.symbol)
+        .map_err(|err| ApiError::new(StatusCode::BAD_REQUEST, "PARSING_ERROR", err.to_string()))?;
+    Result::Ok(QueryInfoRequest {
+        symbol,
+        kind: query.kind.clone().unwrap_or(QuoteKind::Stock),
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/pod/{pod_id}/broker/quote",
+    operation_id = "query_broker_quote",
+    tag = "broker",
+    params(("pod_id" = String, Path, description = "Id of the pod"), QuoteQuery),
+    responses(
+        (status = 200, body = QueryQuoteResponse),
+        (status = 404, body = ErrorResponse),
+        (status = 409, body = ErrorResponse),
+        (status = 502, body = ErrorResponse),
+    )
+)]
+pub(super) async fn quote_handler(
+    State(state): State<AppState>,
+    principal: Principal,
+    ApiPath(pod_id): ApiPath<String>,
+    ApiQuery(query): ApiQuery<QuoteQuery>,
+) -> Result<Json<QueryQuoteResponse>, ApiError> {
+    principal.require(Role::Viewer)?;
+    let request = to_query_info_request(&query)?;
+    let info = create_broker(&state, &pod_id, query.broker_id.as_deref())
+        .await?
+        .create_info();
+    let quote = info
+        .query_real_time_info(request)
+        .await
+        .map_err(to_broker_error)?;
+
+    Result::Ok(axum::Json(QueryQuoteResponse { quote }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/pod/{pod_id}/broker/depth",
+    operation_id = "query_broker_depth",
+    tag = "broker",
+    params(("pod_id" = String, Path, description = "Id of the pod"), QuoteQuery),
+    responses(
+        (status = 200, body = QueryDepthResponse),
+        (status = 404, body = ErrorResponse),
+        (status = 409, body = ErrorResponse),
+        (status = 502, body = ErrorResponse),
+    )
+)]
+pub(super) async fn depth_handler(
+    State(state): State<AppState>,
+    principal: Principal,
+    ApiPath(pod_id): ApiPath<String>,
+    ApiQuery(query): ApiQuery<QuoteQuery>,
+) -> Result<Json<QueryDepthResponse>, ApiError> {
+    principal.require(Role::Viewer)?;
+    let request = to_query_info_request(&query)?;
+    let info = create_broker(&state, &pod_id, query.broker_id.as_deref())
+        .await?
+        .create_info();
+    let depth = info.query_depth(request).await.map_err(to_broker_error)?;
+
+    Result::Ok(axum::Json(QueryDepthResponse { depth }))
+}
@@ -0,0 +1,74 @@
+use axum::{routing::get, Router};
+use utoipa::OpenApi;
+
+use super::{
+    account::{
+        __path_balance_handler, __path_positions_handler, balance_handler, positions_handler,
+    },
+    order::{
+        __path_cancel_order_handler, __path_edit_order_handler, __path_inspect_order_handler,
+        __path_list_order_handler, __path_submit_order_handler, cancel_order_handler,
+        edit_order_handler, inspect_order_handler, list_order_handler, submit_order_handler,
+    },
+    quote::{__path_depth_handler, __path_quote_handler, depth_handler, quote_handler},
+};
+use crate::{
+    handler::state::AppState,
+    model::broker::{
+        account::{QueryBalanceResponse, QueryPositionsResponse},
+        order::{
+            CancelBrokerOrderResponse, EditBrokerOrderRequest, EditBrokerOrderResponse,
+            InspectOrderResponse, ListOrderResponse, SubmitBrokerOrderRequest,
+            SubmitBrokerOrderResponse,
+        },
+        quote::{QueryDepthResponse, QueryQuoteResponse},
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        quote_handler,
+        depth_handler,
+        balance_handler,
+        positions_handler,
+        list_order_handler,
+        inspect_order_handler,
+        submit_order_handler,
+        edit_order_handler,
+        cancel_order_handler
+    ),
+    components(schemas(
+        QueryBalanceResponse,
+        QueryPositionsResponse,
+        CancelBrokerOrderResponse,
+        EditBrokerOrderRequest,
+        EditBrokerOrderResponse,
+        InspectOrderResponse,
+        ListOrderResponse,
+        SubmitBrokerOrderRequest,
+        SubmitBrokerOrderResponse,
+        QueryDepthResponse,
+        QueryQuoteResponse
+    ))
+)]
+pub struct BrokerApiDoc;
+
+// The brokers of a running pod, queried and traded through the interceptors of the pod
+pub fn initialize_broker_router(router: Router<AppState>) -> Router<AppState> {
+    router
+        .route("/pod/:pod_id/broker/quote", get(quote_handler))
+        .route("/pod/:pod_id/broker/depth", get(depth_handler))
+        .route("/pod/:pod_id/broker/balance", get(balance_handler))
+        .route("/pod/:pod_id/broker/positions", get(positions_handler))
+        .route(
+            "/pod/:pod_id/broker/order",
+            get(list_order_handler).post(submit_order_handler),
+        )
+        .route(
+            "/pod/:pod_id/broker/order/:order_id",
+            get(inspect_order_handler)
+                .put(edit_order_handler)
+                .delete(cancel_order_handler),
+        )
+}
@@ -0,0 +1,66 @@
+use axum::{
+    extract::rejection::{JsonRejection, PathRejection, QueryRejection},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::model::error::ErrorResponse;
+
+// Failure of a handler, sent back as an `ErrorResponse` with the status code
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl ApiError {
+    pub fn new(status: StatusCode, code: &'static str, message: String) -> Self {
+        ApiError {
+            status,
+            code,
+            message,
+        }
+    }
+
+    pub fn pod_not_found(pod_id: &str) -> Self {
+        Self::new(
+            StatusCode::NOT_FOUND,
+            "POD_NOT_FOUND",
+            format!("No pod with id {}", pod_id),
+        )
+    }
+
+    pub fn to_error_response(&self) -> ErrorResponse {
+        ErrorResponse {
+            code: self.code.to_owned(),
+            message: self.message.clone(),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(self.to_error_response())).into_response()
+    }
+}
+
+// Malformed requests are rejected by the extractors with the same body as the handlers
+impl From<JsonRejection> for ApiError {
+    fn from(rejection: JsonRejection) -> Self {
+        Self::new(rejection.status(), "PARSING_ERROR", rejection.body_text())
+    }
+}
+
+impl From<PathRejection> for ApiError {
+    fn from(rejection: PathRejection) -> Self {
+        Self::new(rejection.status(), "PARSING_ERROR", rejection.body_text())
+    }
+}
+
+impl From<QueryRejection> for ApiError {
+    fn from(rejection: QueryRejection) -> Self {
+        Self::new(rejection.status(), "PARSING_ERROR", rejection.body_text())
+    }
+}
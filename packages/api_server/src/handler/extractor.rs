@@ -0,0 +1,17 @@
+use axum_macros::{FromRequest, FromRequestParts};
+
+use super::error::ApiError;
+
+// `Json`, `Path` and `Query` of axum, rejecting with an `ApiError`
+
+#[derive(FromRequest)]
+#[from_request(via(axum::Json), rejection(ApiError))]
+pub struct ApiJson<T>(pub T);
+
+#[derive(FromRequestParts)]
+#[from_request(via(axum::extract::Path), rejection(ApiError))]
+pub struct ApiPath<T>(pub T);
+
+#[derive(FromRequestParts)]
+#[from_request(via(axum::extract::Query), rejection(ApiError))]
+pub struct ApiQuery<T>(pub T);
@@ -1,2 +1,10 @@
+pub mod audit;
+pub mod broker;
+pub mod error;
+pub mod extractor;
+pub mod openapi;
 pub mod pod;
+pub mod schema;
 pub mod state;
+#[cfg(test)]
+pub mod test_util;
@@ -0,0 +1,149 @@
+use axum::{routing::get, Json, Router};
+use utoipa::{
+    openapi::{
+        self,
+        header::HeaderBuilder,
+        security::{Http, HttpAuthScheme, SecurityScheme},
+        ContentBuilder, ObjectBuilder, Ref, ResponseBuilder, SchemaType,
+    },
+    Modify, OpenApi,
+};
+use utoipa_redoc::{Redoc, Servable};
+
+use super::{
+    audit::router::AuditApiDoc, broker::router::BrokerApiDoc, pod::router::PodApiDoc,
+    schema::router::SchemaApiDoc, state::AppState,
+};
+use crate::model::error::ErrorResponse;
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Rabbit Trading API Server",
+        description = "Manages trading pods and the brokers they trade through"
+    ),
+    components(schemas(ErrorResponse)),
+    modifiers(&SecurityAddon),
+    security(("basic_auth" = []), ("bearer_auth" = [])),
+    tags(
+        (name = "pod", description = "Start, stop and inspect pods"),
+        (name = "broker", description = "Query and trade through the brokers of a running pod"),
+        (name = "schema", description = "Config keys of the components compiled in"),
+        (name = "audit", description = "Who did what to which pod and when"),
+    )
+)]
+struct ApiDoc;
+
+// Either of them is expected, depending on API_SERVER_AUTH
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut openapi::OpenApi) {
+        if let Option::Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "basic_auth",
+                SecurityScheme::Http(Http::new(HttpAuthScheme::Basic)),
+            );
+            components.add_security_scheme(
+                "bearer_auth",
+                SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)),
+            );
+        }
+    }
+}
+
+// The paths of every router, described next to their routes
+pub fn get_openapi() -> openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    openapi.merge(PodApiDoc::openapi());
+    openapi.merge(BrokerApiDoc::openapi());
+    openapi.merge(SchemaApiDoc::openapi());
+    openapi.merge(AuditApiDoc::openapi());
+    add_rate_limited_response(&mut openapi);
+    openapi
+}
+
+// Every route is rate limited by `rate_limit_middleware`, the routes do not declare it one by one
+fn add_rate_limited_response(openapi: &mut openapi::OpenApi) {
+    let response = ResponseBuilder::new()
+        .description("Too many requests, retry after the seconds in `Retry-After`")
+        .content(
+            "application/json",
+            ContentBuilder::new()
+                .schema(Ref::from_schema_name("ErrorResponse"))
+                .build(),
+        )
+        .header(
+            "Retry-After",
+            HeaderBuilder::new()
+                .schema(ObjectBuilder::new().schema_type(SchemaType::Integer))
+                .description(Option::Some("Seconds to wait before retrying"))
+                .build(),
+        )
+        .build();
+    for operation in openapi
+        .paths
+        .paths
+        .values_mut()
+        .flat_map(|path_item| path_item.operations.values_mut())
+    {
+        operation
+            .responses
+            .responses
+            .entry("429".to_owned())
+            .or_insert_with(|| response.clone().into());
+    }
+}
+
+// `/openapi.json` to generate clients from, rendered by Redoc at `/redoc`, both readable without
+// authentication
+pub fn initialize_openapi_router(router: Router<AppState>) -> Router<AppState> {
+    let openapi = get_openapi();
+    router
+        .merge(Redoc::with_url("/redoc", openapi.clone()))
+        .route("/openapi.json", get(move || async move { Json(openapi) }))
+}
+
+#[cfg(test)]
+mod test_openapi {
+    use std::collections::HashSet;
+
+    use super::get_openapi;
+
+    #[test]
+    fn test_get_openapi() {
+        let openapi = get_openapi();
+        for path in [
+            "/pod",
+            "/pod/{pod_id}",
+            "/pod/{pod_id}/events",
+            "/pod/{pod_id}/broker/order/{order_id}",
+            "/schema/{kind}/{identifier}",
+            "/audit",
+        ] {
+            assert!(openapi.paths.paths.contains_key(path), "{}", path);
+        }
+
+        // generated clients name their methods after the operation ids
+        let operation_id_list: Vec<String> = openapi
+            .paths
+            .paths
+            .values()
+            .flat_map(|path_item| path_item.operations.values())
+            .map(|operation| operation.operation_id.clone().unwrap())
+            .collect();
+        let operation_id_set: HashSet<&String> = operation_id_list.iter().collect();
+        assert_eq!(operation_id_list.len(), operation_id_set.len());
+        assert!(openapi
+            .paths
+            .paths
+            .values()
+            .flat_map(|path_item| path_item.operations.values())
+            .all(|operation| operation.responses.responses.contains_key("429")));
+        assert!(openapi
+            .components
+            .unwrap()
+            .schemas
+            .contains_key("ErrorResponse"));
+    }
+}
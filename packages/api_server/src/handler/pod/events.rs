@@ -0,0 +1,45 @@
+use axum::{extract::State, Json};
+
+use crate::{
+    auth::{principal::Principal, role::Role},
+    handler::{
+        error::ApiError,
+        extractor::{ApiPath, ApiQuery},
+        state::AppState,
+    },
+    model::pod::events::{ListPodEventQuery, ListPodEventResponse},
+};
+
+#[utoipa::path(
+    get,
+    path = "/pod/{pod_id}/events",
+    operation_id = "list_pod_event",
+    tag = "pod",
+    params(("pod_id" = String, Path, description = "Id of the pod"), ListPodEventQuery),
+    responses(
+        (status = 200, body = ListPodEventResponse),
+        (status = 404, body = ErrorResponse),
+    )
+)]
+pub(super) async fn events_handler(
+    State(state): State<AppState>,
+    principal: Principal,
+    ApiPath(pod_id): ApiPath<String>,
+    ApiQuery(query): ApiQuery<ListPodEventQuery>,
+) -> Result<Json<ListPodEventResponse>, ApiError> {
+    principal.require(Role::Viewer)?;
+    let pod = {
+        let readable_pod_store = state.pod_store.read().await;
+        match readable_pod_store.get(&pod_id) {
+            Some(pod_instance) => pod_instance.instance.clone(),
+            None => return Result::Err(ApiError::pod_not_found(&pod_id)),
+        }
+    };
+    let event_log = pod.inspect_log().await;
+    let event_list = event_log.iter().skip(query.offset).cloned().collect();
+
+    Result::Ok(axum::Json(ListPodEventResponse {
+        event_list,
+        next_offset: event_log.len().max(query.offset),
+    }))
+}
@@ -1,19 +1,33 @@
-use axum::{extract::State, http::StatusCode, Json};
+use axum::{extract::State, Json};
 
 use crate::{
-    handler::state::AppState,
-    model::pod::inspect::{InspectPodRequest, InspectPodResponse},
+    auth::{principal::Principal, role::Role},
+    handler::{error::ApiError, extractor::ApiPath, state::AppState},
+    model::pod::inspect::InspectPodResponse,
 };
 
+#[utoipa::path(
+    get,
+    path = "/pod/{pod_id}",
+    operation_id = "inspect_pod",
+    tag = "pod",
+    params(("pod_id" = String, Path, description = "Id of the pod")),
+    responses(
+        (status = 200, body = InspectPodResponse),
+        (status = 404, body = ErrorResponse),
+    )
+)]
 pub(super) async fn inspect_handler(
     State(state): State<AppState>,
-    Json(request): Json<InspectPodRequest>,
-) -> Result<Json<InspectPodResponse>, StatusCode> {
+    principal: Principal,
+    ApiPath(pod_id): ApiPath<String>,
+) -> Result<Json<InspectPodResponse>, ApiError> {
+    principal.require(Role::Viewer)?;
     let readable_pod_store = state.pod_store.read().await;
-    match readable_pod_store.get(&request.pod_id) {
+    match readable_pod_store.get(&pod_id) {
         Some(pod_instance) => Result::Ok(axum::Json(InspectPodResponse {
-            metadata: pod_instance.metadata.clone(),
+            metadata: pod_instance.get_metadata(),
         })),
-        None => Result::Err(StatusCode::NOT_FOUND),
+        None => Result::Err(ApiError::pod_not_found(&pod_id)),
     }
 }
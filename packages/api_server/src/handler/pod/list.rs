@@ -1,19 +1,31 @@
 use axum::{extract::State, Json};
 
 use crate::{
-    handler::state::AppState,
-    model::pod::list::{ListPodRequest, ListPodResponse},
+    auth::{principal::Principal, role::Role},
+    handler::{error::ApiError, state::AppState},
+    model::pod::list::ListPodResponse,
 };
 
+#[utoipa::path(
+    get,
+    path = "/pod",
+    operation_id = "list_pod",
+    tag = "pod",
+    responses(
+        (status = 200, body = ListPodResponse),
+        (status = 401, body = ErrorResponse),
+    )
+)]
 pub(super) async fn list_handler(
     State(state): State<AppState>,
-    Json(_): Json<ListPodRequest>,
-) -> Json<ListPodResponse> {
+    principal: Principal,
+) -> Result<Json<ListPodResponse>, ApiError> {
+    principal.require(Role::Viewer)?;
     let readable_pod_store = state.pod_store.read().await;
     let pod_list = readable_pod_store
         .iter()
-        .map(|(_, instance)| instance.metadata.clone())
+        .map(|(_, instance)| instance.get_metadata())
         .collect();
 
-    axum::Json(ListPodResponse { pod_list })
+    Result::Ok(axum::Json(ListPodResponse { pod_list }))
 }
@@ -1,3 +1,4 @@
+pub mod events;
 pub mod inspect;
 pub mod list;
 pub mod router;
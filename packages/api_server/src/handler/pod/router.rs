@@ -1,13 +1,53 @@
-use axum::{routing::post, Router};
+use axum::{routing::get, Router};
+use utoipa::OpenApi;
 
 use super::{
-    inspect::inspect_handler, list::list_handler, start::start_handler, stop::stop_handler,
+    events::{__path_events_handler, events_handler},
+    inspect::{__path_inspect_handler, inspect_handler},
+    list::{__path_list_handler, list_handler},
+    start::{__path_start_handler, start_handler},
+    stop::{__path_stop_handler, stop_handler},
 };
-use crate::handler::state::AppState;
+use crate::{
+    handler::state::AppState,
+    model::pod::{
+        events::ListPodEventResponse,
+        inspect::InspectPodResponse,
+        list::ListPodResponse,
+        metadata::{PodDesiredState, PodMetadata},
+        restart::{PodRestartRecord, PodRestartStatus},
+        start::{StartPodRequest, StartPodResponse},
+        stop::StopPodResponse,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        list_handler,
+        start_handler,
+        inspect_handler,
+        stop_handler,
+        events_handler
+    ),
+    components(schemas(
+        ListPodEventResponse,
+        InspectPodResponse,
+        ListPodResponse,
+        PodDesiredState,
+        PodMetadata,
+        PodRestartRecord,
+        PodRestartStatus,
+        StartPodRequest,
+        StartPodResponse,
+        StopPodResponse
+    ))
+)]
+pub struct PodApiDoc;
 
 pub fn initialize_pod_router(router: Router<AppState>) -> Router<AppState> {
     router
-        .route("/pod/list", post(list_handler))
-        .route("/pod/inspect", post(inspect_handler))
-        .route("/pod", post(start_handler).delete(stop_handler))
+        .route("/pod", get(list_handler).post(start_handler))
+        .route("/pod/:pod_id", get(inspect_handler).delete(stop_handler))
+        .route("/pod/:pod_id/events", get(events_handler))
 }
@@ -1,32 +1,179 @@
-use axum::{extract::State, Json};
-use rabbit_trading_core::pod::Pod;
-use std::sync::Arc;
+use axum::{extract::State, http::StatusCode, Json};
+use rabbit_trading_core::{model::config::pod::PodConfig, pod::validator::validate_pod_config};
+use serde_json::json;
 
 use crate::{
-    handler::state::{AppState, PodStoreInstance},
-    model::pod::start::{StartPodRequest, StartPodResponse},
-    utils::metadata::generate_pod_metadata,
+    auth::{principal::Principal, role::Role},
+    handler::{
+        error::ApiError,
+        extractor::ApiJson,
+        state::{AppState, PodStoreInstance},
+    },
+    model::{
+        audit::record::AuditAction,
+        pod::start::{StartPodRequest, StartPodResponse},
+    },
 };
 
+#[utoipa::path(
+    post,
+    path = "/pod",
+    operation_id = "start_pod",
+    tag = "pod",
+    request_body = StartPodRequest,
+    responses(
+        (status = 201, body = StartPodResponse),
+        (status = 400, body = ErrorResponse),
+        (status = 403, body = ErrorResponse),
+        (status = 429, body = ErrorResponse, description = "Too many pods running or too many requests"),
+    )
+)]
 pub(super) async fn start_handler(
     State(state): State<AppState>,
-    Json(request): Json<StartPodRequest>,
-) -> Json<StartPodResponse> {
-    let pod_config = request.config;
-    let pod_metadata = generate_pod_metadata(state.id_generator.clone(), pod_config.clone());
-    let pod_id = pod_metadata.id.clone();
-
-    let pod = Arc::new(Pod::new(pod_config, pod_id.clone()));
-    let pod_store_instance = PodStoreInstance {
-        metadata: pod_metadata.clone(),
-        instance: pod.clone(),
-    };
+    principal: Principal,
+    ApiJson(request): ApiJson<StartPodRequest>,
+) -> Result<(StatusCode, Json<StartPodResponse>), ApiError> {
+    // the config map might hold secrets, it is kept out of the audit log
+    let detail = json!({
+        "name": request.config.name,
+        "strategy": request.config.strategy.identifier,
+    });
+    let result = async {
+        principal.require(Role::Operator)?;
+        start_pod(&state, request.config).await
+    }
+    .await;
+    let pod_id = result.as_ref().ok().map(|response| response.pod_id.clone());
+    state
+        .audit(&principal, AuditAction::StartPod, pod_id, detail, &result)
+        .await;
+
+    result.map(|response| (StatusCode::CREATED, axum::Json(response)))
+}
+
+async fn start_pod(state: &AppState, pod_config: PodConfig) -> Result<StartPodResponse, ApiError> {
+    // rejected before an id is taken, with every problem of the config listed
+    validate_pod_config(&pod_config).map_err(|err| {
+        log::error!("Error when validating pod config, {}", err);
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_POD_CONFIG",
+            err.error_list.join("; "),
+        )
+    })?;
     let mut writable_pod_store = state.pod_store.write().await;
+    // counted with the store locked so that concurrent requests cannot go over the limit, and
+    // before an id is taken
+    let active_pod_count = writable_pod_store
+        .values()
+        .filter(|pod_store_instance| pod_store_instance.is_active())
+        .count();
+    if active_pod_count >= state.max_pod_count {
+        return Result::Err(ApiError::new(
+            StatusCode::TOO_MANY_REQUESTS,
+            "TOO_MANY_PODS",
+            format!(
+                "{} pods are running, the limit is {}, stop one of them before starting another",
+                active_pod_count, state.max_pod_count
+            ),
+        ));
+    }
+    let pod_id = state.id_generator.generate();
+
+    let mut pod_store_instance =
+        PodStoreInstance::create(pod_id.clone(), pod_config).map_err(|err| {
+            log::error!("Error when creating pod {}, {}", pod_id, err);
+            ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "INVALID_POD_CONFIG",
+                err.to_string(),
+            )
+        })?;
+    let pod_metadata = pod_store_instance.metadata.clone();
+    pod_store_instance.spawn_supervisor();
     writable_pod_store.insert(pod_id.clone(), pod_store_instance);
+    state.persist_pod_store(&writable_pod_store).await;
 
-    tokio::task::spawn(async move { pod.clone().start().await });
-    axum::Json(StartPodResponse {
+    Result::Ok(StartPodResponse {
         pod_id,
         metadata: pod_metadata,
     })
 }
+
+#[cfg(test)]
+mod test_start {
+    use axum::{extract::State, http::StatusCode};
+    use rabbit_trading_core::pod::state::PodState;
+    use tempfile::TempDir;
+
+    use super::start_handler;
+    use crate::{
+        auth::{principal::Principal, role::Role},
+        handler::{
+            extractor::{ApiJson, ApiPath},
+            pod::stop::stop_handler,
+            state::AppState,
+            test_util::{create_app_state, get_pod_config, wait_for, write_quote_real_time_info},
+        },
+        model::pod::start::StartPodRequest,
+    };
+
+    fn get_principal() -> Principal {
+        Principal {
+            username: "operator".to_owned(),
+            role: Role::Operator,
+        }
+    }
+
+    async fn start_pod(
+        app_state: &AppState,
+        temp_dir: &TempDir,
+    ) -> Result<String, (StatusCode, &'static str)> {
+        start_handler(
+            State(app_state.clone()),
+            get_principal(),
+            ApiJson(StartPodRequest {
+                config: get_pod_config(temp_dir, Default::default()),
+            }),
+        )
+        .await
+        .map(|(_, response)| response.0.pod_id)
+        .map_err(|err| (err.status, err.code))
+    }
+
+    async fn stop_pod(app_state: &AppState, pod_id: &str) {
+        let pod = app_state.pod_store.read().await[pod_id].instance.clone();
+        wait_for(|| pod.get_status().state == PodState::Running).await;
+        assert!(stop_handler(
+            State(app_state.clone()),
+            get_principal(),
+            ApiPath(pod_id.to_owned()),
+        )
+        .await
+        .is_ok());
+        assert!(!app_state.pod_store.read().await[pod_id].is_active());
+    }
+
+    #[tokio::test]
+    async fn test_start_too_many_pods() {
+        let temp_dir = TempDir::new().unwrap();
+        write_quote_real_time_info(&temp_dir);
+        let app_state = create_app_state(1).await;
+
+        assert_eq!(
+            Result::Ok("1".to_owned()),
+            start_pod(&app_state, &temp_dir).await
+        );
+        assert_eq!(
+            Result::Err((StatusCode::TOO_MANY_REQUESTS, "TOO_MANY_PODS")),
+            start_pod(&app_state, &temp_dir).await
+        );
+        stop_pod(&app_state, "1").await;
+        // the rejected request did not take an id
+        assert_eq!(
+            Result::Ok("2".to_owned()),
+            start_pod(&app_state, &temp_dir).await
+        );
+        stop_pod(&app_state, "2").await;
+    }
+}
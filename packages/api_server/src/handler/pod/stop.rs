@@ -1,26 +1,80 @@
 use axum::{extract::State, http::StatusCode, Json};
+use serde_json::Value;
 
 use crate::{
-    handler::state::AppState,
-    model::pod::stop::{StopPodRequest, StopPodResponse},
+    auth::{principal::Principal, role::Role},
+    handler::{error::ApiError, extractor::ApiPath, state::AppState},
+    model::{
+        audit::record::AuditAction,
+        pod::{metadata::PodDesiredState, stop::StopPodResponse},
+    },
 };
 
+#[utoipa::path(
+    delete,
+    path = "/pod/{pod_id}",
+    operation_id = "stop_pod",
+    tag = "pod",
+    params(("pod_id" = String, Path, description = "Id of the pod")),
+    responses(
+        (status = 200, body = StopPodResponse),
+        (status = 403, body = ErrorResponse),
+        (status = 404, body = ErrorResponse),
+        (status = 500, body = ErrorResponse),
+    )
+)]
 pub(super) async fn stop_handler(
     State(state): State<AppState>,
-    Json(request): Json<StopPodRequest>,
-) -> Result<Json<StopPodResponse>, StatusCode> {
-    let readable_pod_store = state.pod_store.read().await;
-    match readable_pod_store.get(&request.pod_id) {
-        Some(pod_instance) => pod_instance
-            .instance
-            .stop()
-            .await
-            .map(|_| {
-                axum::Json(StopPodResponse {
-                    metadata: pod_instance.metadata.clone(),
-                })
-            })
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR),
-        None => Result::Err(StatusCode::NOT_FOUND),
+    principal: Principal,
+    ApiPath(pod_id): ApiPath<String>,
+) -> Result<Json<StopPodResponse>, ApiError> {
+    let result = async {
+        principal.require(Role::Operator)?;
+        stop_pod(&state, &pod_id).await
+    }
+    .await;
+    state
+        .audit(
+            &principal,
+            AuditAction::StopPod,
+            Option::Some(pod_id),
+            Value::Null,
+            &result,
+        )
+        .await;
+
+    result.map(axum::Json)
+}
+
+async fn stop_pod(state: &AppState, pod_id: &str) -> Result<StopPodResponse, ApiError> {
+    let pod = {
+        let mut writable_pod_store = state.pod_store.write().await;
+        let pod_instance = writable_pod_store
+            .get_mut(pod_id)
+            .ok_or(ApiError::pod_not_found(pod_id))?;
+        pod_instance.abort_supervisor();
+        pod_instance.metadata.desired_state = PodDesiredState::Stopped;
+        let pod = pod_instance.instance.clone();
+        state.persist_pod_store(&writable_pod_store).await;
+        pod
+    };
+    // the shutdown waits for the strategy, the pod store is not kept locked meanwhile
+    let shutdown_report = pod.stop().await.map_err(|err| {
+        log::error!("Error when stopping pod {}, {}", pod_id, err);
+        ApiError::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "POD_FAILED",
+            format!("{:#}", err),
+        )
+    })?;
+
+    let writable_pod_store = state.pod_store.write().await;
+    state.persist_pod_store(&writable_pod_store).await;
+    match writable_pod_store.get(pod_id) {
+        Some(pod_instance) => Result::Ok(StopPodResponse {
+            metadata: pod_instance.get_metadata(),
+            shutdown_report,
+        }),
+        None => Result::Err(ApiError::pod_not_found(pod_id)),
     }
 }
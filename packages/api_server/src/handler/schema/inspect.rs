@@ -0,0 +1,34 @@
+use axum::{http::StatusCode, Json};
+use rabbit_trading_core::pod::component::ComponentSchema;
+
+use crate::{
+    auth::{principal::Principal, role::Role},
+    handler::{error::ApiError, extractor::ApiPath},
+    model::schema::inspect::{InspectSchemaPath, InspectSchemaResponse},
+};
+
+#[utoipa::path(
+    get,
+    path = "/schema/{kind}/{identifier}",
+    operation_id = "inspect_schema",
+    tag = "schema",
+    params(InspectSchemaPath),
+    responses(
+        (status = 200, body = InspectSchemaResponse),
+        (status = 404, body = ErrorResponse),
+    )
+)]
+pub(super) async fn inspect_handler(
+    principal: Principal,
+    ApiPath(path): ApiPath<InspectSchemaPath>,
+) -> Result<Json<InspectSchemaResponse>, ApiError> {
+    principal.require(Role::Viewer)?;
+    match ComponentSchema::new(path.kind, &path.identifier) {
+        Result::Ok(schema) => Result::Ok(axum::Json(InspectSchemaResponse { schema })),
+        Result::Err(err) => Result::Err(ApiError::new(
+            StatusCode::NOT_FOUND,
+            "IDENTIFIER_NOT_MATCHED",
+            err.to_string(),
+        )),
+    }
+}
@@ -0,0 +1,29 @@
+use axum::Json;
+use rabbit_trading_core::pod::component::get_component_schema_list;
+
+use crate::{
+    auth::{principal::Principal, role::Role},
+    handler::{error::ApiError, extractor::ApiQuery},
+    model::schema::list::{ListSchemaQuery, ListSchemaResponse},
+};
+
+#[utoipa::path(
+    get,
+    path = "/schema",
+    operation_id = "list_schema",
+    tag = "schema",
+    params(ListSchemaQuery),
+    responses(
+        (status = 200, body = ListSchemaResponse),
+        (status = 400, body = ErrorResponse),
+    )
+)]
+pub(super) async fn list_handler(
+    principal: Principal,
+    ApiQuery(query): ApiQuery<ListSchemaQuery>,
+) -> Result<Json<ListSchemaResponse>, ApiError> {
+    principal.require(Role::Viewer)?;
+    Result::Ok(axum::Json(ListSchemaResponse {
+        schema_list: get_component_schema_list(query.kind),
+    }))
+}
@@ -0,0 +1,3 @@
+pub mod inspect;
+pub mod list;
+pub mod router;
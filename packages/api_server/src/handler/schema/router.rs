@@ -0,0 +1,24 @@
+use axum::{routing::get, Router};
+use utoipa::OpenApi;
+
+use super::{
+    inspect::{__path_inspect_handler, inspect_handler},
+    list::{__path_list_handler, list_handler},
+};
+use crate::{
+    handler::state::AppState,
+    model::schema::{inspect::InspectSchemaResponse, list::ListSchemaResponse},
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(list_handler, inspect_handler),
+    components(schemas(InspectSchemaResponse, ListSchemaResponse))
+)]
+pub struct SchemaApiDoc;
+
+pub fn initialize_schema_router(router: Router<AppState>) -> Router<AppState> {
+    router
+        .route("/schema", get(list_handler))
+        .route("/schema/:kind/:identifier", get(inspect_handler))
+}
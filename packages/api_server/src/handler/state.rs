@@ -1,27 +1,227 @@
-use rabbit_trading_core::pod::Pod;
+use anyhow::Error;
+use rabbit_trading_core::{
+    model::config::pod::PodConfig, pod::Pod, utils::clock::common_trait::ClockTrait,
+};
+use serde_json::Value;
 use std::{collections::BTreeMap, sync::Arc};
-use tokio::sync::RwLock;
+use tokio::{sync::RwLock, task::JoinHandle};
 
+use super::error::ApiError;
 use crate::{
-    model::pod::metadata::PodMetadata, utils::id_generator::common_trait::IdGeneratorTrait,
+    auth::principal::Principal,
+    model::{
+        audit::record::{AuditAction, AuditRecord},
+        pod::metadata::{PodDesiredState, PodMetadata},
+    },
+    supervisor::pod_supervisor::PodSupervisor,
+    utils::{
+        audit_log::AuditLog, id_generator::common_trait::IdGeneratorTrait,
+        metadata::generate_pod_metadata, pod_registry::PodRegistry,
+    },
 };
 
 pub struct PodStoreInstance {
     pub metadata: PodMetadata,
     pub instance: Arc<Pod>,
+    pub supervisor: Arc<PodSupervisor>,
+    // aborted when the pod is stopped so that it is neither restarted nor rescheduled
+    pub supervisor_handle: Option<JoinHandle<()>>,
+}
+
+impl PodStoreInstance {
+    // For a pod restored from its metadata
+    pub fn new(metadata: PodMetadata) -> Result<Self, Error> {
+        let pod = Arc::new(Pod::new(metadata.config.clone(), metadata.id.clone())?);
+        Self::new_with_pod(metadata, pod)
+    }
+
+    // For a new pod, created at the time of its own clock
+    pub fn create(pod_id: String, config: PodConfig) -> Result<Self, Error> {
+        let pod = Arc::new(Pod::new(config.clone(), pod_id.clone())?);
+        let metadata = generate_pod_metadata(pod_id, config, pod.get_clock().as_ref());
+        Self::new_with_pod(metadata, pod)
+    }
+
+    fn new_with_pod(metadata: PodMetadata, pod: Arc<Pod>) -> Result<Self, Error> {
+        let supervisor = Arc::new(PodSupervisor::new(pod.clone())?);
+
+        Result::Ok(PodStoreInstance {
+            metadata,
+            instance: pod,
+            supervisor,
+            supervisor_handle: Option::None,
+        })
+    }
+
+    pub fn spawn_supervisor(&mut self) {
+        let supervisor = self.supervisor.clone();
+        self.supervisor_handle =
+            Option::Some(tokio::task::spawn(async move { supervisor.run().await }));
+    }
+
+    pub fn abort_supervisor(&mut self) {
+        if let Some(supervisor_handle) = self.supervisor_handle.take() {
+            supervisor_handle.abort();
+        }
+    }
+
+    // Counted against the max pod count, a pod whose supervisor ended no longer trades
+    pub fn is_active(&self) -> bool {
+        self.supervisor_handle
+            .as_ref()
+            .is_some_and(|supervisor_handle| !supervisor_handle.is_finished())
+    }
+
+    // The stored metadata with the live status of the pod
+    pub fn get_metadata(&self) -> PodMetadata {
+        PodMetadata {
+            status: self.instance.get_status(),
+            restart: self.supervisor.get_restart_status(),
+            ..self.metadata.clone()
+        }
+    }
 }
 
 #[derive(Clone)]
 pub struct AppState {
     pub pod_store: Arc<RwLock<BTreeMap<String, PodStoreInstance>>>,
-    pub id_generator: Arc<Box<dyn IdGeneratorTrait>>, // todo: add an abstraction layer for this
+    pub id_generator: Arc<Box<dyn IdGeneratorTrait>>,
+    pub pod_registry: Arc<PodRegistry>,
+    pub audit_log: Arc<AuditLog>,
+    pub max_pod_count: usize,
+    // the time of the server, pods keep their own clock
+    pub clock: Arc<dyn ClockTrait>,
 }
 
 impl AppState {
-    pub fn new(id_generator: Arc<Box<dyn IdGeneratorTrait>>) -> Self {
+    pub fn new(
+        id_generator: Arc<Box<dyn IdGeneratorTrait>>,
+        pod_registry: Arc<PodRegistry>,
+        audit_log: Arc<AuditLog>,
+        max_pod_count: usize,
+        clock: Arc<dyn ClockTrait>,
+    ) -> Self {
         AppState {
             pod_store: Arc::new(RwLock::new(BTreeMap::new())),
             id_generator,
+            pod_registry,
+            audit_log,
+            max_pod_count,
+            clock,
         }
     }
+
+    // Records the outcome of an action of `principal`, the action is not undone when the audit
+    // log cannot be written
+    pub async fn audit<T>(
+        &self,
+        principal: &Principal,
+        action: AuditAction,
+        pod_id: Option<String>,
+        detail: Value,
+        result: &Result<T, ApiError>,
+    ) {
+        let audit_record = AuditRecord {
+            sequence: 0,
+            timestamp: self.clock.now().as_secs(),
+            username: principal.username.clone(),
+            role: principal.role,
+            action,
+            pod_id,
+            detail,
+            error: result.as_ref().err().map(ApiError::to_error_response),
+        };
+        if let Result::Err(err) = self.audit_log.append(audit_record.clone()).await {
+            log::error!(
+                "Error when writing the audit log, {:#}, record: {:?}",
+                err,
+                audit_record
+            );
+        }
+    }
+
+    // Called with the pod store locked so that writes to the registry are serialized
+    pub async fn persist_pod_store(&self, pod_store: &BTreeMap<String, PodStoreInstance>) {
+        let metadata_list: Vec<PodMetadata> = pod_store
+            .values()
+            .map(|pod_instance| pod_instance.get_metadata())
+            .collect();
+        if let Result::Err(err) = self.pod_registry.save(&metadata_list).await {
+            log::error!("Error when persisting the pod registry, {:#}", err);
+        }
+    }
+
+    // Puts back the pods of a previous run, the ones meant to be running are started again when
+    // `auto_restart` is set, up to the max pod count
+    pub async fn restore_pod_store(&self, metadata_list: Vec<PodMetadata>, auto_restart: bool) {
+        let mut writable_pod_store = self.pod_store.write().await;
+        let mut active_pod_count = 0;
+        for metadata in metadata_list {
+            let pod_id = metadata.id.clone();
+            let desired_state = metadata.desired_state;
+            let mut pod_store_instance = match PodStoreInstance::new(metadata) {
+                Result::Ok(pod_store_instance) => pod_store_instance,
+                Result::Err(err) => {
+                    log::error!("Error when restoring pod {}, {:#}", pod_id, err);
+                    continue;
+                }
+            };
+            if auto_restart && desired_state == PodDesiredState::Running {
+                // still meant to be running, it is restarted by a later restart of the server
+                if active_pod_count >= self.max_pod_count {
+                    log::error!(
+                        "Not restarting restored pod {}, the limit of {} pods is reached",
+                        pod_id,
+                        self.max_pod_count
+                    );
+                } else {
+                    log::info!("Restarting restored pod {}", pod_id);
+                    pod_store_instance.spawn_supervisor();
+                    active_pod_count += 1;
+                }
+            }
+            writable_pod_store.insert(pod_id, pod_store_instance);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_state {
+    use rabbit_trading_core::pod::state::PodState;
+    use tempfile::TempDir;
+
+    use super::PodStoreInstance;
+    use crate::handler::test_util::{
+        create_app_state, get_pod_config, wait_for, write_quote_real_time_info,
+    };
+
+    #[tokio::test]
+    async fn test_restore_pod_store_max_pod_count() {
+        let temp_dir = TempDir::new().unwrap();
+        write_quote_real_time_info(&temp_dir);
+        let app_state = create_app_state(1).await;
+        let metadata_list = ["1", "2"]
+            .into_iter()
+            .map(|pod_id| {
+                PodStoreInstance::create(
+                    pod_id.to_owned(),
+                    get_pod_config(&temp_dir, Default::default()),
+                )
+                .unwrap()
+                .metadata
+            })
+            .collect();
+
+        app_state.restore_pod_store(metadata_list, true).await;
+        let mut writable_pod_store = app_state.pod_store.write().await;
+        assert_eq!(2, writable_pod_store.len());
+        assert!(writable_pod_store["1"].is_active());
+        assert!(!writable_pod_store["2"].is_active());
+
+        let pod_store_instance = writable_pod_store.get_mut("1").unwrap();
+        let pod = pod_store_instance.instance.clone();
+        wait_for(|| pod.get_status().state == PodState::Running).await;
+        pod_store_instance.abort_supervisor();
+        pod.stop().await.unwrap();
+    }
 }
@@ -0,0 +1,136 @@
+use rabbit_trading_core::{
+    model::{
+        common::{timestamp::Timestamp, types::ConfigMap},
+        config::{
+            broker::BrokerConfig, clock::ClockConfig, metrics_registry::MetricsRegistryConfig,
+            persistent_kv_store::PersistentKVStoreConfig, pod::PodConfig,
+            restart_policy::RestartPolicyConfig, strategy::StrategyConfig,
+        },
+        trading::{market::Market, quote::QuoteRealTimeInfo, symbol::Symbol},
+    },
+    persistent_kv::{common::store::PersistentKVStoreTrait, memory::store::MemoryKVStore},
+    pod::recorder::writer::{RecordCompression, RecordFileWriter, RecordFormat},
+    utils::clock::{common_trait::ClockTrait, system::SystemClock},
+};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tempfile::TempDir;
+
+use super::state::AppState;
+use crate::utils::{
+    audit_log::AuditLog, id_generator::auto_increment::AutoIncrementIdGenerator,
+    pod_registry::PodRegistry,
+};
+
+pub const START_TIMESTAMP: u64 = 1718000000;
+
+pub fn get_symbol() -> Symbol {
+    Symbol {
+        market: Market::US,
+        identifier: "ABNB".to_owned(),
+    }
+}
+
+// A quote at the start of the clock and one the clock never reaches, which keeps the strategy of
+// the pod waiting and the pod running
+pub fn write_quote_real_time_info(temp_dir: &TempDir) {
+    write_quote_real_time_info_at(temp_dir, [0, 3600].into_iter());
+}
+
+// One quote at each of the seconds after the start of the clock
+pub fn write_quote_real_time_info_at(temp_dir: &TempDir, second_list: impl Iterator<Item = u64>) {
+    let mut writer = RecordFileWriter::new(
+        temp_dir.path().to_path_buf(),
+        RecordFormat::JsonLines,
+        RecordCompression::Gzip,
+    );
+    for (sequence, second) in second_list.enumerate() {
+        writer
+            .write(&QuoteRealTimeInfo {
+                symbol: get_symbol(),
+                sequence: sequence as u64,
+                timestamp: Timestamp::from_secs(START_TIMESTAMP + second),
+                current_price: 100u64.into(),
+                volume: Option::None,
+                low_price: Option::None,
+                high_price: Option::None,
+                open_price: Option::None,
+                prev_close: Option::None,
+                turnover: Option::None,
+                extra: Option::None,
+            })
+            .unwrap();
+    }
+    writer.close().unwrap();
+}
+
+// A pod replaying the quotes of `temp_dir` on a manual clock, with a market data broker next to
+// the replay one
+pub fn get_pod_config(temp_dir: &TempDir, restart_policy: RestartPolicyConfig) -> PodConfig {
+    PodConfig {
+        name: "test_pod".to_owned(),
+        broker_list: vec![
+            BrokerConfig {
+                identifier: "replay".to_owned(),
+                config_map: HashMap::from([(
+                    "replay.base_path".to_owned(),
+                    temp_dir.path().to_str().unwrap().to_owned(),
+                )]),
+            },
+            BrokerConfig {
+                identifier: "yahoo_finance".to_owned(),
+                config_map: HashMap::new(),
+            },
+        ],
+        persistent_kv_store: PersistentKVStoreConfig {
+            identifier: "MemoryKVStore".to_owned(),
+            config_map: HashMap::new(),
+        },
+        strategy: StrategyConfig {
+            identifier: "ExamplePrintLivePriceStrategy".to_owned(),
+            config_map: HashMap::new(),
+        },
+        metrics_registry: MetricsRegistryConfig {
+            identifier: "NoOpMetricRegistryFactory".to_owned(),
+            config_map: HashMap::new(),
+        },
+        event_listener_list: vec![],
+        clock: Option::Some(ClockConfig {
+            identifier: "ManualClock".to_owned(),
+            config_map: HashMap::from([(
+                "clock.manual.start".to_owned(),
+                START_TIMESTAMP.to_string(),
+            )]),
+        }),
+        recorder_list: vec![],
+        schedule: Option::None,
+        restart_policy,
+        shutdown_policy: Default::default(),
+    }
+}
+
+// Without any pod, the registry and the audit log are kept in memory
+pub async fn create_app_state(max_pod_count: usize) -> AppState {
+    AppState::new(
+        Arc::new(Box::new(AutoIncrementIdGenerator::new(1))),
+        Arc::new(PodRegistry::new(Box::new(
+            MemoryKVStore::new(ConfigMap::new()).await,
+        ))),
+        Arc::new(
+            AuditLog::new(Box::new(MemoryKVStore::new(ConfigMap::new()).await))
+                .await
+                .unwrap(),
+        ),
+        max_pod_count,
+        Arc::new(SystemClock::new(ConfigMap::new())),
+    )
+}
+
+pub async fn wait_for(condition: impl Fn() -> bool) {
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while !condition() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+}
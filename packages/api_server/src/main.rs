@@ -1,24 +1,63 @@
-use auth::auth_config::AuthConfig;
-use axum::Router;
+use anyhow::anyhow;
+use auth::{
+    auth_config::AuthConfig, authenticator::Authenticator, middleware::auth_middleware,
+    user::UserListConfig,
+};
+use axum::{middleware, Router};
+use axum_server::tls_rustls::RustlsConfig;
 use dotenv::dotenv;
-use rabbit_trading_core::utils::error::env_var_error_to_anyhow_error;
+use rabbit_trading_core::{
+    model::common::types::ConfigMap,
+    persistent_kv::{
+        common::store::PersistentKVStoreTrait, fs::store::FileSystemKVStore,
+        initializer::get_persistent_kv_instance, memory::store::MemoryKVStore,
+    },
+    utils::{
+        clock::{common_trait::ClockTrait, system::SystemClock},
+        error::env_var_error_to_anyhow_error,
+    },
+};
+use rate_limit::{
+    middleware::{ip_rate_limit_middleware, rate_limit_middleware},
+    rate_limit_config::RateLimitConfig,
+    rate_limiter::RateLimiter,
+};
 use simple_logger::SimpleLogger;
-use std::{env, str::FromStr, sync::Arc};
+use std::{env, net::SocketAddr, path::Path, str::FromStr, sync::Arc};
+use tls::{reloader::reload_tls_config, tls_config::TlsConfig};
 
 use crate::{
-    handler::{pod::router::initialize_pod_router, state::AppState},
-    utils::id_generator::auto_increment::AutoIncrementIdGenerator,
+    handler::{
+        audit::router::initialize_audit_router, broker::router::initialize_broker_router,
+        openapi::initialize_openapi_router, pod::router::initialize_pod_router,
+        schema::router::initialize_schema_router, state::AppState,
+    },
+    utils::{
+        audit_log::AuditLog,
+        id_generator::{
+            auto_increment::AutoIncrementIdGenerator, common_trait::IdGeneratorTrait,
+            id_generator_config::IdGeneratorConfig, snowflake::SnowflakeIdGenerator,
+            uuid_v7::UuidV7IdGenerator,
+        },
+        pod_registry::PodRegistry,
+    },
 };
 
 mod auth;
 mod handler;
 mod model;
+mod rate_limit;
+mod supervisor;
+mod tls;
 mod utils;
 
 const LOG_LEVEL: log::LevelFilter = ::log::LevelFilter::Info;
 const DEFAULT_HOST: &'static str = "127.0.0.1";
 const DEFAULT_PORT: &'static str = "7000";
 const DEFAULT_AUTH: AuthConfig = AuthConfig::NoAuth;
+const DEFAULT_ID_GENERATOR: IdGeneratorConfig = IdGeneratorConfig::AutoIncrement;
+const DEFAULT_NODE_ID: u64 = 0;
+const DEFAULT_MAX_POD_COUNT: usize = 100;
 
 #[tokio::main]
 async fn main() {
@@ -31,20 +70,188 @@ async fn main() {
 
     let server_host = env::var("API_SERVER_HOST").unwrap_or(DEFAULT_HOST.to_owned());
     let server_port = env::var("API_SERVER_PORT").unwrap_or(DEFAULT_PORT.to_owned());
-    let auth_kind = env::var("API_SERVER_AUTH")
-        .map_err(env_var_error_to_anyhow_error)
-        .and_then(|auth_kind| AuthConfig::from_str(&auth_kind))
-        .unwrap_or(DEFAULT_AUTH);
+    // an auth kind that is given but unknown must not fall back to NoAuth
+    let auth_kind = match env::var("API_SERVER_AUTH") {
+        Result::Ok(auth_kind) => AuthConfig::from_str(&auth_kind).unwrap(),
+        Result::Err(_) => DEFAULT_AUTH,
+    };
     let bind_address = format!("{}:{}", server_host, server_port);
 
-    if auth_kind == AuthConfig::NoAuth {
-        log::warn!("NoAuth, might be risky if port was exposed to public network.");
+    let user_list_config = match auth_kind {
+        AuthConfig::NoAuth => {
+            log::warn!("NoAuth, might be risky if port was exposed to public network.");
+            UserListConfig::default()
+        }
+        AuthConfig::BasicAuth | AuthConfig::BearerAuth => {
+            let user_list_path = env::var("API_SERVER_USER_LIST_PATH")
+                .map_err(env_var_error_to_anyhow_error)
+                .unwrap();
+            UserListConfig::load(Path::new(&user_list_path)).unwrap()
+        }
+        AuthConfig::KerberosAuth => panic!("UNKNOWN_AUTH kind: KerberosAuth is not supported yet"),
+    };
+    log::warn!(
+        "auth = {}, {} users",
+        auth_kind.to_string(),
+        user_list_config.user_list.len()
+    );
+    let tls_config = TlsConfig::from_env_var(|name| env::var(name).ok()).unwrap();
+    match &tls_config {
+        Option::Some(tls_config) => {
+            tls_config.check_auth(&auth_kind).unwrap();
+            log::warn!(
+                "tls = {}, mutual tls = {}",
+                tls_config.cert_path.display(),
+                tls_config.client_ca_path.is_some()
+            )
+        }
+        Option::None => {
+            log::warn!("No TLS, requests and credentials are sent in plain text over the network.")
+        }
     }
     log::warn!("bind_address = {}", bind_address);
+    let authenticator = Arc::new(Authenticator::new(auth_kind, user_list_config));
+
+    // the built-in limits apply unless a config file is given
+    let rate_limit_config = match env::var("API_SERVER_RATE_LIMIT_PATH") {
+        Result::Ok(rate_limit_path) => RateLimitConfig::load(Path::new(&rate_limit_path)).unwrap(),
+        Result::Err(_) => RateLimitConfig::default(),
+    };
+    log::warn!("rate_limit = {:?}", rate_limit_config);
+    let rate_limiter = Arc::new(RateLimiter::new(rate_limit_config));
+    // pods hold broker connections, starting more than this is rejected
+    let max_pod_count = match env::var("API_SERVER_MAX_POD_COUNT") {
+        Result::Ok(max_pod_count) => max_pod_count
+            .parse::<usize>()
+            .map_err(|err| {
+                anyhow!(
+                    "PARSING_ERROR API_SERVER_MAX_POD_COUNT: {}, {}",
+                    max_pod_count,
+                    err
+                )
+            })
+            .unwrap(),
+        Result::Err(_) => DEFAULT_MAX_POD_COUNT,
+    };
+    log::warn!("max_pod_count = {}", max_pod_count);
+
+    // pods are only kept in memory unless a registry path is given
+    let registry_path = env::var("API_SERVER_REGISTRY_PATH").ok();
+    if registry_path.is_none() {
+        log::warn!("No API_SERVER_REGISTRY_PATH, pods are lost when the server restarts.");
+    }
+    let pod_registry_store = initialize_persistent_kv_store(registry_path.clone()).await;
+    // kept next to the pod registry unless a path of its own is given
+    let audit_log_path = env::var("API_SERVER_AUDIT_LOG_PATH").ok().or(registry_path);
+    if audit_log_path.is_none() {
+        log::warn!("No API_SERVER_AUDIT_LOG_PATH, the audit log is lost when the server restarts.");
+    }
+    let audit_log = Arc::new(
+        AuditLog::new(initialize_persistent_kv_store(audit_log_path).await)
+            .await
+            .unwrap(),
+    );
+    let auto_restart = env::var("API_SERVER_AUTO_RESTART")
+        .map(|auto_restart| auto_restart.to_lowercase() == "true")
+        .unwrap_or(false);
+    let pod_registry = Arc::new(PodRegistry::new(pod_registry_store));
+    let metadata_list = pod_registry.load().await.unwrap();
+
+    // given but invalid values must not fall back to the defaults, which could repeat the ids
+    // of another API server
+    let id_generator_kind = match env::var("API_SERVER_ID_GENERATOR") {
+        Result::Ok(id_generator_kind) => IdGeneratorConfig::from_str(&id_generator_kind).unwrap(),
+        Result::Err(_) => DEFAULT_ID_GENERATOR,
+    };
+    // unique per API server instance when several of them share a registry
+    let node_id = match env::var("API_SERVER_NODE_ID") {
+        Result::Ok(node_id) => node_id
+            .parse::<u64>()
+            .map_err(|err| anyhow!("PARSING_ERROR API_SERVER_NODE_ID: {}, {}", node_id, err))
+            .unwrap(),
+        Result::Err(_) => DEFAULT_NODE_ID,
+    };
+    let clock: Arc<dyn ClockTrait> = Arc::new(SystemClock::new(ConfigMap::new()));
+    let id_generator: Box<dyn IdGeneratorTrait> = match id_generator_kind {
+        IdGeneratorConfig::AutoIncrement => Box::new(AutoIncrementIdGenerator::new(
+            PodRegistry::get_next_numeric_id(&metadata_list),
+        )),
+        IdGeneratorConfig::UuidV7 => Box::new(UuidV7IdGenerator::new()),
+        IdGeneratorConfig::Snowflake => {
+            Box::new(SnowflakeIdGenerator::new(node_id, clock.clone()).unwrap())
+        }
+    };
+    log::warn!("id_generator = {:?}", id_generator_kind);
 
     let app = Router::new();
-    let app_state = AppState::new(Arc::new(Box::new(AutoIncrementIdGenerator::new(1i64))));
-    let app = initialize_pod_router(app).with_state(app_state);
-    let listener = tokio::net::TcpListener::bind(bind_address).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+    let app_state = AppState::new(
+        Arc::new(id_generator),
+        pod_registry,
+        audit_log,
+        max_pod_count,
+        clock,
+    );
+    app_state
+        .restore_pod_store(metadata_list, auto_restart)
+        .await;
+    let app = initialize_pod_router(app);
+    let app = initialize_broker_router(app);
+    let app = initialize_schema_router(app);
+    let app = initialize_audit_router(app);
+    // inside the auth layer so that it is known which user sent the request
+    let app = app.route_layer(middleware::from_fn_with_state(
+        rate_limiter.clone(),
+        rate_limit_middleware,
+    ));
+    // only the routes above are authenticated
+    let app = app.layer(middleware::from_fn_with_state(
+        authenticator,
+        auth_middleware,
+    ));
+    // outside the auth layer so that guessing credentials is limited too
+    let app = app.layer(middleware::from_fn_with_state(
+        rate_limiter,
+        ip_rate_limit_middleware,
+    ));
+    let app = initialize_openapi_router(app).with_state(app_state);
+    match tls_config {
+        Option::Some(tls_config) => {
+            let rustls_config = RustlsConfig::from_config(tls_config.load_server_config().unwrap());
+            if !tls_config.reload_interval.is_zero() {
+                tokio::spawn(reload_tls_config(tls_config, rustls_config.clone()));
+            }
+            let listener = std::net::TcpListener::bind(bind_address).unwrap();
+            axum_server::from_tcp_rustls(listener, rustls_config)
+                .serve(app.into_make_service_with_connect_info::<SocketAddr>())
+                .await
+                .unwrap();
+        }
+        Option::None => {
+            let listener = tokio::net::TcpListener::bind(bind_address).await.unwrap();
+            axum::serve(
+                listener,
+                app.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .await
+            .unwrap();
+        }
+    }
+}
+
+// Files under `path` when it is set, memory otherwise
+async fn initialize_persistent_kv_store(path: Option<String>) -> Box<dyn PersistentKVStoreTrait> {
+    match path {
+        Option::Some(path) => {
+            std::fs::create_dir_all(&path).unwrap();
+            get_persistent_kv_instance(
+                FileSystemKVStore::get_identifier(),
+                ConfigMap::from([(FileSystemKVStore::CONFIG_KEY_BASE_PATH.to_owned(), path)]),
+            )
+            .await
+        }
+        Option::None => {
+            get_persistent_kv_instance(MemoryKVStore::get_identifier(), ConfigMap::new()).await
+        }
+    }
+    .unwrap()
 }
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::record::{AuditAction, AuditRecord};
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListAuditRecordQuery {
+    // sequence of the first record to look at
+    #[serde(default)]
+    pub offset: u64,
+    #[serde(default = "ListAuditRecordQuery::get_default_limit")]
+    pub limit: usize,
+    pub username: Option<String>,
+    pub pod_id: Option<String>,
+    pub action: Option<AuditAction>,
+}
+
+impl ListAuditRecordQuery {
+    const DEFAULT_LIMIT: usize = 100;
+
+    fn get_default_limit() -> usize {
+        Self::DEFAULT_LIMIT
+    }
+
+    pub fn is_matched(&self, audit_record: &AuditRecord) -> bool {
+        self.username
+            .as_ref()
+            .is_none_or(|username| *username == audit_record.username)
+            && self
+                .pod_id
+                .as_ref()
+                .is_none_or(|pod_id| Option::Some(pod_id) == audit_record.pod_id.as_ref())
+            && self
+                .action
+                .is_none_or(|action| action == audit_record.action)
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ListAuditRecordResponse {
+    pub record_list: Vec<AuditRecord>,
+    // offset of the next request when paging through the audit log
+    pub next_offset: u64,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use crate::{auth::role::Role, model::error::ErrorResponse};
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    StartPod,
+    StopPod,
+    SubmitOrder,
+    EditOrder,
+    CancelOrder,
+}
+
+// Who did what to which pod and when, recorded whether the request succeeded or not
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct AuditRecord {
+    // position in the audit log, starting from 0
+    pub sequence: u64,
+    pub timestamp: u64,
+    pub username: String,
+    pub role: Role,
+    pub action: AuditAction,
+    pub pod_id: Option<String>,
+    // what the action was asked to do, e.g. the manual order
+    #[schema(value_type = Object)]
+    pub detail: Value,
+    // absent when the action succeeded
+    pub error: Option<ErrorResponse>,
+}
@@ -0,0 +1,15 @@
+use rabbit_trading_core::model::trading::{balance::BalanceHashMap, position::PositionList};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct QueryBalanceResponse {
+    #[schema(value_type = Object)]
+    pub balance: BalanceHashMap,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct QueryPositionsResponse {
+    #[schema(value_type = Vec<Object>)]
+    pub position_list: PositionList,
+}
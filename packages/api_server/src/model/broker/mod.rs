@@ -0,0 +1,4 @@
+pub mod account;
+pub mod order;
+pub mod quote;
+pub mod target;
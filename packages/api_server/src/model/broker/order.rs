@@ -0,0 +1,53 @@
+use rabbit_trading_core::model::trading::transaction::{
+    CancelOrderResponse, EditOrderRequest, EditOrderResponse, OrderDetail, SubmitOrderRequest,
+    SubmitOrderResponse,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct ListOrderResponse {
+    #[schema(value_type = Vec<Object>)]
+    pub order_list: Vec<OrderDetail>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct InspectOrderResponse {
+    #[schema(value_type = Object)]
+    pub order: OrderDetail,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct SubmitBrokerOrderRequest {
+    #[serde(default)]
+    pub broker_id: Option<String>,
+    #[schema(value_type = Object)]
+    pub order: SubmitOrderRequest,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SubmitBrokerOrderResponse {
+    #[schema(value_type = Object)]
+    pub response: SubmitOrderResponse,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct EditBrokerOrderRequest {
+    #[serde(default)]
+    pub broker_id: Option<String>,
+    // `order_id` of the order should be the one in the path
+    #[schema(value_type = Object)]
+    pub order: EditOrderRequest,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct EditBrokerOrderResponse {
+    #[schema(value_type = Object)]
+    pub response: EditOrderResponse,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct CancelBrokerOrderResponse {
+    #[schema(value_type = Object)]
+    pub response: CancelOrderResponse,
+}
@@ -0,0 +1,28 @@
+use rabbit_trading_core::model::trading::quote::{QuoteDepthInfo, QuoteKind, QuoteRealTimeInfo};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct QuoteQuery {
+    #[serde(default)]
+    pub broker_id: Option<String>,
+    // e.g. AAPL.US or 0700.HK
+    pub symbol: String,
+    // Stock when absent
+    #[serde(default)]
+    #[param(value_type = Option<String>)]
+    pub kind: Option<QuoteKind>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct QueryQuoteResponse {
+    #[schema(value_type = Object)]
+    pub quote: QuoteRealTimeInfo,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct QueryDepthResponse {
+    #[schema(value_type = Object)]
+    pub depth: QuoteDepthInfo,
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+// The broker of the pod serving the request, the first broker of the pod is used when
+// `broker_id` is absent
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BrokerQuery {
+    #[serde(default)]
+    pub broker_id: Option<String>,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+// Body of every failed request, `code` is one of the upper case codes used across the crates,
+// e.g. POD_NOT_FOUND or ILLEGAL_POD_STATE
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
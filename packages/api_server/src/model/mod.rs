@@ -1 +1,5 @@
+pub mod audit;
+pub mod broker;
+pub mod error;
 pub mod pod;
+pub mod schema;
@@ -0,0 +1,19 @@
+use rabbit_trading_core::model::trading::event::RabbitTradingEvent;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListPodEventQuery {
+    // number of events already received, only the later ones are returned
+    #[serde(default)]
+    pub offset: usize,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ListPodEventResponse {
+    #[schema(value_type = Vec<Object>)]
+    pub event_list: Vec<RabbitTradingEvent>,
+    // offset of the next request when following the events
+    pub next_offset: usize,
+}
@@ -1,13 +1,9 @@
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
+use utoipa::ToSchema;
 
 use super::metadata::PodMetadata;
 
-#[derive(Deserialize)]
-pub struct InspectPodRequest {
-    pub pod_id: String,
-}
-
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct InspectPodResponse {
     pub metadata: PodMetadata,
 }
@@ -1,11 +1,9 @@
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
+use utoipa::ToSchema;
 
 use super::metadata::PodMetadata;
 
-#[derive(Deserialize)]
-pub struct ListPodRequest {}
-
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct ListPodResponse {
     pub pod_list: Vec<PodMetadata>,
 }
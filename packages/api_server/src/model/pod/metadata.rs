@@ -1,9 +1,29 @@
-use rabbit_trading_core::model::config::pod::PodConfig;
+use rabbit_trading_core::{model::config::pod::PodConfig, pod::state::PodStatus};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Deserialize, Serialize, Clone)]
+use super::restart::PodRestartStatus;
+
+// What the pod should be doing, kept across API server restarts
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq, ToSchema)]
+pub enum PodDesiredState {
+    #[default]
+    Running,
+    Stopped,
+}
+
+#[derive(Deserialize, Serialize, Clone, ToSchema)]
 pub struct PodMetadata {
     pub id: String,
     pub created_at: u64,
+    #[schema(value_type = Object)]
     pub config: PodConfig,
+    #[serde(default)]
+    pub desired_state: PodDesiredState,
+    // `state` and `last_error` of the pod
+    #[serde(flatten)]
+    #[schema(value_type = Object)]
+    pub status: PodStatus,
+    #[serde(default)]
+    pub restart: PodRestartStatus,
 }
@@ -1,5 +1,7 @@
+pub mod events;
 pub mod inspect;
 pub mod list;
 pub mod metadata;
+pub mod restart;
 pub mod start;
 pub mod stop;
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Serialize, Clone, Default, ToSchema)]
+pub struct PodRestartRecord {
+    pub restarted_at: u64,
+    pub reason: String,
+}
+
+#[derive(Deserialize, Serialize, Clone, Default, ToSchema)]
+pub struct PodRestartStatus {
+    pub restart_count: u32,
+    // the most recent restarts, oldest first
+    pub restart_history: Vec<PodRestartRecord>,
+}
@@ -1,14 +1,17 @@
 use rabbit_trading_core::model::config::pod::PodConfig;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use super::metadata::PodMetadata;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 pub struct StartPodRequest {
+    // the keys of each component are listed by GET /schema
+    #[schema(value_type = Object)]
     pub config: PodConfig,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct StartPodResponse {
     pub pod_id: String,
     pub metadata: PodMetadata,
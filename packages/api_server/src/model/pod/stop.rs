@@ -1,13 +1,12 @@
-use serde::{Deserialize, Serialize};
+use rabbit_trading_core::pod::shutdown::ShutdownReport;
+use serde::Serialize;
+use utoipa::ToSchema;
 
 use super::metadata::PodMetadata;
 
-#[derive(Deserialize)]
-pub struct StopPodRequest {
-    pub pod_id: String,
-}
-
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct StopPodResponse {
     pub metadata: PodMetadata,
+    #[schema(value_type = Object)]
+    pub shutdown_report: ShutdownReport,
 }
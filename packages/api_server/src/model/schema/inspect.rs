@@ -0,0 +1,17 @@
+use rabbit_trading_core::pod::component::{ComponentKind, ComponentSchema};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct InspectSchemaPath {
+    #[param(value_type = String)]
+    pub kind: ComponentKind,
+    pub identifier: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct InspectSchemaResponse {
+    #[schema(value_type = Object)]
+    pub schema: ComponentSchema,
+}
@@ -0,0 +1,17 @@
+use rabbit_trading_core::pod::component::{ComponentKind, ComponentSchema};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListSchemaQuery {
+    // lists every kind when not set
+    #[param(value_type = Option<String>)]
+    pub kind: Option<ComponentKind>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ListSchemaResponse {
+    #[schema(value_type = Vec<Object>)]
+    pub schema_list: Vec<ComponentSchema>,
+}
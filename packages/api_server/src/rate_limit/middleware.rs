@@ -0,0 +1,84 @@
+use axum::{
+    extract::{ConnectInfo, MatchedPath, Request, State},
+    http::{header, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::{
+    net::SocketAddr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use super::rate_limiter::RateLimiter;
+use crate::{
+    auth::{authenticator::Authenticator, principal::Principal},
+    handler::error::ApiError,
+};
+
+// Answers 429 with a `Retry-After` header once an IP address is over its limit across all routes.
+// Runs before the auth middleware so that requests with wrong credentials are limited too.
+pub async fn ip_rate_limit_middleware(
+    State(rate_limiter): State<Arc<RateLimiter>>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let ip = address.ip().to_string();
+    match rate_limiter.acquire_ip(&ip, Instant::now()) {
+        Result::Ok(()) => next.run(request).await,
+        Result::Err(retry_after) => {
+            get_rate_limited_response(&ip, "all routes".to_owned(), retry_after)
+        }
+    }
+}
+
+// Answers 429 with a `Retry-After` header once a client is over the limit of a route. Runs after
+// the auth middleware so that users are told apart, IP addresses are used without auth.
+pub async fn rate_limit_middleware(
+    State(rate_limiter): State<Arc<RateLimiter>>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    matched_path: MatchedPath,
+    request: Request,
+    next: Next,
+) -> Response {
+    let client = match request.extensions().get::<Principal>() {
+        Option::Some(principal) if principal.username != Authenticator::ANONYMOUS_USERNAME => {
+            principal.username.clone()
+        }
+        _ => address.ip().to_string(),
+    };
+    let method = request.method().to_string();
+    let route = matched_path.as_str();
+
+    match rate_limiter.acquire(&client, &method, route, Instant::now()) {
+        Result::Ok(()) => next.run(request).await,
+        Result::Err(retry_after) => {
+            get_rate_limited_response(&client, format!("{} {}", method, route), retry_after)
+        }
+    }
+}
+
+fn get_rate_limited_response(client: &str, target: String, retry_after: Duration) -> Response {
+    // rounded up, retrying earlier would be rejected again
+    let retry_after_seconds = retry_after.as_secs_f64().ceil() as u64;
+    log::warn!(
+        "Rate limited {} of {}, retry after {} seconds",
+        target,
+        client,
+        retry_after_seconds
+    );
+    let mut response = ApiError::new(
+        StatusCode::TOO_MANY_REQUESTS,
+        "RATE_LIMITED",
+        format!(
+            "Too many requests to {}, retry after {} seconds",
+            target, retry_after_seconds
+        ),
+    )
+    .into_response();
+    response
+        .headers_mut()
+        .insert(header::RETRY_AFTER, HeaderValue::from(retry_after_seconds));
+    response
+}
@@ -0,0 +1,3 @@
+pub mod middleware;
+pub mod rate_limit_config;
+pub mod rate_limiter;
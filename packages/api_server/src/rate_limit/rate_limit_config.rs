@@ -0,0 +1,159 @@
+use anyhow::{anyhow, Context, Error};
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+// `limit` requests every `period_seconds`, sent at once or spread over the period
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RateLimitRule {
+    pub limit: u32,
+    pub period_seconds: u64,
+}
+
+// The rule of one route, `route` is the path as declared by the router, e.g. `/pod/:pod_id`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RouteRateLimitConfig {
+    pub method: String,
+    pub route: String,
+    #[serde(flatten)]
+    pub rule: RateLimitRule,
+}
+
+// Every client has its own budget on every route, `default` applies to the routes without a rule
+// of their own and leaves them unlimited when unset. `ip` is the budget of an IP address across
+// all routes, spent before auth so that unauthenticated requests are limited too.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RateLimitConfig {
+    #[serde(default)]
+    pub ip: Option<RateLimitRule>,
+    #[serde(default)]
+    pub default: Option<RateLimitRule>,
+    #[serde(default)]
+    pub route_list: Vec<RouteRateLimitConfig>,
+}
+
+impl Default for RateLimitConfig {
+    // starting a pod opens broker connections, so it is limited much more than the rest
+    fn default() -> Self {
+        RateLimitConfig {
+            // above the limit of one user, several of them might share an address
+            ip: Option::Some(RateLimitRule {
+                limit: 1200,
+                period_seconds: 60,
+            }),
+            default: Option::Some(RateLimitRule {
+                limit: 600,
+                period_seconds: 60,
+            }),
+            route_list: vec![RouteRateLimitConfig {
+                method: "POST".to_owned(),
+                route: "/pod".to_owned(),
+                rule: RateLimitRule {
+                    limit: 10,
+                    period_seconds: 60,
+                },
+            }],
+        }
+    }
+}
+
+impl RateLimitConfig {
+    pub fn parse(content: &str) -> Result<Self, Error> {
+        let rate_limit_config: RateLimitConfig = serde_yaml_ng::from_str(content)
+            .context("PARSING_ERROR Error when parsing the rate limit config")?;
+
+        let rule_list = rate_limit_config
+            .ip
+            .iter()
+            .chain(rate_limit_config.default.iter())
+            .chain(
+                rate_limit_config
+                    .route_list
+                    .iter()
+                    .map(|route_rate_limit_config| &route_rate_limit_config.rule),
+            );
+        for rule in rule_list {
+            if rule.limit == 0 || rule.period_seconds == 0 {
+                return Result::Err(anyhow!(
+                    "ILLEGAL_VALUE limit and period_seconds should be positive, {:?}",
+                    rule
+                ));
+            }
+        }
+        Result::Ok(rate_limit_config)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let content = fs::read_to_string(path).with_context(|| {
+            format!(
+                "Error when reading the rate limit config {}",
+                path.display()
+            )
+        })?;
+        Self::parse(&content)
+    }
+
+    pub fn get_rule(&self, method: &str, route: &str) -> Option<&RateLimitRule> {
+        self.route_list
+            .iter()
+            .find(|route_rate_limit_config| {
+                route_rate_limit_config.method.eq_ignore_ascii_case(method)
+                    && route_rate_limit_config.route == route
+            })
+            .map(|route_rate_limit_config| &route_rate_limit_config.rule)
+            .or(self.default.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod test_rate_limit_config {
+    use super::{RateLimitConfig, RateLimitRule};
+
+    #[test]
+    fn test_parse() {
+        let rate_limit_config = RateLimitConfig::parse(
+            r#"
+route_list:
+  - method: post
+    route: /pod
+    limit: 5
+    period_seconds: 60
+"#,
+        )
+        .unwrap();
+        assert_eq!(Option::None, rate_limit_config.ip);
+        assert_eq!(
+            Option::Some(&RateLimitRule {
+                limit: 5,
+                period_seconds: 60
+            }),
+            rate_limit_config.get_rule("POST", "/pod")
+        );
+        assert_eq!(Option::None, rate_limit_config.get_rule("GET", "/pod"));
+
+        let rate_limit_config = RateLimitConfig::default();
+        assert_eq!(
+            rate_limit_config.default.as_ref(),
+            rate_limit_config.get_rule("GET", "/pod")
+        );
+
+        let err = RateLimitConfig::parse(
+            r#"
+default:
+  limit: 0
+  period_seconds: 60
+"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("ILLEGAL_VALUE"));
+
+        let err = RateLimitConfig::parse(
+            r#"
+ip:
+  limit: 10
+  period_seconds: 0
+"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("ILLEGAL_VALUE"));
+    }
+}
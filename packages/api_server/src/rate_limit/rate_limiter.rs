@@ -0,0 +1,239 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use super::rate_limit_config::{RateLimitConfig, RateLimitRule};
+
+// Holds up to `limit` tokens and gets them back at `limit / period_seconds` per second
+struct TokenBucket {
+    capacity: f64,
+    refill_per_second: f64,
+    token_count: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(rule: &RateLimitRule, now: Instant) -> Self {
+        let capacity = rule.limit as f64;
+        TokenBucket {
+            capacity,
+            refill_per_second: capacity / rule.period_seconds as f64,
+            token_count: capacity,
+            updated_at: now,
+        }
+    }
+
+    fn get_token_count(&self, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        (self.token_count + elapsed * self.refill_per_second).min(self.capacity)
+    }
+
+    fn refill(&mut self, now: Instant) {
+        self.token_count = self.get_token_count(now);
+        self.updated_at = now;
+    }
+
+    fn acquire(&mut self, now: Instant) -> Result<(), Duration> {
+        self.refill(now);
+        match self.token_count >= 1.0 {
+            true => {
+                self.token_count -= 1.0;
+                Result::Ok(())
+            }
+            false => Result::Err(Duration::from_secs_f64(
+                (1.0 - self.token_count) / self.refill_per_second,
+            )),
+        }
+    }
+}
+
+// Token buckets per client and route, a client is either a user or an IP address
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    bucket_map: Mutex<HashMap<(String, String), TokenBucket>>,
+}
+
+impl RateLimiter {
+    // buckets of idle clients are dropped past this count, they would be full again anyway, then
+    // the least recently used ones
+    const MAX_BUCKET_COUNT: usize = 10_000;
+    // never a route, which all start with `METHOD /`
+    const IP_BUCKET_NAME: &'static str = "ip";
+
+    pub fn new(config: RateLimitConfig) -> Self {
+        RateLimiter {
+            config,
+            bucket_map: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // Takes a token from the bucket of `client` on the route, the error tells how long to wait
+    // for the next one
+    pub fn acquire(
+        &self,
+        client: &str,
+        method: &str,
+        route: &str,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        match self.config.get_rule(method, route) {
+            Option::Some(rule) => {
+                self.acquire_bucket(client, format!("{} {}", method, route), rule, now)
+            }
+            Option::None => Result::Ok(()),
+        }
+    }
+
+    // Takes a token from the bucket of the IP address shared by all routes, checked before the
+    // user is authenticated
+    pub fn acquire_ip(&self, ip: &str, now: Instant) -> Result<(), Duration> {
+        match &self.config.ip {
+            Option::Some(rule) => {
+                self.acquire_bucket(ip, Self::IP_BUCKET_NAME.to_owned(), rule, now)
+            }
+            Option::None => Result::Ok(()),
+        }
+    }
+
+    fn acquire_bucket(
+        &self,
+        client: &str,
+        bucket_name: String,
+        rule: &RateLimitRule,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        let mut bucket_map = self.bucket_map.lock().unwrap();
+        let key = (client.to_owned(), bucket_name);
+        if !bucket_map.contains_key(&key) && bucket_map.len() >= Self::MAX_BUCKET_COUNT {
+            bucket_map.retain(|_, bucket| bucket.get_token_count(now) < bucket.capacity);
+            // every bucket is in use, e.g. under a flood of addresses
+            if bucket_map.len() >= Self::MAX_BUCKET_COUNT {
+                let least_recent_key = bucket_map
+                    .iter()
+                    .min_by_key(|(_, bucket)| bucket.updated_at)
+                    .map(|(key, _)| key.clone());
+                if let Option::Some(least_recent_key) = least_recent_key {
+                    bucket_map.remove(&least_recent_key);
+                }
+            }
+        }
+        bucket_map
+            .entry(key)
+            .or_insert_with(|| TokenBucket::new(rule, now))
+            .acquire(now)
+    }
+}
+
+#[cfg(test)]
+mod test_rate_limiter {
+    use std::time::{Duration, Instant};
+
+    use super::RateLimiter;
+    use crate::rate_limit::rate_limit_config::{
+        RateLimitConfig, RateLimitRule, RouteRateLimitConfig,
+    };
+
+    #[test]
+    fn test_acquire() {
+        let rate_limiter = RateLimiter::new(RateLimitConfig {
+            ip: Option::None,
+            default: Option::None,
+            route_list: vec![RouteRateLimitConfig {
+                method: "POST".to_owned(),
+                route: "/pod".to_owned(),
+                rule: RateLimitRule {
+                    limit: 2,
+                    period_seconds: 2,
+                },
+            }],
+        });
+        let now = Instant::now();
+
+        assert!(rate_limiter.acquire("desk", "POST", "/pod", now).is_ok());
+        assert!(rate_limiter.acquire("desk", "POST", "/pod", now).is_ok());
+        assert_eq!(
+            Result::Err(Duration::from_secs(1)),
+            rate_limiter.acquire("desk", "POST", "/pod", now)
+        );
+        // other clients and routes have budgets of their own
+        assert!(rate_limiter.acquire("ops", "POST", "/pod", now).is_ok());
+        for _ in 0..10 {
+            assert!(rate_limiter.acquire("desk", "GET", "/pod", now).is_ok());
+        }
+
+        let later = now + Duration::from_millis(500);
+        assert_eq!(
+            Result::Err(Duration::from_millis(500)),
+            rate_limiter.acquire("desk", "POST", "/pod", later)
+        );
+        let later = now + Duration::from_secs(1);
+        assert!(rate_limiter.acquire("desk", "POST", "/pod", later).is_ok());
+        assert!(rate_limiter.acquire("desk", "POST", "/pod", later).is_err());
+    }
+
+    #[test]
+    fn test_acquire_ip() {
+        let rate_limiter = RateLimiter::new(RateLimitConfig {
+            ip: Option::Some(RateLimitRule {
+                limit: 1,
+                period_seconds: 1,
+            }),
+            default: Option::None,
+            route_list: vec![],
+        });
+        let now = Instant::now();
+
+        assert!(rate_limiter.acquire_ip("10.0.0.1", now).is_ok());
+        assert_eq!(
+            Result::Err(Duration::from_secs(1)),
+            rate_limiter.acquire_ip("10.0.0.1", now)
+        );
+        assert!(rate_limiter.acquire_ip("10.0.0.2", now).is_ok());
+        // the budget of the address is apart from the ones of the routes
+        assert!(rate_limiter
+            .acquire("10.0.0.1", "POST", "/pod", now)
+            .is_ok());
+        assert!(rate_limiter
+            .acquire_ip("10.0.0.1", now + Duration::from_secs(1))
+            .is_ok());
+
+        let rate_limiter = RateLimiter::new(RateLimitConfig {
+            ip: Option::None,
+            default: Option::None,
+            route_list: vec![],
+        });
+        for _ in 0..10 {
+            assert!(rate_limiter.acquire_ip("10.0.0.1", now).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_acquire_bucket_count() {
+        let rate_limiter = RateLimiter::new(RateLimitConfig {
+            ip: Option::Some(RateLimitRule {
+                limit: 1,
+                period_seconds: 3600,
+            }),
+            default: Option::None,
+            route_list: vec![],
+        });
+        let now = Instant::now();
+
+        // none of the buckets is full again, so the least recently used one makes room
+        for index in 0..RateLimiter::MAX_BUCKET_COUNT {
+            let ip = index.to_string();
+            assert!(rate_limiter
+                .acquire_ip(&ip, now + Duration::from_micros(index as u64))
+                .is_ok());
+        }
+        let later = now + Duration::from_secs(1);
+        assert!(rate_limiter.acquire_ip("10.0.0.1", later).is_ok());
+        let bucket_map = rate_limiter.bucket_map.lock().unwrap();
+        let get_key = |ip: &str| (ip.to_owned(), RateLimiter::IP_BUCKET_NAME.to_owned());
+        assert_eq!(RateLimiter::MAX_BUCKET_COUNT, bucket_map.len());
+        assert!(!bucket_map.contains_key(&get_key("0")));
+        assert!(bucket_map.contains_key(&get_key("1")));
+    }
+}
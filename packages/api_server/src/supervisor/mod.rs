@@ -0,0 +1 @@
+pub mod pod_supervisor;
@@ -0,0 +1,263 @@
+use anyhow::Error;
+use rabbit_trading_core::{
+    model::{config::restart_policy::RestartPolicyConfig, trading::event::RabbitTradingEvent},
+    pod::{scheduler::PodScheduler, state::PodState, Pod},
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::model::pod::restart::{PodRestartRecord, PodRestartStatus};
+
+// Drives a pod for the API server: starts it, follows its schedule when one is configured and
+// restarts it according to its restart policy
+pub struct PodSupervisor {
+    pod: Arc<Pod>,
+    restart_policy: RestartPolicyConfig,
+    scheduler: Option<PodScheduler>,
+    restart_status: Mutex<PodRestartStatus>,
+}
+
+impl PodSupervisor {
+    const MAX_RESTART_HISTORY: usize = 20;
+    const RETRY_INTERVAL: Duration = Duration::from_secs(60);
+
+    pub fn new(pod: Arc<Pod>) -> Result<Self, Error> {
+        let pod_config = pod.get_pod_config();
+        let scheduler = match &pod_config.schedule {
+            Some(schedule_config) => Option::Some(PodScheduler::new(schedule_config.clone())?),
+            None => Option::None,
+        };
+
+        Result::Ok(PodSupervisor {
+            restart_policy: pod_config.restart_policy.clone(),
+            pod,
+            scheduler,
+            restart_status: Mutex::new(PodRestartStatus::default()),
+        })
+    }
+
+    pub fn get_restart_status(&self) -> PodRestartStatus {
+        self.restart_status.lock().unwrap().clone()
+    }
+
+    // Runs until the task is aborted, or until the pod ends for good when it is not scheduled
+    pub async fn run(&self) {
+        let scheduler = match &self.scheduler {
+            Some(scheduler) => scheduler,
+            None => return self.supervise().await,
+        };
+        let clock = self.pod.get_clock();
+
+        loop {
+            let window = match scheduler.get_next_window(clock.now()).await {
+                Result::Ok(window) => window,
+                Result::Err(err) => {
+                    log::error!("Error when getting the next schedule window, {}", err);
+                    clock.sleep(Self::RETRY_INTERVAL).await;
+                    continue;
+                }
+            };
+            log::info!("Next schedule window of the pod, {:?}", window);
+            clock.sleep_until(window.start_timestamp).await;
+            tokio::select! {
+                _ = self.supervise() => clock.sleep_until(window.stop_timestamp).await,
+                _ = clock.sleep_until(window.stop_timestamp) => {},
+            }
+            if let Result::Err(err) = self.pod.stop().await {
+                log::error!("Error when stopping the scheduled pod, {}", err);
+            }
+        }
+    }
+
+    // Starts the pod and restarts it whenever the policy allows, returns once it ends for good
+    async fn supervise(&self) {
+        let clock = self.pod.get_clock();
+        // consecutive restarts, reset once a started pod has been running long enough to be healthy
+        let mut retry_count = 0u32;
+
+        loop {
+            let started_at = clock.now();
+            let is_started = match self.pod.start().await {
+                Result::Ok(_) => true,
+                Result::Err(err) => {
+                    log::error!("Error when starting the pod, {}", err);
+                    false
+                }
+            };
+            self.pod.join().await;
+            if is_started
+                && self
+                    .restart_policy
+                    .is_healthy_run(clock.now().saturating_sub(started_at))
+            {
+                retry_count = 0;
+            }
+
+            let state = self.pod.get_status().state;
+            if !self.restart_policy.should_restart(&state, retry_count) {
+                return;
+            }
+            let reason = match state {
+                PodState::Failed { reason } => reason,
+                _ => "strategy ended".to_owned(),
+            };
+            let backoff = self.restart_policy.get_backoff(retry_count);
+            let restart_count = self.record_restart(reason.clone());
+            log::warn!(
+                "Restarting the pod in {} seconds, restart_count: {}, reason: {}",
+                backoff.as_secs(),
+                restart_count,
+                reason
+            );
+            self.pod
+                .send_event(RabbitTradingEvent::PodRestart {
+                    context: self.pod.create_event_context(),
+                    restart_count,
+                    reason,
+                    backoff_seconds: backoff.as_secs(),
+                })
+                .await;
+
+            clock.sleep(backoff).await;
+            retry_count += 1;
+        }
+    }
+
+    fn record_restart(&self, reason: String) -> u32 {
+        let mut restart_status = self.restart_status.lock().unwrap();
+        restart_status.restart_count += 1;
+        restart_status.restart_history.push(PodRestartRecord {
+            restarted_at: self.pod.get_clock().now().as_secs(),
+            reason,
+        });
+        if restart_status.restart_history.len() > Self::MAX_RESTART_HISTORY {
+            restart_status.restart_history.remove(0);
+        }
+        restart_status.restart_count
+    }
+}
+
+#[cfg(test)]
+mod test_pod_supervisor {
+    use rabbit_trading_core::{
+        model::{
+            common::timestamp::Timestamp,
+            config::{
+                pod::PodConfig,
+                restart_policy::{RestartPolicy, RestartPolicyConfig},
+                strategy::StrategyConfig,
+            },
+        },
+        pod::{state::PodState, Pod},
+        utils::clock::manual::ManualClock,
+    };
+    use std::{collections::HashMap, sync::Arc, time::Duration};
+    use tempfile::TempDir;
+
+    use super::PodSupervisor;
+    use crate::handler::test_util::{
+        get_pod_config, wait_for, write_quote_real_time_info_at, START_TIMESTAMP,
+    };
+
+    #[tokio::test]
+    async fn test_pod_supervisor_on_failure() {
+        let temp_dir = TempDir::new().unwrap();
+        let pod_config = get_pod_config(
+            &temp_dir,
+            RestartPolicyConfig {
+                policy: RestartPolicy::OnFailure,
+                max_retries: Option::Some(2),
+                initial_backoff_seconds: 0,
+                max_backoff_seconds: 0,
+                healthy_seconds: 1,
+            },
+        );
+        let pod = Pod::new(
+            PodConfig {
+                strategy: StrategyConfig {
+                    identifier: "UnknownStrategy".to_owned(),
+                    config_map: HashMap::new(),
+                },
+                ..pod_config
+            },
+            "test_pod_id".to_owned(),
+        )
+        .unwrap();
+        let supervisor = PodSupervisor::new(Arc::new(pod)).unwrap();
+        // returns once the retries are exhausted
+        supervisor.run().await;
+
+        let restart_status = supervisor.get_restart_status();
+        assert_eq!(2, restart_status.restart_count);
+        assert_eq!(2, restart_status.restart_history.len());
+        assert!(restart_status.restart_history[0]
+            .reason
+            .contains("IDENTIFIER_NOT_MATCHED"));
+        assert_eq!(
+            START_TIMESTAMP,
+            restart_status.restart_history[0].restarted_at
+        );
+        assert!(matches!(
+            supervisor.pod.get_status().state,
+            PodState::Failed { .. }
+        ));
+    }
+
+    // The strategy fails after starting once the replayed quotes run out, so a run lasts as long
+    // as the quotes written before it ahead of the clock
+    #[tokio::test]
+    async fn test_pod_supervisor_resets_retries_after_healthy_run() {
+        let temp_dir = TempDir::new().unwrap();
+        let clock = Arc::new(ManualClock::new_with_timestamp(Timestamp::from_secs(
+            START_TIMESTAMP,
+        )));
+        let pod = Pod::new_with_clock(
+            get_pod_config(
+                &temp_dir,
+                RestartPolicyConfig {
+                    policy: RestartPolicy::OnFailure,
+                    max_retries: Option::Some(1),
+                    initial_backoff_seconds: 10,
+                    max_backoff_seconds: 10,
+                    healthy_seconds: 5,
+                },
+            ),
+            "test_pod_id".to_owned(),
+            clock.clone(),
+        );
+        let supervisor = Arc::new(PodSupervisor::new(Arc::new(pod)).unwrap());
+        let handle = tokio::spawn({
+            let supervisor = supervisor.clone();
+            async move { supervisor.run().await }
+        });
+
+        // nothing to replay, the first run fails right after starting and uses up the retry
+        wait_for(|| supervisor.get_restart_status().restart_count == 1).await;
+        assert!(supervisor.get_restart_status().restart_history[0]
+            .reason
+            .contains("EMPTY_MESSAGE_RECEIVED"));
+
+        // the second run replays 10 seconds of quotes
+        write_quote_real_time_info_at(&temp_dir, 10..=20);
+        clock.set(Timestamp::from_secs(START_TIMESTAMP + 10));
+        wait_for(|| supervisor.pod.get_status().state == PodState::Running).await;
+        clock.set(Timestamp::from_secs(START_TIMESTAMP + 20));
+
+        // the healthy run reset the retries, so it is restarted once more
+        wait_for(|| supervisor.get_restart_status().restart_count == 2).await;
+        assert_eq!(
+            START_TIMESTAMP + 20,
+            supervisor.get_restart_status().restart_history[1].restarted_at
+        );
+
+        // the quotes are in the past for the third run, which fails at once
+        clock.set(Timestamp::from_secs(START_TIMESTAMP + 30));
+        tokio::time::timeout(Duration::from_secs(10), handle)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(2, supervisor.get_restart_status().restart_count);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod reloader;
+pub mod tls_config;
@@ -0,0 +1,88 @@
+use axum_server::tls_rustls::RustlsConfig;
+use tokio::time::MissedTickBehavior;
+
+use super::tls_config::TlsConfig;
+
+// Swaps the certificate of the running server once its files change, e.g. after a renewal. Open
+// connections keep the certificate they were accepted with, and a broken file keeps the current
+// certificate in use until it is fixed.
+pub async fn reload_tls_config(tls_config: TlsConfig, rustls_config: RustlsConfig) {
+    let mut modified_time_list = tls_config.get_modified_time_list();
+    let mut interval = tokio::time::interval(tls_config.reload_interval);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    loop {
+        interval.tick().await;
+        let current_modified_time_list = tls_config.get_modified_time_list();
+        if current_modified_time_list == modified_time_list {
+            continue;
+        }
+        // a renewal writing the certificate and the key one after the other is picked up again
+        // at the next change
+        modified_time_list = current_modified_time_list;
+        match tls_config.load_server_config() {
+            Result::Ok(server_config) => {
+                rustls_config.reload_from_config(server_config);
+                log::warn!(
+                    "TLS certificate reloaded from {}",
+                    tls_config.cert_path.display()
+                );
+            }
+            Result::Err(err) => {
+                log::error!(
+                    "Error when reloading the TLS certificate, keeping the current one, {:?}",
+                    err
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_reloader {
+    use axum_server::tls_rustls::RustlsConfig;
+    use std::{fs, path::PathBuf, sync::Arc, time::Duration};
+
+    use super::reload_tls_config;
+    use crate::tls::tls_config::TlsConfig;
+
+    #[tokio::test]
+    async fn test_reload_tls_config() {
+        let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test/tls");
+        let temp_dir = tempfile::tempdir().unwrap();
+        let tls_config = TlsConfig {
+            cert_path: temp_dir.path().join("cert.pem"),
+            key_path: temp_dir.path().join("key.pem"),
+            client_ca_path: Option::None,
+            reload_interval: Duration::from_millis(10),
+        };
+        fs::copy(fixture_path.join("server.pem"), &tls_config.cert_path).unwrap();
+        fs::copy(fixture_path.join("server.key"), &tls_config.key_path).unwrap();
+        let rustls_config = RustlsConfig::from_config(tls_config.load_server_config().unwrap());
+        let initial_server_config = rustls_config.get_inner();
+        let reload_task =
+            tokio::spawn(reload_tls_config(tls_config.clone(), rustls_config.clone()));
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(Arc::ptr_eq(
+            &initial_server_config,
+            &rustls_config.get_inner()
+        ));
+
+        // a broken key keeps the current certificate in use
+        fs::write(&tls_config.key_path, "").unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(Arc::ptr_eq(
+            &initial_server_config,
+            &rustls_config.get_inner()
+        ));
+
+        fs::copy(fixture_path.join("client.key"), &tls_config.key_path).unwrap();
+        fs::copy(fixture_path.join("client.pem"), &tls_config.cert_path).unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(!Arc::ptr_eq(
+            &initial_server_config,
+            &rustls_config.get_inner()
+        ));
+        reload_task.abort();
+    }
+}
@@ -0,0 +1,285 @@
+use anyhow::{anyhow, Context, Error};
+use rustls::{
+    server::AllowAnyAuthenticatedClient, Certificate, PrivateKey, RootCertStore, ServerConfig,
+};
+use std::{
+    fs::{self, File},
+    io::BufReader,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use crate::auth::auth_config::AuthConfig;
+
+// Where the certificate of the API server is read from, clients have to present a certificate
+// signed by `client_ca_path` when it is set. Mutual TLS only secures the transport, the client
+// certificate is not mapped to a user, so requests still authenticate with basic or bearer auth
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TlsConfig {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+    pub client_ca_path: Option<PathBuf>,
+    // how often the files are checked for a new certificate, zero disables the reload
+    pub reload_interval: Duration,
+}
+
+impl TlsConfig {
+    pub const ENV_VAR_CERT_PATH: &'static str = "API_SERVER_TLS_CERT_PATH";
+    pub const ENV_VAR_KEY_PATH: &'static str = "API_SERVER_TLS_KEY_PATH";
+    pub const ENV_VAR_CLIENT_CA_PATH: &'static str = "API_SERVER_TLS_CLIENT_CA_PATH";
+    pub const ENV_VAR_RELOAD_INTERVAL: &'static str = "API_SERVER_TLS_RELOAD_INTERVAL_SECONDS";
+    const DEFAULT_RELOAD_INTERVAL: Duration = Duration::from_secs(30);
+
+    // None when no TLS env var is set, the server then serves plain HTTP
+    pub fn from_env_var(
+        get_env_var: impl Fn(&str) -> Option<String>,
+    ) -> Result<Option<Self>, Error> {
+        // left empty in example.env
+        let get_env_var = |name: &str| get_env_var(name).filter(|value| !value.is_empty());
+        let cert_path = get_env_var(Self::ENV_VAR_CERT_PATH);
+        let key_path = get_env_var(Self::ENV_VAR_KEY_PATH);
+        let client_ca_path = get_env_var(Self::ENV_VAR_CLIENT_CA_PATH);
+        let (cert_path, key_path) = match (cert_path, key_path) {
+            (Option::Some(cert_path), Option::Some(key_path)) => (cert_path, key_path),
+            (Option::None, Option::None) if client_ca_path.is_none() => {
+                return Result::Ok(Option::None)
+            }
+            (Option::Some(_), Option::None) => {
+                return Result::Err(anyhow!("MISSING_ENV_VAR {}", Self::ENV_VAR_KEY_PATH))
+            }
+            (Option::None, _) => {
+                return Result::Err(anyhow!("MISSING_ENV_VAR {}", Self::ENV_VAR_CERT_PATH))
+            }
+        };
+        let reload_interval = match get_env_var(Self::ENV_VAR_RELOAD_INTERVAL) {
+            Option::Some(reload_interval) => {
+                Duration::from_secs(reload_interval.parse::<u64>().with_context(|| {
+                    format!(
+                        "ILLEGAL_VALUE {}: {}",
+                        Self::ENV_VAR_RELOAD_INTERVAL,
+                        reload_interval
+                    )
+                })?)
+            }
+            Option::None => Self::DEFAULT_RELOAD_INTERVAL,
+        };
+
+        Result::Ok(Option::Some(TlsConfig {
+            cert_path: PathBuf::from(cert_path),
+            key_path: PathBuf::from(key_path),
+            client_ca_path: client_ca_path.map(PathBuf::from),
+            reload_interval,
+        }))
+    }
+
+    // With NoAuth every holder of a certificate signed by the client CA would be Admin
+    pub fn check_auth(&self, auth_kind: &AuthConfig) -> Result<(), Error> {
+        match (&self.client_ca_path, auth_kind) {
+            (Option::Some(_), AuthConfig::NoAuth) => Result::Err(anyhow!(
+                "ILLEGAL_VALUE {} requires basic or bearer auth, got {}",
+                Self::ENV_VAR_CLIENT_CA_PATH,
+                auth_kind.to_string()
+            )),
+            _ => Result::Ok(()),
+        }
+    }
+
+    // Reads the PEM files again, on start and every time one of them changes
+    pub fn load_server_config(&self) -> Result<Arc<ServerConfig>, Error> {
+        let cert_list = read_cert_list(&self.cert_path)?;
+        let private_key = read_private_key(&self.key_path)?;
+
+        let builder = ServerConfig::builder().with_safe_defaults();
+        let builder = match &self.client_ca_path {
+            Option::Some(client_ca_path) => {
+                let mut root_cert_store = RootCertStore::empty();
+                for cert in read_cert_list(client_ca_path)? {
+                    root_cert_store.add(&cert).with_context(|| {
+                        format!(
+                            "ILLEGAL_VALUE Error when adding the client CA {}",
+                            client_ca_path.display()
+                        )
+                    })?;
+                }
+                builder.with_client_cert_verifier(
+                    AllowAnyAuthenticatedClient::new(root_cert_store).boxed(),
+                )
+            }
+            Option::None => builder.with_no_client_auth(),
+        };
+        let mut server_config = builder
+            .with_single_cert(cert_list, private_key)
+            .with_context(|| {
+                format!(
+                    "ILLEGAL_VALUE Error when loading the certificate {} with the key {}",
+                    self.cert_path.display(),
+                    self.key_path.display()
+                )
+            })?;
+        server_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+        Result::Ok(Arc::new(server_config))
+    }
+
+    // A change of any of these means the server config should be loaded again
+    pub fn get_modified_time_list(&self) -> Vec<Option<SystemTime>> {
+        [
+            Option::Some(&self.cert_path),
+            Option::Some(&self.key_path),
+            self.client_ca_path.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|path| {
+            fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        })
+        .collect()
+    }
+}
+
+fn open_pem_file(path: &Path) -> Result<BufReader<File>, Error> {
+    let file = File::open(path)
+        .with_context(|| format!("Error when reading the PEM file {}", path.display()))?;
+    Result::Ok(BufReader::new(file))
+}
+
+fn read_cert_list(path: &Path) -> Result<Vec<Certificate>, Error> {
+    let cert_list = rustls_pemfile::certs(&mut open_pem_file(path)?)
+        .with_context(|| format!("PARSING_ERROR Error when parsing {}", path.display()))?;
+    if cert_list.is_empty() {
+        return Result::Err(anyhow!(
+            "PARSING_ERROR No certificate in {}",
+            path.display()
+        ));
+    }
+    Result::Ok(cert_list.into_iter().map(Certificate).collect())
+}
+
+// The first key of the file, in PKCS#8, PKCS#1 (RSA) or SEC1 (EC) format
+fn read_private_key(path: &Path) -> Result<PrivateKey, Error> {
+    let mut reader = open_pem_file(path)?;
+    loop {
+        match rustls_pemfile::read_one(&mut reader)
+            .with_context(|| format!("PARSING_ERROR Error when parsing {}", path.display()))?
+        {
+            Option::Some(
+                rustls_pemfile::Item::PKCS8Key(key)
+                | rustls_pemfile::Item::RSAKey(key)
+                | rustls_pemfile::Item::ECKey(key),
+            ) => return Result::Ok(PrivateKey(key)),
+            Option::Some(_) => continue,
+            Option::None => {
+                return Result::Err(anyhow!(
+                    "PARSING_ERROR No private key in {}",
+                    path.display()
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_tls_config {
+    use std::{collections::HashMap, path::PathBuf, time::Duration};
+
+    use super::TlsConfig;
+    use crate::auth::auth_config::AuthConfig;
+
+    fn get_test_tls_config(client_ca: bool) -> TlsConfig {
+        let tls_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test/tls");
+        TlsConfig {
+            cert_path: tls_path.join("server.pem"),
+            key_path: tls_path.join("server.key"),
+            client_ca_path: client_ca.then(|| tls_path.join("ca.pem")),
+            reload_interval: Duration::from_secs(1),
+        }
+    }
+
+    #[test]
+    fn test_from_env_var() {
+        assert_eq!(
+            Option::None,
+            TlsConfig::from_env_var(|_| Option::None).unwrap()
+        );
+
+        let env_var_map = HashMap::from([
+            (TlsConfig::ENV_VAR_CERT_PATH, "server.pem"),
+            (TlsConfig::ENV_VAR_KEY_PATH, "server.key"),
+            (TlsConfig::ENV_VAR_RELOAD_INTERVAL, "0"),
+        ]);
+        assert_eq!(
+            Option::Some(TlsConfig {
+                cert_path: PathBuf::from("server.pem"),
+                key_path: PathBuf::from("server.key"),
+                client_ca_path: Option::None,
+                reload_interval: Duration::ZERO,
+            }),
+            TlsConfig::from_env_var(|name| env_var_map.get(name).map(|value| value.to_string()))
+                .unwrap()
+        );
+
+        let env_var_map = HashMap::from([
+            (TlsConfig::ENV_VAR_CERT_PATH, ""),
+            (TlsConfig::ENV_VAR_KEY_PATH, ""),
+        ]);
+        assert_eq!(
+            Option::None,
+            TlsConfig::from_env_var(|name| env_var_map.get(name).map(|value| value.to_string()))
+                .unwrap()
+        );
+
+        let env_var_map = HashMap::from([(TlsConfig::ENV_VAR_CLIENT_CA_PATH, "ca.pem")]);
+        let err =
+            TlsConfig::from_env_var(|name| env_var_map.get(name).map(|value| value.to_string()))
+                .unwrap_err();
+        assert_eq!("MISSING_ENV_VAR API_SERVER_TLS_CERT_PATH", err.to_string());
+
+        let env_var_map = HashMap::from([(TlsConfig::ENV_VAR_CERT_PATH, "server.pem")]);
+        let err =
+            TlsConfig::from_env_var(|name| env_var_map.get(name).map(|value| value.to_string()))
+                .unwrap_err();
+        assert_eq!("MISSING_ENV_VAR API_SERVER_TLS_KEY_PATH", err.to_string());
+    }
+
+    #[test]
+    fn test_check_auth() {
+        assert!(get_test_tls_config(false)
+            .check_auth(&AuthConfig::NoAuth)
+            .is_ok());
+        assert!(get_test_tls_config(true)
+            .check_auth(&AuthConfig::BasicAuth)
+            .is_ok());
+        assert!(get_test_tls_config(true)
+            .check_auth(&AuthConfig::BearerAuth)
+            .is_ok());
+        let err = get_test_tls_config(true)
+            .check_auth(&AuthConfig::NoAuth)
+            .unwrap_err();
+        assert_eq!(
+            "ILLEGAL_VALUE API_SERVER_TLS_CLIENT_CA_PATH requires basic or bearer auth, got NoAuth",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_load_server_config() {
+        let tls_config = get_test_tls_config(false);
+        assert!(tls_config.load_server_config().is_ok());
+        assert_eq!(2, tls_config.get_modified_time_list().len());
+
+        let tls_config = get_test_tls_config(true);
+        assert!(tls_config.load_server_config().is_ok());
+        assert_eq!(3, tls_config.get_modified_time_list().len());
+
+        let mut tls_config = get_test_tls_config(false);
+        tls_config.key_path = tls_config.key_path.with_file_name("missing.key");
+        assert!(tls_config.load_server_config().is_err());
+
+        let mut tls_config = get_test_tls_config(false);
+        tls_config.key_path = tls_config.key_path.with_file_name("ca.pem");
+        let err = tls_config.load_server_config().unwrap_err();
+        assert!(err.to_string().starts_with("PARSING_ERROR No private key"));
+    }
+}
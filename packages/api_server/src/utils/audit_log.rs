@@ -0,0 +1,215 @@
+use anyhow::{Context, Error};
+use rabbit_trading_core::persistent_kv::common::store::PersistentKVStoreTrait;
+use tokio::sync::Mutex;
+
+use crate::model::audit::{list::ListAuditRecordQuery, record::AuditRecord};
+
+// Append-only log of the actions taken through the API server, every record is written once under
+// its own key and only counted once the length written after it
+pub struct AuditLog {
+    store: Box<dyn PersistentKVStoreTrait>,
+    // number of records, locked while appending so that sequences are not taken twice
+    length: Mutex<u64>,
+}
+
+impl AuditLog {
+    const LENGTH_KEY: &'static str = "audit_log.length";
+
+    fn get_record_key(sequence: u64) -> String {
+        format!("audit_log.{:020}.json", sequence)
+    }
+
+    pub async fn new(store: Box<dyn PersistentKVStoreTrait>) -> Result<Self, Error> {
+        // empty only when nothing was appended yet, a length that cannot be read is an error so that
+        // the records already written are not overwritten
+        let length = match store.exists(Self::LENGTH_KEY.to_owned()).await? {
+            true => {
+                let bytes = store
+                    .read(Self::LENGTH_KEY.to_owned())
+                    .await
+                    .context("Error when reading the length of the audit log")?;
+                serde_json::from_slice(&bytes)
+                    .context("PARSING_ERROR Error when parsing the length of the audit log")?
+            }
+            false => 0,
+        };
+        Result::Ok(AuditLog {
+            store,
+            length: Mutex::new(length),
+        })
+    }
+
+    // The sequence of the record is the one given by the log
+    pub async fn append(&self, mut audit_record: AuditRecord) -> Result<AuditRecord, Error> {
+        let mut length = self.length.lock().await;
+        audit_record.sequence = *length;
+        self.store
+            .write(
+                Self::get_record_key(audit_record.sequence),
+                serde_json::to_vec(&audit_record)?,
+            )
+            .await?;
+        self.store
+            .write(
+                Self::LENGTH_KEY.to_owned(),
+                serde_json::to_vec(&(*length + 1))?,
+            )
+            .await?;
+        *length += 1;
+        Result::Ok(audit_record)
+    }
+
+    // Matching records from `offset` on, with the offset to continue from
+    pub async fn list(
+        &self,
+        query: &ListAuditRecordQuery,
+    ) -> Result<(Vec<AuditRecord>, u64), Error> {
+        let length = *self.length.lock().await;
+        let mut record_list = Vec::new();
+        let mut sequence = query.offset;
+        while sequence < length && record_list.len() < query.limit {
+            let bytes = self.store.read(Self::get_record_key(sequence)).await?;
+            let audit_record: AuditRecord = serde_json::from_slice(&bytes).with_context(|| {
+                format!("PARSING_ERROR Error when parsing audit record {}", sequence)
+            })?;
+            if query.is_matched(&audit_record) {
+                record_list.push(audit_record);
+            }
+            sequence += 1;
+        }
+        Result::Ok((record_list, sequence.max(query.offset)))
+    }
+}
+
+#[cfg(test)]
+mod test_audit_log {
+    use rabbit_trading_core::{
+        model::common::types::ConfigMap,
+        persistent_kv::{
+            common::store::PersistentKVStoreTrait, fs::store::FileSystemKVStore,
+            memory::store::MemoryKVStore,
+        },
+    };
+    use serde_json::Value;
+    use tempfile::tempdir;
+
+    use super::AuditLog;
+    use crate::{
+        auth::role::Role,
+        model::audit::{
+            list::ListAuditRecordQuery,
+            record::{AuditAction, AuditRecord},
+        },
+    };
+
+    fn get_audit_record(username: &str, action: AuditAction, pod_id: &str) -> AuditRecord {
+        AuditRecord {
+            sequence: 0,
+            timestamp: 1717171717,
+            username: username.to_owned(),
+            role: Role::Admin,
+            action,
+            pod_id: Option::Some(pod_id.to_owned()),
+            detail: Value::Null,
+            error: Option::None,
+        }
+    }
+
+    fn get_query(offset: u64, limit: usize, username: Option<&str>) -> ListAuditRecordQuery {
+        ListAuditRecordQuery {
+            offset,
+            limit,
+            username: username.map(str::to_owned),
+            pod_id: Option::None,
+            action: Option::None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_audit_log() {
+        let audit_log = AuditLog::new(Box::new(MemoryKVStore::new(ConfigMap::new()).await))
+            .await
+            .unwrap();
+        for (username, action, pod_id) in [
+            ("alice", AuditAction::StartPod, "1"),
+            ("bob", AuditAction::SubmitOrder, "1"),
+            ("alice", AuditAction::StopPod, "1"),
+        ] {
+            audit_log
+                .append(get_audit_record(username, action, pod_id))
+                .await
+                .unwrap();
+        }
+
+        let (record_list, next_offset) = audit_log.list(&get_query(0, 100, None)).await.unwrap();
+        assert_eq!(
+            vec![0, 1, 2],
+            record_list
+                .iter()
+                .map(|record| record.sequence)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(3, next_offset);
+
+        let (record_list, next_offset) = audit_log
+            .list(&get_query(0, 1, Option::Some("alice")))
+            .await
+            .unwrap();
+        assert_eq!(1, record_list.len());
+        assert_eq!(AuditAction::StartPod, record_list[0].action);
+        assert_eq!(1, next_offset);
+
+        let (record_list, next_offset) = audit_log
+            .list(&get_query(next_offset, 100, Option::Some("alice")))
+            .await
+            .unwrap();
+        assert_eq!(1, record_list.len());
+        assert_eq!(AuditAction::StopPod, record_list[0].action);
+        assert_eq!(3, next_offset);
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_reload() {
+        let temp_dir = tempdir().unwrap();
+        let config_map = ConfigMap::from([(
+            FileSystemKVStore::CONFIG_KEY_BASE_PATH.to_owned(),
+            temp_dir.path().to_str().unwrap().to_owned(),
+        )]);
+
+        let audit_log = AuditLog::new(Box::new(FileSystemKVStore::new(config_map.clone()).await))
+            .await
+            .unwrap();
+        audit_log
+            .append(get_audit_record("alice", AuditAction::StartPod, "1"))
+            .await
+            .unwrap();
+
+        // a restarted server carries on after the records of the previous run
+        let audit_log = AuditLog::new(Box::new(FileSystemKVStore::new(config_map).await))
+            .await
+            .unwrap();
+        let audit_record = audit_log
+            .append(get_audit_record("bob", AuditAction::StopPod, "1"))
+            .await
+            .unwrap();
+        assert_eq!(1, audit_record.sequence);
+        let (record_list, _) = audit_log.list(&get_query(0, 100, None)).await.unwrap();
+        assert_eq!(2, record_list.len());
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_unreadable() {
+        let temp_dir = tempdir().unwrap();
+        // the length exists but cannot be read as a file
+        std::fs::create_dir(temp_dir.path().join(AuditLog::LENGTH_KEY)).unwrap();
+        let config_map = ConfigMap::from([(
+            FileSystemKVStore::CONFIG_KEY_BASE_PATH.to_owned(),
+            temp_dir.path().to_str().unwrap().to_owned(),
+        )]);
+        assert!(
+            AuditLog::new(Box::new(FileSystemKVStore::new(config_map).await))
+                .await
+                .is_err()
+        );
+    }
+}
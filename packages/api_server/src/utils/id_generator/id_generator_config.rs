@@ -0,0 +1,22 @@
+use anyhow::{anyhow, Error};
+use std::str::FromStr;
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum IdGeneratorConfig {
+    AutoIncrement,
+    UuidV7,
+    Snowflake,
+}
+
+impl FromStr for IdGeneratorConfig {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "autoincrement" | "auto_increment" => Result::Ok(Self::AutoIncrement),
+            "uuid" | "uuidv7" | "uuid_v7" => Result::Ok(Self::UuidV7),
+            "snowflake" => Result::Ok(Self::Snowflake),
+            unknown_kind => Result::Err(anyhow!("UNKNOWN_ID_GENERATOR kind: {}", unknown_kind)),
+        }
+    }
+}
@@ -1,2 +1,5 @@
 pub mod auto_increment;
 pub mod common_trait;
+pub mod id_generator_config;
+pub mod snowflake;
+pub mod uuid_v7;
@@ -0,0 +1,37 @@
+use uuid::Uuid;
+
+use super::common_trait::IdGeneratorTrait;
+
+// Time ordered at millisecond precision, unique without any coordination between servers
+pub struct UuidV7IdGenerator {}
+
+impl UuidV7IdGenerator {
+    pub fn new() -> Self {
+        UuidV7IdGenerator {}
+    }
+}
+
+impl IdGeneratorTrait for UuidV7IdGenerator {
+    fn generate(&self) -> String {
+        Uuid::now_v7().to_string()
+    }
+}
+
+#[cfg(test)]
+mod test_uuid_v7_id_generator {
+    use uuid::Uuid;
+
+    use super::UuidV7IdGenerator;
+    use crate::utils::id_generator::common_trait::IdGeneratorTrait;
+
+    #[test]
+    fn test_uuid_v7_id_generator() {
+        let uuid_v7_id_generator = UuidV7IdGenerator::new();
+        let id = uuid_v7_id_generator.generate();
+        assert_eq!(
+            Option::Some(uuid::Version::SortRand),
+            Uuid::parse_str(&id).unwrap().get_version()
+        );
+        assert_ne!(id, uuid_v7_id_generator.generate());
+    }
+}
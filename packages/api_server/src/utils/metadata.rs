@@ -1,19 +1,21 @@
-use rabbit_trading_core::{model::config::pod::PodConfig, utils::time::get_now_unix_timestamp};
-use std::sync::Arc;
+use rabbit_trading_core::{
+    model::config::pod::PodConfig, pod::state::PodStatus, utils::clock::common_trait::ClockTrait,
+};
 
-use super::id_generator::common_trait::IdGeneratorTrait;
-use crate::model::pod::metadata::PodMetadata;
+use crate::model::pod::metadata::{PodDesiredState, PodMetadata};
 
+// `clock` is the clock of the pod, a backtest is created at its simulated time
 pub fn generate_pod_metadata(
-    id_generator: Arc<Box<dyn IdGeneratorTrait>>,
+    pod_id: String,
     config: PodConfig,
+    clock: &dyn ClockTrait,
 ) -> PodMetadata {
-    let pod_id = id_generator.generate();
-    let created_at = get_now_unix_timestamp();
-
     PodMetadata {
         id: pod_id,
-        created_at,
+        created_at: clock.now().as_secs(),
         config,
+        desired_state: PodDesiredState::Running,
+        status: PodStatus::new(),
+        restart: Default::default(),
     }
 }
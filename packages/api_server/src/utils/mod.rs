@@ -1,2 +1,4 @@
+pub mod audit_log;
 pub mod id_generator;
 pub mod metadata;
+pub mod pod_registry;
@@ -0,0 +1,117 @@
+use anyhow::{Context, Error};
+use rabbit_trading_core::persistent_kv::common::store::PersistentKVStoreTrait;
+
+use crate::model::pod::metadata::PodMetadata;
+
+// Keeps the metadata of every pod in a persistent kv store so that pods survive restarts
+pub struct PodRegistry {
+    store: Box<dyn PersistentKVStoreTrait>,
+}
+
+impl PodRegistry {
+    const REGISTRY_KEY: &'static str = "pod_registry.json";
+
+    pub fn new(store: Box<dyn PersistentKVStoreTrait>) -> Self {
+        PodRegistry { store }
+    }
+
+    // Empty only when nothing was saved yet, a registry that cannot be read is an error so that it
+    // is not overwritten and the ids in it are not given out again
+    pub async fn load(&self) -> Result<Vec<PodMetadata>, Error> {
+        if !self.store.exists(Self::REGISTRY_KEY.to_owned()).await? {
+            return Result::Ok(vec![]);
+        }
+        let bytes = self
+            .store
+            .read(Self::REGISTRY_KEY.to_owned())
+            .await
+            .context("Error when reading the pod registry")?;
+        serde_json::from_slice(&bytes).context("Error when parsing the pod registry")
+    }
+
+    pub async fn save(&self, metadata_list: &[PodMetadata]) -> Result<(), Error> {
+        let bytes = serde_json::to_vec(metadata_list)?;
+        self.store
+            .write(Self::REGISTRY_KEY.to_owned(), bytes)
+            .await
+            .map(|_| ())
+    }
+
+    // The first id of the auto increment generator, after every numeric id ever registered
+    pub fn get_next_numeric_id(metadata_list: &[PodMetadata]) -> i64 {
+        metadata_list
+            .iter()
+            .filter_map(|metadata| metadata.id.parse::<i64>().ok())
+            .max()
+            .map_or(1, |max_id| max_id + 1)
+    }
+}
+
+#[cfg(test)]
+mod test_pod_registry {
+    use rabbit_trading_core::{
+        model::common::types::ConfigMap,
+        persistent_kv::{
+            common::store::PersistentKVStoreTrait, fs::store::FileSystemKVStore,
+            memory::store::MemoryKVStore,
+        },
+    };
+    use tempfile::tempdir;
+
+    use super::PodRegistry;
+    use crate::model::pod::metadata::{PodDesiredState, PodMetadata};
+
+    fn get_pod_metadata(id: &str, desired_state: PodDesiredState) -> PodMetadata {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "created_at": 1717171717,
+            "config": {
+                "name": "test_pod",
+                "broker_list": [],
+                "persistent_kv_store": {"identifier": "MemoryKVStore", "config_map": {}},
+                "strategy": {"identifier": "ExamplePrintLivePriceStrategy", "config_map": {}},
+                "metrics_registry": {"identifier": "NoOpMetricRegistryFactory", "config_map": {}},
+                "event_listener_list": [],
+            },
+            "desired_state": desired_state,
+            "state": "Created",
+            "last_error": null,
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_pod_registry() {
+        let pod_registry = PodRegistry::new(Box::new(MemoryKVStore::new(ConfigMap::new()).await));
+        assert!(pod_registry.load().await.unwrap().is_empty());
+
+        pod_registry
+            .save(&[
+                get_pod_metadata("1", PodDesiredState::Stopped),
+                get_pod_metadata("7", PodDesiredState::Running),
+            ])
+            .await
+            .unwrap();
+        let metadata_list = pod_registry.load().await.unwrap();
+        assert_eq!(2, metadata_list.len());
+        assert_eq!("7", metadata_list[1].id);
+        assert_eq!(PodDesiredState::Running, metadata_list[1].desired_state);
+        assert_eq!(8, PodRegistry::get_next_numeric_id(&metadata_list));
+        assert_eq!(1, PodRegistry::get_next_numeric_id(&[]));
+    }
+
+    #[tokio::test]
+    async fn test_pod_registry_unreadable() {
+        let temp_dir = tempdir().unwrap();
+        // the key exists but cannot be read as a file
+        std::fs::create_dir(temp_dir.path().join(PodRegistry::REGISTRY_KEY)).unwrap();
+        let pod_registry = PodRegistry::new(Box::new(
+            FileSystemKVStore::new(ConfigMap::from([(
+                FileSystemKVStore::CONFIG_KEY_BASE_PATH.to_owned(),
+                temp_dir.path().to_str().unwrap().to_owned(),
+            )]))
+            .await,
+        ));
+        assert!(pod_registry.load().await.is_err());
+    }
+}
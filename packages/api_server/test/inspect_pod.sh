@@ -1,11 +1,5 @@
 #!/bin/bash
 
-curl 'http://127.0.0.1:7000/pod/inspect' \
-  -H 'content-type: application/json; charset=UTF-8' \
-  -X POST \
-  --data-raw $'
-    {
-      "pod_id": "1"
-    }
-  ' \
+curl 'http://127.0.0.1:7000/pod/1' \
+  -X GET \
   --compressed
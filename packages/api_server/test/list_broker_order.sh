@@ -0,0 +1,5 @@
+#!/bin/bash
+
+curl 'http://127.0.0.1:7000/pod/1/broker/order' \
+  -X GET \
+  --compressed
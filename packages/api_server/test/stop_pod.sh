@@ -1,11 +1,5 @@
 #!/bin/bash
 
-curl 'http://127.0.0.1:7000/pod' \
-  -H 'content-type: application/json; charset=UTF-8' \
+curl 'http://127.0.0.1:7000/pod/1' \
   -X DELETE \
-  --data-raw $'
-    {
-      "pod_id": "1"
-    }
-  ' \
   --compressed
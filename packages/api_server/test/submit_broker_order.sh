@@ -0,0 +1,25 @@
+#!/bin/bash
+
+curl 'http://127.0.0.1:7000/pod/1/broker/order' \
+  -H 'content-type: application/json; charset=UTF-8' \
+  -X POST \
+  --data-raw $'
+    {
+      "order": {
+        "symbol": {
+          "market": "US",
+          "identifier": "AAPL"
+        },
+        "quantity": "1",
+        "direction": "Buy",
+        "regular_trading_time": "OnlyRegularTradingTime",
+        "expire": "Day",
+        "price": {
+          "LimitOrder": {
+            "price": "100"
+          }
+        }
+      }
+    }
+  ' \
+  --compressed
@@ -0,0 +1,64 @@
+use anyhow::{anyhow, Context, Error};
+use reqwest::{Client, Method};
+use serde_json::Value;
+
+use super::auth::ClientAuth;
+
+pub struct ApiClient {
+    client: Client,
+    base_url: String,
+    auth: ClientAuth,
+}
+
+impl ApiClient {
+    pub fn new(base_url: &str, auth: ClientAuth, client: Client) -> Self {
+        ApiClient {
+            client,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            auth,
+        }
+    }
+
+    // Responses are kept as JSON values so that the JSON output is exactly what the server sent
+    pub async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        request: Option<&Value>,
+    ) -> Result<Value, Error> {
+        let url = format!("{}{}", self.base_url, path);
+        let mut request_builder = self.client.request(method.clone(), &url);
+        if let Option::Some(request) = request {
+            request_builder = request_builder.json(request);
+        }
+        let response = self
+            .auth
+            .apply(request_builder)
+            .send()
+            .await
+            .with_context(|| format!("Error when sending {} {}", method, url))?;
+
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            // the server answers with `{code, message}`, a proxy in between might not
+            let body = match serde_json::from_str::<Value>(&body) {
+                Result::Ok(error) if error["code"].is_string() => format!(
+                    "{} {}",
+                    error["code"].as_str().unwrap_or_default(),
+                    error["message"].as_str().unwrap_or_default()
+                ),
+                _ => body,
+            };
+            return Result::Err(anyhow!(
+                "API_SERVER_ERROR {} {}, status: {}, body: {}",
+                method,
+                path,
+                status,
+                body
+            ));
+        }
+        serde_json::from_str(&body)
+            .with_context(|| format!("PARSING_ERROR Error when parsing response of {}", path))
+    }
+}
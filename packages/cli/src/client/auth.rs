@@ -0,0 +1,87 @@
+use anyhow::{anyhow, Error};
+use reqwest::RequestBuilder;
+use std::env;
+
+// How the client authenticates to the API server, read from the same env vars as the server
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClientAuth {
+    NoAuth,
+    Basic { username: String, password: String },
+    Bearer { token: String },
+}
+
+impl ClientAuth {
+    pub const ENV_VAR_AUTH: &'static str = "API_SERVER_AUTH";
+    pub const ENV_VAR_USERNAME: &'static str = "API_SERVER_USERNAME";
+    pub const ENV_VAR_PASSWORD: &'static str = "API_SERVER_PASSWORD";
+    pub const ENV_VAR_TOKEN: &'static str = "API_SERVER_TOKEN";
+
+    pub fn from_env() -> Result<Self, Error> {
+        Self::from_env_var(|name| env::var(name).ok())
+    }
+
+    pub fn from_env_var(get_env_var: impl Fn(&str) -> Option<String>) -> Result<Self, Error> {
+        let get_required_env_var =
+            |name: &str| get_env_var(name).ok_or(anyhow!("MISSING_ENV_VAR {}", name));
+        let auth_kind = get_env_var(Self::ENV_VAR_AUTH).unwrap_or("noauth".to_owned());
+        match auth_kind.to_lowercase().as_str() {
+            "noauth" => Result::Ok(ClientAuth::NoAuth),
+            "basic" | "basicauth" => Result::Ok(ClientAuth::Basic {
+                username: get_required_env_var(Self::ENV_VAR_USERNAME)?,
+                password: get_required_env_var(Self::ENV_VAR_PASSWORD)?,
+            }),
+            "bearer" | "bearerauth" => Result::Ok(ClientAuth::Bearer {
+                token: get_required_env_var(Self::ENV_VAR_TOKEN)?,
+            }),
+            unknown_auth_kind => Result::Err(anyhow!("UNKNOWN_AUTH kind: {}", unknown_auth_kind)),
+        }
+    }
+
+    pub fn apply(&self, request_builder: RequestBuilder) -> RequestBuilder {
+        match self {
+            ClientAuth::NoAuth => request_builder,
+            ClientAuth::Basic { username, password } => {
+                request_builder.basic_auth(username, Option::Some(password))
+            }
+            ClientAuth::Bearer { token } => request_builder.bearer_auth(token),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_client_auth {
+    use std::collections::HashMap;
+
+    use super::ClientAuth;
+
+    #[test]
+    fn test_from_env_var() {
+        let env_var_map = HashMap::from([
+            (ClientAuth::ENV_VAR_AUTH, "Bearer"),
+            (ClientAuth::ENV_VAR_TOKEN, "token_1"),
+        ]);
+        assert_eq!(
+            ClientAuth::Bearer {
+                token: "token_1".to_owned()
+            },
+            ClientAuth::from_env_var(|name| env_var_map.get(name).map(|value| value.to_string()))
+                .unwrap()
+        );
+        assert_eq!(
+            ClientAuth::NoAuth,
+            ClientAuth::from_env_var(|_| Option::None).unwrap()
+        );
+
+        let env_var_map = HashMap::from([(ClientAuth::ENV_VAR_AUTH, "basic")]);
+        let err =
+            ClientAuth::from_env_var(|name| env_var_map.get(name).map(|value| value.to_string()))
+                .unwrap_err();
+        assert_eq!("MISSING_ENV_VAR API_SERVER_USERNAME", err.to_string());
+
+        let env_var_map = HashMap::from([(ClientAuth::ENV_VAR_AUTH, "kerberos")]);
+        assert!(ClientAuth::from_env_var(|name| env_var_map
+            .get(name)
+            .map(|value| value.to_string()))
+        .is_err());
+    }
+}
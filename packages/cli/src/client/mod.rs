@@ -0,0 +1,4 @@
+pub mod api_client;
+pub mod auth;
+pub mod model;
+pub mod tls;
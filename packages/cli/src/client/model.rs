@@ -0,0 +1,46 @@
+use rabbit_trading_core::{
+    model::{common::timestamp::Timestamp, config::pod::PodConfig},
+    pod::state::PodState,
+};
+use serde::Deserialize;
+use time::format_description::well_known::Rfc3339;
+
+// The fields of the pod metadata of the API server that are shown in tables
+#[derive(Deserialize)]
+pub struct PodSummary {
+    pub id: String,
+    pub created_at: u64,
+    pub config: PodConfig,
+    pub desired_state: String,
+    pub state: PodState,
+    pub last_error: Option<String>,
+    #[serde(default)]
+    pub restart: PodRestartSummary,
+}
+
+#[derive(Default, Deserialize)]
+pub struct PodRestartSummary {
+    pub restart_count: u32,
+}
+
+impl PodSummary {
+    pub fn get_state_name(&self) -> String {
+        match &self.state {
+            PodState::Failed { .. } => "Failed".to_owned(),
+            state => format!("{:?}", state),
+        }
+    }
+
+    pub fn get_created_at(&self) -> String {
+        format_timestamp(Timestamp::from_secs(self.created_at))
+    }
+}
+
+// RFC 3339 in UTC, falls back to nanoseconds since epoch
+pub fn format_timestamp(timestamp: Timestamp) -> String {
+    timestamp
+        .to_offset_date_time()
+        .ok()
+        .and_then(|date_time| date_time.format(&Rfc3339).ok())
+        .unwrap_or(timestamp.to_string())
+}
@@ -0,0 +1,122 @@
+use anyhow::{anyhow, Context, Error};
+use reqwest::{Certificate, Client, Identity};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+// Which CA the client trusts for an API server behind TLS, and the certificate it presents when
+// the server asks for mutual TLS
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ClientTls {
+    pub ca_cert_path: Option<PathBuf>,
+    pub client_cert_path: Option<PathBuf>,
+    pub client_key_path: Option<PathBuf>,
+}
+
+impl ClientTls {
+    pub const ENV_VAR_CA_CERT_PATH: &'static str = "API_SERVER_CA_CERT_PATH";
+    pub const ENV_VAR_CLIENT_CERT_PATH: &'static str = "API_SERVER_CLIENT_CERT_PATH";
+    pub const ENV_VAR_CLIENT_KEY_PATH: &'static str = "API_SERVER_CLIENT_KEY_PATH";
+
+    pub fn from_env() -> Result<Self, Error> {
+        Self::from_env_var(|name| env::var(name).ok())
+    }
+
+    pub fn from_env_var(get_env_var: impl Fn(&str) -> Option<String>) -> Result<Self, Error> {
+        // left empty in example.env
+        let get_env_var = |name: &str| get_env_var(name).filter(|value| !value.is_empty());
+        let client_tls = ClientTls {
+            ca_cert_path: get_env_var(Self::ENV_VAR_CA_CERT_PATH).map(PathBuf::from),
+            client_cert_path: get_env_var(Self::ENV_VAR_CLIENT_CERT_PATH).map(PathBuf::from),
+            client_key_path: get_env_var(Self::ENV_VAR_CLIENT_KEY_PATH).map(PathBuf::from),
+        };
+        match (&client_tls.client_cert_path, &client_tls.client_key_path) {
+            (Option::Some(_), Option::None) => {
+                Result::Err(anyhow!("MISSING_ENV_VAR {}", Self::ENV_VAR_CLIENT_KEY_PATH))
+            }
+            (Option::None, Option::Some(_)) => Result::Err(anyhow!(
+                "MISSING_ENV_VAR {}",
+                Self::ENV_VAR_CLIENT_CERT_PATH
+            )),
+            _ => Result::Ok(client_tls),
+        }
+    }
+
+    // The system roots are still trusted, the CA is added for servers with a private certificate
+    pub fn build_client(&self) -> Result<Client, Error> {
+        let mut client_builder = Client::builder();
+        if let Option::Some(ca_cert_path) = &self.ca_cert_path {
+            let ca_cert = Certificate::from_pem(&read_pem_file(ca_cert_path)?)
+                .with_context(|| format!("PARSING_ERROR {}", ca_cert_path.display()))?;
+            client_builder = client_builder.add_root_certificate(ca_cert);
+        }
+        if let (Option::Some(client_cert_path), Option::Some(client_key_path)) =
+            (&self.client_cert_path, &self.client_key_path)
+        {
+            // the key has to be in PKCS#8 format
+            let identity = Identity::from_pkcs8_pem(
+                &read_pem_file(client_cert_path)?,
+                &read_pem_file(client_key_path)?,
+            )
+            .with_context(|| {
+                format!(
+                    "PARSING_ERROR {} with {}",
+                    client_cert_path.display(),
+                    client_key_path.display()
+                )
+            })?;
+            client_builder = client_builder.identity(identity);
+        }
+        client_builder
+            .build()
+            .context("Error when building the HTTP client")
+    }
+}
+
+fn read_pem_file(path: &Path) -> Result<Vec<u8>, Error> {
+    fs::read(path).with_context(|| format!("Error when reading the PEM file {}", path.display()))
+}
+
+#[cfg(test)]
+mod test_client_tls {
+    use std::{collections::HashMap, path::PathBuf};
+
+    use super::ClientTls;
+
+    #[test]
+    fn test_from_env_var() {
+        assert_eq!(
+            ClientTls::default(),
+            ClientTls::from_env_var(|_| Option::None).unwrap()
+        );
+        assert_eq!(
+            ClientTls::default(),
+            ClientTls::from_env_var(|_| Option::Some(String::new())).unwrap()
+        );
+
+        let env_var_map = HashMap::from([
+            (ClientTls::ENV_VAR_CA_CERT_PATH, "ca.pem"),
+            (ClientTls::ENV_VAR_CLIENT_CERT_PATH, "client.pem"),
+            (ClientTls::ENV_VAR_CLIENT_KEY_PATH, "client.key"),
+        ]);
+        assert_eq!(
+            ClientTls {
+                ca_cert_path: Option::Some(PathBuf::from("ca.pem")),
+                client_cert_path: Option::Some(PathBuf::from("client.pem")),
+                client_key_path: Option::Some(PathBuf::from("client.key")),
+            },
+            ClientTls::from_env_var(|name| env_var_map.get(name).map(|value| value.to_string()))
+                .unwrap()
+        );
+
+        let env_var_map = HashMap::from([(ClientTls::ENV_VAR_CLIENT_CERT_PATH, "client.pem")]);
+        let err =
+            ClientTls::from_env_var(|name| env_var_map.get(name).map(|value| value.to_string()))
+                .unwrap_err();
+        assert_eq!(
+            "MISSING_ENV_VAR API_SERVER_CLIENT_KEY_PATH",
+            err.to_string()
+        );
+    }
+}
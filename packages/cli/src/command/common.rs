@@ -0,0 +1,79 @@
+use anyhow::{anyhow, Error};
+use clap::Args;
+use rabbit_trading_core::{
+    broker::{
+        common::broker::{BrokerTrait, EmptyBrokerInterceptorFactory},
+        initializer::get_broker_instance,
+    },
+    model::{
+        common::types::ConfigMap,
+        config::{loader::load_pod_config, pod::PodConfig},
+    },
+    pod::validator::validate_pod_config,
+    utils::clock::{
+        common_trait::ClockTrait, initializer::get_clock_instance, system::SystemClock,
+    },
+};
+use std::{
+    path::{Path, PathBuf},
+    sync::{atomic::AtomicBool, Arc},
+};
+
+#[derive(Args)]
+pub struct PodConfigArgs {
+    #[arg(
+        short = 'f',
+        long = "file",
+        help = "Path of the pod config, in JSON, YAML or TOML"
+    )]
+    pub config_path: PathBuf,
+}
+
+#[derive(Args)]
+pub struct BrokerArgs {
+    #[command(flatten)]
+    pub pod_config: PodConfigArgs,
+    #[arg(
+        short = 'b',
+        long = "broker",
+        help = "Identifier of the broker in broker_list, defaults to the first one"
+    )]
+    pub broker_identifier: Option<String>,
+}
+
+pub fn load_valid_pod_config(path: &Path) -> Result<PodConfig, Error> {
+    let pod_config = load_pod_config(path)?;
+    validate_pod_config(&pod_config)?;
+    Result::Ok(pod_config)
+}
+
+// Creates a broker of the pod config without any interceptor, for one-off queries
+pub fn initialize_broker(args: &BrokerArgs) -> Result<Box<dyn BrokerTrait>, Error> {
+    let pod_config = load_valid_pod_config(&args.pod_config.config_path)?;
+    let broker_config = match &args.broker_identifier {
+        Option::Some(broker_identifier) => pod_config
+            .broker_list
+            .iter()
+            .find(|broker_config| &broker_config.identifier == broker_identifier),
+        Option::None => pod_config.broker_list.first(),
+    }
+    .ok_or(anyhow!(
+        "ILLEGAL_BROKER_ID {} is not in broker_list",
+        args.broker_identifier.clone().unwrap_or_default()
+    ))?;
+    let clock: Arc<dyn ClockTrait> = match &pod_config.clock {
+        Option::Some(clock_config) => Arc::from(get_clock_instance(
+            clock_config.identifier.clone(),
+            clock_config.config_map.clone(),
+        )?),
+        Option::None => Arc::new(SystemClock::new(ConfigMap::new())),
+    };
+
+    get_broker_instance(
+        broker_config.identifier.clone(),
+        Box::new(EmptyBrokerInterceptorFactory::new()),
+        broker_config.config_map.clone(),
+        Arc::new(AtomicBool::new(false)),
+        clock,
+    )
+}
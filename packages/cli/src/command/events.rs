@@ -0,0 +1,174 @@
+use anyhow::{anyhow, Context, Error};
+use clap::Args;
+use rabbit_trading_core::{
+    model::config::pod::PodConfig,
+    pod::event::listener::{
+        common::listener::EventListenerTrait, file::listener::FileEventListener,
+    },
+};
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use super::common::{load_valid_pod_config, PodConfigArgs};
+
+#[derive(Args)]
+pub struct EventsArgs {
+    #[command(flatten)]
+    pub pod_config: PodConfigArgs,
+    #[arg(
+        short = 'n',
+        long = "lines",
+        help = "Only print the latest events, all of them are printed by default"
+    )]
+    pub line_count: Option<usize>,
+    #[arg(long = "follow", help = "Keep printing the events appended to the log")]
+    pub is_follow: bool,
+}
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+fn get_event_log_path(pod_config: &PodConfig) -> Result<PathBuf, Error> {
+    pod_config
+        .event_listener_list
+        .iter()
+        .find(|event_listener_config| {
+            event_listener_config.identifier == FileEventListener::get_identifier()
+        })
+        .and_then(|event_listener_config| {
+            event_listener_config
+                .config_map
+                .get(FileEventListener::CONFIG_KEY_PATH)
+        })
+        .map(PathBuf::from)
+        .ok_or(anyhow!(
+            "MISSING_CONFIG No {} in event_listener_list of pod {}",
+            FileEventListener::get_identifier(),
+            pod_config.name
+        ))
+}
+
+// Reads the lines appended since the last call, a trailing line without newline is kept for the
+// next call as the pod might still be writing it
+fn read_line_list(
+    reader: &mut BufReader<File>,
+    pending: &mut String,
+) -> Result<Vec<String>, Error> {
+    let mut line_list = Vec::new();
+    loop {
+        if reader.read_line(pending)? == 0 {
+            return Result::Ok(line_list);
+        }
+        if pending.ends_with('\n') {
+            line_list.push(pending.trim_end().to_owned());
+            pending.clear();
+        }
+    }
+}
+
+async fn open(path: &Path, is_follow: bool) -> Result<File, Error> {
+    loop {
+        match File::open(path) {
+            Result::Ok(file) => return Result::Ok(file),
+            // the log is created once the pod starts
+            Result::Err(err) if is_follow && err.kind() == std::io::ErrorKind::NotFound => {
+                tokio::time::sleep(POLL_INTERVAL).await
+            }
+            Result::Err(err) => {
+                return Result::Err(err)
+                    .with_context(|| format!("Error when opening event log {}", path.display()))
+            }
+        }
+    }
+}
+
+pub async fn events_command(args: EventsArgs) -> Result<(), Error> {
+    let pod_config = load_valid_pod_config(&args.pod_config.config_path)?;
+    let path = get_event_log_path(&pod_config)?;
+    let mut reader = BufReader::new(open(&path, args.is_follow).await?);
+    let mut pending = String::new();
+
+    let mut line_list: VecDeque<String> = read_line_list(&mut reader, &mut pending)?.into();
+    if let Option::Some(line_count) = args.line_count {
+        while line_list.len() > line_count {
+            line_list.pop_front();
+        }
+    }
+    line_list.iter().for_each(|line| println!("{}", line));
+
+    if !args.is_follow {
+        return Result::Ok(());
+    }
+    loop {
+        tokio::time::sleep(POLL_INTERVAL).await;
+        read_line_list(&mut reader, &mut pending)?
+            .iter()
+            .for_each(|line| println!("{}", line));
+    }
+}
+
+#[cfg(test)]
+mod test_events {
+    use std::{
+        fs::{File, OpenOptions},
+        io::{BufReader, Write},
+    };
+
+    use super::{get_event_log_path, read_line_list};
+
+    #[test]
+    fn test_get_event_log_path() {
+        let mut pod_config = serde_json::from_value(serde_json::json!({
+            "name": "test_pod",
+            "broker_list": [],
+            "persistent_kv_store": {"identifier": "MemoryKVStore", "config_map": {}},
+            "strategy": {"identifier": "ExamplePrintLivePriceStrategy", "config_map": {}},
+            "metrics_registry": {"identifier": "NoOpMetricRegistryFactory", "config_map": {}},
+            "event_listener_list": [
+                {"identifier": "LogEventListener", "config_map": {}},
+                {
+                    "identifier": "FileEventListener",
+                    "config_map": {"event_listener.file.path": "./event/test_pod.jsonl"},
+                },
+            ],
+        }))
+        .unwrap();
+        assert_eq!(
+            "./event/test_pod.jsonl",
+            get_event_log_path(&pod_config).unwrap().to_str().unwrap()
+        );
+
+        pod_config.event_listener_list.pop();
+        assert!(get_event_log_path(&pod_config).is_err());
+    }
+
+    #[test]
+    fn test_read_line_list() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("test_pod.jsonl");
+        let mut writer = File::create(&path).unwrap();
+        let mut reader = BufReader::new(File::open(&path).unwrap());
+        let mut pending = String::new();
+
+        writer.write_all(b"{\"a\":1}\n{\"b\":").unwrap();
+        assert_eq!(
+            vec!["{\"a\":1}".to_owned()],
+            read_line_list(&mut reader, &mut pending).unwrap()
+        );
+
+        // the half written line is printed once it is complete
+        let mut writer = OpenOptions::new().append(true).open(&path).unwrap();
+        writer.write_all(b"2}\n").unwrap();
+        assert_eq!(
+            vec!["{\"b\":2}".to_owned()],
+            read_line_list(&mut reader, &mut pending).unwrap()
+        );
+        assert!(read_line_list(&mut reader, &mut pending)
+            .unwrap()
+            .is_empty());
+    }
+}
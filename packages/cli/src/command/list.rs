@@ -0,0 +1,49 @@
+use anyhow::Error;
+use clap::Args;
+use rabbit_trading_core::pod::component::{get_component_schema_list, ComponentKind};
+
+#[derive(Args)]
+pub struct ListArgs {
+    #[arg(
+        short = 'k',
+        long = "kind",
+        help = "Only list this kind, e.g. broker, strategy or event_listener"
+    )]
+    pub kind: Option<ComponentKind>,
+    #[arg(
+        long = "config-key",
+        help = "Also print the config keys of every component"
+    )]
+    pub with_config_key: bool,
+}
+
+pub fn list_command(args: ListArgs) -> Result<(), Error> {
+    let component_schema_list = get_component_schema_list(args.kind);
+    for kind in ComponentKind::ALL {
+        if args.kind.is_some_and(|expected_kind| expected_kind != kind) {
+            continue;
+        }
+        println!("{:?}", kind);
+        for component_schema in component_schema_list
+            .iter()
+            .filter(|component_schema| component_schema.kind == kind)
+        {
+            println!("  {}", component_schema.identifier);
+            if !args.with_config_key {
+                continue;
+            }
+            for config_key in &component_schema.config_key_list {
+                let requirement = match (config_key.is_required, config_key.default) {
+                    (true, _) => "required".to_owned(),
+                    (false, Option::Some(default)) => format!("default: {}", default),
+                    (false, Option::None) => "optional".to_owned(),
+                };
+                println!(
+                    "    {} ({:?}, {}) {}",
+                    config_key.key, config_key.value_type, requirement, config_key.description
+                );
+            }
+        }
+    }
+    Result::Ok(())
+}
@@ -0,0 +1,8 @@
+pub mod common;
+pub mod events;
+pub mod list;
+pub mod pod;
+pub mod positions;
+pub mod quote;
+pub mod run;
+pub mod validate;
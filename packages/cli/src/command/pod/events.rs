@@ -0,0 +1,108 @@
+use anyhow::Error;
+use clap::Args;
+use rabbit_trading_core::model::common::timestamp::Timestamp;
+use reqwest::Method;
+use serde_json::Value;
+use std::time::Duration;
+
+use super::output::OutputFormat;
+use crate::client::{api_client::ApiClient, model::format_timestamp};
+
+#[derive(Args)]
+pub struct EventsArgs {
+    #[arg(help = "Id of the pod, as shown by `rabbit pod list`")]
+    pub pod_id: String,
+    #[arg(long = "follow", help = "Keep printing the new events of the pod")]
+    pub is_follow: bool,
+}
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+// One event per line, `timestamp broker_id kind detail` in table output and JSON Lines otherwise
+fn format_event(event: &Value, output_format: OutputFormat) -> Result<String, Error> {
+    if output_format == OutputFormat::Json {
+        return Result::Ok(serde_json::to_string(event)?);
+    }
+
+    let (kind, detail) = match event.as_object().and_then(|object| object.iter().next()) {
+        Option::Some((kind, detail)) => (kind.clone(), detail.clone()),
+        Option::None => return Result::Ok(event.to_string()),
+    };
+    let context = &detail["context"];
+    let detail: serde_json::Map<String, Value> = detail
+        .as_object()
+        .map(|object| {
+            object
+                .iter()
+                .filter(|(key, _)| key.as_str() != "context")
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+    let broker_id = match context["broker_id"].as_str() {
+        Option::Some(broker_id) if !broker_id.is_empty() => broker_id,
+        _ => "-",
+    };
+    let timestamp = serde_json::from_value::<Timestamp>(context["timestamp"].clone())
+        .map(format_timestamp)
+        .unwrap_or(context["timestamp"].to_string());
+    Result::Ok(format!(
+        "{}  {}  {}  {}",
+        timestamp,
+        broker_id,
+        kind,
+        Value::Object(detail)
+    ))
+}
+
+pub async fn events_command(
+    api_client: &ApiClient,
+    args: EventsArgs,
+    output_format: OutputFormat,
+) -> Result<(), Error> {
+    let mut offset = 0u64;
+    loop {
+        let response = api_client
+            .send(
+                Method::GET,
+                &format!("/pod/{}/events?offset={}", args.pod_id, offset),
+                Option::None,
+            )
+            .await?;
+        for event in response["event_list"].as_array().into_iter().flatten() {
+            println!("{}", format_event(event, output_format)?);
+        }
+        offset = response["next_offset"].as_u64().unwrap_or(offset);
+
+        if !args.is_follow {
+            return Result::Ok(());
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+mod test_events {
+    use serde_json::json;
+
+    use super::format_event;
+    use crate::command::pod::output::OutputFormat;
+
+    #[test]
+    fn test_format_event() {
+        let event = json!({
+            "PodStateChanged": {
+                "context": {"broker_id": "", "pod_id": "1", "timestamp": 1718000000000000000u64},
+                "state": "Running",
+            }
+        });
+        assert_eq!(
+            "2024-06-10T06:13:20Z  -  PodStateChanged  {\"state\":\"Running\"}",
+            format_event(&event, OutputFormat::Table).unwrap()
+        );
+        assert_eq!(
+            event.to_string(),
+            format_event(&event, OutputFormat::Json).unwrap()
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use anyhow::Error;
+use reqwest::Method;
+
+use super::{
+    output::{format_table, print_json, OutputFormat},
+    PodIdArgs,
+};
+use crate::client::{api_client::ApiClient, model::PodSummary};
+
+pub async fn inspect_command(
+    api_client: &ApiClient,
+    args: PodIdArgs,
+    output_format: OutputFormat,
+) -> Result<(), Error> {
+    let response = api_client
+        .send(Method::GET, &format!("/pod/{}", args.pod_id), Option::None)
+        .await?;
+    if output_format == OutputFormat::Json {
+        return print_json(&response);
+    }
+
+    let pod_summary: PodSummary = serde_json::from_value(response["metadata"].clone())?;
+    let pod_config = &pod_summary.config;
+    let broker_list: Vec<&str> = pod_config
+        .broker_list
+        .iter()
+        .map(|broker_config| broker_config.identifier.as_str())
+        .collect();
+    let event_listener_list: Vec<&str> = pod_config
+        .event_listener_list
+        .iter()
+        .map(|event_listener_config| event_listener_config.identifier.as_str())
+        .collect();
+    let row_list: Vec<Vec<String>> = [
+        ("ID", pod_summary.id.clone()),
+        ("NAME", pod_config.name.clone()),
+        ("STATE", pod_summary.get_state_name()),
+        ("DESIRED", pod_summary.desired_state.clone()),
+        ("RESTARTS", pod_summary.restart.restart_count.to_string()),
+        ("CREATED_AT", pod_summary.get_created_at()),
+        (
+            "LAST_ERROR",
+            pod_summary.last_error.clone().unwrap_or_default(),
+        ),
+        ("BROKERS", broker_list.join(", ")),
+        ("STRATEGY", pod_config.strategy.identifier.clone()),
+        ("EVENT_LISTENERS", event_listener_list.join(", ")),
+    ]
+    .into_iter()
+    .map(|(field, value)| vec![field.to_owned(), value])
+    .collect();
+    println!("{}", format_table(&["FIELD", "VALUE"], &row_list));
+    Result::Ok(())
+}
@@ -0,0 +1,21 @@
+use anyhow::Error;
+use reqwest::Method;
+
+use super::output::{print_json, print_pod_table, OutputFormat};
+use crate::client::api_client::ApiClient;
+
+pub async fn list_command(
+    api_client: &ApiClient,
+    output_format: OutputFormat,
+) -> Result<(), Error> {
+    let response = api_client.send(Method::GET, "/pod", Option::None).await?;
+    match output_format {
+        OutputFormat::Table => print_pod_table(
+            response["pod_list"]
+                .as_array()
+                .map(|pod_list| pod_list.as_slice())
+                .unwrap_or_default(),
+        ),
+        OutputFormat::Json => print_json(&response),
+    }
+}
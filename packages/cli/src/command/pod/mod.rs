@@ -0,0 +1,86 @@
+use anyhow::Error;
+use clap::{Args, Subcommand};
+
+use self::{
+    events::{events_command, EventsArgs},
+    inspect::inspect_command,
+    list::list_command,
+    output::OutputFormat,
+    start::{start_command, StartArgs},
+    stop::stop_command,
+};
+use crate::client::{api_client::ApiClient, auth::ClientAuth, tls::ClientTls};
+
+pub mod events;
+pub mod inspect;
+pub mod list;
+pub mod output;
+pub mod start;
+pub mod stop;
+
+const DEFAULT_SERVER_URL: &'static str = "http://127.0.0.1:7000";
+
+#[derive(Args)]
+pub struct PodArgs {
+    #[arg(
+        long = "server",
+        env = "API_SERVER_URL",
+        default_value = DEFAULT_SERVER_URL,
+        global = true,
+        help = "URL of the API server, the auth and TLS env vars are listed in example.env"
+    )]
+    pub server_url: String,
+    #[arg(
+        short = 'o',
+        long = "output",
+        value_enum,
+        default_value_t = OutputFormat::Table,
+        global = true
+    )]
+    pub output_format: OutputFormat,
+    #[command(subcommand)]
+    pub command: PodCommand,
+}
+
+#[derive(Subcommand)]
+pub enum PodCommand {
+    #[command(about = "Start a pod on the API server from a config file")]
+    Start(StartArgs),
+    #[command(about = "List the pods of the API server")]
+    List,
+    #[command(about = "Show the status and config of a pod")]
+    Inspect(PodIdArgs),
+    #[command(about = "Stop a pod, its open orders are handled by its shutdown policy")]
+    Stop(PodIdArgs),
+    #[command(about = "Print the events of a pod")]
+    Events(EventsArgs),
+}
+
+#[derive(Args)]
+pub struct PodIdArgs {
+    #[arg(help = "Id of the pod, as shown by `rabbit pod list`")]
+    pub pod_id: String,
+}
+
+pub async fn pod_command(args: PodArgs) -> Result<(), Error> {
+    let api_client = ApiClient::new(
+        &args.server_url,
+        ClientAuth::from_env()?,
+        ClientTls::from_env()?.build_client()?,
+    );
+    match args.command {
+        PodCommand::Start(start_args) => {
+            start_command(&api_client, start_args, args.output_format).await
+        }
+        PodCommand::List => list_command(&api_client, args.output_format).await,
+        PodCommand::Inspect(pod_id_args) => {
+            inspect_command(&api_client, pod_id_args, args.output_format).await
+        }
+        PodCommand::Stop(pod_id_args) => {
+            stop_command(&api_client, pod_id_args, args.output_format).await
+        }
+        PodCommand::Events(events_args) => {
+            events_command(&api_client, events_args, args.output_format).await
+        }
+    }
+}
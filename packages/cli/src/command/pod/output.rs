@@ -0,0 +1,93 @@
+use anyhow::Error;
+use clap::ValueEnum;
+use serde_json::Value;
+
+use crate::client::model::PodSummary;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    Table,
+    Json,
+}
+
+// Left aligned columns separated by two spaces, the last column is not padded
+pub fn format_table(header_list: &[&str], row_list: &[Vec<String>]) -> String {
+    let mut width_list: Vec<usize> = header_list.iter().map(|header| header.len()).collect();
+    for row in row_list {
+        for (index, cell) in row.iter().enumerate() {
+            width_list[index] = width_list[index].max(cell.chars().count());
+        }
+    }
+
+    let header_row: Vec<String> = header_list
+        .iter()
+        .map(|header| header.to_string())
+        .collect();
+    std::iter::once(&header_row)
+        .chain(row_list)
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .map(|(index, cell)| match index + 1 == row.len() {
+                    true => cell.clone(),
+                    false => format!("{:width$}", cell, width = width_list[index]),
+                })
+                .collect::<Vec<String>>()
+                .join("  ")
+                .trim_end()
+                .to_owned()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn print_json(value: &Value) -> Result<(), Error> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Result::Ok(())
+}
+
+pub fn print_pod_table(pod_list: &[Value]) -> Result<(), Error> {
+    const HEADER_LIST: [&str; 7] = [
+        "ID",
+        "NAME",
+        "STATE",
+        "DESIRED",
+        "RESTARTS",
+        "CREATED_AT",
+        "LAST_ERROR",
+    ];
+    let row_list = pod_list
+        .iter()
+        .map(|pod| {
+            let pod_summary: PodSummary = serde_json::from_value(pod.clone())?;
+            Result::Ok(vec![
+                pod_summary.id.clone(),
+                pod_summary.config.name.clone(),
+                pod_summary.get_state_name(),
+                pod_summary.desired_state.clone(),
+                pod_summary.restart.restart_count.to_string(),
+                pod_summary.get_created_at(),
+                pod_summary.last_error.clone().unwrap_or_default(),
+            ])
+        })
+        .collect::<Result<Vec<Vec<String>>, Error>>()?;
+    println!("{}", format_table(&HEADER_LIST, &row_list));
+    Result::Ok(())
+}
+
+#[cfg(test)]
+mod test_output {
+    use super::format_table;
+
+    #[test]
+    fn test_format_table() {
+        let table = format_table(
+            &["ID", "NAME", "STATE"],
+            &[
+                vec!["1".to_owned(), "test_pod".to_owned(), "Running".to_owned()],
+                vec!["12".to_owned(), "pod".to_owned(), "".to_owned()],
+            ],
+        );
+        assert_eq!("ID  NAME      STATE\n1   test_pod  Running\n12  pod", table);
+    }
+}
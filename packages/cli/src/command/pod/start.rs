@@ -0,0 +1,34 @@
+use anyhow::Error;
+use clap::Args;
+use rabbit_trading_core::model::config::loader::load_pod_config;
+use reqwest::Method;
+use serde_json::json;
+
+use super::output::{print_json, print_pod_table, OutputFormat};
+use crate::{client::api_client::ApiClient, command::common::PodConfigArgs};
+
+#[derive(Args)]
+pub struct StartArgs {
+    #[command(flatten)]
+    pub pod_config: PodConfigArgs,
+}
+
+// The config is interpolated with the env vars of the client, the server validates it
+pub async fn start_command(
+    api_client: &ApiClient,
+    args: StartArgs,
+    output_format: OutputFormat,
+) -> Result<(), Error> {
+    let pod_config = load_pod_config(&args.pod_config.config_path)?;
+    let response = api_client
+        .send(
+            Method::POST,
+            "/pod",
+            Option::Some(&json!({ "config": pod_config })),
+        )
+        .await?;
+    match output_format {
+        OutputFormat::Table => print_pod_table(&[response["metadata"].clone()]),
+        OutputFormat::Json => print_json(&response),
+    }
+}
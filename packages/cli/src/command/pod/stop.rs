@@ -0,0 +1,45 @@
+use anyhow::Error;
+use reqwest::Method;
+
+use super::{
+    output::{print_json, print_pod_table, OutputFormat},
+    PodIdArgs,
+};
+use crate::client::api_client::ApiClient;
+
+pub async fn stop_command(
+    api_client: &ApiClient,
+    args: PodIdArgs,
+    output_format: OutputFormat,
+) -> Result<(), Error> {
+    let response = api_client
+        .send(
+            Method::DELETE,
+            &format!("/pod/{}", args.pod_id),
+            Option::None,
+        )
+        .await?;
+    if output_format == OutputFormat::Json {
+        return print_json(&response);
+    }
+
+    print_pod_table(&[response["metadata"].clone()])?;
+    let shutdown_report = &response["shutdown_report"];
+    println!();
+    println!(
+        "Strategy stopped in time: {}",
+        shutdown_report["is_strategy_stopped"]
+    );
+    for (name, key) in [
+        ("Cancelled orders", "cancelled_order_id_list"),
+        ("Flatten orders", "flatten_order_id_list"),
+        ("Errors", "error_list"),
+    ] {
+        if let Option::Some(value_list) = shutdown_report[key].as_array() {
+            if !value_list.is_empty() {
+                println!("{}: {}", name, serde_json::to_string(value_list)?);
+            }
+        }
+    }
+    Result::Ok(())
+}
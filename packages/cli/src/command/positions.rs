@@ -0,0 +1,17 @@
+use anyhow::Error;
+use clap::Args;
+
+use super::common::{initialize_broker, BrokerArgs};
+
+#[derive(Args)]
+pub struct PositionsArgs {
+    #[command(flatten)]
+    pub broker: BrokerArgs,
+}
+
+pub async fn positions_command(args: PositionsArgs) -> Result<(), Error> {
+    let transaction = initialize_broker(&args.broker)?.create_transaction()?;
+    let position_list = transaction.positions().await?;
+    println!("{}", serde_json::to_string_pretty(&position_list)?);
+    Result::Ok(())
+}
@@ -0,0 +1,35 @@
+use anyhow::Error;
+use clap::Args;
+use rabbit_trading_core::model::trading::{
+    quote::{QueryInfoRequest, QuoteKind},
+    symbol::Symbol,
+};
+
+use super::common::{initialize_broker, BrokerArgs};
+
+#[derive(Args)]
+pub struct QuoteArgs {
+    #[command(flatten)]
+    pub broker: BrokerArgs,
+    #[arg(help = "Symbol to query, e.g. AAPL.US or 0700.HK")]
+    pub symbol: Symbol,
+    #[arg(
+        long = "depth",
+        help = "Query the order book instead of the real time quote"
+    )]
+    pub is_depth: bool,
+}
+
+pub async fn quote_command(args: QuoteArgs) -> Result<(), Error> {
+    let info = initialize_broker(&args.broker)?.create_info();
+    let request = QueryInfoRequest {
+        symbol: args.symbol,
+        kind: QuoteKind::Stock,
+    };
+    let output = match args.is_depth {
+        true => serde_json::to_string_pretty(&info.query_depth(request).await?)?,
+        false => serde_json::to_string_pretty(&info.query_real_time_info(request).await?)?,
+    };
+    println!("{}", output);
+    Result::Ok(())
+}
@@ -0,0 +1,42 @@
+use anyhow::{anyhow, Error};
+use clap::Args;
+use rabbit_trading_core::pod::{state::PodState, Pod};
+
+use super::common::{load_valid_pod_config, PodConfigArgs};
+
+#[derive(Args)]
+pub struct RunArgs {
+    #[command(flatten)]
+    pub pod_config: PodConfigArgs,
+    #[arg(
+        long = "pod-id",
+        help = "Id of the pod, defaults to the name in the pod config"
+    )]
+    pub pod_id: Option<String>,
+}
+
+// Runs the pod once, its restart policy and schedule are only applied by the API server
+pub async fn run_command(args: RunArgs) -> Result<(), Error> {
+    let pod_config = load_valid_pod_config(&args.pod_config.config_path)?;
+    let pod_id = args.pod_id.unwrap_or(pod_config.name.clone());
+    let pod = Pod::new(pod_config, pod_id)?;
+
+    pod.start().await?;
+    log::info!("Pod started, press Ctrl-C to stop it");
+    tokio::select! {
+        _ = pod.join() => log::info!("Pod ended"),
+        result = tokio::signal::ctrl_c() => {
+            result?;
+            log::warn!("Stopping the pod");
+            let report = pod.stop().await?;
+            for error in &report.error_list {
+                log::error!("{}", error);
+            }
+        },
+    }
+
+    match pod.get_status().state {
+        PodState::Failed { reason } => Result::Err(anyhow!("POD_FAILED {}", reason)),
+        _ => Result::Ok(()),
+    }
+}
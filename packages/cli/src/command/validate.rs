@@ -0,0 +1,33 @@
+use anyhow::{anyhow, Error};
+use clap::Args;
+use rabbit_trading_core::{
+    model::config::loader::load_pod_config, pod::validator::validate_pod_config,
+};
+
+use super::common::PodConfigArgs;
+
+#[derive(Args)]
+pub struct ValidateArgs {
+    #[command(flatten)]
+    pub pod_config: PodConfigArgs,
+}
+
+pub fn validate_command(args: ValidateArgs) -> Result<(), Error> {
+    let pod_config = load_pod_config(&args.pod_config.config_path)?;
+    match validate_pod_config(&pod_config) {
+        Result::Ok(_) => {
+            println!("{} is valid", args.pod_config.config_path.display());
+            Result::Ok(())
+        }
+        Result::Err(err) => {
+            err.error_list
+                .iter()
+                .for_each(|error| println!("{}", error));
+            Result::Err(anyhow!(
+                "INVALID_POD_CONFIG {} error(s) in {}",
+                err.error_list.len(),
+                args.pod_config.config_path.display()
+            ))
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use anyhow::Error;
+use clap::{Parser, Subcommand};
+use dotenv::dotenv;
+use simple_logger::SimpleLogger;
+use std::process::ExitCode;
+
+use crate::command::{
+    events::{events_command, EventsArgs},
+    list::{list_command, ListArgs},
+    pod::{pod_command, PodArgs},
+    positions::{positions_command, PositionsArgs},
+    quote::{quote_command, QuoteArgs},
+    run::{run_command, RunArgs},
+    validate::{validate_command, ValidateArgs},
+};
+
+mod client;
+mod command;
+
+const LOG_LEVEL: log::LevelFilter = log::LevelFilter::Info;
+// keeps the output of the query commands free of broker logs
+const QUERY_LOG_LEVEL: log::LevelFilter = log::LevelFilter::Warn;
+
+#[derive(Parser)]
+#[command(
+    name = "rabbit",
+    version,
+    about = "Runs and inspects a single pod from a config file, or manages the pods of an API server"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    #[command(about = "Run a pod in the foreground until its strategy ends or Ctrl-C is pressed")]
+    Run(RunArgs),
+    #[command(about = "Validate a pod config without running it")]
+    Validate(ValidateArgs),
+    #[command(about = "List the components compiled in through cargo features")]
+    List(ListArgs),
+    #[command(about = "Query the quote of a symbol through a broker of a pod config")]
+    Quote(QuoteArgs),
+    #[command(about = "Query the positions through a broker of a pod config")]
+    Positions(PositionsArgs),
+    #[command(about = "Print the event log written by the FileEventListener of a pod config")]
+    Events(EventsArgs),
+    #[command(about = "Manage the pods of an API server")]
+    Pod(PodArgs),
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    dotenv().ok();
+    let cli = Cli::parse();
+    let log_level = match cli.command {
+        Command::Run(_) => LOG_LEVEL,
+        _ => QUERY_LOG_LEVEL,
+    };
+    SimpleLogger::new()
+        .env()
+        .with_level(log_level)
+        .init()
+        .unwrap();
+
+    match execute(cli.command).await {
+        Result::Ok(_) => ExitCode::SUCCESS,
+        Result::Err(err) => {
+            eprintln!("{:#}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn execute(command: Command) -> Result<(), Error> {
+    match command {
+        Command::Run(args) => run_command(args).await,
+        Command::Validate(args) => validate_command(args),
+        Command::List(args) => list_command(args),
+        Command::Quote(args) => quote_command(args).await,
+        Command::Positions(args) => positions_command(args).await,
+        Command::Events(args) => events_command(args).await,
+        Command::Pod(args) => pod_command(args).await,
+    }
+}
@@ -1,29 +1,43 @@
+use anyhow::Error;
 use std::sync::{atomic::AtomicBool, Arc};
 
 use super::{
+    fee::FeeModelTrait,
     heartbeat::HeartbeatTrait,
     info::{InfoInterceptorTrait, InfoTrait},
     subscription::{SubscriptionInterceptorTrait, SubscriptionTrait},
     transaction::{TransactionInterceptorTrait, TransactionTrait},
 };
-use crate::model::common::types::ConfigMap;
+use crate::{
+    model::common::{config_key::ConfigKeySpec, types::ConfigMap},
+    utils::clock::common_trait::ClockTrait,
+};
 
 pub trait BrokerTrait: Send + Sync {
     fn new(
         interceptor_factory: Box<dyn BrokerInterceptorFactoryTrait>,
         config_map: ConfigMap,
         stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
     ) -> Self
     where
         Self: Sized;
     fn get_identifier() -> String
     where
         Self: Sized;
+    fn get_config_key_list() -> Vec<ConfigKeySpec>
+    where
+        Self: Sized,
+    {
+        vec![]
+    }
 
     fn create_info(&self) -> Box<dyn InfoTrait>;
     fn create_subscription(&self) -> Box<dyn SubscriptionTrait>;
-    fn create_transaction(&self) -> Box<dyn TransactionTrait>;
+    // fails for brokers that only serve market data
+    fn create_transaction(&self) -> Result<Box<dyn TransactionTrait>, Error>;
     fn create_heartbeat(&self) -> Option<Box<dyn HeartbeatTrait>>;
+    fn create_fee_model(&self) -> Result<Box<dyn FeeModelTrait>, Error>;
 }
 
 pub trait BrokerInterceptorFactoryTrait: Send + Sync {
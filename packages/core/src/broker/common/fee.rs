@@ -0,0 +1,107 @@
+use anyhow::Error;
+use rust_decimal::{Decimal, RoundingStrategy};
+use rust_decimal_macros::dec;
+
+use crate::model::{
+    common::{config_key::ConfigKeySpec, types::ConfigMap},
+    trading::{
+        fee::{CalculateFeeRequest, Fee, FeeItem, FeeKind},
+        transaction::Direction,
+    },
+};
+
+pub trait FeeModelTrait: Send + Sync {
+    fn new(config_map: ConfigMap) -> Self
+    where
+        Self: Sized;
+    fn get_identifier() -> String
+    where
+        Self: Sized;
+    fn get_config_key_list() -> Vec<ConfigKeySpec>
+    where
+        Self: Sized,
+    {
+        vec![]
+    }
+
+    fn calculate_fee(&self, request: &CalculateFeeRequest) -> Result<Fee, Error>;
+}
+
+pub struct NoFeeModel {}
+
+impl FeeModelTrait for NoFeeModel {
+    fn new(_config_map: ConfigMap) -> Self {
+        NoFeeModel {}
+    }
+
+    fn get_identifier() -> String {
+        const IDENTIFIER: &'static str = "NoFeeModel";
+        IDENTIFIER.to_owned()
+    }
+
+    fn calculate_fee(&self, request: &CalculateFeeRequest) -> Result<Fee, Error> {
+        Result::Ok(Fee {
+            currency: request.symbol.market.get_currency(),
+            item_list: vec![],
+        })
+    }
+}
+
+pub fn round_up_to_cent(amount: Decimal) -> Decimal {
+    amount.round_dp_with_strategy(2, RoundingStrategy::AwayFromZero)
+}
+
+// Per share rate bounded by a per order minimum and a maximum in percent of the trade value
+pub fn get_per_share_fee(
+    request: &CalculateFeeRequest,
+    rate: Decimal,
+    min_amount: Decimal,
+    max_value_rate: Decimal,
+) -> Decimal {
+    let max_amount = request.get_value() * max_value_rate;
+    round_up_to_cent((request.quantity * rate).max(min_amount).min(max_amount))
+}
+
+// Levies and fees charged by HKEX, SFC and AFRC on both sides, the same for all brokers
+pub fn get_hk_market_fee_item_list(request: &CalculateFeeRequest) -> Vec<FeeItem> {
+    let value = request.get_value();
+    vec![
+        FeeItem {
+            kind: FeeKind::StampDuty,
+            amount: (value * dec!(0.001)).ceil(),
+        },
+        FeeItem {
+            kind: FeeKind::TradingFee,
+            amount: round_up_to_cent(value * dec!(0.0000565)),
+        },
+        FeeItem {
+            kind: FeeKind::SfcLevy,
+            amount: round_up_to_cent(value * dec!(0.000027)),
+        },
+        FeeItem {
+            kind: FeeKind::AfrcLevy,
+            amount: round_up_to_cent(value * dec!(0.0000015)),
+        },
+        FeeItem {
+            kind: FeeKind::SettlementFee,
+            amount: round_up_to_cent((value * dec!(0.00002)).clamp(dec!(2), dec!(100))),
+        },
+    ]
+}
+
+// SEC fee and FINRA trading activity fee, both charged on sales only
+pub fn get_us_regulatory_fee_item_list(request: &CalculateFeeRequest) -> Vec<FeeItem> {
+    if request.direction == Direction::Buy {
+        return vec![];
+    }
+    vec![
+        FeeItem {
+            kind: FeeKind::SecFee,
+            amount: round_up_to_cent(request.get_value() * dec!(0.0000278)),
+        },
+        FeeItem {
+            kind: FeeKind::FinraTaf,
+            amount: round_up_to_cent((request.quantity * dec!(0.000166)).min(dec!(8.30))),
+        },
+    ]
+}
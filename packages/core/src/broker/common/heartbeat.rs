@@ -2,11 +2,15 @@ use anyhow::Error;
 use async_trait::async_trait;
 use std::sync::{atomic::AtomicBool, Arc};
 
-use crate::model::common::types::ConfigMap;
+use crate::{model::common::types::ConfigMap, utils::clock::common_trait::ClockTrait};
 
 #[async_trait]
 pub trait HeartbeatTrait: Send + Sync {
-    fn new(config_map: ConfigMap, stopped_indicator: Arc<AtomicBool>) -> Self
+    fn new(
+        config_map: ConfigMap,
+        stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
+    ) -> Self
     where
         Self: Sized;
 
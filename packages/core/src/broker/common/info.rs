@@ -1,15 +1,21 @@
 use anyhow::Error;
 use async_trait::async_trait;
-use std::time::{Duration, Instant};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use crate::model::{
-    common::types::ConfigMap,
-    trading::quote::{QueryInfoRequest, QuoteBasicInfo, QuoteDepthInfo, QuoteRealTimeInfo},
+use crate::{
+    model::{
+        common::types::ConfigMap,
+        trading::quote::{QueryInfoRequest, QuoteBasicInfo, QuoteDepthInfo, QuoteRealTimeInfo},
+    },
+    utils::clock::common_trait::ClockTrait,
 };
 
 #[async_trait]
 pub trait InfoTrait: Send + Sync {
-    fn new(config_map: ConfigMap) -> Self
+    fn new(config_map: ConfigMap, clock: Arc<dyn ClockTrait>) -> Self
     where
         Self: Sized;
 
@@ -91,7 +97,7 @@ impl InfoProxy {
 
 #[async_trait]
 impl InfoTrait for InfoProxy {
-    fn new(_config_map: ConfigMap) -> Self {
+    fn new(_config_map: ConfigMap, _clock: Arc<dyn ClockTrait>) -> Self {
         panic!("Cannot Call \"new\" on the proxy method!");
     }
 
@@ -1,5 +1,7 @@
 pub mod broker;
+pub mod fee;
 pub mod heartbeat;
 pub mod info;
+pub mod order_emulator;
 pub mod subscription;
 pub mod transaction;
@@ -0,0 +1,886 @@
+use anyhow::{anyhow, Error};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::sync::Mutex;
+
+use super::{info::InfoTrait, transaction::TransactionTrait};
+use crate::{
+    model::{
+        common::types::ConfigMap,
+        trading::{
+            balance::BalanceHashMap,
+            position::PositionList,
+            quote::{QueryInfoRequest, QuoteKind},
+            transaction::{
+                BuyingPower, CancelOrderRequest, CancelOrderResponse, Direction, EditOrderRequest,
+                EditOrderResponse, EstimateMaxBuyingPowerRequest, Execution, ListExecutionsRequest,
+                ListOrderHistoryRequest, OrderDetail, OrderDetailRequest, OrderStatus, Price,
+                SubmitBracketOrderRequest, SubmitBracketOrderResponse, SubmitOcoOrderRequest,
+                SubmitOcoOrderResponse, SubmitOrderRequest, SubmitOrderResponse,
+            },
+        },
+    },
+    utils::clock::common_trait::ClockTrait,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum EmulatedOrderState {
+    // waiting for the parent order to be filled
+    Waiting {
+        parent_order_id: String,
+    },
+    // waiting for the stop price to be reached
+    Armed,
+    Working {
+        order_id: String,
+    },
+    Done {
+        order_id: Option<String>,
+        status: OrderStatus,
+    },
+}
+
+#[derive(Clone, Debug)]
+struct EmulatedOrder {
+    local_order_id: String,
+    request: SubmitOrderRequest,
+    oca_group: Option<String>,
+    executed_quantity: Decimal,
+    state: EmulatedOrderState,
+}
+
+impl EmulatedOrder {
+    fn is_active(&self) -> bool {
+        !matches!(self.state, EmulatedOrderState::Done { .. })
+    }
+}
+
+// Only locked to read or update the emulated orders, never while calling the broker
+struct OrderEmulatorContext {
+    emulated_order_list: Vec<EmulatedOrder>,
+    sequence: u64,
+}
+
+impl OrderEmulatorContext {
+    fn next_local_order_id(&mut self) -> String {
+        self.sequence += 1;
+        format!(
+            "{}{}",
+            OrderEmulatorTransaction::LOCAL_ORDER_ID_PREFIX,
+            self.sequence
+        )
+    }
+
+    fn get_index(&self, local_order_id: &str) -> Result<usize, Error> {
+        self.emulated_order_list
+            .iter()
+            .position(|order| order.local_order_id == local_order_id)
+            .ok_or_else(|| anyhow!("ORDER_NOT_FOUND Emulated order {}", local_order_id))
+    }
+
+    fn get_order(&self, local_order_id: &str) -> Result<&EmulatedOrder, Error> {
+        Result::Ok(&self.emulated_order_list[self.get_index(local_order_id)?])
+    }
+
+    fn get_order_mut(&mut self, local_order_id: &str) -> Result<&mut EmulatedOrder, Error> {
+        let index = self.get_index(local_order_id)?;
+        Result::Ok(&mut self.emulated_order_list[index])
+    }
+
+    // The state moves on only if nothing else, e.g. a cancel, changed it in the meantime
+    fn compare_and_set_state(
+        &mut self,
+        local_order_id: &str,
+        expected_state: &EmulatedOrderState,
+        state: EmulatedOrderState,
+    ) -> bool {
+        match self.get_order_mut(local_order_id) {
+            Result::Ok(emulated_order) if &emulated_order.state == expected_state => {
+                emulated_order.state = state;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn list_local_order_id(&self, predicate: impl Fn(&EmulatedOrder) -> bool) -> Vec<String> {
+        self.emulated_order_list
+            .iter()
+            .filter(|order| predicate(order))
+            .map(|order| order.local_order_id.clone())
+            .collect()
+    }
+
+    fn has_active_order(&self) -> bool {
+        self.emulated_order_list
+            .iter()
+            .any(|order| order.is_active())
+    }
+}
+
+// Emulates stop, bracket and OCO orders for brokers without native support by polling
+// quotes and order states, the emulated orders are referred by local order ids.
+#[derive(Clone)]
+pub struct OrderEmulatorTransaction {
+    shadowed_transaction: Arc<Mutex<Box<dyn TransactionTrait>>>,
+    context: Arc<Mutex<OrderEmulatorContext>>,
+    info: Arc<dyn InfoTrait>,
+    clock: Arc<dyn ClockTrait>,
+    stopped_indicator: Arc<AtomicBool>,
+    is_polling: Arc<AtomicBool>,
+    poll_interval: Duration,
+}
+
+impl OrderEmulatorTransaction {
+    pub const CONFIG_KEY_POLL_INTERVAL_MS: &'static str = "order_emulator.poll_interval_ms";
+    pub const LOCAL_ORDER_ID_PREFIX: &'static str = "emulated_";
+    const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+    pub fn new(
+        shadowed_transaction: Box<dyn TransactionTrait>,
+        info: Box<dyn InfoTrait>,
+        config_map: &ConfigMap,
+        stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
+    ) -> Self {
+        let poll_interval = config_map
+            .get(Self::CONFIG_KEY_POLL_INTERVAL_MS)
+            .and_then(|poll_interval| poll_interval.parse().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(Self::DEFAULT_POLL_INTERVAL);
+        OrderEmulatorTransaction {
+            shadowed_transaction: Arc::new(Mutex::new(shadowed_transaction)),
+            context: Arc::new(Mutex::new(OrderEmulatorContext {
+                emulated_order_list: vec![],
+                sequence: 0,
+            })),
+            info: Arc::from(info),
+            clock,
+            stopped_indicator,
+            is_polling: Arc::new(AtomicBool::new(false)),
+            poll_interval,
+        }
+    }
+
+    pub fn is_local_order_id(order_id: &str) -> bool {
+        order_id.starts_with(Self::LOCAL_ORDER_ID_PREFIX)
+    }
+
+    pub fn is_emulated_price(price: &Price) -> bool {
+        matches!(price, Price::Stop { .. } | Price::StopLimit { .. })
+    }
+
+    pub fn get_triggered_price(price: &Price) -> Price {
+        match price {
+            Price::Stop { .. } => Price::MarketOrder,
+            Price::StopLimit { limit_price, .. } => Price::LimitOrder {
+                price: *limit_price,
+            },
+            _ => price.clone(),
+        }
+    }
+
+    pub fn is_triggered(direction: &Direction, price: &Price, current_price: Decimal) -> bool {
+        let stop_price = match price {
+            Price::Stop { stop_price } | Price::StopLimit { stop_price, .. } => *stop_price,
+            _ => return false,
+        };
+        match direction {
+            Direction::Buy => current_price >= stop_price,
+            Direction::Sell => current_price <= stop_price,
+        }
+    }
+
+    fn to_pending_order_detail(emulated_order: &EmulatedOrder, status: OrderStatus) -> OrderDetail {
+        let request = &emulated_order.request;
+        OrderDetail {
+            order_id: emulated_order.local_order_id.clone(),
+            symbol: request.symbol.clone(),
+            currency: request.symbol.market.get_currency(),
+            quantity: request.quantity,
+            executed_quantity: emulated_order.executed_quantity,
+            price: request.price.clone(),
+            executed_price: Option::None,
+            fee: Option::None,
+            direction: request.direction.clone(),
+            regular_trading_time: request.regular_trading_time.clone(),
+            expire: request.expire.clone(),
+            status,
+            created_timestamp: Option::None,
+            updated_timestamp: Option::None,
+            triggered_timestamp: Option::None,
+        }
+    }
+
+    fn ensure_polling(&self) {
+        if self.is_polling.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let emulator = self.clone();
+        tokio::spawn(async move {
+            loop {
+                emulator.clock.sleep(emulator.poll_interval).await;
+                if emulator.stopped_indicator.load(Ordering::Relaxed) {
+                    log::warn!("Broker stopped, emulated orders are no longer managed");
+                    break;
+                }
+                if let Result::Err(err) = emulator.poll().await {
+                    log::error!("Error when polling emulated orders, {}", err);
+                }
+                // checked under the lock so that orders registered meanwhile restart the polling
+                let context = emulator.context.lock().await;
+                if !context.has_active_order() {
+                    emulator.is_polling.store(false, Ordering::SeqCst);
+                    break;
+                }
+            }
+        });
+    }
+
+    // Places the order at the broker on behalf of the emulated one, the placed order is cancelled
+    // again if the emulated one left `expected_state` meanwhile
+    async fn place(
+        &self,
+        local_order_id: &str,
+        expected_state: &EmulatedOrderState,
+        request: SubmitOrderRequest,
+    ) -> Result<(), Error> {
+        let order_id = self
+            .shadowed_transaction
+            .lock()
+            .await
+            .submit_order(request)
+            .await?
+            .order_id;
+        let is_placed = self.context.lock().await.compare_and_set_state(
+            local_order_id,
+            expected_state,
+            EmulatedOrderState::Working {
+                order_id: order_id.clone(),
+            },
+        );
+        if is_placed {
+            return Result::Ok(());
+        }
+        log::warn!(
+            "Emulated order {} changed while being placed, cancelling order {}",
+            local_order_id,
+            order_id
+        );
+        self.shadowed_transaction
+            .lock()
+            .await
+            .cancel_order(CancelOrderRequest { order_id })
+            .await
+            .map(|_| ())
+    }
+
+    // Stop orders are kept locally until triggered, others are submitted to the broker at once
+    async fn activate(
+        &self,
+        local_order_id: &str,
+        expected_state: &EmulatedOrderState,
+    ) -> Result<(), Error> {
+        let request = {
+            let mut context = self.context.lock().await;
+            let request = context.get_order(local_order_id)?.request.clone();
+            if Self::is_emulated_price(&request.price) {
+                context.compare_and_set_state(
+                    local_order_id,
+                    expected_state,
+                    EmulatedOrderState::Armed,
+                );
+                return Result::Ok(());
+            }
+            request
+        };
+        self.place(local_order_id, expected_state, request).await
+    }
+
+    // Children waiting for this order are cancelled on the next poll
+    async fn cancel(&self, local_order_id: &str) -> Result<(), Error> {
+        let order_id = {
+            let mut context = self.context.lock().await;
+            let emulated_order = context.get_order_mut(local_order_id)?;
+            match emulated_order.state.clone() {
+                EmulatedOrderState::Working { order_id } => order_id,
+                EmulatedOrderState::Waiting { .. } | EmulatedOrderState::Armed => {
+                    emulated_order.state = EmulatedOrderState::Done {
+                        order_id: Option::None,
+                        status: OrderStatus::Cancelled,
+                    };
+                    return Result::Ok(());
+                }
+                EmulatedOrderState::Done { .. } => return Result::Ok(()),
+            }
+        };
+        self.shadowed_transaction
+            .lock()
+            .await
+            .cancel_order(CancelOrderRequest {
+                order_id: order_id.clone(),
+            })
+            .await?;
+        self.context.lock().await.compare_and_set_state(
+            local_order_id,
+            &EmulatedOrderState::Working {
+                order_id: order_id.clone(),
+            },
+            EmulatedOrderState::Done {
+                order_id: Option::Some(order_id),
+                status: OrderStatus::Cancelled,
+            },
+        );
+        Result::Ok(())
+    }
+
+    // Marks an order that could not be placed, unless it changed meanwhile
+    async fn reject(&self, local_order_id: &str, expected_state: &EmulatedOrderState) {
+        self.context.lock().await.compare_and_set_state(
+            local_order_id,
+            expected_state,
+            EmulatedOrderState::Done {
+                order_id: Option::None,
+                status: OrderStatus::Rejected,
+            },
+        );
+    }
+
+    async fn remove(&self, local_order_id: &str) {
+        let mut context = self.context.lock().await;
+        if let Result::Ok(index) = context.get_index(local_order_id) {
+            context.emulated_order_list.remove(index);
+        }
+    }
+
+    // refresh the orders placed at the broker
+    async fn refresh_working_order_list(&self) {
+        let working_list: Vec<(String, String)> = self
+            .context
+            .lock()
+            .await
+            .emulated_order_list
+            .iter()
+            .filter_map(|order| match &order.state {
+                EmulatedOrderState::Working { order_id } => {
+                    Option::Some((order.local_order_id.clone(), order_id.clone()))
+                }
+                _ => Option::None,
+            })
+            .collect();
+        for (local_order_id, order_id) in working_list {
+            let result = self
+                .shadowed_transaction
+                .lock()
+                .await
+                .order_detail(OrderDetailRequest {
+                    order_id: order_id.clone(),
+                })
+                .await;
+            let order_detail = match result {
+                Result::Ok(order_detail) => order_detail,
+                Result::Err(err) => {
+                    log::warn!("Error when refreshing emulated order {}, {}", order_id, err);
+                    continue;
+                }
+            };
+            let mut context = self.context.lock().await;
+            let emulated_order = match context.get_order_mut(&local_order_id) {
+                Result::Ok(emulated_order) => emulated_order,
+                Result::Err(_) => continue,
+            };
+            if emulated_order.state
+                != (EmulatedOrderState::Working {
+                    order_id: order_id.clone(),
+                })
+            {
+                continue;
+            }
+            emulated_order.executed_quantity = order_detail.executed_quantity;
+            if !order_detail.status.is_open() {
+                emulated_order.state = EmulatedOrderState::Done {
+                    order_id: Option::Some(order_id),
+                    status: order_detail.status,
+                };
+            }
+        }
+    }
+
+    // a fill cancels the rest of the OCA group
+    async fn cancel_oca_group_list(&self, error_list: &mut Vec<String>) {
+        let sibling_list = {
+            let context = self.context.lock().await;
+            let filled_list: Vec<(String, String)> = context
+                .emulated_order_list
+                .iter()
+                .filter(|order| order.executed_quantity > Decimal::ZERO)
+                .filter_map(|order| {
+                    order
+                        .oca_group
+                        .clone()
+                        .map(|oca_group| (oca_group, order.local_order_id.clone()))
+                })
+                .collect();
+            context.list_local_order_id(|order| {
+                order.is_active()
+                    && filled_list.iter().any(|(oca_group, local_order_id)| {
+                        order.oca_group.as_ref() == Option::Some(oca_group)
+                            && &order.local_order_id != local_order_id
+                    })
+            })
+        };
+        for local_order_id in sibling_list {
+            if let Result::Err(err) = self.cancel(&local_order_id).await {
+                log::error!(
+                    "Error when cancelling emulated order {}, {}",
+                    local_order_id,
+                    err
+                );
+                error_list.push(format!("{}: {}", local_order_id, err));
+            }
+        }
+    }
+
+    // children are activated once the parent is filled
+    async fn activate_child_list(&self, error_list: &mut Vec<String>) {
+        let waiting_list: Vec<(
+            String,
+            EmulatedOrderState,
+            Result<EmulatedOrderState, String>,
+        )> = {
+            let context = self.context.lock().await;
+            context
+                .emulated_order_list
+                .iter()
+                .filter_map(|order| match &order.state {
+                    EmulatedOrderState::Waiting { parent_order_id } => Option::Some((
+                        order.local_order_id.clone(),
+                        order.state.clone(),
+                        context
+                            .get_order(parent_order_id)
+                            .map(|parent_order| parent_order.state.clone())
+                            .map_err(|err| err.to_string()),
+                    )),
+                    _ => Option::None,
+                })
+                .collect()
+        };
+        for (local_order_id, state, parent_state) in waiting_list {
+            let result = match parent_state {
+                Result::Ok(EmulatedOrderState::Done {
+                    status: OrderStatus::Filled,
+                    ..
+                }) => {
+                    let result = self.activate(&local_order_id, &state).await;
+                    if result.is_err() {
+                        self.reject(&local_order_id, &state).await;
+                    }
+                    result
+                }
+                Result::Ok(EmulatedOrderState::Done { .. }) => self.cancel(&local_order_id).await,
+                Result::Ok(_) => Result::Ok(()),
+                Result::Err(err) => Result::Err(anyhow!(err)),
+            };
+            if let Result::Err(err) = result {
+                log::error!(
+                    "Error when activating emulated order {}, {}",
+                    local_order_id,
+                    err
+                );
+                error_list.push(format!("{}: {}", local_order_id, err));
+            }
+        }
+    }
+
+    // stop orders are submitted once the stop price is reached
+    async fn trigger_armed_list(&self, error_list: &mut Vec<String>) {
+        let armed_list: Vec<(String, SubmitOrderRequest)> = self
+            .context
+            .lock()
+            .await
+            .emulated_order_list
+            .iter()
+            .filter(|order| order.state == EmulatedOrderState::Armed)
+            .map(|order| (order.local_order_id.clone(), order.request.clone()))
+            .collect();
+        for (local_order_id, mut request) in armed_list {
+            let quote_info = match self
+                .info
+                .query_real_time_info(QueryInfoRequest {
+                    symbol: request.symbol.clone(),
+                    kind: QuoteKind::Stock,
+                })
+                .await
+            {
+                Result::Ok(quote_info) => quote_info,
+                Result::Err(err) => {
+                    log::error!(
+                        "Error when querying the price of emulated order {}, {}",
+                        local_order_id,
+                        err
+                    );
+                    error_list.push(format!("{}: {}", local_order_id, err));
+                    continue;
+                }
+            };
+            if !Self::is_triggered(&request.direction, &request.price, quote_info.current_price) {
+                continue;
+            }
+            request.price = Self::get_triggered_price(&request.price);
+            if let Result::Err(err) = self
+                .place(&local_order_id, &EmulatedOrderState::Armed, request)
+                .await
+            {
+                log::error!(
+                    "Error when submitting triggered emulated order {}, {}",
+                    local_order_id,
+                    err
+                );
+                error_list.push(format!("{}: {}", local_order_id, err));
+                self.reject(&local_order_id, &EmulatedOrderState::Armed)
+                    .await;
+            }
+        }
+    }
+
+    // Runs a single round of the emulation, it is driven by a background task after an
+    // emulated order is submitted. An order that fails is reported without holding back the
+    // others, the errors of the round are returned together.
+    pub async fn poll(&self) -> Result<(), Error> {
+        let mut error_list = vec![];
+        self.refresh_working_order_list().await;
+        self.cancel_oca_group_list(&mut error_list).await;
+        self.activate_child_list(&mut error_list).await;
+        self.trigger_armed_list(&mut error_list).await;
+        match error_list.is_empty() {
+            true => Result::Ok(()),
+            false => Result::Err(anyhow!(
+                "EMULATION_ERROR {} emulated orders failed, {}",
+                error_list.len(),
+                error_list.join("; ")
+            )),
+        }
+    }
+
+    // Registers an emulated order that is not placed at the broker yet
+    async fn push(
+        &self,
+        request: SubmitOrderRequest,
+        oca_group: Option<String>,
+        state: EmulatedOrderState,
+    ) -> String {
+        let mut context = self.context.lock().await;
+        let local_order_id = context.next_local_order_id();
+        context.emulated_order_list.push(EmulatedOrder {
+            local_order_id: local_order_id.clone(),
+            request,
+            oca_group,
+            executed_quantity: Decimal::ZERO,
+            state,
+        });
+        local_order_id
+    }
+}
+
+#[async_trait]
+impl TransactionTrait for OrderEmulatorTransaction {
+    fn new(_config_map: ConfigMap, _clock: Arc<dyn ClockTrait>) -> Self {
+        panic!("Cannot Call \"new\" on the emulator method!");
+    }
+
+    async fn account_balance(&self) -> Result<BalanceHashMap, Error> {
+        self.shadowed_transaction
+            .lock()
+            .await
+            .account_balance()
+            .await
+    }
+
+    async fn positions(&self) -> Result<PositionList, Error> {
+        self.shadowed_transaction.lock().await.positions().await
+    }
+
+    async fn estimate_max_buying_power(
+        &self,
+        request: EstimateMaxBuyingPowerRequest,
+    ) -> Result<BuyingPower, Error> {
+        self.shadowed_transaction
+            .lock()
+            .await
+            .estimate_max_buying_power(EstimateMaxBuyingPowerRequest {
+                price: Self::get_triggered_price(&request.price),
+                ..request
+            })
+            .await
+    }
+
+    async fn order_detail(&self, request: OrderDetailRequest) -> Result<OrderDetail, Error> {
+        if !Self::is_local_order_id(&request.order_id) {
+            return self
+                .shadowed_transaction
+                .lock()
+                .await
+                .order_detail(request)
+                .await;
+        }
+
+        let emulated_order = self
+            .context
+            .lock()
+            .await
+            .get_order(&request.order_id)?
+            .clone();
+        match &emulated_order.state {
+            EmulatedOrderState::Working { order_id }
+            | EmulatedOrderState::Done {
+                order_id: Option::Some(order_id),
+                ..
+            } => {
+                let mut order_detail = self
+                    .shadowed_transaction
+                    .lock()
+                    .await
+                    .order_detail(OrderDetailRequest {
+                        order_id: order_id.clone(),
+                    })
+                    .await?;
+                order_detail.order_id = emulated_order.local_order_id.clone();
+                Result::Ok(order_detail)
+            }
+            EmulatedOrderState::Waiting { .. } | EmulatedOrderState::Armed => Result::Ok(
+                Self::to_pending_order_detail(&emulated_order, OrderStatus::Pending),
+            ),
+            EmulatedOrderState::Done {
+                order_id: Option::None,
+                status,
+            } => Result::Ok(Self::to_pending_order_detail(
+                &emulated_order,
+                status.clone(),
+            )),
+        }
+    }
+
+    // Emulated orders not placed at the broker yet are listed as pending
+    async fn list_open_orders(&self) -> Result<Vec<OrderDetail>, Error> {
+        let mut order_detail_list = self
+            .shadowed_transaction
+            .lock()
+            .await
+            .list_open_orders()
+            .await?;
+        order_detail_list.extend(
+            self.context
+                .lock()
+                .await
+                .emulated_order_list
+                .iter()
+                .filter(|order| {
+                    matches!(
+                        order.state,
+                        EmulatedOrderState::Waiting { .. } | EmulatedOrderState::Armed
+                    )
+                })
+                .map(|order| Self::to_pending_order_detail(order, OrderStatus::Pending)),
+        );
+        Result::Ok(order_detail_list)
+    }
+
+    async fn list_order_history(
+        &self,
+        request: ListOrderHistoryRequest,
+    ) -> Result<Vec<OrderDetail>, Error> {
+        self.shadowed_transaction
+            .lock()
+            .await
+            .list_order_history(request)
+            .await
+    }
+
+    async fn list_executions(
+        &self,
+        request: ListExecutionsRequest,
+    ) -> Result<Vec<Execution>, Error> {
+        self.shadowed_transaction
+            .lock()
+            .await
+            .list_executions(request)
+            .await
+    }
+
+    async fn submit_order(
+        &mut self,
+        request: SubmitOrderRequest,
+    ) -> Result<SubmitOrderResponse, Error> {
+        if !Self::is_emulated_price(&request.price) {
+            return self
+                .shadowed_transaction
+                .lock()
+                .await
+                .submit_order(request)
+                .await;
+        }
+
+        let local_order_id = self
+            .push(request, Option::None, EmulatedOrderState::Armed)
+            .await;
+        self.ensure_polling();
+        Result::Ok(SubmitOrderResponse {
+            order_id: local_order_id,
+        })
+    }
+
+    async fn submit_bracket_order(
+        &mut self,
+        request: SubmitBracketOrderRequest,
+    ) -> Result<SubmitBracketOrderResponse, Error> {
+        let entry_order_id = self
+            .push(
+                request.entry.clone(),
+                Option::None,
+                EmulatedOrderState::Armed,
+            )
+            .await;
+        if let Result::Err(err) = self
+            .activate(&entry_order_id, &EmulatedOrderState::Armed)
+            .await
+        {
+            self.remove(&entry_order_id).await;
+            return Result::Err(err);
+        }
+
+        let oca_group = Option::Some(format!("{}_oca", entry_order_id));
+        let mut child_order_id_list = vec![];
+        for price_option in [request.take_profit.clone(), request.stop_loss.clone()] {
+            let child_order_id = match price_option {
+                Option::Some(price) => Option::Some(
+                    self.push(
+                        request.get_child_request(price),
+                        oca_group.clone(),
+                        EmulatedOrderState::Waiting {
+                            parent_order_id: entry_order_id.clone(),
+                        },
+                    )
+                    .await,
+                ),
+                Option::None => Option::None,
+            };
+            child_order_id_list.push(child_order_id);
+        }
+
+        self.ensure_polling();
+        let stop_loss_order_id = child_order_id_list.pop().flatten();
+        let take_profit_order_id = child_order_id_list.pop().flatten();
+        Result::Ok(SubmitBracketOrderResponse {
+            entry_order_id,
+            take_profit_order_id,
+            stop_loss_order_id,
+        })
+    }
+
+    async fn submit_oco_order(
+        &mut self,
+        request: SubmitOcoOrderRequest,
+    ) -> Result<SubmitOcoOrderResponse, Error> {
+        let oca_group = Option::Some(format!(
+            "{}_oca",
+            self.context.lock().await.next_local_order_id()
+        ));
+
+        let mut order_id_list: Vec<String> = vec![];
+        for order in request.order_list.iter() {
+            let local_order_id = self
+                .push(order.clone(), oca_group.clone(), EmulatedOrderState::Armed)
+                .await;
+            if let Result::Err(err) = self
+                .activate(&local_order_id, &EmulatedOrderState::Armed)
+                .await
+            {
+                // roll back the legs placed so far
+                self.remove(&local_order_id).await;
+                for local_order_id in order_id_list.iter() {
+                    if let Result::Err(err) = self.cancel(local_order_id).await {
+                        log::error!("Error when cancelling OCO order, {}", err);
+                    }
+                }
+                return Result::Err(err);
+            }
+            order_id_list.push(local_order_id);
+        }
+
+        self.ensure_polling();
+        Result::Ok(SubmitOcoOrderResponse { order_id_list })
+    }
+
+    async fn edit_order(&mut self, request: EditOrderRequest) -> Result<EditOrderResponse, Error> {
+        if !Self::is_local_order_id(&request.order_id) {
+            return self
+                .shadowed_transaction
+                .lock()
+                .await
+                .edit_order(request)
+                .await;
+        }
+
+        let order_id = {
+            let mut context = self.context.lock().await;
+            let emulated_order = context.get_order_mut(&request.order_id)?;
+            match emulated_order.state.clone() {
+                EmulatedOrderState::Working { order_id } => order_id,
+                // not placed at the broker, edited under the lock so that it is not triggered
+                // with the previous request
+                EmulatedOrderState::Waiting { .. } | EmulatedOrderState::Armed => {
+                    emulated_order.request.quantity = request.quantity;
+                    emulated_order.request.direction = request.direction;
+                    emulated_order.request.expire = request.expire;
+                    emulated_order.request.price = request.price;
+                    return Result::Ok(EditOrderResponse {});
+                }
+                EmulatedOrderState::Done { .. } => {
+                    return Result::Err(anyhow!(
+                        "ORDER_NOT_EDITABLE Emulated order {} is done",
+                        request.order_id
+                    ));
+                }
+            }
+        };
+        self.shadowed_transaction
+            .lock()
+            .await
+            .edit_order(EditOrderRequest {
+                order_id,
+                ..request.clone()
+            })
+            .await?;
+        let mut context = self.context.lock().await;
+        let emulated_order = context.get_order_mut(&request.order_id)?;
+        emulated_order.request.quantity = request.quantity;
+        emulated_order.request.direction = request.direction;
+        emulated_order.request.expire = request.expire;
+        emulated_order.request.price = request.price;
+        Result::Ok(EditOrderResponse {})
+    }
+
+    async fn cancel_order(
+        &mut self,
+        request: CancelOrderRequest,
+    ) -> Result<CancelOrderResponse, Error> {
+        if !Self::is_local_order_id(&request.order_id) {
+            return self
+                .shadowed_transaction
+                .lock()
+                .await
+                .cancel_order(request)
+                .await;
+        }
+
+        self.cancel(&request.order_id).await?;
+        Result::Ok(CancelOrderResponse {})
+    }
+}
@@ -6,14 +6,21 @@ use std::{
 };
 use tokio::sync::mpsc::Receiver;
 
-use crate::model::{
-    common::types::ConfigMap,
-    trading::quote::{QueryInfoRequest, QuoteDepthInfo, QuoteRealTimeInfo},
+use crate::{
+    model::{
+        common::types::ConfigMap,
+        trading::quote::{QueryInfoRequest, QuoteDepthInfo, QuoteRealTimeInfo},
+    },
+    utils::clock::common_trait::ClockTrait,
 };
 
 #[async_trait]
 pub trait SubscriptionTrait: Send + Sync {
-    fn new(config_map: ConfigMap, global_stopped_indicator: Arc<AtomicBool>) -> Self
+    fn new(
+        config_map: ConfigMap,
+        global_stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
+    ) -> Self
     where
         Self: Sized;
     async fn real_time_info(
@@ -28,7 +35,7 @@ pub trait SubscriptionTrait: Send + Sync {
 
 #[async_trait]
 pub trait SubscriptionController: Send + Sync {
-    async fn stop(self) -> Result<(), Error>;
+    async fn stop(&self) -> Result<(), Error>;
 }
 
 #[async_trait]
@@ -93,7 +100,11 @@ impl SubscriptionProxy {
 
 #[async_trait]
 impl SubscriptionTrait for SubscriptionProxy {
-    fn new(_config_map: ConfigMap, _global_stopped_indicator: Arc<AtomicBool>) -> Self {
+    fn new(
+        _config_map: ConfigMap,
+        _global_stopped_indicator: Arc<AtomicBool>,
+        _clock: Arc<dyn ClockTrait>,
+    ) -> Self {
         panic!("Cannot Call \"new\" on the proxy method!");
     }
 
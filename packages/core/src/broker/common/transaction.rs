@@ -1,23 +1,31 @@
 use anyhow::Error;
 use async_trait::async_trait;
-use std::time::{Duration, Instant};
-
-use crate::model::{
-    common::types::ConfigMap,
-    trading::{
-        balance::BalanceHashMap,
-        position::PositionList,
-        transaction::{
-            BuyingPower, CancelOrderRequest, CancelOrderResponse, EditOrderRequest,
-            EditOrderResponse, EstimateMaxBuyingPowerRequest, OrderDetail, OrderDetailRequest,
-            SubmitOrderRequest, SubmitOrderResponse,
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    model::{
+        common::types::ConfigMap,
+        trading::{
+            balance::BalanceHashMap,
+            position::PositionList,
+            transaction::{
+                BuyingPower, CancelOrderRequest, CancelOrderResponse, EditOrderRequest,
+                EditOrderResponse, EstimateMaxBuyingPowerRequest, Execution, ListExecutionsRequest,
+                ListOrderHistoryRequest, OrderDetail, OrderDetailRequest,
+                SubmitBracketOrderRequest, SubmitBracketOrderResponse, SubmitOcoOrderRequest,
+                SubmitOcoOrderResponse, SubmitOrderRequest, SubmitOrderResponse,
+            },
         },
     },
+    utils::clock::common_trait::ClockTrait,
 };
 
 #[async_trait]
 pub trait TransactionTrait: Send + Sync {
-    fn new(config_map: ConfigMap) -> Self
+    fn new(config_map: ConfigMap, clock: Arc<dyn ClockTrait>) -> Self
     where
         Self: Sized;
 
@@ -29,12 +37,29 @@ pub trait TransactionTrait: Send + Sync {
         request: EstimateMaxBuyingPowerRequest,
     ) -> Result<BuyingPower, Error>;
     async fn order_detail(&self, request: OrderDetailRequest) -> Result<OrderDetail, Error>;
+    async fn list_open_orders(&self) -> Result<Vec<OrderDetail>, Error>;
+    async fn list_order_history(
+        &self,
+        request: ListOrderHistoryRequest,
+    ) -> Result<Vec<OrderDetail>, Error>;
+    async fn list_executions(
+        &self,
+        request: ListExecutionsRequest,
+    ) -> Result<Vec<Execution>, Error>;
 
     // <-- Mutate APIs
     async fn submit_order(
         &mut self,
         request: SubmitOrderRequest,
     ) -> Result<SubmitOrderResponse, Error>;
+    async fn submit_bracket_order(
+        &mut self,
+        request: SubmitBracketOrderRequest,
+    ) -> Result<SubmitBracketOrderResponse, Error>;
+    async fn submit_oco_order(
+        &mut self,
+        request: SubmitOcoOrderRequest,
+    ) -> Result<SubmitOcoOrderResponse, Error>;
     async fn edit_order(&mut self, request: EditOrderRequest) -> Result<EditOrderResponse, Error>;
     async fn cancel_order(
         &mut self,
@@ -98,6 +123,48 @@ pub trait TransactionInterceptorTrait: Send + Sync {
         result
     }
 
+    async fn before_list_open_orders(&self) -> Result<(), Error> {
+        Result::Ok(())
+    }
+    async fn after_list_open_orders(
+        &self,
+        _request: (),
+        result: Result<Vec<OrderDetail>, Error>,
+        _duration: Duration,
+    ) -> Result<Vec<OrderDetail>, Error> {
+        result
+    }
+
+    async fn before_list_order_history(
+        &self,
+        request: ListOrderHistoryRequest,
+    ) -> Result<ListOrderHistoryRequest, Error> {
+        Result::Ok(request)
+    }
+    async fn after_list_order_history(
+        &self,
+        _request: ListOrderHistoryRequest,
+        result: Result<Vec<OrderDetail>, Error>,
+        _duration: Duration,
+    ) -> Result<Vec<OrderDetail>, Error> {
+        result
+    }
+
+    async fn before_list_executions(
+        &self,
+        request: ListExecutionsRequest,
+    ) -> Result<ListExecutionsRequest, Error> {
+        Result::Ok(request)
+    }
+    async fn after_list_executions(
+        &self,
+        _request: ListExecutionsRequest,
+        result: Result<Vec<Execution>, Error>,
+        _duration: Duration,
+    ) -> Result<Vec<Execution>, Error> {
+        result
+    }
+
     async fn before_submit_order(
         &self,
         request: SubmitOrderRequest,
@@ -113,6 +180,36 @@ pub trait TransactionInterceptorTrait: Send + Sync {
         result
     }
 
+    async fn before_submit_bracket_order(
+        &self,
+        request: SubmitBracketOrderRequest,
+    ) -> Result<SubmitBracketOrderRequest, Error> {
+        Result::Ok(request)
+    }
+    async fn after_submit_bracket_order(
+        &self,
+        _request: SubmitBracketOrderRequest,
+        result: Result<SubmitBracketOrderResponse, Error>,
+        _duration: Duration,
+    ) -> Result<SubmitBracketOrderResponse, Error> {
+        result
+    }
+
+    async fn before_submit_oco_order(
+        &self,
+        request: SubmitOcoOrderRequest,
+    ) -> Result<SubmitOcoOrderRequest, Error> {
+        Result::Ok(request)
+    }
+    async fn after_submit_oco_order(
+        &self,
+        _request: SubmitOcoOrderRequest,
+        result: Result<SubmitOcoOrderResponse, Error>,
+        _duration: Duration,
+    ) -> Result<SubmitOcoOrderResponse, Error> {
+        result
+    }
+
     async fn before_edit_order(
         &self,
         request: EditOrderRequest,
@@ -166,7 +263,7 @@ impl TransactionProxy {
 
 #[async_trait]
 impl TransactionTrait for TransactionProxy {
-    fn new(_config_map: ConfigMap) -> Self {
+    fn new(_config_map: ConfigMap, _clock: Arc<dyn ClockTrait>) -> Self {
         panic!("Cannot Call \"new\" on the proxy method!");
     }
 
@@ -233,6 +330,58 @@ impl TransactionTrait for TransactionProxy {
         }
     }
 
+    async fn list_open_orders(&self) -> Result<Vec<OrderDetail>, Error> {
+        if let Err(err) = self.interceptor.before_list_open_orders().await {
+            return Err(err);
+        }
+        let instant = Instant::now();
+        let result = self.shadowed_transaction.list_open_orders().await;
+        let duration = instant.elapsed();
+        self.interceptor
+            .after_list_open_orders((), result, duration)
+            .await
+    }
+
+    async fn list_order_history(
+        &self,
+        request: ListOrderHistoryRequest,
+    ) -> Result<Vec<OrderDetail>, Error> {
+        match self.interceptor.before_list_order_history(request).await {
+            Ok(request) => {
+                let instant = Instant::now();
+                let result = self
+                    .shadowed_transaction
+                    .list_order_history(request.clone())
+                    .await;
+                let duration = instant.elapsed();
+                self.interceptor
+                    .after_list_order_history(request, result, duration)
+                    .await
+            }
+            Err(err) => Result::Err(err),
+        }
+    }
+
+    async fn list_executions(
+        &self,
+        request: ListExecutionsRequest,
+    ) -> Result<Vec<Execution>, Error> {
+        match self.interceptor.before_list_executions(request).await {
+            Ok(request) => {
+                let instant = Instant::now();
+                let result = self
+                    .shadowed_transaction
+                    .list_executions(request.clone())
+                    .await;
+                let duration = instant.elapsed();
+                self.interceptor
+                    .after_list_executions(request, result, duration)
+                    .await
+            }
+            Err(err) => Result::Err(err),
+        }
+    }
+
     async fn submit_order(
         &mut self,
         request: SubmitOrderRequest,
@@ -253,6 +402,46 @@ impl TransactionTrait for TransactionProxy {
         }
     }
 
+    async fn submit_bracket_order(
+        &mut self,
+        request: SubmitBracketOrderRequest,
+    ) -> Result<SubmitBracketOrderResponse, Error> {
+        match self.interceptor.before_submit_bracket_order(request).await {
+            Ok(request) => {
+                let instant = Instant::now();
+                let result = self
+                    .shadowed_transaction
+                    .submit_bracket_order(request.clone())
+                    .await;
+                let duration = instant.elapsed();
+                self.interceptor
+                    .after_submit_bracket_order(request, result, duration)
+                    .await
+            }
+            Err(err) => Result::Err(err),
+        }
+    }
+
+    async fn submit_oco_order(
+        &mut self,
+        request: SubmitOcoOrderRequest,
+    ) -> Result<SubmitOcoOrderResponse, Error> {
+        match self.interceptor.before_submit_oco_order(request).await {
+            Ok(request) => {
+                let instant = Instant::now();
+                let result = self
+                    .shadowed_transaction
+                    .submit_oco_order(request.clone())
+                    .await;
+                let duration = instant.elapsed();
+                self.interceptor
+                    .after_submit_oco_order(request, result, duration)
+                    .await
+            }
+            Err(err) => Result::Err(err),
+        }
+    }
+
     async fn edit_order(&mut self, request: EditOrderRequest) -> Result<EditOrderResponse, Error> {
         match self.interceptor.before_edit_order(request).await {
             Ok(request) => {
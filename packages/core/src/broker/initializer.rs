@@ -1,13 +1,23 @@
 use anyhow::{anyhow, Error};
 use std::sync::{atomic::AtomicBool, Arc};
 
-use super::common::broker::{BrokerInterceptorFactoryTrait, BrokerTrait};
-use crate::model::common::types::ConfigMap;
+use super::common::{
+    broker::{BrokerInterceptorFactoryTrait, BrokerTrait},
+    fee::{FeeModelTrait, NoFeeModel},
+};
+use crate::{
+    model::common::{config_key::ConfigKeySpec, types::ConfigMap},
+    utils::clock::common_trait::ClockTrait,
+};
 
 #[cfg(feature = "broker__interactive_brokers")]
-use super::interactive_brokers::broker::InteractiveBrokersBroker;
+use super::interactive_brokers::{
+    broker::InteractiveBrokersBroker, fee::InteractiveBrokersFeeModel,
+};
 #[cfg(feature = "broker__longbridge")]
-use super::longbridge::broker::LongBridgeBroker;
+use super::longbridge::{broker::LongBridgeBroker, fee::LongBridgeFeeModel};
+#[cfg(feature = "broker__replay")]
+use super::replay::broker::ReplayBroker;
 #[cfg(feature = "broker__yahoo_finance")]
 use super::yahoo_finance::broker::YahooFinanceBroker;
 
@@ -16,16 +26,17 @@ pub fn get_broker_instance(
     interceptor_factory: Box<dyn BrokerInterceptorFactoryTrait>,
     config_map: ConfigMap,
     stopped_indicator: Arc<AtomicBool>,
+    clock: Arc<dyn ClockTrait>,
 ) -> Result<Box<dyn BrokerTrait>, Error> {
     match identifier {
         #[cfg(feature = "broker__longbridge")]
         identifier if identifier == LongBridgeBroker::get_identifier() => Result::Ok(Box::new(
-            LongBridgeBroker::new(interceptor_factory, config_map, stopped_indicator),
+            LongBridgeBroker::new(interceptor_factory, config_map, stopped_indicator, clock),
         )),
 
         #[cfg(feature = "broker__yahoo_finance")]
         identifier if identifier == YahooFinanceBroker::get_identifier() => Result::Ok(Box::new(
-            YahooFinanceBroker::new(interceptor_factory, config_map, stopped_indicator),
+            YahooFinanceBroker::new(interceptor_factory, config_map, stopped_indicator, clock),
         )),
 
         #[cfg(feature = "broker__interactive_brokers")]
@@ -34,9 +45,107 @@ pub fn get_broker_instance(
                 interceptor_factory,
                 config_map,
                 stopped_indicator,
+                clock,
             )))
         }
 
+        #[cfg(feature = "broker__replay")]
+        identifier if identifier == ReplayBroker::get_identifier() => Result::Ok(Box::new(
+            ReplayBroker::new(interceptor_factory, config_map, stopped_indicator, clock),
+        )),
+
+        _ => Result::Err(anyhow!("IDENTIFIER_NOT_MATCHED Broker: {}", identifier)),
+    }
+}
+
+pub fn get_fee_model_instance(
+    identifier: String,
+    config_map: ConfigMap,
+) -> Result<Box<dyn FeeModelTrait>, Error> {
+    match identifier {
+        identifier if identifier == NoFeeModel::get_identifier() => {
+            Result::Ok(Box::new(NoFeeModel::new(config_map)))
+        }
+
+        #[cfg(feature = "broker__longbridge")]
+        identifier if identifier == LongBridgeFeeModel::get_identifier() => {
+            Result::Ok(Box::new(LongBridgeFeeModel::new(config_map)))
+        }
+
+        #[cfg(feature = "broker__interactive_brokers")]
+        identifier if identifier == InteractiveBrokersFeeModel::get_identifier() => {
+            Result::Ok(Box::new(InteractiveBrokersFeeModel::try_new(config_map)?))
+        }
+
+        _ => Result::Err(anyhow!("IDENTIFIER_NOT_MATCHED FeeModel: {}", identifier)),
+    }
+}
+
+pub fn get_broker_identifier_list() -> Vec<String> {
+    vec![
+        #[cfg(feature = "broker__longbridge")]
+        LongBridgeBroker::get_identifier(),
+        #[cfg(feature = "broker__yahoo_finance")]
+        YahooFinanceBroker::get_identifier(),
+        #[cfg(feature = "broker__interactive_brokers")]
+        InteractiveBrokersBroker::get_identifier(),
+        #[cfg(feature = "broker__replay")]
+        ReplayBroker::get_identifier(),
+    ]
+}
+
+pub fn get_fee_model_identifier_list() -> Vec<String> {
+    vec![
+        NoFeeModel::get_identifier(),
+        #[cfg(feature = "broker__longbridge")]
+        LongBridgeFeeModel::get_identifier(),
+        #[cfg(feature = "broker__interactive_brokers")]
+        InteractiveBrokersFeeModel::get_identifier(),
+    ]
+}
+
+pub fn get_broker_config_key_list(identifier: &str) -> Result<Vec<ConfigKeySpec>, Error> {
+    match identifier {
+        #[cfg(feature = "broker__longbridge")]
+        identifier if identifier == LongBridgeBroker::get_identifier() => {
+            Result::Ok(LongBridgeBroker::get_config_key_list())
+        }
+
+        #[cfg(feature = "broker__yahoo_finance")]
+        identifier if identifier == YahooFinanceBroker::get_identifier() => {
+            Result::Ok(YahooFinanceBroker::get_config_key_list())
+        }
+
+        #[cfg(feature = "broker__interactive_brokers")]
+        identifier if identifier == InteractiveBrokersBroker::get_identifier() => {
+            Result::Ok(InteractiveBrokersBroker::get_config_key_list())
+        }
+
+        #[cfg(feature = "broker__replay")]
+        identifier if identifier == ReplayBroker::get_identifier() => {
+            Result::Ok(ReplayBroker::get_config_key_list())
+        }
+
         _ => Result::Err(anyhow!("IDENTIFIER_NOT_MATCHED Broker: {}", identifier)),
     }
 }
+
+pub fn get_fee_model_config_key_list(identifier: &str) -> Result<Vec<ConfigKeySpec>, Error> {
+    match identifier {
+        identifier if identifier == NoFeeModel::get_identifier() => {
+            Result::Ok(NoFeeModel::get_config_key_list())
+        }
+
+        #[cfg(feature = "broker__longbridge")]
+        identifier if identifier == LongBridgeFeeModel::get_identifier() => {
+            Result::Ok(LongBridgeFeeModel::get_config_key_list())
+        }
+
+        #[cfg(feature = "broker__interactive_brokers")]
+        identifier if identifier == InteractiveBrokersFeeModel::get_identifier() => {
+            Result::Ok(InteractiveBrokersFeeModel::get_config_key_list())
+        }
+
+        _ => Result::Err(anyhow!("IDENTIFIER_NOT_MATCHED FeeModel: {}", identifier)),
+    }
+}
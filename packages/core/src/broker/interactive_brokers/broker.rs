@@ -1,25 +1,33 @@
+use anyhow::Error;
 use ibkr_client_portal::{client::IBClientPortal, retry_policies::ExponentialBackoff};
 use std::sync::{atomic::AtomicBool, Arc};
 
 use super::{
-    heartbeat::InteractiveBrokersHeartbeat, info::InteractiveBrokersInfo,
-    subscription::InteractiveBrokersSubscription, transaction::InteractiveBrokersTransaction,
+    fee::InteractiveBrokersFeeModel, heartbeat::InteractiveBrokersHeartbeat,
+    info::InteractiveBrokersInfo, subscription::InteractiveBrokersSubscription,
+    transaction::InteractiveBrokersTransaction,
 };
 use crate::{
     broker::common::{
         broker::{BrokerInterceptorFactoryTrait, BrokerTrait},
+        fee::FeeModelTrait,
         heartbeat::HeartbeatTrait,
         info::{InfoProxy, InfoTrait},
         subscription::{SubscriptionProxy, SubscriptionTrait},
         transaction::{TransactionProxy, TransactionTrait},
     },
-    model::common::types::ConfigMap,
+    model::common::{
+        config_key::{ConfigKeySpec, ConfigValueType},
+        types::ConfigMap,
+    },
+    utils::clock::common_trait::ClockTrait,
 };
 
 pub struct InteractiveBrokersBroker {
     config_map: ConfigMap,
     interceptor_factory: Box<dyn BrokerInterceptorFactoryTrait>,
     stopped_indicator: Arc<AtomicBool>,
+    clock: Arc<dyn ClockTrait>,
 }
 
 impl InteractiveBrokersBroker {
@@ -28,6 +36,7 @@ impl InteractiveBrokersBroker {
     pub const CONFIG_KEY_SSL: &'static str = "ibkr.cp.ssl";
     pub const CONFIG_KEY_HOST: &'static str = "ibkr.cp.host";
     pub const CONFIG_KEY_YAML_PATH: &'static str = "ibkr.cp.yaml.path";
+    pub const CONFIG_KEY_UTC_OFFSET: &'static str = "ibkr.cp.utc.offset";
     pub const CONFIG_VALUE_DEFAULT_HOST: &'static str = "localhost:5000";
     pub const CONFIG_VALUE_DEFAULT_YAML_PATH: &'static str = "./ib.yaml";
 
@@ -67,11 +76,13 @@ impl BrokerTrait for InteractiveBrokersBroker {
         interceptor_factory: Box<dyn BrokerInterceptorFactoryTrait>,
         config_map: ConfigMap,
         stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
     ) -> Self {
         InteractiveBrokersBroker {
             config_map,
             interceptor_factory,
             stopped_indicator,
+            clock,
         }
     }
 
@@ -80,9 +91,52 @@ impl BrokerTrait for InteractiveBrokersBroker {
         IDENTIFIER.to_owned()
     }
 
+    fn get_config_key_list() -> Vec<ConfigKeySpec> {
+        let mut config_key_list = vec![
+            ConfigKeySpec::required(
+                Self::CONFIG_KEY_ACCOUNT,
+                ConfigValueType::String,
+                "Account id used by the client portal",
+            ),
+            ConfigKeySpec::required(
+                Self::CONFIG_KEY_MAX_REPLY_COUNT,
+                ConfigValueType::Integer,
+                "Maximum number of order confirmation messages replied to when placing an order",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_SSL,
+                ConfigValueType::Bool,
+                Option::Some("true"),
+                "Whether the client portal gateway listens with SSL",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_HOST,
+                ConfigValueType::String,
+                Option::Some(Self::CONFIG_VALUE_DEFAULT_HOST),
+                "Host and port of the client portal gateway",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_YAML_PATH,
+                ConfigValueType::String,
+                Option::Some(Self::CONFIG_VALUE_DEFAULT_YAML_PATH),
+                "Path of the client portal gateway config",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_UTC_OFFSET,
+                ConfigValueType::UtcOffset,
+                Option::None,
+                "UTC offset of the account time zone, e.g. -05:00, the time zone of the market is used when unset",
+            ),
+        ];
+        config_key_list.extend(InteractiveBrokersFeeModel::get_config_key_list());
+        config_key_list
+    }
+
     fn create_info(&self) -> Box<dyn InfoTrait> {
-        let interactive_brokers_info =
-            Box::new(InteractiveBrokersInfo::new(self.config_map.clone()));
+        let interactive_brokers_info = Box::new(InteractiveBrokersInfo::new(
+            self.config_map.clone(),
+            self.clock.clone(),
+        ));
         Box::new(InfoProxy::new(
             interactive_brokers_info,
             self.interceptor_factory.create_info_interceptor(),
@@ -93,6 +147,7 @@ impl BrokerTrait for InteractiveBrokersBroker {
         let interactive_brokers_subscription = Box::new(InteractiveBrokersSubscription::new(
             self.config_map.clone(),
             self.stopped_indicator.clone(),
+            self.clock.clone(),
         ));
         Box::new(SubscriptionProxy::new(
             interactive_brokers_subscription,
@@ -100,19 +155,28 @@ impl BrokerTrait for InteractiveBrokersBroker {
         ))
     }
 
-    fn create_transaction(&self) -> Box<dyn TransactionTrait> {
-        let interactive_brokers_transaction =
-            Box::new(InteractiveBrokersTransaction::new(self.config_map.clone()));
-        Box::new(TransactionProxy::new(
+    fn create_transaction(&self) -> Result<Box<dyn TransactionTrait>, Error> {
+        let interactive_brokers_transaction = Box::new(InteractiveBrokersTransaction::new(
+            self.config_map.clone(),
+            self.clock.clone(),
+        ));
+        Result::Ok(Box::new(TransactionProxy::new(
             interactive_brokers_transaction,
             self.interceptor_factory.create_transaction_interceptor(),
-        ))
+        )))
     }
 
     fn create_heartbeat(&self) -> Option<Box<dyn HeartbeatTrait>> {
         Option::Some(Box::new(InteractiveBrokersHeartbeat::new(
             self.config_map.clone(),
             self.stopped_indicator.clone(),
+            self.clock.clone(),
         )))
     }
+
+    fn create_fee_model(&self) -> Result<Box<dyn FeeModelTrait>, Error> {
+        Result::Ok(Box::new(InteractiveBrokersFeeModel::try_new(
+            self.config_map.clone(),
+        )?))
+    }
 }
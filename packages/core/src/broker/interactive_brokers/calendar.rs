@@ -0,0 +1,322 @@
+use anyhow::{anyhow, Context, Error};
+use async_trait::async_trait;
+use ibkr_client_portal::{
+    client::IBClientPortal,
+    model::{
+        contract::{GetSecurityTradingScheduleRequest, TradingSchedule},
+        definition::AssetClass,
+    },
+};
+use std::collections::HashMap;
+use time::{macros::format_description, Date, Time};
+use tokio::sync::RwLock;
+
+use super::broker::InteractiveBrokersBroker;
+use crate::{
+    broker::common::broker::BrokerTrait,
+    model::{
+        common::{
+            config_key::{ConfigKeySpec, ConfigValueType},
+            timestamp::Timestamp,
+            types::ConfigMap,
+        },
+        trading::market::Market,
+    },
+    utils::calendar::{
+        common_trait::{TradingCalendarTrait, TradingDay, TradingSessionKind},
+        schedule::{MarketSchedule, SessionTime},
+        static_calendar::StaticTradingCalendar,
+    },
+};
+
+// Overrides the built-in sessions with the schedule IBKR reports for a reference contract of
+// the market, the static calendar is used for the days not covered or when the request fails.
+// The schedule is refreshed at most once per local day of each market, the built-in one used
+// after a failed request is not kept so that the next query fetches again.
+pub struct InteractiveBrokersTradingCalendar {
+    client_portal: IBClientPortal,
+    static_calendar: StaticTradingCalendar,
+    reference_map: HashMap<Market, (String, Option<String>)>,
+    market_schedule_map: RwLock<HashMap<Market, (Date, MarketSchedule)>>,
+}
+
+impl InteractiveBrokersTradingCalendar {
+    // value in "<symbol>" or "<symbol>@<exchange>" format, e.g. "700@SEHK"
+    pub const CONFIG_KEY_REFERENCE_PREFIX: &'static str = "ibkr.calendar.reference.";
+    pub const CONFIG_VALUE_DEFAULT_US_REFERENCE: &'static str = "SPY";
+    pub const CONFIG_VALUE_DEFAULT_HK_REFERENCE: &'static str = "700@SEHK";
+
+    fn parse_reference(reference: &str) -> (String, Option<String>) {
+        match reference.split_once('@') {
+            Option::Some((symbol, exchange)) => {
+                (symbol.to_owned(), Option::Some(exchange.to_owned()))
+            }
+            Option::None => (reference.to_owned(), Option::None),
+        }
+    }
+
+    fn parse_time(time: &Option<String>) -> Result<Time, Error> {
+        let time = time
+            .as_deref()
+            .with_context(|| format!("Error time not exists in the schedule"))?;
+        Time::parse(time, format_description!("[hour][minute]"))
+            .with_context(|| format!("PARSING_ERROR Error when parsing time {}", time))
+    }
+
+    // Liquid hours are the regular sessions, the trading times around them are the extended
+    // hours and the gaps in between are lunch breaks
+    pub fn trading_schedule_to_session_list(
+        trading_schedule: &TradingSchedule,
+    ) -> Result<Vec<SessionTime>, Error> {
+        let mut regular_list = trading_schedule
+            .sessions
+            .iter()
+            .flatten()
+            .map(|session| {
+                Result::Ok(SessionTime::new(
+                    TradingSessionKind::Regular,
+                    Self::parse_time(&session.opening_time)?,
+                    Self::parse_time(&session.closing_time)?,
+                ))
+            })
+            .collect::<Result<Vec<SessionTime>, Error>>()?;
+        regular_list.sort_by_key(|session| session.start);
+        let (first, last) = match (regular_list.first(), regular_list.last()) {
+            (Option::Some(first), Option::Some(last)) => (first.start, last.end),
+            _ => return Result::Ok(vec![]),
+        };
+
+        let mut session_list = vec![];
+        if let Option::Some(trading_time) = &trading_schedule.trading_times {
+            let opening_time = Self::parse_time(&trading_time.opening_time)?;
+            if opening_time < first {
+                session_list.push(SessionTime::new(
+                    TradingSessionKind::PreMarket,
+                    opening_time,
+                    first,
+                ));
+            }
+        }
+        for (index, session) in regular_list.iter().enumerate() {
+            if index > 0 && regular_list[index - 1].end < session.start {
+                session_list.push(SessionTime::new(
+                    TradingSessionKind::LunchBreak,
+                    regular_list[index - 1].end,
+                    session.start,
+                ));
+            }
+            session_list.push(session.clone());
+        }
+        if let Option::Some(trading_time) = &trading_schedule.trading_times {
+            let closing_time = Self::parse_time(&trading_time.closing_time)?;
+            if last < closing_time {
+                session_list.push(SessionTime::new(
+                    TradingSessionKind::AfterHours,
+                    last,
+                    closing_time,
+                ));
+            }
+        }
+        Result::Ok(session_list)
+    }
+
+    // Dates like 20000101 to 20000107 stand for weekdays and are already part of the built-in
+    // sessions, only concrete dates are applied
+    pub fn parse_schedule_date(trading_schedule: &TradingSchedule) -> Option<Date> {
+        trading_schedule
+            .trading_schedule_date
+            .as_deref()
+            .filter(|date| !date.starts_with("2000010"))
+            .and_then(|date| Date::parse(date, format_description!("[year][month][day]")).ok())
+    }
+
+    async fn fetch_market_schedule(&self, market: &Market) -> Result<MarketSchedule, Error> {
+        let mut market_schedule = self.static_calendar.get_market_schedule(market).clone();
+        let (symbol, exchange) = self
+            .reference_map
+            .get(market)
+            .with_context(|| format!("Error no reference contract for market {:?}", market))?;
+        let response = self
+            .client_portal
+            .get_security_trading_schedule(GetSecurityTradingScheduleRequest {
+                asset_class: AssetClass::Stock,
+                symbol: symbol.clone(),
+                exchange: exchange.clone(),
+                exchange_filter: Option::None,
+            })
+            .await?;
+        let trading_schedule_list = response
+            .into_iter()
+            .find_map(|security_trading_schedule| security_trading_schedule.schedules)
+            .ok_or_else(|| anyhow!("Error empty trading schedule for {}", symbol))?;
+        for trading_schedule in trading_schedule_list.iter() {
+            let date = match Self::parse_schedule_date(trading_schedule) {
+                Option::Some(date) => date,
+                Option::None => continue,
+            };
+            market_schedule.set_day_session_list(
+                date,
+                Self::trading_schedule_to_session_list(trading_schedule)?,
+            );
+        }
+        Result::Ok(market_schedule)
+    }
+
+    async fn get_market_schedule(
+        &self,
+        market: &Market,
+        timestamp: Timestamp,
+    ) -> Result<MarketSchedule, Error> {
+        let local_date = timestamp
+            .to_offset_date_time()?
+            .to_offset(MarketSchedule::get_standard_utc_offset(market))
+            .date();
+        if let Option::Some((refreshed_date, market_schedule)) =
+            self.market_schedule_map.read().await.get(market)
+        {
+            if *refreshed_date == local_date {
+                return Result::Ok(market_schedule.clone());
+            }
+        }
+
+        let market_schedule = match self.reference_map.contains_key(market) {
+            true => match self.fetch_market_schedule(market).await {
+                Result::Ok(market_schedule) => market_schedule,
+                Result::Err(err) => {
+                    // not cached, the fetch is retried on the next query
+                    log::warn!(
+                        "Error when fetching trading schedule of {:?}, using the built-in one, {}",
+                        market,
+                        err
+                    );
+                    return Result::Ok(self.static_calendar.get_market_schedule(market).clone());
+                }
+            },
+            false => self.static_calendar.get_market_schedule(market).clone(),
+        };
+        self.market_schedule_map
+            .write()
+            .await
+            .insert(*market, (local_date, market_schedule.clone()));
+        Result::Ok(market_schedule)
+    }
+
+    // fails when the account is not configured or the holiday file cannot be read
+    pub fn try_new(config_map: ConfigMap) -> Result<Self, Error> {
+        if !config_map.contains_key(InteractiveBrokersBroker::CONFIG_KEY_ACCOUNT) {
+            return Result::Err(anyhow!(
+                "MISSING_CONFIG {} is required for the calendar",
+                InteractiveBrokersBroker::CONFIG_KEY_ACCOUNT
+            ));
+        }
+        let static_calendar = StaticTradingCalendar::try_new(config_map.clone())?;
+        let mut reference_map = HashMap::from([
+            (
+                Market::US,
+                Self::parse_reference(Self::CONFIG_VALUE_DEFAULT_US_REFERENCE),
+            ),
+            (
+                Market::HK,
+                Self::parse_reference(Self::CONFIG_VALUE_DEFAULT_HK_REFERENCE),
+            ),
+        ]);
+        for market in [Market::CN, Market::HK, Market::US] {
+            let key = format!(
+                "{}{}",
+                Self::CONFIG_KEY_REFERENCE_PREFIX,
+                market.to_string()
+            );
+            if let Option::Some(reference) = config_map.get(&key) {
+                reference_map.insert(market, Self::parse_reference(reference));
+            }
+        }
+        Result::Ok(InteractiveBrokersTradingCalendar {
+            client_portal: InteractiveBrokersBroker::create_ib_client_portal(config_map),
+            static_calendar,
+            reference_map,
+            market_schedule_map: RwLock::new(HashMap::new()),
+        })
+    }
+}
+
+#[async_trait]
+impl TradingCalendarTrait for InteractiveBrokersTradingCalendar {
+    fn new(config_map: ConfigMap) -> Self {
+        Self::try_new(config_map).unwrap()
+    }
+
+    fn get_identifier() -> String {
+        const IDENTIFIER: &'static str = "InteractiveBrokersTradingCalendar";
+        IDENTIFIER.to_owned()
+    }
+
+    fn get_config_key_list() -> Vec<ConfigKeySpec> {
+        let mut config_key_list: Vec<ConfigKeySpec> =
+            InteractiveBrokersBroker::get_config_key_list()
+                .into_iter()
+                .filter(|config_key| {
+                    [
+                        InteractiveBrokersBroker::CONFIG_KEY_ACCOUNT,
+                        InteractiveBrokersBroker::CONFIG_KEY_HOST,
+                        InteractiveBrokersBroker::CONFIG_KEY_SSL,
+                    ]
+                    .contains(&config_key.key)
+                })
+                .collect();
+        config_key_list.extend([
+            ConfigKeySpec::optional(
+                "ibkr.calendar.reference.US",
+                ConfigValueType::String,
+                Option::Some(Self::CONFIG_VALUE_DEFAULT_US_REFERENCE),
+                "Contract whose trading schedule is used for the US market, as `symbol@exchange`",
+            ),
+            ConfigKeySpec::optional(
+                "ibkr.calendar.reference.HK",
+                ConfigValueType::String,
+                Option::Some(Self::CONFIG_VALUE_DEFAULT_HK_REFERENCE),
+                "Contract whose trading schedule is used for the HK market, as `symbol@exchange`",
+            ),
+            ConfigKeySpec::optional(
+                "ibkr.calendar.reference.CN",
+                ConfigValueType::String,
+                Option::None,
+                "Contract whose trading schedule is used for the CN market, as `symbol@exchange`",
+            ),
+        ]);
+        // markets without a reference fall back to the static calendar
+        config_key_list.extend(StaticTradingCalendar::get_config_key_list());
+        config_key_list
+    }
+
+    async fn get_session_kind(
+        &self,
+        market: &Market,
+        timestamp: Timestamp,
+    ) -> Result<Option<TradingSessionKind>, Error> {
+        self.get_market_schedule(market, timestamp)
+            .await?
+            .get_session_kind(timestamp)
+    }
+
+    async fn next_open(&self, market: &Market, timestamp: Timestamp) -> Result<Timestamp, Error> {
+        self.get_market_schedule(market, timestamp)
+            .await?
+            .next_open(timestamp)
+    }
+
+    async fn next_close(&self, market: &Market, timestamp: Timestamp) -> Result<Timestamp, Error> {
+        self.get_market_schedule(market, timestamp)
+            .await?
+            .next_close(timestamp)
+    }
+
+    async fn next_trading_day(
+        &self,
+        market: &Market,
+        timestamp: Timestamp,
+    ) -> Result<TradingDay, Error> {
+        self.get_market_schedule(market, timestamp)
+            .await?
+            .next_trading_day(timestamp)
+    }
+}
@@ -0,0 +1,176 @@
+use anyhow::{anyhow, Error};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::{
+    broker::common::fee::{
+        get_hk_market_fee_item_list, get_per_share_fee, get_us_regulatory_fee_item_list,
+        round_up_to_cent, FeeModelTrait,
+    },
+    model::{
+        common::{
+            config_key::{ConfigKeySpec, ConfigValueType},
+            types::ConfigMap,
+        },
+        trading::{
+            fee::{CalculateFeeRequest, Fee, FeeItem, FeeKind},
+            market::Market,
+        },
+    },
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InteractiveBrokersPricingPlan {
+    Fixed,
+    // the per share rate of US stocks depends on the shares traded in the month
+    Tiered { monthly_volume: Decimal },
+}
+
+// Exchange fees of the tiered plan depend on the venue and liquidity, they are not modelled
+pub struct InteractiveBrokersFeeModel {
+    pricing_plan: InteractiveBrokersPricingPlan,
+}
+
+impl InteractiveBrokersFeeModel {
+    pub const CONFIG_KEY_PRICING_PLAN: &'static str = "ibkr.fee.pricing_plan";
+    pub const CONFIG_KEY_MONTHLY_VOLUME: &'static str = "ibkr.fee.monthly_volume";
+    pub const CONFIG_VALUE_PRICING_PLAN_FIXED: &'static str = "fixed";
+    pub const CONFIG_VALUE_PRICING_PLAN_TIERED: &'static str = "tiered";
+
+    pub fn new_with_pricing_plan(pricing_plan: InteractiveBrokersPricingPlan) -> Self {
+        InteractiveBrokersFeeModel { pricing_plan }
+    }
+
+    // fails on an unknown pricing plan or a monthly volume that is not a decimal, rather than
+    // estimating the fees with another plan
+    pub fn try_new(config_map: ConfigMap) -> Result<Self, Error> {
+        let pricing_plan = match config_map
+            .get(Self::CONFIG_KEY_PRICING_PLAN)
+            .map(|pricing_plan| pricing_plan.as_str())
+        {
+            Option::None | Option::Some(Self::CONFIG_VALUE_PRICING_PLAN_FIXED) => {
+                InteractiveBrokersPricingPlan::Fixed
+            }
+            Option::Some(Self::CONFIG_VALUE_PRICING_PLAN_TIERED) => {
+                InteractiveBrokersPricingPlan::Tiered {
+                    monthly_volume: match config_map.get(Self::CONFIG_KEY_MONTHLY_VOLUME) {
+                        Option::Some(monthly_volume) => monthly_volume.parse().map_err(|err| {
+                            anyhow!(
+                                "PARSING_ERROR {}: {}, {}",
+                                Self::CONFIG_KEY_MONTHLY_VOLUME,
+                                monthly_volume,
+                                err
+                            )
+                        })?,
+                        Option::None => Decimal::ZERO,
+                    },
+                }
+            }
+            Option::Some(pricing_plan) => {
+                return Result::Err(anyhow!(
+                    "ILLEGAL_VALUE {}: {}",
+                    Self::CONFIG_KEY_PRICING_PLAN,
+                    pricing_plan
+                ));
+            }
+        };
+        Result::Ok(Self::new_with_pricing_plan(pricing_plan))
+    }
+
+    fn get_us_tiered_rate(monthly_volume: Decimal) -> Decimal {
+        match monthly_volume {
+            volume if volume <= dec!(300_000) => dec!(0.0035),
+            volume if volume <= dec!(3_000_000) => dec!(0.002),
+            volume if volume <= dec!(20_000_000) => dec!(0.0015),
+            volume if volume <= dec!(100_000_000) => dec!(0.001),
+            _ => dec!(0.0005),
+        }
+    }
+
+    fn get_us_fee_item_list(&self, request: &CalculateFeeRequest) -> Vec<FeeItem> {
+        let mut item_list = match &self.pricing_plan {
+            InteractiveBrokersPricingPlan::Fixed => vec![FeeItem {
+                kind: FeeKind::Commission,
+                amount: get_per_share_fee(request, dec!(0.005), dec!(1), dec!(0.01)),
+            }],
+            InteractiveBrokersPricingPlan::Tiered { monthly_volume } => vec![
+                FeeItem {
+                    kind: FeeKind::Commission,
+                    amount: get_per_share_fee(
+                        request,
+                        Self::get_us_tiered_rate(*monthly_volume),
+                        dec!(0.35),
+                        dec!(0.01),
+                    ),
+                },
+                FeeItem {
+                    kind: FeeKind::ClearingFee,
+                    amount: round_up_to_cent(request.quantity * dec!(0.0002)),
+                },
+            ],
+        };
+        item_list.extend(get_us_regulatory_fee_item_list(request));
+        item_list
+    }
+
+    fn get_hk_fee_item_list(&self, request: &CalculateFeeRequest) -> Vec<FeeItem> {
+        let rate = match &self.pricing_plan {
+            InteractiveBrokersPricingPlan::Fixed => dec!(0.0008),
+            InteractiveBrokersPricingPlan::Tiered { .. } => dec!(0.0005),
+        };
+        let mut item_list = vec![FeeItem {
+            kind: FeeKind::Commission,
+            amount: round_up_to_cent((request.get_value() * rate).max(dec!(18))),
+        }];
+        item_list.extend(get_hk_market_fee_item_list(request));
+        item_list
+    }
+}
+
+impl FeeModelTrait for InteractiveBrokersFeeModel {
+    fn new(config_map: ConfigMap) -> Self {
+        Self::try_new(config_map).unwrap()
+    }
+
+    fn get_identifier() -> String {
+        const IDENTIFIER: &'static str = "InteractiveBrokersFeeModel";
+        IDENTIFIER.to_owned()
+    }
+
+    fn get_config_key_list() -> Vec<ConfigKeySpec> {
+        vec![
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_PRICING_PLAN,
+                ConfigValueType::OneOf(&[
+                    Self::CONFIG_VALUE_PRICING_PLAN_FIXED,
+                    Self::CONFIG_VALUE_PRICING_PLAN_TIERED,
+                ]),
+                Option::Some(Self::CONFIG_VALUE_PRICING_PLAN_FIXED),
+                "Pricing plan of the account",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_MONTHLY_VOLUME,
+                ConfigValueType::Decimal,
+                Option::Some("0"),
+                "Monthly traded shares, which decide the rate of the tiered plan",
+            ),
+        ]
+    }
+
+    fn calculate_fee(&self, request: &CalculateFeeRequest) -> Result<Fee, Error> {
+        let item_list = match request.symbol.market {
+            Market::US => self.get_us_fee_item_list(request),
+            Market::HK => self.get_hk_fee_item_list(request),
+            Market::CN => {
+                return Result::Err(anyhow!(
+                    "FEE_NOT_SUPPORTED market {:?}",
+                    request.symbol.market
+                ))
+            }
+        };
+        Result::Ok(Fee {
+            currency: request.symbol.market.get_currency(),
+            item_list,
+        })
+    }
+}
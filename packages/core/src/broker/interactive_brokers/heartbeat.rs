@@ -5,22 +5,31 @@ use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc,
 };
-use tokio::time::{sleep, Duration};
+use tokio::time::Duration;
 
 use super::broker::InteractiveBrokersBroker;
-use crate::{broker::common::heartbeat::HeartbeatTrait, model::common::types::ConfigMap};
+use crate::{
+    broker::common::heartbeat::HeartbeatTrait, model::common::types::ConfigMap,
+    utils::clock::common_trait::ClockTrait,
+};
 
 pub struct InteractiveBrokersHeartbeat {
     client_portal: IBClientPortal,
     stopped_indicator: Arc<AtomicBool>,
+    clock: Arc<dyn ClockTrait>,
 }
 
 #[async_trait]
 impl HeartbeatTrait for InteractiveBrokersHeartbeat {
-    fn new(config_map: ConfigMap, stopped_indicator: Arc<AtomicBool>) -> Self {
+    fn new(
+        config_map: ConfigMap,
+        stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
+    ) -> Self {
         InteractiveBrokersHeartbeat {
             client_portal: InteractiveBrokersBroker::create_ib_client_portal(config_map),
             stopped_indicator,
+            clock,
         }
     }
 
@@ -33,7 +42,7 @@ impl HeartbeatTrait for InteractiveBrokersHeartbeat {
             if let Err(err) = self.client_portal.tickle().await {
                 log::error!("Error when tickle {}", err);
             }
-            sleep(Duration::from_millis(1000)).await;
+            self.clock.sleep(Duration::from_millis(1000)).await;
         }
     }
 
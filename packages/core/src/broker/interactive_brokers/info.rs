@@ -8,23 +8,25 @@ use ibkr_client_portal::{
         market_data::{GetMarketDataRequest, MarketData},
     },
 };
+use std::sync::Arc;
 
 use super::{broker::InteractiveBrokersBroker, config::IBConfig, symbol::IBSymbolHelper};
 use crate::{
     broker::common::info::InfoTrait,
     model::{
-        common::types::ConfigMap,
+        common::{timestamp::Timestamp, types::ConfigMap},
         trading::{
             quote::{Depth, QueryInfoRequest, QuoteBasicInfo, QuoteDepthInfo, QuoteRealTimeInfo},
             symbol::Symbol,
         },
     },
-    utils::time::get_now_unix_timestamp,
+    utils::clock::common_trait::ClockTrait,
 };
 
 pub struct InteractiveBrokersInfo {
     client_portal: IBClientPortal,
     ib_symbol_helper: IBSymbolHelper,
+    clock: Arc<dyn ClockTrait>,
 }
 
 impl InteractiveBrokersInfo {
@@ -38,16 +40,16 @@ impl InteractiveBrokersInfo {
     fn market_data_to_quote_real_time_info(
         symbol: Symbol,
         market_data: &MarketData,
+        current_timestamp: Timestamp,
     ) -> Result<QuoteRealTimeInfo, Error> {
-        let sequence = get_now_unix_timestamp();
         let timestamp = market_data
             .updated
-            .map(|val| val as u64)
-            .unwrap_or(sequence);
+            .map(|val| Timestamp::from_millis(val as u64))
+            .unwrap_or(current_timestamp);
 
         Result::Ok(QuoteRealTimeInfo {
             symbol,
-            sequence,
+            sequence: current_timestamp.as_nanos(),
             timestamp,
             current_price: InteractiveBrokersBroker::parse_last_price(
                 market_data.last_price.clone(),
@@ -65,6 +67,7 @@ impl InteractiveBrokersInfo {
     fn market_data_to_quote_depth_info(
         symbol: Symbol,
         market_data: &MarketData,
+        current_timestamp: Timestamp,
     ) -> Result<QuoteDepthInfo, Error> {
         let ask_price = market_data
             .ask_price
@@ -88,14 +91,13 @@ impl InteractiveBrokersInfo {
             order_count: Option::None,
         };
 
-        let sequence = get_now_unix_timestamp();
         let timestamp = market_data
             .updated
-            .map(|val| val as u64)
-            .unwrap_or(sequence);
+            .map(|val| Timestamp::from_millis(val as u64))
+            .unwrap_or(current_timestamp);
         Result::Ok(QuoteDepthInfo {
             symbol,
-            sequence,
+            sequence: current_timestamp.as_nanos(),
             timestamp,
             ask_list: vec![ask_depth],
             bid_list: vec![bid_depth],
@@ -105,7 +107,7 @@ impl InteractiveBrokersInfo {
 
 #[async_trait]
 impl InfoTrait for InteractiveBrokersInfo {
-    fn new(config_map: ConfigMap) -> Self {
+    fn new(config_map: ConfigMap, clock: Arc<dyn ClockTrait>) -> Self {
         let client_portal = InteractiveBrokersBroker::create_ib_client_portal(config_map.clone());
         let ib_config = IBConfig::new(&config_map).unwrap();
         let ib_symbol_helper = IBSymbolHelper::new(ib_config);
@@ -113,6 +115,7 @@ impl InfoTrait for InteractiveBrokersInfo {
         InteractiveBrokersInfo {
             client_portal,
             ib_symbol_helper,
+            clock,
         }
     }
 
@@ -154,6 +157,7 @@ impl InfoTrait for InteractiveBrokersInfo {
             Some(market_data) => Result::Ok(Self::market_data_to_quote_real_time_info(
                 request.symbol.clone(),
                 market_data,
+                self.clock.now(),
             )?),
             None => Result::Err(anyhow!(
                 "Error when retrieving the real_time_info of the security {:?}",
@@ -184,6 +188,7 @@ impl InfoTrait for InteractiveBrokersInfo {
             Some(market_data) => Result::Ok(Self::market_data_to_quote_depth_info(
                 request.symbol.clone(),
                 market_data,
+                self.clock.now(),
             )?),
             None => Result::Err(anyhow!(
                 "Error when retrieving the depth of the security {:?}",
@@ -1,5 +1,7 @@
 pub mod broker;
+pub mod calendar;
 pub mod config;
+pub mod fee;
 pub mod heartbeat;
 pub mod info;
 pub mod subscription;
@@ -15,19 +15,26 @@ use crate::{
         common::types::ConfigMap,
         trading::quote::{QueryInfoRequest, QuoteDepthInfo, QuoteRealTimeInfo},
     },
+    utils::clock::common_trait::ClockTrait,
 };
 
 pub struct InteractiveBrokersSubscription {
     config_map: ConfigMap,
     global_stopped_indicator: Arc<AtomicBool>,
+    clock: Arc<dyn ClockTrait>,
 }
 
 #[async_trait]
 impl SubscriptionTrait for InteractiveBrokersSubscription {
-    fn new(config_map: ConfigMap, global_stopped_indicator: Arc<AtomicBool>) -> Self {
+    fn new(
+        config_map: ConfigMap,
+        global_stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
+    ) -> Self {
         InteractiveBrokersSubscription {
             config_map,
             global_stopped_indicator,
+            clock,
         }
     }
 
@@ -44,6 +51,7 @@ impl SubscriptionTrait for InteractiveBrokersSubscription {
             sys_sender,
             local_stopped_indicator.clone(),
             self.global_stopped_indicator.clone(),
+            self.clock.clone(),
         );
         let controller = IBQuoteRealTimeInfoSubscriptionController::new(local_stopped_indicator);
         tokio::task::spawn(worker.start());
@@ -63,6 +71,7 @@ impl SubscriptionTrait for InteractiveBrokersSubscription {
             sys_sender,
             local_stopped_indicator.clone(),
             self.global_stopped_indicator.clone(),
+            self.clock.clone(),
         );
         let controller = IBQuoteDepthInfoSubscriptionController::new(local_stopped_indicator);
         tokio::task::spawn(worker.start());
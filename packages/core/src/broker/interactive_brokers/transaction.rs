@@ -3,41 +3,53 @@ use async_trait::async_trait;
 use ibkr_client_portal::{
     client::IBClientPortal,
     model::{
-        account::{GetAccountSummaryRequest, GetAccountSummaryResponse},
+        account::{AccountTrade, GetAccountSummaryRequest, GetAccountSummaryResponse},
         order::{
-            CancelOrderRequest as IBCancelOrderRequest, GetOrderStatusRequest, ModifyOrderRequest,
-            OrderRequest, OrderStatus, PlaceOrdersRequest,
+            CancelOrderRequest as IBCancelOrderRequest, GetOrderStatusRequest, LiveOrder,
+            ModifyOrderRequest, OrderRequest, OrderStatus, PlaceOrdersRequest,
         },
         portfolio::GetPortfolioPositionsRequest,
     },
-    utils::reply::handle_reply_order_requests,
+    utils::reply::{handle_reply_multiple_order_requests, handle_reply_order_requests},
 };
+use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
-use std::time::SystemTime;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+use time::{macros::format_description, PrimitiveDateTime, UtcOffset};
 
 use super::{broker::InteractiveBrokersBroker, config::IBConfig, symbol::IBSymbolHelper};
 use crate::{
     broker::common::transaction::TransactionTrait,
     model::{
-        common::types::ConfigMap,
+        common::{timestamp::Timestamp, types::ConfigMap},
         trading::{
             balance::{BalanceDetail, BalanceHashMap},
             currency::Currency,
+            market::Market,
             position::PositionList,
             transaction::{
                 BuyingPower, CancelOrderRequest, CancelOrderResponse, Direction, EditOrderRequest,
-                EditOrderResponse, EstimateMaxBuyingPowerRequest, Expire, OrderDetail,
-                OrderDetailRequest, Price, SubmitOrderRequest, SubmitOrderResponse,
-                TrailingLimitPrice, TrailingMarketPrice,
+                EditOrderResponse, EstimateMaxBuyingPowerRequest, Execution, Expire,
+                ListExecutionsRequest, ListOrderHistoryRequest, OrderDetail, OrderDetailRequest,
+                OrderStatus as CoreOrderStatus, Price, SubmitBracketOrderRequest,
+                SubmitBracketOrderResponse, SubmitOcoOrderRequest, SubmitOcoOrderResponse,
+                SubmitOrderRequest, SubmitOrderResponse, TrailingLimitPrice, TrailingMarketPrice,
             },
         },
     },
+    utils::{
+        calendar::schedule::MarketSchedule, clock::common_trait::ClockTrait, time::parse_utc_offset,
+    },
 };
 
 pub struct InteractiveBrokersTransaction {
     config_map: ConfigMap,
     client_portal: IBClientPortal,
     ib_symbol_helper: IBSymbolHelper,
+    clock: Arc<dyn ClockTrait>,
 }
 
 impl InteractiveBrokersTransaction {
@@ -175,18 +187,18 @@ impl InteractiveBrokersTransaction {
                     .with_context(|| format!("Error limit_price not exists in the response"))?,
             },
             "MKT" | "MARKET" => Price::MarketOrder,
-            "STP" => Price::MarketIfTouched {
-                trigger_price: order_status
+            "STP" => Price::Stop {
+                stop_price: order_status
                     .stop_price
                     .with_context(|| format!("Error stop_price not exists in the response"))?,
             },
-            "STOP_LIMIT" => Price::LimitIfTouched {
-                submit_price: order_status
-                    .limit_price
-                    .with_context(|| format!("Error limit_price not exists in the response"))?,
-                trigger_price: order_status
+            "STOP_LIMIT" => Price::StopLimit {
+                stop_price: order_status
                     .stop_price
                     .with_context(|| format!("Error stop_price not exists in the response"))?,
+                limit_price: order_status
+                    .limit_price
+                    .with_context(|| format!("Error limit_price not exists in the response"))?,
             },
             "TRAIL" | "TRAILING_STOP" => Price::TrailingMarketIfTouched {
                 trailing: match order_status
@@ -252,9 +264,20 @@ impl InteractiveBrokersTransaction {
         let executed_quantity = order_status
             .cum_fill
             .with_context(|| format!("Error cum_fill not exists in the response"))?;
+        let status = Self::ib_order_status_to_core_order_status(
+            order_status.order_status.as_deref().unwrap_or_default(),
+            executed_quantity,
+        );
+        let utc_offset = self
+            .config_map
+            .get(InteractiveBrokersBroker::CONFIG_KEY_UTC_OFFSET)
+            .map(|utc_offset| parse_utc_offset(utc_offset))
+            .transpose()?;
         let created_timestamp = order_status
             .order_time
-            .and_then(|order_time| order_time.parse::<u64>().ok());
+            .as_deref()
+            .map(|order_time| Self::parse_order_time(order_time, utc_offset, &symbol.market))
+            .transpose()?;
 
         Result::Ok(OrderDetail {
             order_id,
@@ -264,15 +287,128 @@ impl InteractiveBrokersTransaction {
             executed_quantity,
             price,
             executed_price: Option::None, // TODO
+            fee: Option::None,            // commissions are only reported per execution
             direction,
             regular_trading_time,
             expire,
+            status,
             created_timestamp,
             updated_timestamp: Option::None,
             triggered_timestamp: Option::None,
         })
     }
 
+    // IBKR reports order times as yyMMddHHmmss, e.g. 231012093000, in the time zone of the account
+    pub fn parse_order_time(
+        order_time: &str,
+        utc_offset: Option<UtcOffset>,
+        market: &Market,
+    ) -> Result<Timestamp, Error> {
+        let date_time = PrimitiveDateTime::parse(
+            &format!("20{}", order_time),
+            format_description!("[year][month][day][hour][minute][second]"),
+        )
+        .with_context(|| format!("PARSING_ERROR Error when parsing order time {}", order_time))?;
+        let utc_offset =
+            utc_offset.unwrap_or_else(|| MarketSchedule::get_utc_offset(market, date_time.date()));
+        Result::Ok(Timestamp::from(date_time.assume_offset(utc_offset)))
+    }
+
+    pub fn ib_order_status_to_core_order_status(
+        order_status: &str,
+        executed_quantity: Decimal,
+    ) -> CoreOrderStatus {
+        match order_status {
+            "PendingSubmit" | "PreSubmitted" => CoreOrderStatus::Pending,
+            "Submitted" | "PendingCancel" | "WarnState" => match executed_quantity.is_zero() {
+                true => CoreOrderStatus::Submitted,
+                false => CoreOrderStatus::PartiallyFilled,
+            },
+            "Filled" => CoreOrderStatus::Filled,
+            "Cancelled" => CoreOrderStatus::Cancelled,
+            "Inactive" => CoreOrderStatus::Rejected,
+            _ => CoreOrderStatus::Unknown,
+        }
+    }
+
+    // The live orders endpoint only carries a summary, so the details are fetched one by one
+    async fn list_live_order_details(
+        &self,
+        predicate: impl Fn(&LiveOrder) -> bool,
+    ) -> Result<Vec<OrderDetail>, Error> {
+        let live_orders = self
+            .client_portal
+            .get_live_orders()
+            .await
+            .with_context(|| format!("Error when get_live_orders"))?;
+
+        let mut order_detail_list = vec![];
+        for live_order in live_orders.orders.iter().filter(|order| predicate(order)) {
+            let order_id = live_order
+                .order_id
+                .with_context(|| format!("Error order_id not exists in {:?}", live_order))?;
+            order_detail_list.push(
+                self.order_detail(OrderDetailRequest {
+                    order_id: order_id.to_string(),
+                })
+                .await?,
+            );
+        }
+        Result::Ok(order_detail_list)
+    }
+
+    fn ib_account_trade_to_core_execution(
+        &self,
+        account_trade: &AccountTrade,
+    ) -> Result<Execution, Error> {
+        let execution_id = account_trade
+            .execution_id
+            .clone()
+            .with_context(|| format!("Error execution_id not exists in {:?}", account_trade))?;
+        let conid = account_trade
+            .conid
+            .with_context(|| format!("Error conid not exists in {:?}", account_trade))?;
+        let symbol = self
+            .ib_symbol_helper
+            .get_symbol(conid)
+            .with_context(|| format!("Error when get symbol from conid {}", conid))?;
+        let direction = account_trade
+            .side
+            .as_deref()
+            .map(Self::side_to_direction)
+            .transpose()?;
+        let quantity = account_trade
+            .size
+            .as_deref()
+            .with_context(|| format!("Error size not exists in {:?}", account_trade))?
+            .replace(',', "")
+            .parse::<Decimal>()
+            .with_context(|| format!("Error when parsing size {:?}", account_trade.size))?;
+        let price = account_trade
+            .price
+            .as_deref()
+            .with_context(|| format!("Error price not exists in {:?}", account_trade))?
+            .replace(',', "")
+            .parse::<Decimal>()
+            .with_context(|| format!("Error when parsing price {:?}", account_trade.price))?;
+        let timestamp = account_trade
+            .trade_time_r
+            .map(|trade_time| Timestamp::from_millis(trade_time as u64))
+            .with_context(|| format!("Error trade_time_r not exists in {:?}", account_trade))?;
+
+        Result::Ok(Execution {
+            execution_id,
+            order_id: Option::None, // not provided by the trades endpoint
+            symbol,
+            direction,
+            quantity,
+            price,
+            fee: account_trade.commission,
+            currency: Option::None,
+            timestamp,
+        })
+    }
+
     fn core_edit_order_request_to_ib_modify_order_request(
         &self,
         account_id: String,
@@ -331,6 +467,18 @@ impl InteractiveBrokersTransaction {
                 modify_order_request.order_type = Option::Some("STP".to_owned());
                 modify_order_request.price = Option::Some(trigger_price.clone());
             }
+            Price::Stop { stop_price } => {
+                modify_order_request.order_type = Option::Some("STP".to_owned());
+                modify_order_request.price = Option::Some(*stop_price);
+            }
+            Price::StopLimit {
+                stop_price,
+                limit_price,
+            } => {
+                modify_order_request.order_type = Option::Some("STOP_LIMIT".to_owned());
+                modify_order_request.price = Option::Some(*limit_price);
+                modify_order_request.aux_price = Option::Some(*stop_price);
+            }
             Price::TrailingLimitIfTouched { trailing } => match trailing {
                 TrailingLimitPrice::Amount {
                     limit_offset,
@@ -392,12 +540,13 @@ impl InteractiveBrokersTransaction {
             conid: Option::None,
             conidex: Option::Some(conid.to_string()),
             sec_type: Option::None,
-            c_oid: Option::Some(Self::generate_customer_order_id()),
+            c_oid: Option::Some(Self::generate_customer_order_id(self.clock.as_ref())),
             parent_id: Option::None,
             order_type: "LMT".to_owned(),
             limit_offset: Option::None,
             listing_exchange: Option::None,
             is_single_group: Option::None,
+            oca_group: Option::None,
             outside_regular_trading_hours,
             price: Option::None,
             aux_price: Option::None,
@@ -441,6 +590,18 @@ impl InteractiveBrokersTransaction {
                 order_request.order_type = "STP".to_owned();
                 order_request.price = Option::Some(trigger_price.clone());
             }
+            Price::Stop { stop_price } => {
+                order_request.order_type = "STP".to_owned();
+                order_request.price = Option::Some(*stop_price);
+            }
+            Price::StopLimit {
+                stop_price,
+                limit_price,
+            } => {
+                order_request.order_type = "STOP_LIMIT".to_owned();
+                order_request.price = Option::Some(*limit_price);
+                order_request.aux_price = Option::Some(*stop_price);
+            }
             Price::TrailingLimitIfTouched { trailing } => match trailing {
                 TrailingLimitPrice::Amount {
                     limit_offset,
@@ -521,21 +682,21 @@ impl InteractiveBrokersTransaction {
         }
     }
 
-    fn generate_customer_order_id() -> String {
-        // TODO: modularize this function
+    // Unique across the calls of the process, also when several come within the same tick of
+    // the clock, so that unrelated orders never share a cOID or an OCA group
+    pub fn generate_customer_order_id(clock: &dyn ClockTrait) -> String {
+        static SEQUENCE: AtomicU64 = AtomicU64::new(0);
         format!(
-            "rt_{}",
-            SystemTime::now()
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .unwrap()
-                .as_secs() as u64
+            "rt_{}_{}",
+            clock.now().as_nanos(),
+            SEQUENCE.fetch_add(1, Ordering::Relaxed)
         )
     }
 }
 
 #[async_trait]
 impl TransactionTrait for InteractiveBrokersTransaction {
-    fn new(config_map: ConfigMap) -> Self {
+    fn new(config_map: ConfigMap, clock: Arc<dyn ClockTrait>) -> Self {
         let client_portal = InteractiveBrokersBroker::create_ib_client_portal(config_map.clone());
         let ib_config = IBConfig::new(&config_map).unwrap();
         let ib_symbol_helper = IBSymbolHelper::new(ib_config);
@@ -544,6 +705,7 @@ impl TransactionTrait for InteractiveBrokersTransaction {
             config_map,
             client_portal,
             ib_symbol_helper,
+            clock,
         }
     }
 
@@ -608,6 +770,66 @@ impl TransactionTrait for InteractiveBrokersTransaction {
         )
     }
 
+    async fn list_open_orders(&self) -> Result<Vec<OrderDetail>, Error> {
+        self.list_live_order_details(|live_order| {
+            Self::ib_order_status_to_core_order_status(
+                live_order.status.as_deref().unwrap_or_default(),
+                live_order.filled_quantity.unwrap_or_default(),
+            )
+            .is_open()
+        })
+        .await
+    }
+
+    // IBKR only returns the orders of the current trading session
+    async fn list_order_history(
+        &self,
+        request: ListOrderHistoryRequest,
+    ) -> Result<Vec<OrderDetail>, Error> {
+        let conid_option = match &request.symbol {
+            Option::Some(symbol) => Option::Some(
+                self.ib_symbol_helper
+                    .get_conid(symbol)
+                    .with_context(|| format!("Error when get conid from symbol {:?}", symbol))?,
+            ),
+            Option::None => Option::None,
+        };
+        let order_detail_list = self
+            .list_live_order_details(|live_order| {
+                conid_option.is_none_or(|conid| live_order.conid == Option::Some(conid))
+            })
+            .await
+            .with_context(|| format!("Error when list_order_history {:?}", request))?;
+
+        Result::Ok(
+            order_detail_list
+                .into_iter()
+                .filter(|order_detail| request.is_matched(order_detail))
+                .collect(),
+        )
+    }
+
+    // IBKR returns the trades of the current day and six previous days
+    async fn list_executions(
+        &self,
+        request: ListExecutionsRequest,
+    ) -> Result<Vec<Execution>, Error> {
+        let account_trades = self
+            .client_portal
+            .get_account_trades()
+            .await
+            .with_context(|| format!("Error when list_executions {:?}", request))?;
+
+        let mut execution_list = vec![];
+        for account_trade in account_trades.iter() {
+            let execution = self.ib_account_trade_to_core_execution(account_trade)?;
+            if request.is_matched(&execution) {
+                execution_list.push(execution);
+            }
+        }
+        Result::Ok(execution_list)
+    }
+
     async fn submit_order(
         &mut self,
         request: SubmitOrderRequest,
@@ -634,6 +856,115 @@ impl TransactionTrait for InteractiveBrokersTransaction {
         Result::Ok(SubmitOrderResponse { order_id })
     }
 
+    // Children refer to the cOID of the parent, IBKR puts them into the same OCA group
+    async fn submit_bracket_order(
+        &mut self,
+        request: SubmitBracketOrderRequest,
+    ) -> Result<SubmitBracketOrderResponse, Error> {
+        let account_id = InteractiveBrokersBroker::get_account_id(&self.config_map);
+        let max_retry_count =
+            InteractiveBrokersBroker::get_place_order_max_reply_count(&self.config_map);
+        let parent_order = self
+            .core_submit_order_request_to_ib_order(account_id.clone(), request.entry.clone())
+            .await?;
+        let parent_c_oid = parent_order
+            .c_oid
+            .clone()
+            .with_context(|| format!("Error cOID not exists in {:?}", parent_order))?;
+
+        let mut orders = vec![parent_order];
+        for price in [request.take_profit.clone(), request.stop_loss.clone()]
+            .into_iter()
+            .flatten()
+        {
+            let mut child_order = self
+                .core_submit_order_request_to_ib_order(
+                    account_id.clone(),
+                    request.get_child_request(price),
+                )
+                .await?;
+            child_order.c_oid = Option::None;
+            child_order.parent_id = Option::Some(parent_c_oid.clone());
+            orders.push(child_order);
+        }
+        let order_count = orders.len();
+
+        let place_order_response = self
+            .client_portal
+            .place_orders(PlaceOrdersRequest { account_id, orders })
+            .await?;
+        let order_id_list = handle_reply_multiple_order_requests(
+            self.client_portal.clone(),
+            place_order_response,
+            max_retry_count,
+        )
+        .await?;
+        if order_id_list.len() != order_count {
+            return Result::Err(anyhow!(
+                "Error when submit_bracket_order {:?}, order ids {:?}",
+                request,
+                order_id_list
+            ));
+        }
+
+        let mut order_id_iter = order_id_list.into_iter();
+        let entry_order_id = order_id_iter.next().unwrap();
+        let take_profit_order_id = request
+            .take_profit
+            .as_ref()
+            .and_then(|_| order_id_iter.next());
+        let stop_loss_order_id = request
+            .stop_loss
+            .as_ref()
+            .and_then(|_| order_id_iter.next());
+        Result::Ok(SubmitBracketOrderResponse {
+            entry_order_id,
+            take_profit_order_id,
+            stop_loss_order_id,
+        })
+    }
+
+    async fn submit_oco_order(
+        &mut self,
+        request: SubmitOcoOrderRequest,
+    ) -> Result<SubmitOcoOrderResponse, Error> {
+        let account_id = InteractiveBrokersBroker::get_account_id(&self.config_map);
+        let max_retry_count =
+            InteractiveBrokersBroker::get_place_order_max_reply_count(&self.config_map);
+        let oca_group = format!(
+            "oca_{}",
+            Self::generate_customer_order_id(self.clock.as_ref())
+        );
+
+        let mut orders = vec![];
+        for order in request.order_list.iter() {
+            let mut order_request = self
+                .core_submit_order_request_to_ib_order(account_id.clone(), order.clone())
+                .await?;
+            order_request.oca_group = Option::Some(oca_group.clone());
+            orders.push(order_request);
+        }
+
+        let place_order_response = self
+            .client_portal
+            .place_orders(PlaceOrdersRequest { account_id, orders })
+            .await?;
+        let order_id_list = handle_reply_multiple_order_requests(
+            self.client_portal.clone(),
+            place_order_response,
+            max_retry_count,
+        )
+        .await?;
+        if order_id_list.len() != request.order_list.len() {
+            return Result::Err(anyhow!(
+                "Error when submit_oco_order {:?}, order ids {:?}",
+                request,
+                order_id_list
+            ));
+        }
+        Result::Ok(SubmitOcoOrderResponse { order_id_list })
+    }
+
     async fn edit_order(&mut self, request: EditOrderRequest) -> Result<EditOrderResponse, Error> {
         let account_id = InteractiveBrokersBroker::get_account_id(&self.config_map);
         let max_retry_count =
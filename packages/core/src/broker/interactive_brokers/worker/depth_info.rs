@@ -21,13 +21,13 @@ use crate::{
         },
     },
     model::{
-        common::types::ConfigMap,
+        common::{timestamp::Timestamp, types::ConfigMap},
         trading::{
             quote::{Depth, QuoteDepthInfo},
             symbol::Symbol,
         },
     },
-    utils::time::get_now_unix_timestamp,
+    utils::clock::common_trait::ClockTrait,
 };
 
 pub struct IBQuoteDepthInfoSubscriptionWorker {
@@ -37,6 +37,7 @@ pub struct IBQuoteDepthInfoSubscriptionWorker {
     local_stopped_indicator: Arc<AtomicBool>,
     global_stopped_indicator: Arc<AtomicBool>,
     ib_symbol_helper: IBSymbolHelper,
+    clock: Arc<dyn ClockTrait>,
 }
 
 impl IBQuoteDepthInfoSubscriptionWorker {
@@ -46,6 +47,7 @@ impl IBQuoteDepthInfoSubscriptionWorker {
         sys_sender: Sender<QuoteDepthInfo>,
         local_stopped_indicator: Arc<AtomicBool>,
         global_stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
     ) -> Self {
         let ib_config = IBConfig::new(&config_map).unwrap();
         let ib_symbol_helper = IBSymbolHelper::new(ib_config);
@@ -57,6 +59,7 @@ impl IBQuoteDepthInfoSubscriptionWorker {
             local_stopped_indicator,
             global_stopped_indicator,
             ib_symbol_helper,
+            clock,
         }
     }
 
@@ -90,6 +93,7 @@ impl IBQuoteDepthInfoSubscriptionWorker {
     fn market_data_response_to_quote_depth_info(
         symbol: Symbol,
         data: MarketDataResponse,
+        current_timestamp: Timestamp,
     ) -> QuoteDepthInfo {
         // TODO: use the macro to unify the codes
         let ask_depth = Depth {
@@ -105,11 +109,13 @@ impl IBQuoteDepthInfoSubscriptionWorker {
             order_count: Option::None,
         };
 
-        let sequence = get_now_unix_timestamp();
-        let timestamp = data.updated.map(|val| val as u64).unwrap_or(sequence);
+        let timestamp = data
+            .updated
+            .map(|val| Timestamp::from_millis(val as u64))
+            .unwrap_or(current_timestamp);
         QuoteDepthInfo {
             symbol,
-            sequence,
+            sequence: current_timestamp.as_nanos(),
             timestamp,
             ask_list: vec![ask_depth],
             bid_list: vec![bid_depth],
@@ -156,6 +162,7 @@ impl SubscriptionWorker for IBQuoteDepthInfoSubscriptionWorker {
                             .send(Self::market_data_response_to_quote_depth_info(
                                 self.symbol.clone(),
                                 data,
+                                self.clock.now(),
                             ))
                             .await
                         {
@@ -186,8 +193,8 @@ impl IBQuoteDepthInfoSubscriptionController {
 
 #[async_trait]
 impl SubscriptionController for IBQuoteDepthInfoSubscriptionController {
-    async fn stop(self) -> Result<(), Error> {
-        self.local_stopped_indicator.store(false, Ordering::Relaxed);
+    async fn stop(&self) -> Result<(), Error> {
+        self.local_stopped_indicator.store(true, Ordering::Relaxed);
         Result::Ok(())
     }
 }
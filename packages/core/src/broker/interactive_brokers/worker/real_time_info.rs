@@ -21,10 +21,10 @@ use crate::{
         },
     },
     model::{
-        common::types::ConfigMap,
+        common::{timestamp::Timestamp, types::ConfigMap},
         trading::{quote::QuoteRealTimeInfo, symbol::Symbol},
     },
-    utils::time::get_now_unix_timestamp,
+    utils::clock::common_trait::ClockTrait,
 };
 
 pub struct IBQuoteRealTimeInfoSubscriptionWorker {
@@ -34,6 +34,7 @@ pub struct IBQuoteRealTimeInfoSubscriptionWorker {
     local_stopped_indicator: Arc<AtomicBool>,
     global_stopped_indicator: Arc<AtomicBool>,
     ib_symbol_helper: IBSymbolHelper,
+    clock: Arc<dyn ClockTrait>,
 }
 
 impl IBQuoteRealTimeInfoSubscriptionWorker {
@@ -43,6 +44,7 @@ impl IBQuoteRealTimeInfoSubscriptionWorker {
         sys_sender: Sender<QuoteRealTimeInfo>,
         local_stopped_indicator: Arc<AtomicBool>,
         global_stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
     ) -> Self {
         let ib_config = IBConfig::new(&config_map).unwrap();
         let ib_symbol_helper = IBSymbolHelper::new(ib_config);
@@ -54,6 +56,7 @@ impl IBQuoteRealTimeInfoSubscriptionWorker {
             local_stopped_indicator,
             global_stopped_indicator,
             ib_symbol_helper,
+            clock,
         }
     }
 
@@ -89,12 +92,15 @@ impl IBQuoteRealTimeInfoSubscriptionWorker {
     fn market_data_response_to_quote_real_time_info(
         symbol: Symbol,
         data: MarketDataResponse,
+        current_timestamp: Timestamp,
     ) -> Result<QuoteRealTimeInfo, Error> {
-        let sequence = get_now_unix_timestamp();
-        let timestamp = data.updated.map(|val| val as u64).unwrap_or(sequence);
+        let timestamp = data
+            .updated
+            .map(|val| Timestamp::from_millis(val as u64))
+            .unwrap_or(current_timestamp);
         Result::Ok(QuoteRealTimeInfo {
             symbol,
-            sequence,
+            sequence: current_timestamp.as_nanos(),
             timestamp,
             current_price: InteractiveBrokersBroker::parse_last_price(data.last_price)?,
             volume: data.volume_long,
@@ -146,6 +152,7 @@ impl SubscriptionWorker for IBQuoteRealTimeInfoSubscriptionWorker {
                             match Self::market_data_response_to_quote_real_time_info(
                                 self.symbol.clone(),
                                 data,
+                                self.clock.now(),
                             ) {
                                 Err(err) => {
                                     log::warn!("Error when market_data_response_to_quote_real_time_info {:?}", err);
@@ -185,8 +192,8 @@ impl IBQuoteRealTimeInfoSubscriptionController {
 
 #[async_trait]
 impl SubscriptionController for IBQuoteRealTimeInfoSubscriptionController {
-    async fn stop(self) -> Result<(), Error> {
-        self.local_stopped_indicator.store(false, Ordering::Relaxed);
+    async fn stop(&self) -> Result<(), Error> {
+        self.local_stopped_indicator.store(true, Ordering::Relaxed);
         Result::Ok(())
     }
 }
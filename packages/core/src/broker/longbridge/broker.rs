@@ -1,23 +1,32 @@
+use anyhow::Error;
 use std::sync::{atomic::AtomicBool, Arc};
 
 use super::{
-    info::LongBridgeInfo, subscription::LongBridgeSubscription, transaction::LongBridgeTransaction,
+    fee::LongBridgeFeeModel, info::LongBridgeInfo, subscription::LongBridgeSubscription,
+    transaction::LongBridgeTransaction,
 };
 use crate::{
     broker::common::{
         broker::{BrokerInterceptorFactoryTrait, BrokerTrait},
+        fee::FeeModelTrait,
         heartbeat::HeartbeatTrait,
         info::{InfoProxy, InfoTrait},
+        order_emulator::OrderEmulatorTransaction,
         subscription::{SubscriptionProxy, SubscriptionTrait},
         transaction::{TransactionProxy, TransactionTrait},
     },
-    model::common::types::ConfigMap,
+    model::common::{
+        config_key::{ConfigKeySpec, ConfigValueType},
+        types::ConfigMap,
+    },
+    utils::clock::common_trait::ClockTrait,
 };
 
 pub struct LongBridgeBroker {
     config_map: ConfigMap,
     interceptor_factory: Box<dyn BrokerInterceptorFactoryTrait>,
     stopped_indicator: Arc<AtomicBool>,
+    clock: Arc<dyn ClockTrait>,
 }
 
 impl BrokerTrait for LongBridgeBroker {
@@ -25,11 +34,13 @@ impl BrokerTrait for LongBridgeBroker {
         interceptor_factory: Box<dyn BrokerInterceptorFactoryTrait>,
         config_map: ConfigMap,
         stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
     ) -> Self {
         LongBridgeBroker {
             config_map,
             interceptor_factory,
             stopped_indicator,
+            clock,
         }
     }
 
@@ -38,8 +49,22 @@ impl BrokerTrait for LongBridgeBroker {
         IDENTIFIER.to_owned()
     }
 
+    fn get_config_key_list() -> Vec<ConfigKeySpec> {
+        let mut config_key_list = vec![ConfigKeySpec::optional(
+            OrderEmulatorTransaction::CONFIG_KEY_POLL_INTERVAL_MS,
+            ConfigValueType::Integer,
+            Option::Some("1000"),
+            "Interval in milliseconds between two checks of locally emulated orders",
+        )];
+        config_key_list.extend(LongBridgeFeeModel::get_config_key_list());
+        config_key_list
+    }
+
     fn create_info(&self) -> Box<dyn InfoTrait> {
-        let longbridge_info = Box::new(LongBridgeInfo::new(self.config_map.clone()));
+        let longbridge_info = Box::new(LongBridgeInfo::new(
+            self.config_map.clone(),
+            self.clock.clone(),
+        ));
         Box::new(InfoProxy::new(
             longbridge_info,
             self.interceptor_factory.create_info_interceptor(),
@@ -50,6 +75,7 @@ impl BrokerTrait for LongBridgeBroker {
         let longbridge_subscription = Box::new(LongBridgeSubscription::new(
             self.config_map.clone(),
             self.stopped_indicator.clone(),
+            self.clock.clone(),
         ));
         Box::new(SubscriptionProxy::new(
             longbridge_subscription,
@@ -57,15 +83,33 @@ impl BrokerTrait for LongBridgeBroker {
         ))
     }
 
-    fn create_transaction(&self) -> Box<dyn TransactionTrait> {
-        let longbridge_transaction = Box::new(LongBridgeTransaction::new(self.config_map.clone()));
-        Box::new(TransactionProxy::new(
-            longbridge_transaction,
+    fn create_transaction(&self) -> Result<Box<dyn TransactionTrait>, Error> {
+        // the emulator places its orders through the proxy, so the interceptor also sees the
+        // triggered stop orders and the legs of bracket and OCO orders
+        let longbridge_transaction = Box::new(TransactionProxy::new(
+            Box::new(LongBridgeTransaction::new(
+                self.config_map.clone(),
+                self.clock.clone(),
+            )),
             self.interceptor_factory.create_transaction_interceptor(),
-        ))
+        ));
+        Result::Ok(Box::new(OrderEmulatorTransaction::new(
+            longbridge_transaction,
+            Box::new(LongBridgeInfo::new(
+                self.config_map.clone(),
+                self.clock.clone(),
+            )),
+            &self.config_map,
+            self.stopped_indicator.clone(),
+            self.clock.clone(),
+        )))
     }
 
     fn create_heartbeat(&self) -> Option<Box<dyn HeartbeatTrait>> {
         Option::None
     }
+
+    fn create_fee_model(&self) -> Result<Box<dyn FeeModelTrait>, Error> {
+        Result::Ok(Box::new(LongBridgeFeeModel::new(self.config_map.clone())))
+    }
 }
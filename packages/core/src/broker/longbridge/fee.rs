@@ -0,0 +1,117 @@
+use anyhow::{anyhow, Error};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::{
+    broker::common::fee::{
+        get_hk_market_fee_item_list, get_per_share_fee, get_us_regulatory_fee_item_list,
+        round_up_to_cent, FeeModelTrait,
+    },
+    model::{
+        common::{
+            config_key::{ConfigKeySpec, ConfigValueType},
+            types::ConfigMap,
+        },
+        trading::{
+            fee::{CalculateFeeRequest, Fee, FeeItem, FeeKind},
+            market::Market,
+        },
+    },
+};
+
+// Standard schedule, accounts under a commission free promotion only pay the platform fee
+pub struct LongBridgeFeeModel {
+    is_commission_free: bool,
+}
+
+impl LongBridgeFeeModel {
+    pub const CONFIG_KEY_COMMISSION_FREE: &'static str = "longbridge.fee.commission_free";
+
+    fn get_commission(&self, amount: Decimal) -> FeeItem {
+        FeeItem {
+            kind: FeeKind::Commission,
+            amount: match self.is_commission_free {
+                true => Decimal::ZERO,
+                false => amount,
+            },
+        }
+    }
+
+    fn get_us_fee_item_list(&self, request: &CalculateFeeRequest) -> Vec<FeeItem> {
+        let mut item_list = vec![
+            self.get_commission(get_per_share_fee(
+                request,
+                dec!(0.0049),
+                dec!(0.99),
+                dec!(0.005),
+            )),
+            FeeItem {
+                kind: FeeKind::PlatformFee,
+                amount: get_per_share_fee(request, dec!(0.005), dec!(1), dec!(0.005)),
+            },
+            FeeItem {
+                kind: FeeKind::SettlementFee,
+                amount: round_up_to_cent(
+                    (request.quantity * dec!(0.003)).min(request.get_value() * dec!(0.07)),
+                ),
+            },
+        ];
+        item_list.extend(get_us_regulatory_fee_item_list(request));
+        item_list
+    }
+
+    fn get_hk_fee_item_list(&self, request: &CalculateFeeRequest) -> Vec<FeeItem> {
+        let mut item_list = vec![
+            self.get_commission(round_up_to_cent(
+                (request.get_value() * dec!(0.0003)).max(dec!(3)),
+            )),
+            FeeItem {
+                kind: FeeKind::PlatformFee,
+                amount: dec!(15),
+            },
+        ];
+        item_list.extend(get_hk_market_fee_item_list(request));
+        item_list
+    }
+}
+
+impl FeeModelTrait for LongBridgeFeeModel {
+    fn new(config_map: ConfigMap) -> Self {
+        LongBridgeFeeModel {
+            is_commission_free: config_map
+                .get(Self::CONFIG_KEY_COMMISSION_FREE)
+                .is_some_and(|is_commission_free| is_commission_free == "true"),
+        }
+    }
+
+    fn get_identifier() -> String {
+        const IDENTIFIER: &'static str = "LongBridgeFeeModel";
+        IDENTIFIER.to_owned()
+    }
+
+    fn get_config_key_list() -> Vec<ConfigKeySpec> {
+        vec![ConfigKeySpec::optional(
+            Self::CONFIG_KEY_COMMISSION_FREE,
+            ConfigValueType::Bool,
+            Option::Some("false"),
+            "Whether the account has a commission free plan",
+        )]
+    }
+
+    fn calculate_fee(&self, request: &CalculateFeeRequest) -> Result<Fee, Error> {
+        let item_list = match request.symbol.market {
+            Market::US => self.get_us_fee_item_list(request),
+            Market::HK => self.get_hk_fee_item_list(request),
+            Market::CN => {
+                return Result::Err(anyhow!(
+                    "FEE_NOT_SUPPORTED market {:?}",
+                    request.symbol.market
+                ))
+            }
+        };
+        Result::Ok(Fee {
+            currency: request.symbol.market.get_currency(),
+            item_list,
+        })
+    }
+}
@@ -1,28 +1,30 @@
 use anyhow::{anyhow, Context, Error};
 use async_trait::async_trait;
 use longbridge::quote::{SecurityDepth, SecurityQuote, SecurityStaticInfo};
-use std::result::Result;
+use std::{result::Result, sync::Arc};
 
 use super::broker::LongBridgeBroker;
 use crate::broker::common::info::InfoTrait;
 use crate::model::{
-    common::types::ConfigMap,
+    common::{timestamp::Timestamp, types::ConfigMap},
     trading::{
         quote::{QueryInfoRequest, QuoteBasicInfo, QuoteDepthInfo, QuoteRealTimeInfo},
         symbol::Symbol,
     },
 };
-use crate::utils::time::get_now_unix_timestamp;
+use crate::utils::clock::common_trait::ClockTrait;
 
-pub struct LongBridgeInfo {}
+pub struct LongBridgeInfo {
+    clock: Arc<dyn ClockTrait>,
+}
 
 impl LongBridgeInfo {
     fn to_quote_real_time_info(symbol: Symbol, security_quote: SecurityQuote) -> QuoteRealTimeInfo {
-        let unix_timestamp = security_quote.timestamp.unix_timestamp() as u64;
+        let timestamp = Timestamp::from(security_quote.timestamp);
         QuoteRealTimeInfo {
             symbol,
-            sequence: unix_timestamp,
-            timestamp: unix_timestamp,
+            sequence: timestamp.as_nanos(),
+            timestamp,
             current_price: security_quote.last_done,
             low_price: Option::Some(security_quote.low),
             high_price: Option::Some(security_quote.high),
@@ -60,12 +62,14 @@ impl LongBridgeInfo {
         }
     }
 
-    fn to_quote_depth_info(symbol: Symbol, security_depth: SecurityDepth) -> QuoteDepthInfo {
-        let current_timestamp = get_now_unix_timestamp();
-
+    fn to_quote_depth_info(
+        symbol: Symbol,
+        security_depth: SecurityDepth,
+        current_timestamp: Timestamp,
+    ) -> QuoteDepthInfo {
         QuoteDepthInfo {
             symbol,
-            sequence: current_timestamp,
+            sequence: current_timestamp.as_nanos(),
             timestamp: current_timestamp,
             ask_list: security_depth
                 .asks
@@ -92,8 +96,8 @@ impl LongBridgeInfo {
 
 #[async_trait]
 impl InfoTrait for LongBridgeInfo {
-    fn new(_config_map: ConfigMap) -> Self {
-        LongBridgeInfo {}
+    fn new(_config_map: ConfigMap, clock: Arc<dyn ClockTrait>) -> Self {
+        LongBridgeInfo { clock }
     }
 
     async fn query_basic_info(&self, request: QueryInfoRequest) -> Result<QuoteBasicInfo, Error> {
@@ -137,7 +141,9 @@ impl InfoTrait for LongBridgeInfo {
             .await
             .depth(symbol_identifier)
             .await
-            .map(|depth_info| Self::to_quote_depth_info(request.symbol.clone(), depth_info))
+            .map(|depth_info| {
+                Self::to_quote_depth_info(request.symbol.clone(), depth_info, self.clock.now())
+            })
             .with_context(|| format!("Error when querying depth info {:?}", request))
     }
 }
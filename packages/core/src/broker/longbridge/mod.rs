@@ -1,4 +1,5 @@
 pub mod broker;
+pub mod fee;
 pub mod info;
 pub mod subscription;
 pub mod transaction;
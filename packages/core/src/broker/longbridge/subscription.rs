@@ -22,20 +22,27 @@ use crate::{
         common::types::ConfigMap,
         trading::quote::{QueryInfoRequest, QuoteDepthInfo, QuoteRealTimeInfo},
     },
+    utils::clock::common_trait::ClockTrait,
 };
 
 // https://crates.io/crates/longbridge
 pub struct LongBridgeSubscription {
     pub global_stopped_indicator: Arc<AtomicBool>,
+    clock: Arc<dyn ClockTrait>,
 }
 
 impl LongBridgeSubscription {}
 
 #[async_trait]
 impl SubscriptionTrait for LongBridgeSubscription {
-    fn new(_config_map: ConfigMap, global_stopped_indicator: Arc<AtomicBool>) -> Self {
+    fn new(
+        _config_map: ConfigMap,
+        global_stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
+    ) -> Self {
         LongBridgeSubscription {
             global_stopped_indicator,
+            clock,
         }
     }
 
@@ -85,6 +92,7 @@ impl SubscriptionTrait for LongBridgeSubscription {
             longbridge_receiver,
             local_stopped_indicator.clone(),
             self.global_stopped_indicator.clone(),
+            self.clock.clone(),
         );
         let controller =
             LongBridgeQuoteDepthInfoSubscriptionController::new(local_stopped_indicator.clone());
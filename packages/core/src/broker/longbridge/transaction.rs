@@ -2,28 +2,34 @@ use anyhow::{anyhow, Context, Error};
 use async_trait::async_trait;
 use longbridge::trade::{
     AccountBalance, EstimateMaxPurchaseQuantityOptions, EstimateMaxPurchaseQuantityResponse,
-    OrderSide, OrderType, OutsideRTH, ReplaceOrderOptions, StockPosition, SubmitOrderOptions,
-    TimeInForceType,
+    GetHistoryExecutionsOptions, GetHistoryOrdersOptions, GetTodayExecutionsOptions,
+    GetTodayOrdersOptions, Order, OrderSide, OrderStatus, OrderType, OutsideRTH,
+    ReplaceOrderOptions, StockPosition, SubmitOrderOptions, TimeInForceType,
 };
-use rust_decimal::prelude::ToPrimitive;
-use time::Date;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use std::{collections::HashSet, sync::Arc};
+use time::{Date, OffsetDateTime, Time};
 
 use super::broker::LongBridgeBroker;
 use crate::{
     broker::common::transaction::TransactionTrait,
     model::{
-        common::types::ConfigMap,
+        common::{timestamp::Timestamp, types::ConfigMap},
         trading::{
             balance::{BalanceDetail, BalanceHashMap},
             position::PositionList,
             transaction::{
                 BuyingPower, CancelOrderRequest, CancelOrderResponse, Direction, EditOrderRequest,
-                EditOrderResponse, EstimateMaxBuyingPowerRequest, Expire, OrderDetail,
-                OrderDetailRequest, Price, RegularTradingTime, SubmitOrderRequest,
-                SubmitOrderResponse, TrailingLimitPrice, TrailingMarketPrice,
+                EditOrderResponse, EstimateMaxBuyingPowerRequest, Execution, Expire,
+                ListExecutionsRequest, ListOrderHistoryRequest, OrderDetail, OrderDetailRequest,
+                OrderStatus as CoreOrderStatus, Price, RegularTradingTime,
+                SubmitBracketOrderRequest, SubmitBracketOrderResponse, SubmitOcoOrderRequest,
+                SubmitOcoOrderResponse, SubmitOrderRequest, SubmitOrderResponse,
+                TrailingLimitPrice, TrailingMarketPrice,
             },
         },
     },
+    utils::clock::common_trait::ClockTrait,
 };
 
 pub struct LongBridgeTransaction {}
@@ -36,20 +42,25 @@ impl LongBridgeTransaction {
         }
     }
 
-    fn to_order_type(price: &Price) -> OrderType {
+    fn to_order_type(price: &Price) -> Result<OrderType, Error> {
         match price {
-            Price::LimitOrder { .. } => OrderType::LO,
-            Price::MarketOrder { .. } => OrderType::MO,
-            Price::LimitIfTouched { .. } => OrderType::LIT,
-            Price::MarketIfTouched { .. } => OrderType::MIT,
+            Price::LimitOrder { .. } => Result::Ok(OrderType::LO),
+            Price::MarketOrder { .. } => Result::Ok(OrderType::MO),
+            Price::LimitIfTouched { .. } => Result::Ok(OrderType::LIT),
+            Price::MarketIfTouched { .. } => Result::Ok(OrderType::MIT),
             Price::TrailingLimitIfTouched { trailing } => match trailing {
-                TrailingLimitPrice::Amount { .. } => OrderType::TSLPAMT,
-                TrailingLimitPrice::Percent { .. } => OrderType::TSLPPCT,
+                TrailingLimitPrice::Amount { .. } => Result::Ok(OrderType::TSLPAMT),
+                TrailingLimitPrice::Percent { .. } => Result::Ok(OrderType::TSLPPCT),
             },
             Price::TrailingMarketIfTouched { trailing } => match trailing {
-                TrailingMarketPrice::Amount { .. } => OrderType::TSMAMT,
-                TrailingMarketPrice::Percent { .. } => OrderType::TSMPCT,
+                TrailingMarketPrice::Amount { .. } => Result::Ok(OrderType::TSMAMT),
+                TrailingMarketPrice::Percent { .. } => Result::Ok(OrderType::TSMPCT),
             },
+            // emulated by the OrderEmulatorTransaction
+            Price::Stop { .. } | Price::StopLimit { .. } => Result::Err(anyhow!(
+                "ORDER_TYPE_NOT_SUPPORTED Stop orders are not supported by longbridge, {:?}",
+                price
+            )),
         }
     }
 
@@ -65,7 +76,7 @@ impl LongBridgeTransaction {
     fn to_submit_order_options(request: &SubmitOrderRequest) -> Result<SubmitOrderOptions, Error> {
         let mut submit_order_options_builder = SubmitOrderOptions::new(
             request.symbol.to_string(),
-            Self::to_order_type(&request.price),
+            Self::to_order_type(&request.price)?,
             Self::to_order_side(&request.direction),
             request.quantity.to_i64().unwrap(),
             Self::to_time_in_force(&request.expire)?,
@@ -121,12 +132,14 @@ impl LongBridgeTransaction {
                     submit_order_options_builder.trailing_percent(trailing_percent.clone())
                 }
             },
+            Price::Stop { .. } | Price::StopLimit { .. } => submit_order_options_builder,
         };
 
         Result::Ok(submit_order_options_builder)
     }
 
-    fn to_replace_order_options(request: &EditOrderRequest) -> ReplaceOrderOptions {
+    fn to_replace_order_options(request: &EditOrderRequest) -> Result<ReplaceOrderOptions, Error> {
+        Self::to_order_type(&request.price)?;
         let mut replace_order_options_builder =
             ReplaceOrderOptions::new(request.order_id.clone(), request.quantity.to_i64().unwrap());
 
@@ -166,9 +179,10 @@ impl LongBridgeTransaction {
                     replace_order_options_builder.trailing_percent(trailing_percent.clone())
                 }
             },
+            Price::Stop { .. } | Price::StopLimit { .. } => replace_order_options_builder,
         };
 
-        replace_order_options_builder
+        Result::Ok(replace_order_options_builder)
     }
 
     fn to_submit_order_response(
@@ -188,10 +202,10 @@ impl LongBridgeTransaction {
 
     fn to_estimate_max_purchase_quantity_options(
         request: EstimateMaxBuyingPowerRequest,
-    ) -> EstimateMaxPurchaseQuantityOptions {
+    ) -> Result<EstimateMaxPurchaseQuantityOptions, Error> {
         let mut builder = EstimateMaxPurchaseQuantityOptions::new(
             request.symbol.to_string(),
-            Self::to_order_type(&request.price),
+            Self::to_order_type(&request.price)?,
             Self::to_order_side(&request.direction),
         );
 
@@ -201,9 +215,11 @@ impl LongBridgeTransaction {
             Price::MarketIfTouched { trigger_price } => builder.price(trigger_price),
             Price::MarketOrder
             | Price::TrailingLimitIfTouched { .. }
-            | Price::TrailingMarketIfTouched { .. } => builder,
+            | Price::TrailingMarketIfTouched { .. }
+            | Price::Stop { .. }
+            | Price::StopLimit { .. } => builder,
         };
-        builder
+        Result::Ok(builder)
     }
 
     fn to_balance_detail(account_balance: &AccountBalance) -> BalanceDetail {
@@ -237,101 +253,205 @@ impl LongBridgeTransaction {
         }
     }
 
-    fn to_order_detail_response(
-        longbridge_order_detail: longbridge::trade::OrderDetail,
-    ) -> Result<OrderDetail, Error> {
-        let symbol = longbridge_order_detail.symbol.parse()?;
-        let currency = longbridge_order_detail.currency.parse()?;
-        let direction = Self::to_order_direction(longbridge_order_detail.side)?;
-        let regular_trading_time = match longbridge_order_detail
-            .outside_rth
-            .unwrap_or(OutsideRTH::AnyTime)
-        {
-            OutsideRTH::Unknown => Result::Err(anyhow!("PARSING_ERROR UNKNOWN_OUTSIDE_RTH"))?,
-            OutsideRTH::RTHOnly => RegularTradingTime::OnlyRegularTradingTime,
-            OutsideRTH::AnyTime => RegularTradingTime::AllTime,
-        };
-        let expire = match longbridge_order_detail.time_in_force {
-            TimeInForceType::Unknown => {
-                Result::Err(anyhow!("PARSING_ERROR UNKNOWN_TIME_IN_FORCE"))?
-            }
-            TimeInForceType::Day => Expire::Day,
-            TimeInForceType::GoodTilCanceled => Expire::GoodTillCancelled,
+    fn to_regular_trading_time(
+        outside_rth: Option<OutsideRTH>,
+    ) -> Result<RegularTradingTime, Error> {
+        match outside_rth.unwrap_or(OutsideRTH::AnyTime) {
+            OutsideRTH::Unknown => Result::Err(anyhow!("PARSING_ERROR UNKNOWN_OUTSIDE_RTH")),
+            OutsideRTH::RTHOnly => Result::Ok(RegularTradingTime::OnlyRegularTradingTime),
+            OutsideRTH::AnyTime => Result::Ok(RegularTradingTime::AllTime),
+        }
+    }
+
+    fn to_expire(
+        time_in_force: TimeInForceType,
+        expire_date: Option<Date>,
+    ) -> Result<Expire, Error> {
+        match time_in_force {
+            TimeInForceType::Unknown => Result::Err(anyhow!("PARSING_ERROR UNKNOWN_TIME_IN_FORCE")),
+            TimeInForceType::Day => Result::Ok(Expire::Day),
+            TimeInForceType::GoodTilCanceled => Result::Ok(Expire::GoodTillCancelled),
             TimeInForceType::GoodTilDate => {
-                let order_expire_date = longbridge_order_detail.expire_date.unwrap();
-                Expire::GoodTillDate {
-                    year: order_expire_date.clone().year(),
-                    month: order_expire_date.clone().month() as i32,
-                    day: order_expire_date.clone().day() as i32,
-                }
+                let order_expire_date = expire_date.unwrap();
+                Result::Ok(Expire::GoodTillDate {
+                    year: order_expire_date.year(),
+                    month: order_expire_date.month() as i32,
+                    day: order_expire_date.day() as i32,
+                })
             }
-        };
-        let price: Price = match longbridge_order_detail.order_type {
-            OrderType::LO => Price::LimitOrder {
-                price: longbridge_order_detail.price.unwrap(),
-            },
-            OrderType::MO => Price::MarketOrder {},
-            OrderType::LIT => Price::LimitIfTouched {
-                submit_price: longbridge_order_detail.price.unwrap(),
-                trigger_price: longbridge_order_detail.trigger_price.unwrap(),
-            },
-            OrderType::MIT => Price::MarketIfTouched {
-                trigger_price: longbridge_order_detail.trigger_price.unwrap(),
-            },
-            OrderType::TSLPAMT => Price::TrailingLimitIfTouched {
+        }
+    }
+
+    fn to_price(
+        order_type: OrderType,
+        price: Option<Decimal>,
+        trigger_price: Option<Decimal>,
+        limit_offset: Option<Decimal>,
+        trailing_amount: Option<Decimal>,
+        trailing_percent: Option<Decimal>,
+    ) -> Result<Price, Error> {
+        match order_type {
+            OrderType::LO => Result::Ok(Price::LimitOrder {
+                price: price.unwrap(),
+            }),
+            OrderType::MO => Result::Ok(Price::MarketOrder {}),
+            OrderType::LIT => Result::Ok(Price::LimitIfTouched {
+                submit_price: price.unwrap(),
+                trigger_price: trigger_price.unwrap(),
+            }),
+            OrderType::MIT => Result::Ok(Price::MarketIfTouched {
+                trigger_price: trigger_price.unwrap(),
+            }),
+            OrderType::TSLPAMT => Result::Ok(Price::TrailingLimitIfTouched {
                 trailing: TrailingLimitPrice::Amount {
-                    limit_offset: longbridge_order_detail.limit_offset.unwrap(),
-                    trailing_amount: longbridge_order_detail.trailing_amount.unwrap(),
+                    limit_offset: limit_offset.unwrap(),
+                    trailing_amount: trailing_amount.unwrap(),
                 },
-            },
-            OrderType::TSLPPCT => Price::TrailingLimitIfTouched {
+            }),
+            OrderType::TSLPPCT => Result::Ok(Price::TrailingLimitIfTouched {
                 trailing: TrailingLimitPrice::Percent {
-                    limit_offset: longbridge_order_detail.limit_offset.unwrap(),
-                    trailing_percent: longbridge_order_detail.trailing_percent.unwrap(),
+                    limit_offset: limit_offset.unwrap(),
+                    trailing_percent: trailing_percent.unwrap(),
                 },
-            },
-            OrderType::TSMAMT => Price::TrailingMarketIfTouched {
+            }),
+            OrderType::TSMAMT => Result::Ok(Price::TrailingMarketIfTouched {
                 trailing: TrailingMarketPrice::Amount {
-                    trailing_amount: longbridge_order_detail.trailing_amount.unwrap(),
+                    trailing_amount: trailing_amount.unwrap(),
                 },
-            },
-            OrderType::TSMPCT => Price::TrailingMarketIfTouched {
+            }),
+            OrderType::TSMPCT => Result::Ok(Price::TrailingMarketIfTouched {
                 trailing: TrailingMarketPrice::Percent {
-                    trailing_percent: longbridge_order_detail.trailing_percent.unwrap(),
+                    trailing_percent: trailing_percent.unwrap(),
                 },
-            },
+            }),
             OrderType::Unknown
             | OrderType::ELO
             | OrderType::AO
             | OrderType::ALO
             | OrderType::ODD
-            | OrderType::SLO => Result::Err(anyhow!("PARSING_ERROR UNKNOWN_ORDER_TYPE_MESSAGE"))?,
-        };
+            | OrderType::SLO => Result::Err(anyhow!("PARSING_ERROR UNKNOWN_ORDER_TYPE_MESSAGE")),
+        }
+    }
+
+    pub fn to_order_status(order_status: OrderStatus) -> CoreOrderStatus {
+        match order_status {
+            OrderStatus::NotReported
+            | OrderStatus::ReplacedNotReported
+            | OrderStatus::ProtectedNotReported
+            | OrderStatus::VarietiesNotReported
+            | OrderStatus::WaitToNew => CoreOrderStatus::Pending,
+            OrderStatus::New
+            | OrderStatus::WaitToReplace
+            | OrderStatus::PendingReplace
+            | OrderStatus::Replaced
+            | OrderStatus::WaitToCancel
+            | OrderStatus::PendingCancel => CoreOrderStatus::Submitted,
+            OrderStatus::PartialFilled => CoreOrderStatus::PartiallyFilled,
+            OrderStatus::Filled => CoreOrderStatus::Filled,
+            OrderStatus::Canceled | OrderStatus::PartialWithdrawal => CoreOrderStatus::Cancelled,
+            OrderStatus::Rejected => CoreOrderStatus::Rejected,
+            OrderStatus::Expired => CoreOrderStatus::Expired,
+            OrderStatus::Unknown => CoreOrderStatus::Unknown,
+        }
+    }
 
+    fn to_order_detail_response(
+        longbridge_order_detail: longbridge::trade::OrderDetail,
+    ) -> Result<OrderDetail, Error> {
         let order_detail = OrderDetail {
             order_id: longbridge_order_detail.order_id,
-            symbol,
-            currency,
+            symbol: longbridge_order_detail.symbol.parse()?,
+            currency: longbridge_order_detail.currency.parse()?,
             quantity: longbridge_order_detail.quantity.into(),
             executed_quantity: longbridge_order_detail.executed_quantity.into(),
-            price,
+            price: Self::to_price(
+                longbridge_order_detail.order_type,
+                longbridge_order_detail.price,
+                longbridge_order_detail.trigger_price,
+                longbridge_order_detail.limit_offset,
+                longbridge_order_detail.trailing_amount,
+                longbridge_order_detail.trailing_percent,
+            )?,
             executed_price: longbridge_order_detail.executed_price,
-            direction,
-            regular_trading_time,
-            expire,
-            created_timestamp: Option::Some(
-                longbridge_order_detail.submitted_at.unix_timestamp() as u64
-            ),
-            updated_timestamp: longbridge_order_detail
-                .updated_at
-                .map(|timestamp| timestamp.unix_timestamp() as u64),
-            triggered_timestamp: longbridge_order_detail
-                .trigger_at
-                .map(|timestamp| timestamp.unix_timestamp() as u64),
+            fee: Option::Some(longbridge_order_detail.charge_detail.total_amount),
+            direction: Self::to_order_direction(longbridge_order_detail.side)?,
+            regular_trading_time: Self::to_regular_trading_time(
+                longbridge_order_detail.outside_rth,
+            )?,
+            expire: Self::to_expire(
+                longbridge_order_detail.time_in_force,
+                longbridge_order_detail.expire_date,
+            )?,
+            status: Self::to_order_status(longbridge_order_detail.status),
+            created_timestamp: Option::Some(Timestamp::from(longbridge_order_detail.submitted_at)),
+            updated_timestamp: longbridge_order_detail.updated_at.map(Timestamp::from),
+            triggered_timestamp: longbridge_order_detail.trigger_at.map(Timestamp::from),
         };
         Result::Ok(order_detail)
     }
 
+    fn to_order(longbridge_order: Order) -> Result<OrderDetail, Error> {
+        Result::Ok(OrderDetail {
+            order_id: longbridge_order.order_id,
+            symbol: longbridge_order.symbol.parse()?,
+            currency: longbridge_order.currency.parse()?,
+            quantity: longbridge_order.quantity.into(),
+            executed_quantity: longbridge_order.executed_quantity.into(),
+            price: Self::to_price(
+                longbridge_order.order_type,
+                longbridge_order.price,
+                longbridge_order.trigger_price,
+                longbridge_order.limit_offset,
+                longbridge_order.trailing_amount,
+                longbridge_order.trailing_percent,
+            )?,
+            executed_price: longbridge_order.executed_price,
+            fee: Option::None,
+            direction: Self::to_order_direction(longbridge_order.side)?,
+            regular_trading_time: Self::to_regular_trading_time(longbridge_order.outside_rth)?,
+            expire: Self::to_expire(longbridge_order.time_in_force, longbridge_order.expire_date)?,
+            status: Self::to_order_status(longbridge_order.status),
+            created_timestamp: Option::Some(Timestamp::from(longbridge_order.submitted_at)),
+            updated_timestamp: longbridge_order.updated_at.map(Timestamp::from),
+            triggered_timestamp: longbridge_order.trigger_at.map(Timestamp::from),
+        })
+    }
+
+    fn to_order_list(longbridge_order_list: Vec<Order>) -> Result<Vec<OrderDetail>, Error> {
+        longbridge_order_list
+            .into_iter()
+            .map(Self::to_order)
+            .collect()
+    }
+
+    fn to_execution(
+        longbridge_execution: longbridge::trade::Execution,
+    ) -> Result<Execution, Error> {
+        Result::Ok(Execution {
+            execution_id: longbridge_execution.trade_id,
+            order_id: Option::Some(longbridge_execution.order_id),
+            symbol: longbridge_execution.symbol.parse()?,
+            direction: Option::None,
+            quantity: longbridge_execution.quantity.into(),
+            price: longbridge_execution.price,
+            fee: Option::None,
+            currency: Option::None,
+            timestamp: Timestamp::from(longbridge_execution.trade_done_at),
+        })
+    }
+
+    fn to_offset_date_time(timestamp: Option<Timestamp>) -> Result<Option<OffsetDateTime>, Error> {
+        timestamp
+            .map(|timestamp| timestamp.to_offset_date_time())
+            .transpose()
+    }
+
+    // History APIs exclude today, so today's records are merged into the result
+    fn is_today_included(end_timestamp: Option<Timestamp>) -> bool {
+        let today = OffsetDateTime::now_utc().replace_time(Time::MIDNIGHT);
+        end_timestamp.is_none_or(|end| Timestamp::from(today) <= end)
+    }
+
     async fn get_longbridge_trade_context(&self) -> longbridge::TradeContext {
         let (longbridge_quote_context, _) = LongBridgeBroker::create_trade_context().await.unwrap();
         longbridge_quote_context
@@ -340,7 +460,7 @@ impl LongBridgeTransaction {
 
 #[async_trait]
 impl TransactionTrait for LongBridgeTransaction {
-    fn new(_config_map: ConfigMap) -> Self {
+    fn new(_config_map: ConfigMap, _clock: Arc<dyn ClockTrait>) -> Self {
         LongBridgeTransaction {}
     }
 
@@ -356,10 +476,32 @@ impl TransactionTrait for LongBridgeTransaction {
             .with_context(|| format!("Error when calling submit_order, request: {:?}", request))
     }
 
+    // Longbridge has no native bracket and OCO orders, they are emulated by the
+    // OrderEmulatorTransaction wrapping this transaction
+    async fn submit_bracket_order(
+        &mut self,
+        request: SubmitBracketOrderRequest,
+    ) -> Result<SubmitBracketOrderResponse, Error> {
+        Result::Err(anyhow!(
+            "ORDER_TYPE_NOT_SUPPORTED Bracket orders are not supported by longbridge, {:?}",
+            request
+        ))
+    }
+
+    async fn submit_oco_order(
+        &mut self,
+        request: SubmitOcoOrderRequest,
+    ) -> Result<SubmitOcoOrderResponse, Error> {
+        Result::Err(anyhow!(
+            "ORDER_TYPE_NOT_SUPPORTED OCO orders are not supported by longbridge, {:?}",
+            request
+        ))
+    }
+
     async fn edit_order(&mut self, request: EditOrderRequest) -> Result<EditOrderResponse, Error> {
         self.get_longbridge_trade_context()
             .await
-            .replace_order(Self::to_replace_order_options(&request))
+            .replace_order(Self::to_replace_order_options(&request)?)
             .await
             .map(|_| EditOrderResponse {})
             .with_context(|| format!("Error when calling edit_order, request: {:?}", request))
@@ -385,7 +527,7 @@ impl TransactionTrait for LongBridgeTransaction {
             .await
             .estimate_max_purchase_quantity(Self::to_estimate_max_purchase_quantity_options(
                 request.clone(),
-            ))
+            )?)
             .await
             .map(Self::to_buying_power)
             .with_context(|| {
@@ -405,6 +547,109 @@ impl TransactionTrait for LongBridgeTransaction {
             .and_then(Self::to_order_detail_response)
     }
 
+    async fn list_open_orders(&self) -> Result<Vec<OrderDetail>, Error> {
+        let order_detail_list = self
+            .get_longbridge_trade_context()
+            .await
+            .today_orders(Option::None)
+            .await
+            .with_context(|| format!("Error when calling list_open_orders"))
+            .and_then(Self::to_order_list)?;
+        Result::Ok(
+            order_detail_list
+                .into_iter()
+                .filter(|order_detail| order_detail.status.is_open())
+                .collect(),
+        )
+    }
+
+    async fn list_order_history(
+        &self,
+        request: ListOrderHistoryRequest,
+    ) -> Result<Vec<OrderDetail>, Error> {
+        let trade_context = self.get_longbridge_trade_context().await;
+        let mut options = GetHistoryOrdersOptions::new();
+        let mut today_options = GetTodayOrdersOptions::new();
+        if let Option::Some(symbol) = &request.symbol {
+            options = options.symbol(symbol.to_string());
+            today_options = today_options.symbol(symbol.to_string());
+        }
+        if let Option::Some(start_at) = Self::to_offset_date_time(request.start_timestamp)? {
+            options = options.start_at(start_at);
+        }
+        if let Option::Some(end_at) = Self::to_offset_date_time(request.end_timestamp)? {
+            options = options.end_at(end_at);
+        }
+
+        let mut order_list = trade_context
+            .history_orders(options)
+            .await
+            .with_context(|| format!("Error when calling list_order_history {:?}", request))?;
+        if Self::is_today_included(request.end_timestamp) {
+            order_list.extend(
+                trade_context
+                    .today_orders(today_options)
+                    .await
+                    .with_context(|| {
+                        format!("Error when calling list_order_history {:?}", request)
+                    })?,
+            );
+        }
+
+        let mut order_id_set = HashSet::new();
+        Result::Ok(
+            Self::to_order_list(order_list)?
+                .into_iter()
+                .filter(|order_detail| request.is_matched(order_detail))
+                .filter(|order_detail| order_id_set.insert(order_detail.order_id.clone()))
+                .collect(),
+        )
+    }
+
+    async fn list_executions(
+        &self,
+        request: ListExecutionsRequest,
+    ) -> Result<Vec<Execution>, Error> {
+        let trade_context = self.get_longbridge_trade_context().await;
+        let mut options = GetHistoryExecutionsOptions::new();
+        let mut today_options = GetTodayExecutionsOptions::new();
+        if let Option::Some(symbol) = &request.symbol {
+            options = options.symbol(symbol.to_string());
+            today_options = today_options.symbol(symbol.to_string());
+        }
+        if let Option::Some(start_at) = Self::to_offset_date_time(request.start_timestamp)? {
+            options = options.start_at(start_at);
+        }
+        if let Option::Some(end_at) = Self::to_offset_date_time(request.end_timestamp)? {
+            options = options.end_at(end_at);
+        }
+
+        let mut execution_list = trade_context
+            .history_executions(options)
+            .await
+            .with_context(|| format!("Error when calling list_executions {:?}", request))?;
+        if Self::is_today_included(request.end_timestamp) {
+            execution_list.extend(
+                trade_context
+                    .today_executions(today_options)
+                    .await
+                    .with_context(|| format!("Error when calling list_executions {:?}", request))?,
+            );
+        }
+
+        let mut execution_id_set = HashSet::new();
+        let mut result = vec![];
+        for execution in execution_list {
+            let execution = Self::to_execution(execution)?;
+            if request.is_matched(&execution)
+                && execution_id_set.insert(execution.execution_id.clone())
+            {
+                result.push(execution);
+            }
+        }
+        Result::Ok(result)
+    }
+
     async fn account_balance(&self) -> Result<BalanceHashMap, Error> {
         self.get_longbridge_trade_context()
             .await
@@ -21,8 +21,11 @@ use crate::{
         common::subscription::{SubscriptionController, SubscriptionWorker},
         longbridge::info::LongBridgeInfo,
     },
-    model::trading::{quote::QuoteDepthInfo, symbol::Symbol},
-    utils::time::get_now_unix_timestamp,
+    model::{
+        common::timestamp::Timestamp,
+        trading::{quote::QuoteDepthInfo, symbol::Symbol},
+    },
+    utils::clock::common_trait::ClockTrait,
 };
 
 pub struct LongBridgeQuoteDepthInfoSubscriptionWorker {
@@ -32,6 +35,7 @@ pub struct LongBridgeQuoteDepthInfoSubscriptionWorker {
     longbridge_receiver: UnboundedReceiver<PushEvent>,
     local_stopped_indicator: Arc<AtomicBool>,
     global_stopped_indicator: Arc<AtomicBool>,
+    clock: Arc<dyn ClockTrait>,
 }
 
 impl LongBridgeQuoteDepthInfoSubscriptionWorker {
@@ -42,6 +46,7 @@ impl LongBridgeQuoteDepthInfoSubscriptionWorker {
         longbridge_receiver: UnboundedReceiver<PushEvent>,
         local_stopped_indicator: Arc<AtomicBool>,
         global_stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
     ) -> Self {
         LongBridgeQuoteDepthInfoSubscriptionWorker {
             symbol,
@@ -50,18 +55,18 @@ impl LongBridgeQuoteDepthInfoSubscriptionWorker {
             longbridge_receiver,
             local_stopped_indicator,
             global_stopped_indicator,
+            clock,
         }
     }
 
     pub(super) fn to_quote_depth_info(
         symbol: Symbol,
         longbridge_depth: PushDepth,
+        current_timestamp: Timestamp,
     ) -> QuoteDepthInfo {
-        let current_timestamp = get_now_unix_timestamp();
-
         QuoteDepthInfo {
             symbol,
-            sequence: current_timestamp,
+            sequence: current_timestamp.as_nanos(),
             timestamp: current_timestamp,
             ask_list: longbridge_depth
                 .asks
@@ -118,6 +123,7 @@ impl SubscriptionWorker for LongBridgeQuoteDepthInfoSubscriptionWorker {
                                 let depth_info = Self::to_quote_depth_info(
                                     self.symbol.clone(),
                                     longbridge_depth,
+                                    self.clock.now(),
                                 );
                                 if let Err(send_result_err) = sys_sender.send(depth_info).await {
                                     log::error!("error when sending into mpsc {}", send_result_err);
@@ -148,8 +154,8 @@ impl LongBridgeQuoteDepthInfoSubscriptionController {
 
 #[async_trait]
 impl SubscriptionController for LongBridgeQuoteDepthInfoSubscriptionController {
-    async fn stop(self) -> Result<(), Error> {
-        self.local_stopped_indicator.store(false, Ordering::Relaxed);
+    async fn stop(&self) -> Result<(), Error> {
+        self.local_stopped_indicator.store(true, Ordering::Relaxed);
         Result::Ok(())
     }
 }
@@ -18,7 +18,10 @@ use tokio::{
 
 use crate::{
     broker::common::subscription::{SubscriptionController, SubscriptionWorker},
-    model::trading::{quote::QuoteRealTimeInfo, symbol::Symbol},
+    model::{
+        common::timestamp::Timestamp,
+        trading::{quote::QuoteRealTimeInfo, symbol::Symbol},
+    },
 };
 
 pub struct LongBridgeQuoteRealTimeInfoSubscriptionWorker {
@@ -53,10 +56,10 @@ impl LongBridgeQuoteRealTimeInfoSubscriptionWorker {
         symbol: Symbol,
         longbridge_quote: PushQuote,
     ) -> QuoteRealTimeInfo {
-        let timestamp = longbridge_quote.timestamp.unix_timestamp() as u64;
+        let timestamp = Timestamp::from(longbridge_quote.timestamp);
         QuoteRealTimeInfo {
             symbol,
-            sequence: timestamp,
+            sequence: timestamp.as_nanos(),
             timestamp,
             current_price: longbridge_quote.last_done,
             low_price: Option::Some(longbridge_quote.low),
@@ -141,8 +144,8 @@ impl LongBridgeQuoteRealTimeInfoSubscriptionController {
 
 #[async_trait]
 impl SubscriptionController for LongBridgeQuoteRealTimeInfoSubscriptionController {
-    async fn stop(self) -> Result<(), Error> {
-        self.local_stopped_indicator.store(false, Ordering::Relaxed);
+    async fn stop(&self) -> Result<(), Error> {
+        self.local_stopped_indicator.store(true, Ordering::Relaxed);
         Result::Ok(())
     }
 }
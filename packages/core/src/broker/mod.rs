@@ -7,3 +7,5 @@ pub mod longbridge;
 pub mod yahoo_finance;
 #[cfg(feature = "broker__interactive_brokers")]
 pub mod interactive_brokers;
+#[cfg(feature = "broker__replay")]
+pub mod replay;
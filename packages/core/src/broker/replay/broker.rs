@@ -0,0 +1,192 @@
+use anyhow::Error;
+use std::{
+    path::PathBuf,
+    sync::{atomic::AtomicBool, Arc},
+};
+
+use super::{
+    info::ReplayInfo, subscription::ReplaySubscription, transaction::ReplayTransaction,
+    worker::driver::ReplayDriver,
+};
+use crate::{
+    broker::{
+        common::{
+            broker::{BrokerInterceptorFactoryTrait, BrokerTrait},
+            fee::{FeeModelTrait, NoFeeModel},
+            heartbeat::HeartbeatTrait,
+            info::{InfoProxy, InfoTrait},
+            subscription::{SubscriptionProxy, SubscriptionTrait},
+            transaction::{TransactionProxy, TransactionTrait},
+        },
+        initializer::get_fee_model_instance,
+    },
+    model::common::{
+        config_key::{ConfigKeySpec, ConfigValueType},
+        timestamp::Timestamp,
+        types::ConfigMap,
+    },
+    pod::recorder::{
+        market_data_recorder::MarketDataRecorder,
+        reader::RecordFileReader,
+        record::RecordTrait,
+        writer::{RecordCompression, RecordFormat},
+    },
+    utils::clock::common_trait::ClockTrait,
+};
+
+pub struct ReplayBroker {
+    interceptor_factory: Box<dyn BrokerInterceptorFactoryTrait>,
+    config_map: ConfigMap,
+    stopped_indicator: Arc<AtomicBool>,
+    clock: Arc<dyn ClockTrait>,
+    driver: Arc<ReplayDriver>,
+}
+
+impl ReplayBroker {
+    pub const CONFIG_KEY_BASE_PATH: &'static str = "replay.base_path";
+    pub const CONFIG_KEY_FORMAT: &'static str = "replay.format";
+    pub const CONFIG_KEY_COMPRESSION: &'static str = "replay.compression";
+    pub const CONFIG_KEY_START: &'static str = "replay.start";
+    pub const CONFIG_KEY_END: &'static str = "replay.end";
+    pub const CONFIG_KEY_FEE_MODEL: &'static str = "replay.fee_model";
+
+    pub(super) fn create_reader<T: RecordTrait>(config_map: &ConfigMap) -> RecordFileReader<T> {
+        let base_path = config_map
+            .get(Self::CONFIG_KEY_BASE_PATH)
+            .cloned()
+            .unwrap_or(MarketDataRecorder::CONFIG_VALUE_DEFAULT_BASE_PATH.to_owned());
+        let format = config_map
+            .get(Self::CONFIG_KEY_FORMAT)
+            .and_then(|format| format.parse().ok())
+            .unwrap_or(RecordFormat::JsonLines);
+        let compression = config_map
+            .get(Self::CONFIG_KEY_COMPRESSION)
+            .and_then(|compression| compression.parse().ok())
+            .unwrap_or(RecordCompression::Gzip);
+        RecordFileReader::new(PathBuf::from(base_path), format, compression)
+    }
+
+    pub(super) fn get_time_range(config_map: &ConfigMap) -> (Option<Timestamp>, Option<Timestamp>) {
+        let get_timestamp = |key: &str| {
+            config_map
+                .get(key)
+                .and_then(|timestamp| timestamp.parse::<Timestamp>().ok())
+        };
+        (
+            get_timestamp(Self::CONFIG_KEY_START),
+            get_timestamp(Self::CONFIG_KEY_END),
+        )
+    }
+}
+
+impl BrokerTrait for ReplayBroker {
+    fn new(
+        interceptor_factory: Box<dyn BrokerInterceptorFactoryTrait>,
+        config_map: ConfigMap,
+        stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
+    ) -> Self {
+        let driver = Arc::new(ReplayDriver::new(clock.clone(), stopped_indicator.clone()));
+        ReplayBroker {
+            interceptor_factory,
+            config_map,
+            stopped_indicator,
+            clock,
+            driver,
+        }
+    }
+
+    fn get_identifier() -> String {
+        const IDENTIFIER: &'static str = "replay";
+        IDENTIFIER.to_owned()
+    }
+
+    fn get_config_key_list() -> Vec<ConfigKeySpec> {
+        vec![
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_BASE_PATH,
+                ConfigValueType::String,
+                Option::Some(MarketDataRecorder::CONFIG_VALUE_DEFAULT_BASE_PATH),
+                "Directory of the recorded market data",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_FORMAT,
+                ConfigValueType::RecordFormat,
+                Option::Some("jsonl"),
+                "Format of the recorded files",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_COMPRESSION,
+                ConfigValueType::RecordCompression,
+                Option::Some("gzip"),
+                "Compression of the recorded files",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_START,
+                ConfigValueType::Timestamp,
+                Option::None,
+                "Records before this timestamp are skipped",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_END,
+                ConfigValueType::Timestamp,
+                Option::None,
+                "Records after this timestamp are skipped",
+            ),
+            // the keys of the fee model itself are read from the same config map
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_FEE_MODEL,
+                ConfigValueType::String,
+                Option::Some("NoFeeModel"),
+                "Identifier of the fee model of the backtested broker",
+            ),
+        ]
+    }
+
+    fn create_info(&self) -> Box<dyn InfoTrait> {
+        let replay_info = Box::new(ReplayInfo::new(self.config_map.clone(), self.clock.clone()));
+        Box::new(InfoProxy::new(
+            replay_info,
+            self.interceptor_factory.create_info_interceptor(),
+        ))
+    }
+
+    fn create_subscription(&self) -> Box<dyn SubscriptionTrait> {
+        let replay_subscription = Box::new(ReplaySubscription::new_with_driver(
+            self.config_map.clone(),
+            self.stopped_indicator.clone(),
+            self.driver.clone(),
+        ));
+        Box::new(SubscriptionProxy::new(
+            replay_subscription,
+            self.interceptor_factory.create_subscription_interceptor(),
+        ))
+    }
+
+    // Backtests trade against the replayed quotes
+    fn create_transaction(&self) -> Result<Box<dyn TransactionTrait>, Error> {
+        let replay_transaction = Box::new(ReplayTransaction::new_with_fee_model(
+            self.config_map.clone(),
+            self.clock.clone(),
+            self.create_fee_model()?,
+        ));
+        Result::Ok(Box::new(TransactionProxy::new(
+            replay_transaction,
+            self.interceptor_factory.create_transaction_interceptor(),
+        )))
+    }
+
+    fn create_heartbeat(&self) -> Option<Box<dyn HeartbeatTrait>> {
+        Option::None
+    }
+
+    // Simulated fees follow the schedule of the broker being backtested
+    fn create_fee_model(&self) -> Result<Box<dyn FeeModelTrait>, Error> {
+        let identifier = self
+            .config_map
+            .get(Self::CONFIG_KEY_FEE_MODEL)
+            .cloned()
+            .unwrap_or(NoFeeModel::get_identifier());
+        get_fee_model_instance(identifier, self.config_map.clone())
+    }
+}
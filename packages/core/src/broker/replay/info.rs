@@ -0,0 +1,64 @@
+use anyhow::{anyhow, Error};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use super::broker::ReplayBroker;
+use crate::{
+    broker::common::info::InfoTrait,
+    model::{
+        common::types::ConfigMap,
+        trading::quote::{QueryInfoRequest, QuoteBasicInfo, QuoteDepthInfo, QuoteRealTimeInfo},
+    },
+    pod::recorder::{record::RecordTrait, writer::RecordFileWriter},
+    utils::clock::common_trait::ClockTrait,
+};
+
+pub struct ReplayInfo {
+    config_map: ConfigMap,
+    clock: Arc<dyn ClockTrait>,
+}
+
+impl ReplayInfo {
+    // the latest record not later than the replay clock
+    fn query_latest_record<T: RecordTrait>(&self, request: QueryInfoRequest) -> Result<T, Error> {
+        let now = self.clock.now();
+        let date = RecordFileWriter::<T>::get_date(now)?;
+
+        ReplayBroker::create_reader::<T>(&self.config_map)
+            .read(&request.symbol, &date)?
+            .into_iter()
+            .take_while(|record| record.get_timestamp() <= now)
+            .last()
+            .ok_or(anyhow!(
+                "REPLAY_NO_DATA No {} record of {} before {}",
+                T::get_kind(),
+                request.symbol.to_string(),
+                now
+            ))
+    }
+}
+
+#[async_trait]
+impl InfoTrait for ReplayInfo {
+    fn new(config_map: ConfigMap, clock: Arc<dyn ClockTrait>) -> Self {
+        ReplayInfo { config_map, clock }
+    }
+
+    async fn query_basic_info(&self, request: QueryInfoRequest) -> Result<QuoteBasicInfo, Error> {
+        Result::Err(anyhow!(
+            "REPLAY_NOT_SUPPORTED Basic info of {} is not recorded",
+            request.symbol.to_string()
+        ))
+    }
+
+    async fn query_real_time_info(
+        &self,
+        request: QueryInfoRequest,
+    ) -> Result<QuoteRealTimeInfo, Error> {
+        self.query_latest_record(request)
+    }
+
+    async fn query_depth(&self, request: QueryInfoRequest) -> Result<QuoteDepthInfo, Error> {
+        self.query_latest_record(request)
+    }
+}
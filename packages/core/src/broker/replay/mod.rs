@@ -0,0 +1,5 @@
+pub mod broker;
+pub mod info;
+pub mod subscription;
+pub mod transaction;
+pub mod worker;
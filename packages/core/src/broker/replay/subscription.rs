@@ -0,0 +1,88 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use std::sync::{atomic::AtomicBool, Arc};
+use tokio::sync::mpsc;
+
+use super::{
+    broker::ReplayBroker,
+    worker::{
+        driver::ReplayDriver,
+        record::{ReplayRecordFeed, ReplayRecordSubscriptionController},
+    },
+};
+use crate::{
+    broker::common::subscription::{SubscriptionData, SubscriptionTrait},
+    model::{
+        common::types::ConfigMap,
+        trading::quote::{QueryInfoRequest, QuoteDepthInfo, QuoteRealTimeInfo},
+    },
+    pod::recorder::record::RecordTrait,
+    utils::clock::common_trait::ClockTrait,
+};
+
+pub struct ReplaySubscription {
+    config_map: ConfigMap,
+    global_stopped_indicator: Arc<AtomicBool>,
+    driver: Arc<ReplayDriver>,
+}
+
+impl ReplaySubscription {
+    // Subscriptions of the same broker share the driver, their records are then replayed in order
+    pub fn new_with_driver(
+        config_map: ConfigMap,
+        global_stopped_indicator: Arc<AtomicBool>,
+        driver: Arc<ReplayDriver>,
+    ) -> Self {
+        ReplaySubscription {
+            config_map,
+            global_stopped_indicator,
+            driver,
+        }
+    }
+
+    fn subscribe<T: RecordTrait>(
+        &self,
+        request: QueryInfoRequest,
+    ) -> Result<SubscriptionData<T>, Error> {
+        let (sender, receiver) = mpsc::channel(64);
+        let local_stopped_indicator = Arc::new(AtomicBool::new(false));
+        let feed = ReplayRecordFeed::new(
+            request.symbol,
+            ReplayBroker::create_reader(&self.config_map),
+            ReplayBroker::get_time_range(&self.config_map),
+            sender,
+            local_stopped_indicator.clone(),
+            self.global_stopped_indicator.clone(),
+        )?;
+        let controller = ReplayRecordSubscriptionController::new(local_stopped_indicator);
+
+        self.driver.register(Box::new(feed));
+        Result::Ok((receiver, Box::new(controller)))
+    }
+}
+
+#[async_trait]
+impl SubscriptionTrait for ReplaySubscription {
+    fn new(
+        config_map: ConfigMap,
+        global_stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
+    ) -> Self {
+        let driver = Arc::new(ReplayDriver::new(clock, global_stopped_indicator.clone()));
+        ReplaySubscription::new_with_driver(config_map, global_stopped_indicator, driver)
+    }
+
+    async fn real_time_info(
+        &self,
+        request: QueryInfoRequest,
+    ) -> Result<SubscriptionData<QuoteRealTimeInfo>, Error> {
+        self.subscribe(request)
+    }
+
+    async fn depth_info(
+        &self,
+        request: QueryInfoRequest,
+    ) -> Result<SubscriptionData<QuoteDepthInfo>, Error> {
+        self.subscribe(request)
+    }
+}
@@ -0,0 +1,420 @@
+use anyhow::{anyhow, Error};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+};
+
+use super::info::ReplayInfo;
+use crate::{
+    broker::common::{
+        fee::{FeeModelTrait, NoFeeModel},
+        info::InfoTrait,
+        transaction::TransactionTrait,
+    },
+    model::{
+        common::{timestamp::Timestamp, types::ConfigMap},
+        trading::{
+            balance::{BalanceDetail, BalanceHashMap},
+            fee::CalculateFeeRequest,
+            position::{Position, PositionList},
+            quote::{QueryInfoRequest, QuoteKind},
+            symbol::Symbol,
+            transaction::{
+                BuyingPower, CancelOrderRequest, CancelOrderResponse, Direction, EditOrderRequest,
+                EditOrderResponse, EstimateMaxBuyingPowerRequest, Execution, ListExecutionsRequest,
+                ListOrderHistoryRequest, OrderDetail, OrderDetailRequest, OrderStatus, Price,
+                SubmitBracketOrderRequest, SubmitBracketOrderResponse, SubmitOcoOrderRequest,
+                SubmitOcoOrderResponse, SubmitOrderRequest, SubmitOrderResponse,
+            },
+        },
+    },
+    utils::clock::common_trait::ClockTrait,
+};
+
+#[derive(Default)]
+struct ReplayTransactionContext {
+    order_list: Vec<OrderDetail>,
+    execution_list: Vec<Execution>,
+}
+
+// Backtest transaction, orders fill against the replayed quotes at the time of the replay clock
+// and are charged by the fee model of the backtested broker. Only market and limit orders are
+// supported, a limit order fills in full at the quote once the quote reaches its price.
+pub struct ReplayTransaction {
+    info: ReplayInfo,
+    fee_model: Box<dyn FeeModelTrait>,
+    clock: Arc<dyn ClockTrait>,
+    context: Mutex<ReplayTransactionContext>,
+}
+
+impl ReplayTransaction {
+    pub const ORDER_ID_PREFIX: &'static str = "replay_";
+
+    pub fn new_with_fee_model(
+        config_map: ConfigMap,
+        clock: Arc<dyn ClockTrait>,
+        fee_model: Box<dyn FeeModelTrait>,
+    ) -> Self {
+        ReplayTransaction {
+            info: ReplayInfo::new(config_map, clock.clone()),
+            fee_model,
+            clock,
+            context: Mutex::new(ReplayTransactionContext::default()),
+        }
+    }
+
+    fn validate_price(price: &Price) -> Result<(), Error> {
+        match price {
+            Price::MarketOrder | Price::LimitOrder { .. } => Result::Ok(()),
+            _ => Result::Err(anyhow!(
+                "REPLAY_NOT_SUPPORTED Only market and limit orders can be replayed, {:?}",
+                price
+            )),
+        }
+    }
+
+    fn get_fill_price(order_detail: &OrderDetail, current_price: Decimal) -> Option<Decimal> {
+        match (&order_detail.price, &order_detail.direction) {
+            (Price::MarketOrder, _) => Option::Some(current_price),
+            (Price::LimitOrder { price }, Direction::Buy) if current_price <= *price => {
+                Option::Some(current_price)
+            }
+            (Price::LimitOrder { price }, Direction::Sell) if current_price >= *price => {
+                Option::Some(current_price)
+            }
+            _ => Option::None,
+        }
+    }
+
+    // Fills the open orders that are marketable at the latest replayed quote
+    async fn match_open_orders(&self) -> Result<(), Error> {
+        let symbol_set: HashSet<Symbol> = self
+            .context
+            .lock()
+            .unwrap()
+            .order_list
+            .iter()
+            .filter(|order_detail| order_detail.status.is_open())
+            .map(|order_detail| order_detail.symbol.clone())
+            .collect();
+
+        for symbol in symbol_set {
+            // nothing has been replayed for the symbol yet
+            let current_price = match self
+                .info
+                .query_real_time_info(QueryInfoRequest {
+                    symbol: symbol.clone(),
+                    kind: QuoteKind::Stock,
+                })
+                .await
+            {
+                Result::Ok(quote_info) => quote_info.current_price,
+                Result::Err(_) => continue,
+            };
+
+            let now = self.clock.now();
+            let mut context = self.context.lock().unwrap();
+            let context = &mut *context;
+            for order_detail in context.order_list.iter_mut().filter(|order_detail| {
+                order_detail.status.is_open() && order_detail.symbol == symbol
+            }) {
+                if let Option::Some(fill_price) = Self::get_fill_price(order_detail, current_price)
+                {
+                    let execution = self.fill(order_detail, fill_price, now)?;
+                    context.execution_list.push(execution);
+                }
+            }
+        }
+        Result::Ok(())
+    }
+
+    fn fill(
+        &self,
+        order_detail: &mut OrderDetail,
+        price: Decimal,
+        now: Timestamp,
+    ) -> Result<Execution, Error> {
+        let quantity = order_detail.quantity - order_detail.executed_quantity;
+        let fee = self
+            .fee_model
+            .calculate_fee(&CalculateFeeRequest {
+                symbol: order_detail.symbol.clone(),
+                direction: order_detail.direction.clone(),
+                quantity,
+                price,
+            })?
+            .get_total();
+
+        order_detail.executed_quantity = order_detail.quantity;
+        order_detail.executed_price = Option::Some(price);
+        order_detail.fee = Option::Some(order_detail.fee.unwrap_or_default() + fee);
+        order_detail.status = OrderStatus::Filled;
+        order_detail.updated_timestamp = Option::Some(now);
+        Result::Ok(Execution {
+            execution_id: format!(
+                "{}execution_{}",
+                Self::ORDER_ID_PREFIX,
+                order_detail.order_id
+            ),
+            order_id: Option::Some(order_detail.order_id.clone()),
+            symbol: order_detail.symbol.clone(),
+            direction: Option::Some(order_detail.direction.clone()),
+            quantity,
+            price,
+            fee: Option::Some(fee),
+            currency: Option::Some(order_detail.currency.clone()),
+            timestamp: now,
+        })
+    }
+
+    fn get_open_order<'a>(
+        context: &'a mut ReplayTransactionContext,
+        order_id: &str,
+    ) -> Result<&'a mut OrderDetail, Error> {
+        let order_detail = context
+            .order_list
+            .iter_mut()
+            .find(|order_detail| order_detail.order_id == order_id)
+            .ok_or(anyhow!("ORDER_NOT_FOUND {}", order_id))?;
+        match order_detail.status.is_open() {
+            true => Result::Ok(order_detail),
+            false => Result::Err(anyhow!(
+                "ORDER_NOT_OPEN {}, status: {:?}",
+                order_id,
+                order_detail.status
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionTrait for ReplayTransaction {
+    // charges no fee, the replay broker creates its transactions with the configured fee model
+    fn new(config_map: ConfigMap, clock: Arc<dyn ClockTrait>) -> Self {
+        let fee_model = Box::new(NoFeeModel::new(config_map.clone()));
+        ReplayTransaction::new_with_fee_model(config_map, clock, fee_model)
+    }
+
+    // the cash flow of the fills and fees since the replay started
+    async fn account_balance(&self) -> Result<BalanceHashMap, Error> {
+        self.match_open_orders().await?;
+        let mut cash_map: HashMap<_, Decimal> = HashMap::new();
+        for execution in self.context.lock().unwrap().execution_list.iter() {
+            let value = execution.quantity * execution.price;
+            let cash_flow = match execution.direction {
+                Option::Some(Direction::Sell) => value,
+                _ => -value,
+            } - execution.fee.unwrap_or_default();
+            *cash_map
+                .entry(execution.symbol.market.get_currency())
+                .or_default() += cash_flow;
+        }
+        Result::Ok(
+            cash_map
+                .into_iter()
+                .map(|(currency, total_cash)| {
+                    (
+                        currency,
+                        BalanceDetail {
+                            total_cash,
+                            net_assets: total_cash,
+                            margin_call: Decimal::ZERO,
+                            init_margin: Decimal::ZERO,
+                            maintenance_margin: Decimal::ZERO,
+                        },
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    // the cost price is the break-even price of the fills, fees excluded
+    async fn positions(&self) -> Result<PositionList, Error> {
+        self.match_open_orders().await?;
+        let mut position_map: HashMap<Symbol, (Decimal, Decimal)> = HashMap::new();
+        for execution in self.context.lock().unwrap().execution_list.iter() {
+            let (quantity, cost) = position_map.entry(execution.symbol.clone()).or_default();
+            match execution.direction {
+                Option::Some(Direction::Sell) => {
+                    *quantity -= execution.quantity;
+                    *cost -= execution.quantity * execution.price;
+                }
+                _ => {
+                    *quantity += execution.quantity;
+                    *cost += execution.quantity * execution.price;
+                }
+            }
+        }
+        Result::Ok(
+            position_map
+                .into_iter()
+                .filter(|(_, (quantity, _))| !quantity.is_zero())
+                .map(|(symbol, (quantity, cost))| Position {
+                    currency: symbol.market.get_currency(),
+                    symbol,
+                    cost_price: cost / quantity,
+                    quantity,
+                })
+                .collect(),
+        )
+    }
+
+    async fn estimate_max_buying_power(
+        &self,
+        request: EstimateMaxBuyingPowerRequest,
+    ) -> Result<BuyingPower, Error> {
+        Result::Err(anyhow!(
+            "REPLAY_NOT_SUPPORTED Buying power is not simulated, {:?}",
+            request
+        ))
+    }
+
+    async fn order_detail(&self, request: OrderDetailRequest) -> Result<OrderDetail, Error> {
+        self.match_open_orders().await?;
+        self.context
+            .lock()
+            .unwrap()
+            .order_list
+            .iter()
+            .find(|order_detail| order_detail.order_id == request.order_id)
+            .cloned()
+            .ok_or(anyhow!("ORDER_NOT_FOUND {}", request.order_id))
+    }
+
+    async fn list_open_orders(&self) -> Result<Vec<OrderDetail>, Error> {
+        self.match_open_orders().await?;
+        Result::Ok(
+            self.context
+                .lock()
+                .unwrap()
+                .order_list
+                .iter()
+                .filter(|order_detail| order_detail.status.is_open())
+                .cloned()
+                .collect(),
+        )
+    }
+
+    async fn list_order_history(
+        &self,
+        request: ListOrderHistoryRequest,
+    ) -> Result<Vec<OrderDetail>, Error> {
+        self.match_open_orders().await?;
+        Result::Ok(
+            self.context
+                .lock()
+                .unwrap()
+                .order_list
+                .iter()
+                .filter(|order_detail| request.is_matched(order_detail))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    async fn list_executions(
+        &self,
+        request: ListExecutionsRequest,
+    ) -> Result<Vec<Execution>, Error> {
+        self.match_open_orders().await?;
+        Result::Ok(
+            self.context
+                .lock()
+                .unwrap()
+                .execution_list
+                .iter()
+                .filter(|execution| request.is_matched(execution))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    async fn submit_order(
+        &mut self,
+        request: SubmitOrderRequest,
+    ) -> Result<SubmitOrderResponse, Error> {
+        Self::validate_price(&request.price)?;
+        if request.quantity <= Decimal::ZERO {
+            return Result::Err(anyhow!(
+                "ILLEGAL_QUANTITY quantity should be positive, {:?}",
+                request
+            ));
+        }
+
+        let now = self.clock.now();
+        let order_id = {
+            let mut context = self.context.lock().unwrap();
+            let order_id = format!("{}{}", Self::ORDER_ID_PREFIX, context.order_list.len() + 1);
+            context.order_list.push(OrderDetail {
+                order_id: order_id.clone(),
+                currency: request.symbol.market.get_currency(),
+                symbol: request.symbol,
+                quantity: request.quantity,
+                executed_quantity: Decimal::ZERO,
+                price: request.price,
+                executed_price: Option::None,
+                fee: Option::None,
+                direction: request.direction,
+                regular_trading_time: request.regular_trading_time,
+                expire: request.expire,
+                status: OrderStatus::Submitted,
+                created_timestamp: Option::Some(now),
+                updated_timestamp: Option::Some(now),
+                triggered_timestamp: Option::None,
+            });
+            order_id
+        };
+        self.match_open_orders().await?;
+        Result::Ok(SubmitOrderResponse { order_id })
+    }
+
+    async fn submit_bracket_order(
+        &mut self,
+        request: SubmitBracketOrderRequest,
+    ) -> Result<SubmitBracketOrderResponse, Error> {
+        Result::Err(anyhow!(
+            "REPLAY_NOT_SUPPORTED Bracket orders cannot be replayed, {:?}",
+            request
+        ))
+    }
+
+    async fn submit_oco_order(
+        &mut self,
+        request: SubmitOcoOrderRequest,
+    ) -> Result<SubmitOcoOrderResponse, Error> {
+        Result::Err(anyhow!(
+            "REPLAY_NOT_SUPPORTED OCO orders cannot be replayed, {:?}",
+            request
+        ))
+    }
+
+    async fn edit_order(&mut self, request: EditOrderRequest) -> Result<EditOrderResponse, Error> {
+        Self::validate_price(&request.price)?;
+        {
+            let now = self.clock.now();
+            let mut context = self.context.lock().unwrap();
+            let order_detail = Self::get_open_order(&mut context, &request.order_id)?;
+            order_detail.quantity = request.quantity;
+            order_detail.price = request.price;
+            order_detail.expire = request.expire;
+            order_detail.updated_timestamp = Option::Some(now);
+        }
+        self.match_open_orders().await?;
+        Result::Ok(EditOrderResponse {})
+    }
+
+    async fn cancel_order(
+        &mut self,
+        request: CancelOrderRequest,
+    ) -> Result<CancelOrderResponse, Error> {
+        self.match_open_orders().await?;
+        let now = self.clock.now();
+        let mut context = self.context.lock().unwrap();
+        let order_detail = Self::get_open_order(&mut context, &request.order_id)?;
+        order_detail.status = OrderStatus::Cancelled;
+        order_detail.updated_timestamp = Option::Some(now);
+        Result::Ok(CancelOrderResponse {})
+    }
+}
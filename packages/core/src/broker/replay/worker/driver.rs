@@ -0,0 +1,124 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::{
+    sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    time::timeout,
+};
+
+use crate::{model::common::timestamp::Timestamp, utils::clock::common_trait::ClockTrait};
+
+#[async_trait]
+pub trait ReplayFeedTrait: Send {
+    fn is_stopped(&self) -> bool;
+    // None once the feed ran out of records
+    fn peek_timestamp(&mut self) -> Result<Option<Timestamp>, Error>;
+    // returns false when the subscriber is gone
+    async fn send_next(&mut self) -> bool;
+}
+
+// Replays every feed of a broker in a single task, always sending the record with the smallest
+// timestamp next. The feeds share one clock, so at max speed a feed advancing it on its own would
+// make the clock jump past the records of the other feeds.
+pub struct ReplayDriver {
+    clock: Arc<dyn ClockTrait>,
+    global_stopped_indicator: Arc<AtomicBool>,
+    // None while no task is running, the task is started by the first feed
+    feed_sender: Mutex<Option<UnboundedSender<Box<dyn ReplayFeedTrait>>>>,
+}
+
+impl ReplayDriver {
+    const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+    pub fn new(clock: Arc<dyn ClockTrait>, global_stopped_indicator: Arc<AtomicBool>) -> Self {
+        ReplayDriver {
+            clock,
+            global_stopped_indicator,
+            feed_sender: Mutex::new(Option::None),
+        }
+    }
+
+    pub fn register(self: &Arc<Self>, feed: Box<dyn ReplayFeedTrait>) {
+        let mut feed_sender = self.feed_sender.lock().unwrap();
+        let feed = match feed_sender.as_ref() {
+            Option::Some(sender) => match sender.send(feed) {
+                Result::Ok(()) => return,
+                Result::Err(mpsc::error::SendError(feed)) => feed,
+            },
+            Option::None => feed,
+        };
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        sender.send(feed).ok();
+        *feed_sender = Option::Some(sender);
+        tokio::task::spawn(self.clone().run(receiver));
+    }
+
+    async fn run(self: Arc<Self>, mut feed_receiver: UnboundedReceiver<Box<dyn ReplayFeedTrait>>) {
+        let mut feed_list: Vec<Box<dyn ReplayFeedTrait>> = vec![];
+        loop {
+            if self.global_stopped_indicator.load(Ordering::Relaxed) {
+                self.feed_sender.lock().unwrap().take();
+                return;
+            }
+            while let Result::Ok(feed) = feed_receiver.try_recv() {
+                feed_list.push(feed);
+            }
+            feed_list.retain_mut(|feed| {
+                !feed.is_stopped()
+                    && match feed.peek_timestamp() {
+                        Result::Ok(timestamp) => timestamp.is_some(),
+                        Result::Err(err) => {
+                            log::error!("Error when reading the replayed records, {}", err);
+                            false
+                        }
+                    }
+            });
+
+            let next = feed_list
+                .iter_mut()
+                .enumerate()
+                .filter_map(|(index, feed)| {
+                    feed.peek_timestamp()
+                        .ok()
+                        .flatten()
+                        .map(|timestamp| (timestamp, index))
+                })
+                .min();
+            let (timestamp, index) = match next {
+                Option::Some(next) => next,
+                Option::None => {
+                    // feeds registered meanwhile are picked up by this task, later ones by a new one
+                    let mut feed_sender = self.feed_sender.lock().unwrap();
+                    match feed_receiver.try_recv() {
+                        Result::Ok(feed) => {
+                            feed_list.push(feed);
+                            continue;
+                        }
+                        Result::Err(_) => {
+                            feed_sender.take();
+                            return;
+                        }
+                    }
+                }
+            };
+
+            // woken up regularly, a feed registered meanwhile could have an earlier record
+            if timeout(Self::STOP_CHECK_INTERVAL, self.clock.sleep_until(timestamp))
+                .await
+                .is_err()
+            {
+                continue;
+            }
+            if !feed_list[index].send_next().await {
+                feed_list.swap_remove(index);
+            }
+        }
+    }
+}
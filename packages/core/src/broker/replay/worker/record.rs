@@ -0,0 +1,156 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::{sync::mpsc::Sender, time::timeout};
+
+use super::driver::ReplayFeedTrait;
+use crate::{
+    broker::common::subscription::SubscriptionController,
+    model::{common::timestamp::Timestamp, trading::symbol::Symbol},
+    pod::recorder::{reader::RecordFileReader, record::RecordTrait, writer::RecordFileWriter},
+};
+
+// The records of one subscription, read a day at a time and sent by the `ReplayDriver`
+pub struct ReplayRecordFeed<T: RecordTrait> {
+    symbol: Symbol,
+    reader: RecordFileReader<T>,
+    start_timestamp: Option<Timestamp>,
+    end_timestamp: Option<Timestamp>,
+    date_list: VecDeque<String>,
+    record_list: VecDeque<T>,
+    sender: Sender<T>,
+
+    local_stopped_indicator: Arc<AtomicBool>,
+    global_stopped_indicator: Arc<AtomicBool>,
+}
+
+impl<T: RecordTrait> ReplayRecordFeed<T> {
+    const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+    pub fn new(
+        symbol: Symbol,
+        reader: RecordFileReader<T>,
+        (start_timestamp, end_timestamp): (Option<Timestamp>, Option<Timestamp>),
+        sender: Sender<T>,
+        local_stopped_indicator: Arc<AtomicBool>,
+        global_stopped_indicator: Arc<AtomicBool>,
+    ) -> Result<Self, Error> {
+        let start_date = start_timestamp
+            .map(RecordFileWriter::<T>::get_date)
+            .transpose()?;
+        let end_date = end_timestamp
+            .map(RecordFileWriter::<T>::get_date)
+            .transpose()?;
+        let date_list = reader
+            .list_date(&symbol)?
+            .into_iter()
+            .filter(|date| {
+                start_date
+                    .as_ref()
+                    .is_none_or(|start_date| date >= start_date)
+                    && end_date.as_ref().is_none_or(|end_date| date <= end_date)
+            })
+            .collect();
+
+        Result::Ok(ReplayRecordFeed {
+            symbol,
+            reader,
+            start_timestamp,
+            end_timestamp,
+            date_list,
+            record_list: VecDeque::new(),
+            sender,
+            local_stopped_indicator,
+            global_stopped_indicator,
+        })
+    }
+}
+
+#[async_trait]
+impl<T: RecordTrait> ReplayFeedTrait for ReplayRecordFeed<T> {
+    fn is_stopped(&self) -> bool {
+        self.local_stopped_indicator.load(Ordering::Relaxed)
+            || self.global_stopped_indicator.load(Ordering::Relaxed)
+    }
+
+    fn peek_timestamp(&mut self) -> Result<Option<Timestamp>, Error> {
+        loop {
+            if let Option::Some(record) = self.record_list.front() {
+                let timestamp = record.get_timestamp();
+                if self
+                    .start_timestamp
+                    .is_some_and(|start_timestamp| timestamp < start_timestamp)
+                {
+                    self.record_list.pop_front();
+                    continue;
+                }
+                if self
+                    .end_timestamp
+                    .is_some_and(|end_timestamp| timestamp > end_timestamp)
+                {
+                    self.record_list.clear();
+                    self.date_list.clear();
+                    return Result::Ok(Option::None);
+                }
+                return Result::Ok(Option::Some(timestamp));
+            }
+            match self.date_list.pop_front() {
+                Option::Some(date) => {
+                    self.record_list = self.reader.read(&self.symbol, &date)?.into();
+                }
+                Option::None => return Result::Ok(Option::None),
+            }
+        }
+    }
+
+    async fn send_next(&mut self) -> bool {
+        let record = match self.record_list.pop_front() {
+            Option::Some(record) => record,
+            Option::None => return true,
+        };
+        // a full channel must not keep the other feeds from being stopped
+        loop {
+            if self.is_stopped() {
+                return false;
+            }
+            match timeout(Self::STOP_CHECK_INTERVAL, self.sender.reserve()).await {
+                Result::Ok(Result::Ok(permit)) => {
+                    permit.send(record);
+                    return true;
+                }
+                Result::Ok(Result::Err(send_result_err)) => {
+                    log::error!("error when sending into mpsc {}", send_result_err);
+                    return false;
+                }
+                Result::Err(_) => continue,
+            }
+        }
+    }
+}
+
+pub struct ReplayRecordSubscriptionController {
+    local_stopped_indicator: Arc<AtomicBool>,
+}
+
+impl ReplayRecordSubscriptionController {
+    pub fn new(local_stopped_indicator: Arc<AtomicBool>) -> Self {
+        ReplayRecordSubscriptionController {
+            local_stopped_indicator,
+        }
+    }
+}
+
+#[async_trait]
+impl SubscriptionController for ReplayRecordSubscriptionController {
+    async fn stop(&self) -> Result<(), Error> {
+        self.local_stopped_indicator.store(true, Ordering::Relaxed);
+        Result::Ok(())
+    }
+}
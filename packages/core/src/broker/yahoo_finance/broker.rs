@@ -1,21 +1,25 @@
+use anyhow::{anyhow, Error};
 use std::sync::{atomic::AtomicBool, Arc};
 
 use super::{info::YahooFinanceInfo, subscription::YahooFinanceSubscription};
 use crate::{
     broker::common::{
         broker::{BrokerInterceptorFactoryTrait, BrokerTrait},
+        fee::{FeeModelTrait, NoFeeModel},
         heartbeat::HeartbeatTrait,
         info::{InfoProxy, InfoTrait},
         subscription::{SubscriptionProxy, SubscriptionTrait},
         transaction::TransactionTrait,
     },
     model::common::types::ConfigMap,
+    utils::clock::common_trait::ClockTrait,
 };
 
 pub struct YahooFinanceBroker {
     interceptor_factory: Box<dyn BrokerInterceptorFactoryTrait>,
     config_map: ConfigMap,
     stopped_indicator: Arc<AtomicBool>,
+    clock: Arc<dyn ClockTrait>,
 }
 
 impl BrokerTrait for YahooFinanceBroker {
@@ -23,11 +27,13 @@ impl BrokerTrait for YahooFinanceBroker {
         interceptor_factory: Box<dyn BrokerInterceptorFactoryTrait>,
         config_map: ConfigMap,
         stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
     ) -> Self {
         YahooFinanceBroker {
             interceptor_factory,
             config_map,
             stopped_indicator,
+            clock,
         }
     }
 
@@ -37,7 +43,10 @@ impl BrokerTrait for YahooFinanceBroker {
     }
 
     fn create_info(&self) -> Box<dyn InfoTrait> {
-        let yahoo_finance_info = Box::new(YahooFinanceInfo::new(self.config_map.clone()));
+        let yahoo_finance_info = Box::new(YahooFinanceInfo::new(
+            self.config_map.clone(),
+            self.clock.clone(),
+        ));
         Box::new(InfoProxy::new(
             yahoo_finance_info,
             self.interceptor_factory.create_info_interceptor(),
@@ -48,6 +57,7 @@ impl BrokerTrait for YahooFinanceBroker {
         let yahoo_finance_subscription = Box::new(YahooFinanceSubscription::new(
             self.config_map.clone(),
             self.stopped_indicator.clone(),
+            self.clock.clone(),
         ));
         Box::new(SubscriptionProxy::new(
             yahoo_finance_subscription,
@@ -55,11 +65,17 @@ impl BrokerTrait for YahooFinanceBroker {
         ))
     }
 
-    fn create_transaction(&self) -> Box<dyn TransactionTrait> {
-        panic!("Yahoo Finance cannot be used for trading")
+    fn create_transaction(&self) -> Result<Box<dyn TransactionTrait>, Error> {
+        Result::Err(anyhow!(
+            "NOT_SUPPORTED Yahoo Finance cannot be used for trading"
+        ))
     }
 
     fn create_heartbeat(&self) -> Option<Box<dyn HeartbeatTrait>> {
         Option::None
     }
+
+    fn create_fee_model(&self) -> Result<Box<dyn FeeModelTrait>, Error> {
+        Result::Ok(Box::new(NoFeeModel::new(self.config_map.clone())))
+    }
 }
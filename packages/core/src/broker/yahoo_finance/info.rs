@@ -1,15 +1,16 @@
 use anyhow::Error;
 use async_trait::async_trait;
 use rust_decimal::Decimal;
-use std::result::Result;
+use std::{result::Result, sync::Arc};
 use yahoo_finance_api::YahooConnector;
 
 use crate::broker::{common::info::InfoTrait, yahoo_finance::broker::YahooFinanceBroker};
-use crate::model::common::types::ConfigMap;
+use crate::model::common::{timestamp::Timestamp, types::ConfigMap};
 use crate::model::trading::{
     quote::{QueryInfoRequest, QuoteBasicInfo, QuoteDepthInfo, QuoteRealTimeInfo},
     symbol::Symbol,
 };
+use crate::utils::clock::common_trait::ClockTrait;
 
 pub struct YahooFinanceInfo {
     config_map: ConfigMap,
@@ -26,7 +27,7 @@ impl YahooFinanceInfo {
         QuoteRealTimeInfo {
             symbol,
             sequence: yahoo_quote.timestamp,
-            timestamp: yahoo_quote.timestamp,
+            timestamp: Timestamp::from_secs(yahoo_quote.timestamp),
             current_price: Decimal::from_str_exact(format!("{:.2}", yahoo_quote.close).as_str())
                 .unwrap(),
             volume: Option::Some(yahoo_quote.volume.into()),
@@ -42,7 +43,7 @@ impl YahooFinanceInfo {
 
 #[async_trait]
 impl InfoTrait for YahooFinanceInfo {
-    fn new(config_map: ConfigMap) -> Self {
+    fn new(config_map: ConfigMap, _clock: Arc<dyn ClockTrait>) -> Self {
         let provider = YahooConnector::new();
         YahooFinanceInfo {
             config_map,
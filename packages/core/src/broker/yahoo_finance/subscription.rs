@@ -15,18 +15,25 @@ use crate::model::{
     common::types::ConfigMap,
     trading::quote::{QueryInfoRequest, QuoteDepthInfo, QuoteRealTimeInfo},
 };
+use crate::utils::clock::common_trait::ClockTrait;
 
 pub struct YahooFinanceSubscription {
     config_map: ConfigMap,
     global_stopped_indicator: Arc<AtomicBool>,
+    clock: Arc<dyn ClockTrait>,
 }
 
 #[async_trait]
 impl SubscriptionTrait for YahooFinanceSubscription {
-    fn new(config_map: ConfigMap, global_stopped_indicator: Arc<AtomicBool>) -> Self {
+    fn new(
+        config_map: ConfigMap,
+        global_stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
+    ) -> Self {
         YahooFinanceSubscription {
             config_map,
             global_stopped_indicator,
+            clock,
         }
     }
 
@@ -42,6 +49,7 @@ impl SubscriptionTrait for YahooFinanceSubscription {
             sender,
             local_stopped_indicator.clone(),
             self.global_stopped_indicator.clone(),
+            self.clock.clone(),
         );
         let controller = YahooFinanceQuoteRealTimeInfoSubscriptionController::new(
             local_stopped_indicator.clone(),
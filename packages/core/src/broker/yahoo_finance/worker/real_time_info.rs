@@ -3,7 +3,7 @@ use async_trait::async_trait;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tokio::sync::mpsc::Sender;
-use tokio::time::{sleep, Duration};
+use tokio::time::Duration;
 
 use crate::broker::{
     common::{
@@ -16,6 +16,7 @@ use crate::model::{
     common::types::ConfigMap,
     trading::quote::{QueryInfoRequest, QuoteRealTimeInfo},
 };
+use crate::utils::clock::common_trait::ClockTrait;
 
 pub struct YahooFinanceQuoteRealTimeInfoSubscriptionWorker {
     request: QueryInfoRequest,
@@ -23,6 +24,7 @@ pub struct YahooFinanceQuoteRealTimeInfoSubscriptionWorker {
 
     local_stopped_indicator: Arc<AtomicBool>,
     global_stopped_indicator: Arc<AtomicBool>,
+    clock: Arc<dyn ClockTrait>,
 }
 
 impl YahooFinanceQuoteRealTimeInfoSubscriptionWorker {
@@ -31,12 +33,14 @@ impl YahooFinanceQuoteRealTimeInfoSubscriptionWorker {
         sender: Sender<QuoteRealTimeInfo>,
         local_stopped_indicator: Arc<AtomicBool>,
         global_stopped_indicator: Arc<AtomicBool>,
+        clock: Arc<dyn ClockTrait>,
     ) -> Self {
         YahooFinanceQuoteRealTimeInfoSubscriptionWorker {
             request,
             sender,
             local_stopped_indicator,
             global_stopped_indicator,
+            clock,
         }
     }
 }
@@ -44,7 +48,7 @@ impl YahooFinanceQuoteRealTimeInfoSubscriptionWorker {
 #[async_trait]
 impl SubscriptionWorker for YahooFinanceQuoteRealTimeInfoSubscriptionWorker {
     async fn start(self) -> Result<(), Error> {
-        let info = YahooFinanceInfo::new(ConfigMap::new());
+        let info = YahooFinanceInfo::new(ConfigMap::new(), self.clock.clone());
 
         loop {
             if self.local_stopped_indicator.load(Ordering::Relaxed)
@@ -59,7 +63,7 @@ impl SubscriptionWorker for YahooFinanceQuoteRealTimeInfoSubscriptionWorker {
                     log::error!("error when sending into mpsc {}", send_result_err);
                 }
             }
-            sleep(Duration::from_millis(1000)).await;
+            self.clock.sleep(Duration::from_millis(1000)).await;
         }
     }
 }
@@ -78,8 +82,8 @@ impl YahooFinanceQuoteRealTimeInfoSubscriptionController {
 
 #[async_trait]
 impl SubscriptionController for YahooFinanceQuoteRealTimeInfoSubscriptionController {
-    async fn stop(self) -> Result<(), Error> {
-        self.local_stopped_indicator.store(false, Ordering::Relaxed);
+    async fn stop(&self) -> Result<(), Error> {
+        self.local_stopped_indicator.store(true, Ordering::Relaxed);
         Result::Ok(())
     }
 }
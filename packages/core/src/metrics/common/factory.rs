@@ -1,13 +1,19 @@
 use async_trait::async_trait;
 
 use super::registry::MetricRegistryTrait;
-use crate::model::common::types::ConfigMap;
+use crate::model::common::{config_key::ConfigKeySpec, types::ConfigMap};
 
 #[async_trait]
 pub trait MetricRegistryFactoryTrait: Send + Sync {
     fn get_identifier() -> String
     where
         Self: Sized;
+    fn get_config_key_list() -> Vec<ConfigKeySpec>
+    where
+        Self: Sized,
+    {
+        vec![]
+    }
     fn new(config_map: ConfigMap) -> Self
     where
         Self: Sized;
@@ -1,7 +1,7 @@
 use anyhow::{anyhow, Error};
 
 use super::common::factory::MetricRegistryFactoryTrait;
-use crate::model::common::types::ConfigMap;
+use crate::model::common::{config_key::ConfigKeySpec, types::ConfigMap};
 
 #[cfg(feature = "metrics__noops")]
 use crate::metrics::noops::factory::NoOpMetricRegistryFactory;
@@ -29,3 +29,33 @@ pub fn get_metrics_registry_factory(
         )),
     }
 }
+
+pub fn get_metrics_registry_factory_identifier_list() -> Vec<String> {
+    vec![
+        #[cfg(feature = "metrics__noops")]
+        NoOpMetricRegistryFactory::get_identifier(),
+        #[cfg(feature = "metrics__statsd")]
+        StatsDMetricRegistryFactory::get_identifier(),
+    ]
+}
+
+pub fn get_metrics_registry_factory_config_key_list(
+    identifier: &str,
+) -> Result<Vec<ConfigKeySpec>, Error> {
+    match identifier {
+        #[cfg(feature = "metrics__noops")]
+        identifier if identifier == NoOpMetricRegistryFactory::get_identifier() => {
+            Result::Ok(NoOpMetricRegistryFactory::get_config_key_list())
+        }
+
+        #[cfg(feature = "metrics__statsd")]
+        identifier if identifier == StatsDMetricRegistryFactory::get_identifier() => {
+            Result::Ok(StatsDMetricRegistryFactory::get_config_key_list())
+        }
+
+        _ => Result::Err(anyhow!(
+            "IDENTIFIER_NOT_MATCHED MetricsRegistryFactory: {}",
+            identifier
+        )),
+    }
+}
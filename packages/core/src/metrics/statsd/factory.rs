@@ -4,13 +4,32 @@ use dogstatsd::{Client, Options};
 use super::registry::StatsDMetricRegistry;
 use crate::{
     metrics::common::{factory::MetricRegistryFactoryTrait, registry::MetricRegistryTrait},
-    model::common::types::ConfigMap,
+    model::common::{
+        config_key::{ConfigKeySpec, ConfigValueType},
+        types::ConfigMap,
+    },
 };
 
 pub struct StatsDMetricRegistryFactory {
     config_map: ConfigMap,
 }
 
+impl StatsDMetricRegistryFactory {
+    pub const CONFIG_KEY_CLIENT_FROM_ADDRESS: &'static str = "metrics.statsd.from.address";
+    pub const CONFIG_KEY_CLIENT_TO_ADDRESS: &'static str = "metrics.statsd.to.address";
+    pub const CONFIG_KEY_CLIENT_PREFIX: &'static str = "metrics.statsd.prefix";
+    pub const CONFIG_VALUE_DEFAULT_CLIENT_FROM_ADDRESS: &'static str = "0.0.0.0:0";
+    pub const CONFIG_VALUE_DEFAULT_CLIENT_TO_ADDRESS: &'static str = "127.0.0.1:8125";
+    pub const CONFIG_VALUE_DEFAULT_CLIENT_PREFIX: &'static str = "rabbit.trading.";
+
+    fn get_config_value(&self, key: &str, default: &str) -> String {
+        self.config_map
+            .get(key)
+            .cloned()
+            .unwrap_or(default.to_owned())
+    }
+}
+
 #[async_trait]
 impl MetricRegistryFactoryTrait for StatsDMetricRegistryFactory {
     fn get_identifier() -> String {
@@ -18,31 +37,46 @@ impl MetricRegistryFactoryTrait for StatsDMetricRegistryFactory {
         IDENTIFIER.to_owned()
     }
 
+    fn get_config_key_list() -> Vec<ConfigKeySpec> {
+        vec![
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_CLIENT_FROM_ADDRESS,
+                ConfigValueType::String,
+                Option::Some(Self::CONFIG_VALUE_DEFAULT_CLIENT_FROM_ADDRESS),
+                "Local address the metrics are sent from",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_CLIENT_TO_ADDRESS,
+                ConfigValueType::String,
+                Option::Some(Self::CONFIG_VALUE_DEFAULT_CLIENT_TO_ADDRESS),
+                "Address of the StatsD agent",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_CLIENT_PREFIX,
+                ConfigValueType::String,
+                Option::Some(Self::CONFIG_VALUE_DEFAULT_CLIENT_PREFIX),
+                "Prefix of every metric name",
+            ),
+        ]
+    }
+
     fn new(config_map: ConfigMap) -> Self {
         StatsDMetricRegistryFactory { config_map }
     }
 
     fn create(&self) -> Box<dyn MetricRegistryTrait> {
-        const CONFIG_KEY_CLIENT_FROM_ADDRESS: &'static str = "metrics.statsd.from.address";
-        const CONFIG_KEY_CLIENT_TO_ADDRESS: &'static str = "metrics.statsd.to.address";
-        const CONFIG_KEY_CLIENT_PREFIX: &'static str = "metrics.statsd.prefix";
-        const CONFIG_DEFAULT_VALUE_CLIENT_PREFIX: &'static str = "rabbit.trading.";
-
-        let from_address = self
-            .config_map
-            .get(CONFIG_KEY_CLIENT_FROM_ADDRESS)
-            .unwrap()
-            .clone();
-        let to_address = self
-            .config_map
-            .get(CONFIG_KEY_CLIENT_TO_ADDRESS)
-            .unwrap()
-            .clone();
-        let prefix = self
-            .config_map
-            .get(CONFIG_KEY_CLIENT_PREFIX)
-            .map(|value| value.to_string())
-            .unwrap_or(CONFIG_DEFAULT_VALUE_CLIENT_PREFIX.to_owned());
+        let from_address = self.get_config_value(
+            Self::CONFIG_KEY_CLIENT_FROM_ADDRESS,
+            Self::CONFIG_VALUE_DEFAULT_CLIENT_FROM_ADDRESS,
+        );
+        let to_address = self.get_config_value(
+            Self::CONFIG_KEY_CLIENT_TO_ADDRESS,
+            Self::CONFIG_VALUE_DEFAULT_CLIENT_TO_ADDRESS,
+        );
+        let prefix = self.get_config_value(
+            Self::CONFIG_KEY_CLIENT_PREFIX,
+            Self::CONFIG_VALUE_DEFAULT_CLIENT_PREFIX,
+        );
 
         let client_options = Options::new(
             from_address.as_str(),
@@ -52,7 +86,17 @@ impl MetricRegistryFactoryTrait for StatsDMetricRegistryFactory {
             Option::None,
             Option::None,
         );
-        let client = Client::new(client_options).unwrap();
+        // metrics must never take the pod down, they are dropped when the client is unavailable
+        let client = Client::new(client_options)
+            .inspect_err(|err| {
+                log::error!(
+                    "Error when creating StatsD client from {} to {}, metrics are dropped, {}",
+                    from_address,
+                    to_address,
+                    err
+                )
+            })
+            .ok();
         Box::new(StatsDMetricRegistry::new(client))
     }
 }
@@ -5,11 +5,11 @@ use std::{collections::HashMap, time::Duration};
 use crate::metrics::common::registry::MetricRegistryTrait;
 
 pub struct StatsDMetricRegistry {
-    client: Client,
+    client: Option<Client>,
 }
 
 impl StatsDMetricRegistry {
-    pub fn new(client: Client) -> Self {
+    pub fn new(client: Option<Client>) -> Self {
         StatsDMetricRegistry { client }
     }
 
@@ -23,16 +23,19 @@ impl StatsDMetricRegistry {
 #[async_trait]
 impl MetricRegistryTrait for StatsDMetricRegistry {
     async fn inc_counter(&self, name: String, tags: HashMap<String, String>, times: i64) {
-        if let Err(err) = self
-            .client
-            .incr_by_value(&name, times, Self::transform_tags(tags))
-        {
+        let Option::Some(client) = &self.client else {
+            return;
+        };
+        if let Err(err) = client.incr_by_value(&name, times, Self::transform_tags(tags)) {
             log::error!("Error when inc_counter for name={}, {}", name, err);
         }
     }
 
     async fn timer(&self, name: String, tags: HashMap<String, String>, duration: Duration) {
-        if let Err(err) = self.client.timing(
+        let Option::Some(client) = &self.client else {
+            return;
+        };
+        if let Err(err) = client.timing(
             &name,
             duration.as_millis() as i64,
             Self::transform_tags(tags),
@@ -42,10 +45,10 @@ impl MetricRegistryTrait for StatsDMetricRegistry {
     }
 
     async fn gauge(&self, name: String, tags: HashMap<String, String>, value: String) {
-        if let Err(err) = self
-            .client
-            .gauge(&name, value.to_string(), Self::transform_tags(tags))
-        {
+        let Option::Some(client) = &self.client else {
+            return;
+        };
+        if let Err(err) = client.gauge(&name, value.to_string(), Self::transform_tags(tags)) {
             log::error!("Error when gauge for name={}, {}", name, err);
         }
     }
@@ -0,0 +1,165 @@
+use rust_decimal::Decimal;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::{
+    model::{common::timestamp::Timestamp, trading::symbol::Symbol},
+    pod::recorder::writer::{RecordCompression, RecordFormat},
+    utils::{clock::simulated::SimulationSpeed, time::parse_utc_offset},
+};
+
+// Values of a `ConfigMap` are always strings, the type tells how the component parses them
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub enum ConfigValueType {
+    String,
+    Bool,
+    Integer,
+    Decimal,
+    Timestamp,
+    SymbolList,
+    RecordFormat,
+    RecordCompression,
+    SimulationSpeed,
+    UtcOffset,
+    OneOf(&'static [&'static str]),
+}
+
+impl ConfigValueType {
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        let is_valid = match self {
+            ConfigValueType::String => true,
+            ConfigValueType::Bool => value == "true" || value == "false",
+            ConfigValueType::Integer => value.parse::<i64>().is_ok(),
+            ConfigValueType::Decimal => value.parse::<Decimal>().is_ok(),
+            ConfigValueType::Timestamp => value.parse::<Timestamp>().is_ok(),
+            ConfigValueType::SymbolList => {
+                return value
+                    .split(',')
+                    .map(|symbol| symbol.trim())
+                    .filter(|symbol| !symbol.is_empty())
+                    .try_for_each(|symbol| symbol.parse::<Symbol>().map(|_| ()))
+                    .map_err(|err| format!("{}", err));
+            }
+            ConfigValueType::RecordFormat => value.parse::<RecordFormat>().is_ok(),
+            ConfigValueType::RecordCompression => value.parse::<RecordCompression>().is_ok(),
+            ConfigValueType::SimulationSpeed => value.parse::<SimulationSpeed>().is_ok(),
+            ConfigValueType::UtcOffset => parse_utc_offset(value).is_ok(),
+            ConfigValueType::OneOf(option_list) => option_list.contains(&value),
+        };
+        match is_valid {
+            true => Result::Ok(()),
+            false => Result::Err(format!("ILLEGAL_VALUE {}, expected {:?}", value, self)),
+        }
+    }
+
+    fn get_json_schema(&self) -> Map<String, Value> {
+        let mut schema = Map::new();
+        schema.insert("type".to_owned(), json!("string"));
+        match self {
+            ConfigValueType::String | ConfigValueType::Timestamp => {}
+            ConfigValueType::Bool => {
+                schema.insert("enum".to_owned(), json!(["true", "false"]));
+            }
+            ConfigValueType::Integer => {
+                schema.insert("pattern".to_owned(), json!("^-?[0-9]+$"));
+            }
+            ConfigValueType::Decimal => {
+                schema.insert("pattern".to_owned(), json!("^-?[0-9]+(\\.[0-9]+)?$"));
+            }
+            ConfigValueType::UtcOffset => {
+                schema.insert("pattern".to_owned(), json!("^[+-][0-9]{2}:[0-9]{2}$"));
+            }
+            ConfigValueType::SymbolList => {
+                schema.insert(
+                    "pattern".to_owned(),
+                    json!("^[^,.]+\\.[^,.]+(,[^,.]+\\.[^,.]+)*$"),
+                );
+            }
+            ConfigValueType::RecordFormat => {
+                schema.insert("enum".to_owned(), json!(["jsonl", "csv"]));
+            }
+            ConfigValueType::RecordCompression => {
+                schema.insert("enum".to_owned(), json!(["none", "gzip"]));
+            }
+            ConfigValueType::SimulationSpeed => {
+                schema.insert(
+                    "pattern".to_owned(),
+                    json!("^(max|realtime|[0-9]+(\\.[0-9]+)?x?)$"),
+                );
+            }
+            ConfigValueType::OneOf(option_list) => {
+                schema.insert("enum".to_owned(), json!(option_list));
+            }
+        }
+        schema
+    }
+}
+
+// A key a component reads from its `ConfigMap`
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct ConfigKeySpec {
+    pub key: &'static str,
+    pub value_type: ConfigValueType,
+    pub is_required: bool,
+    pub default: Option<&'static str>,
+    pub description: &'static str,
+}
+
+impl ConfigKeySpec {
+    pub const fn required(
+        key: &'static str,
+        value_type: ConfigValueType,
+        description: &'static str,
+    ) -> Self {
+        ConfigKeySpec {
+            key,
+            value_type,
+            is_required: true,
+            default: Option::None,
+            description,
+        }
+    }
+
+    pub const fn optional(
+        key: &'static str,
+        value_type: ConfigValueType,
+        default: Option<&'static str>,
+        description: &'static str,
+    ) -> Self {
+        ConfigKeySpec {
+            key,
+            value_type,
+            is_required: false,
+            default,
+            description,
+        }
+    }
+}
+
+// JSON Schema of a `ConfigMap` holding the given keys, keys that are not declared are allowed
+pub fn get_config_map_json_schema(config_key_list: &[ConfigKeySpec]) -> Value {
+    let properties: Map<String, Value> = config_key_list
+        .iter()
+        .map(|config_key| {
+            let mut schema = config_key.value_type.get_json_schema();
+            schema.insert("description".to_owned(), json!(config_key.description));
+            if let Option::Some(default) = config_key.default {
+                schema.insert("default".to_owned(), json!(default));
+            }
+            (config_key.key.to_owned(), Value::Object(schema))
+        })
+        .collect();
+    let required: Vec<&str> = config_key_list
+        .iter()
+        .filter(|config_key| config_key.is_required)
+        .map(|config_key| config_key.key)
+        .collect();
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": { "type": "string" },
+    })
+}
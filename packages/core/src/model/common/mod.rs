@@ -1 +1,3 @@
+pub mod config_key;
+pub mod timestamp;
 pub mod types;
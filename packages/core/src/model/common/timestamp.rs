@@ -0,0 +1,197 @@
+use anyhow::{anyhow, Error};
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{
+    fmt,
+    ops::{Add, Sub},
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
+use time::OffsetDateTime;
+
+// Nanoseconds since the unix epoch, serialized as an integer.
+// Integers are read by magnitude so that data written with second, millisecond or microsecond
+// precision keeps working, which also means nanosecond values before 1973-03-03 are ambiguous.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Timestamp(u64);
+
+impl Timestamp {
+    const NANOS_PER_MICRO: u64 = 1_000;
+    const NANOS_PER_MILLI: u64 = 1_000_000;
+    const NANOS_PER_SEC: u64 = 1_000_000_000;
+    const MAX_SECS: u64 = 100_000_000_000;
+    const MAX_MILLIS: u64 = 100_000_000_000_000;
+    const MAX_MICROS: u64 = 100_000_000_000_000_000;
+
+    pub const UNIX_EPOCH: Timestamp = Timestamp(0);
+
+    pub fn now() -> Self {
+        Timestamp::from(SystemTime::now())
+    }
+
+    pub const fn from_secs(secs: u64) -> Self {
+        Timestamp(secs * Self::NANOS_PER_SEC)
+    }
+
+    pub const fn from_millis(millis: u64) -> Self {
+        Timestamp(millis * Self::NANOS_PER_MILLI)
+    }
+
+    pub const fn from_micros(micros: u64) -> Self {
+        Timestamp(micros * Self::NANOS_PER_MICRO)
+    }
+
+    pub const fn from_nanos(nanos: u64) -> Self {
+        Timestamp(nanos)
+    }
+
+    // guesses the unit of an epoch integer of unknown precision
+    pub const fn from_epoch(value: u64) -> Self {
+        if value < Self::MAX_SECS {
+            Self::from_secs(value)
+        } else if value < Self::MAX_MILLIS {
+            Self::from_millis(value)
+        } else if value < Self::MAX_MICROS {
+            Self::from_micros(value)
+        } else {
+            Self::from_nanos(value)
+        }
+    }
+
+    pub const fn as_secs(&self) -> u64 {
+        self.0 / Self::NANOS_PER_SEC
+    }
+
+    pub const fn as_millis(&self) -> u64 {
+        self.0 / Self::NANOS_PER_MILLI
+    }
+
+    pub const fn as_micros(&self) -> u64 {
+        self.0 / Self::NANOS_PER_MICRO
+    }
+
+    pub const fn as_nanos(&self) -> u64 {
+        self.0
+    }
+
+    pub const fn as_duration(&self) -> Duration {
+        Duration::from_nanos(self.0)
+    }
+
+    pub fn saturating_sub(&self, other: Timestamp) -> Duration {
+        Duration::from_nanos(self.0.saturating_sub(other.0))
+    }
+
+    pub fn to_offset_date_time(&self) -> Result<OffsetDateTime, Error> {
+        OffsetDateTime::from_unix_timestamp_nanos(self.0 as i128)
+            .map_err(|err| anyhow!("Error when converting timestamp {}, {}", self.0, err))
+    }
+}
+
+// Durations past the year 2554 do not fit in u64 nanoseconds and saturate
+fn duration_to_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+impl From<Duration> for Timestamp {
+    fn from(duration: Duration) -> Self {
+        Timestamp(duration_to_nanos(duration))
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(system_time: SystemTime) -> Self {
+        Timestamp::from(
+            system_time
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default(),
+        )
+    }
+}
+
+impl From<OffsetDateTime> for Timestamp {
+    fn from(offset_date_time: OffsetDateTime) -> Self {
+        Timestamp(offset_date_time.unix_timestamp_nanos().max(0) as u64)
+    }
+}
+
+impl Add<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    fn add(self, duration: Duration) -> Self::Output {
+        Timestamp(self.0.saturating_add(duration_to_nanos(duration)))
+    }
+}
+
+impl Sub<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    fn sub(self, duration: Duration) -> Self::Output {
+        Timestamp(self.0.saturating_sub(duration_to_nanos(duration)))
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Result::Ok(value) = s.parse::<u64>() {
+            return Result::Ok(Timestamp::from_epoch(value));
+        }
+        match s.parse::<f64>() {
+            Result::Ok(secs) if secs >= 0.0 && secs.is_finite() => {
+                Result::Ok(Timestamp::from(Duration::from_secs_f64(secs)))
+            }
+            _ => Result::Err(anyhow!("PARSING_ERROR Error when parsing timestamp {}", s)),
+        }
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.0)
+    }
+}
+
+struct TimestampVisitor;
+
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = Timestamp;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an unix epoch timestamp")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Result::Ok(Timestamp::from_epoch(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        u64::try_from(value)
+            .map(Timestamp::from_epoch)
+            .map_err(|_| E::custom(format!("negative timestamp {}", value)))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        self.visit_str(&value.to_string())
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(TimestampVisitor)
+    }
+}
@@ -0,0 +1,169 @@
+use anyhow::{anyhow, Context, Error};
+use serde_json::Value;
+use std::{env, fs, path::Path, str::FromStr};
+
+use super::pod::PodConfig;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PodConfigFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl PodConfigFormat {
+    pub fn from_path(path: &Path) -> Result<Self, Error> {
+        path.extension()
+            .and_then(|extension| extension.to_str())
+            .ok_or(anyhow!(
+                "PARSING_ERROR No extension to guess the format of pod config {}",
+                path.display()
+            ))?
+            .parse()
+    }
+}
+
+impl FromStr for PodConfigFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Result::Ok(PodConfigFormat::Json),
+            "yaml" | "yml" => Result::Ok(PodConfigFormat::Yaml),
+            "toml" => Result::Ok(PodConfigFormat::Toml),
+            _ => Result::Err(anyhow!(
+                "PARSING_ERROR Error when parsing pod config format {}",
+                s
+            )),
+        }
+    }
+}
+
+// Replaces `${NAME}` with the value of the variable NAME, `${NAME:-default}` falls back to
+// `default` when NAME is not set and `$${` is kept as a literal `${`.
+// Every missing variable is reported in a single error.
+pub fn interpolate_env_var(
+    content: &str,
+    get_env_var: impl Fn(&str) -> Option<String>,
+) -> Result<String, Error> {
+    let mut missing_env_var_list: Vec<String> = Vec::new();
+    let result = interpolate_env_var_into(content, &get_env_var, &mut missing_env_var_list)?;
+    check_missing_env_var(missing_env_var_list)?;
+    Result::Ok(result)
+}
+
+// Interpolates every string in `value`, keys and other scalars are kept as they are, so a value
+// never changes the structure of the document it is parsed from
+pub fn interpolate_env_var_in_value(
+    value: &mut Value,
+    get_env_var: impl Fn(&str) -> Option<String>,
+) -> Result<(), Error> {
+    fn interpolate(
+        value: &mut Value,
+        get_env_var: &dyn Fn(&str) -> Option<String>,
+        missing_env_var_list: &mut Vec<String>,
+    ) -> Result<(), Error> {
+        match value {
+            Value::String(content) => {
+                *content = interpolate_env_var_into(content, get_env_var, missing_env_var_list)?;
+            }
+            Value::Array(value_list) => {
+                for value in value_list.iter_mut() {
+                    interpolate(value, get_env_var, missing_env_var_list)?;
+                }
+            }
+            Value::Object(value_map) => {
+                for value in value_map.values_mut() {
+                    interpolate(value, get_env_var, missing_env_var_list)?;
+                }
+            }
+            Value::Null | Value::Bool(_) | Value::Number(_) => {}
+        }
+        Result::Ok(())
+    }
+
+    let mut missing_env_var_list: Vec<String> = Vec::new();
+    interpolate(value, &get_env_var, &mut missing_env_var_list)?;
+    check_missing_env_var(missing_env_var_list)
+}
+
+fn interpolate_env_var_into(
+    content: &str,
+    get_env_var: &dyn Fn(&str) -> Option<String>,
+    missing_env_var_list: &mut Vec<String>,
+) -> Result<String, Error> {
+    let mut result = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Option::Some(index) = rest.find('$') {
+        result.push_str(&rest[..index]);
+        rest = &rest[index..];
+        if rest.starts_with("$${") {
+            result.push_str("${");
+            rest = &rest[3..];
+            continue;
+        }
+        if !rest.starts_with("${") {
+            result.push('$');
+            rest = &rest[1..];
+            continue;
+        }
+        let end = rest.find('}').ok_or(anyhow!(
+            "PARSING_ERROR Unclosed ${{ in {}",
+            rest.lines().next().unwrap_or_default()
+        ))?;
+        let expression = &rest[2..end];
+        let (name, default) = match expression.split_once(":-") {
+            Option::Some((name, default)) => (name, Option::Some(default)),
+            Option::None => (expression, Option::None),
+        };
+        match (get_env_var(name), default) {
+            (Option::Some(value), _) => result.push_str(&value),
+            (Option::None, Option::Some(default)) => result.push_str(default),
+            (Option::None, Option::None) => {
+                if !missing_env_var_list.iter().any(|missing| missing == name) {
+                    missing_env_var_list.push(name.to_owned());
+                }
+            }
+        }
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+    Result::Ok(result)
+}
+
+fn check_missing_env_var(missing_env_var_list: Vec<String>) -> Result<(), Error> {
+    match missing_env_var_list.is_empty() {
+        true => Result::Ok(()),
+        false => Result::Err(anyhow!(
+            "MISSING_ENV_VAR {}",
+            missing_env_var_list.join(", ")
+        )),
+    }
+}
+
+// The document is parsed before its strings are interpolated, so env vars in comments are ignored
+// and their values need no quoting or escaping. Numbers and booleans cannot come from env vars.
+pub fn parse_pod_config(
+    content: &str,
+    format: PodConfigFormat,
+    get_env_var: impl Fn(&str) -> Option<String>,
+) -> Result<PodConfig, Error> {
+    let mut value: Value = match format {
+        PodConfigFormat::Json => serde_json::from_str(content).map_err(Error::from),
+        PodConfigFormat::Yaml => serde_yaml_ng::from_str(content).map_err(Error::from),
+        PodConfigFormat::Toml => toml::from_str(content).map_err(Error::from),
+    }
+    .with_context(|| format!("PARSING_ERROR Error when parsing {:?} pod config", format))?;
+    interpolate_env_var_in_value(&mut value, get_env_var)?;
+    serde_json::from_value(value)
+        .with_context(|| format!("PARSING_ERROR Error when parsing {:?} pod config", format))
+}
+
+// Reads a JSON, YAML or TOML pod config, picked by the file extension, with env vars interpolated
+pub fn load_pod_config(path: &Path) -> Result<PodConfig, Error> {
+    let format = PodConfigFormat::from_path(path)?;
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Error when reading pod config {}", path.display()))?;
+    parse_pod_config(&content, format, |name| env::var(name).ok())
+}
@@ -1,6 +1,13 @@
 pub mod broker;
+pub mod calendar;
+pub mod clock;
 pub mod event_listener;
+pub mod loader;
 pub mod metrics_registry;
 pub mod persistent_kv_store;
 pub mod pod;
+pub mod recorder;
+pub mod restart_policy;
+pub mod schedule;
+pub mod shutdown_policy;
 pub mod strategy;
@@ -1,9 +1,10 @@
 use serde::{Deserialize, Serialize};
 
 use super::{
-    broker::BrokerConfig, event_listener::EventListenerConfig,
+    broker::BrokerConfig, clock::ClockConfig, event_listener::EventListenerConfig,
     metrics_registry::MetricsRegistryConfig, persistent_kv_store::PersistentKVStoreConfig,
-    strategy::StrategyConfig,
+    recorder::RecorderConfig, restart_policy::RestartPolicyConfig, schedule::ScheduleConfig,
+    shutdown_policy::ShutdownPolicyConfig, strategy::StrategyConfig,
 };
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -14,4 +15,14 @@ pub struct PodConfig {
     pub strategy: StrategyConfig,
     pub metrics_registry: MetricsRegistryConfig,
     pub event_listener_list: Vec<EventListenerConfig>,
+    #[serde(default)]
+    pub clock: Option<ClockConfig>,
+    #[serde(default)]
+    pub recorder_list: Vec<RecorderConfig>,
+    #[serde(default)]
+    pub schedule: Option<ScheduleConfig>,
+    #[serde(default)]
+    pub restart_policy: RestartPolicyConfig,
+    #[serde(default)]
+    pub shutdown_policy: ShutdownPolicyConfig,
 }
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::common::types::ConfigMap;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RecorderConfig {
+    pub broker_identifier: String,
+    pub config_map: ConfigMap,
+}
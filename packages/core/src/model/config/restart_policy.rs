@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::pod::state::PodState;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum RestartPolicy {
+    #[default]
+    Never,
+    // restarts a failed pod, up to `max_retries` consecutive times when set
+    OnFailure,
+    // restarts a pod whenever it ends, unless it is stopped on purpose
+    Always,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RestartPolicyConfig {
+    #[serde(default)]
+    pub policy: RestartPolicy,
+    #[serde(default)]
+    pub max_retries: Option<u32>,
+    #[serde(default = "RestartPolicyConfig::default_initial_backoff_seconds")]
+    pub initial_backoff_seconds: u64,
+    #[serde(default = "RestartPolicyConfig::default_max_backoff_seconds")]
+    pub max_backoff_seconds: u64,
+    // a pod running at least this long counts as healthy and resets the consecutive restarts
+    #[serde(default = "RestartPolicyConfig::default_healthy_seconds")]
+    pub healthy_seconds: u64,
+}
+
+impl RestartPolicyConfig {
+    fn default_initial_backoff_seconds() -> u64 {
+        1
+    }
+
+    fn default_max_backoff_seconds() -> u64 {
+        300
+    }
+
+    fn default_healthy_seconds() -> u64 {
+        60
+    }
+
+    // `retry_count` is the number of consecutive restarts already made
+    pub fn should_restart(&self, state: &PodState, retry_count: u32) -> bool {
+        let is_failed = matches!(state, PodState::Failed { .. });
+        match self.policy {
+            RestartPolicy::Never => false,
+            RestartPolicy::OnFailure => {
+                is_failed
+                    && self
+                        .max_retries
+                        .is_none_or(|max_retries| retry_count < max_retries)
+            }
+            RestartPolicy::Always => is_failed || *state == PodState::Stopped,
+        }
+    }
+
+    // Exponential backoff, doubled on every consecutive restart and capped at `max_backoff_seconds`
+    pub fn get_backoff(&self, retry_count: u32) -> Duration {
+        let backoff_seconds = self
+            .initial_backoff_seconds
+            .saturating_mul(2u64.saturating_pow(retry_count))
+            .min(self.max_backoff_seconds);
+        Duration::from_secs(backoff_seconds)
+    }
+
+    // At least a second, a pod failing right after starting never counts as healthy
+    pub fn is_healthy_run(&self, run_duration: Duration) -> bool {
+        run_duration >= Duration::from_secs(self.healthy_seconds.max(1))
+    }
+}
+
+impl Default for RestartPolicyConfig {
+    fn default() -> Self {
+        RestartPolicyConfig {
+            policy: RestartPolicy::Never,
+            max_retries: Option::None,
+            initial_backoff_seconds: Self::default_initial_backoff_seconds(),
+            max_backoff_seconds: Self::default_max_backoff_seconds(),
+            healthy_seconds: Self::default_healthy_seconds(),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use super::calendar::TradingCalendarConfig;
+use crate::model::trading::market::Market;
+
+// Runs the pod on every trading day of the market, from `start_before_open_minutes` before the
+// first regular open to `stop_after_close_minutes` after the last regular close. Holidays and
+// early closes follow the calendar, `StaticTradingCalendar` is used when none is configured.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ScheduleConfig {
+    pub market: Market,
+    #[serde(default)]
+    pub start_before_open_minutes: u64,
+    #[serde(default)]
+    pub stop_after_close_minutes: u64,
+    #[serde(default)]
+    pub calendar: Option<TradingCalendarConfig>,
+}
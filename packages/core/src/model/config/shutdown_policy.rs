@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum OpenPositionAction {
+    // leaves orders working and positions open at the broker
+    #[default]
+    Keep,
+    // cancels every open order of the accounts of the brokers, including the orders not placed
+    // by the pod
+    CancelOpenOrders,
+    // cancels open orders, then closes every position of the accounts with market orders, also
+    // the positions of other pods or placed by hand, so it is only meant for accounts dedicated
+    // to the pod
+    FlattenPositions,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ShutdownPolicyConfig {
+    #[serde(default)]
+    pub open_position_action: OpenPositionAction,
+    // how long the strategy has to return after `stop` before its task is aborted
+    #[serde(default = "ShutdownPolicyConfig::default_strategy_stop_timeout_seconds")]
+    pub strategy_stop_timeout_seconds: u64,
+    // how long the cancelled orders have to close, positions are not flattened while some of
+    // them are still open
+    #[serde(default = "ShutdownPolicyConfig::default_order_cancel_timeout_seconds")]
+    pub order_cancel_timeout_seconds: u64,
+}
+
+impl ShutdownPolicyConfig {
+    fn default_strategy_stop_timeout_seconds() -> u64 {
+        30
+    }
+
+    fn default_order_cancel_timeout_seconds() -> u64 {
+        10
+    }
+}
+
+impl Default for ShutdownPolicyConfig {
+    fn default() -> Self {
+        ShutdownPolicyConfig {
+            open_position_action: OpenPositionAction::Keep,
+            strategy_stop_timeout_seconds: Self::default_strategy_stop_timeout_seconds(),
+            order_cancel_timeout_seconds: Self::default_order_cancel_timeout_seconds(),
+        }
+    }
+}
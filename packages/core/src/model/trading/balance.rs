@@ -1,10 +1,12 @@
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::currency::Currency;
 
 pub type BalanceHashMap = HashMap<Currency, BalanceDetail>;
 
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct BalanceDetail {
     pub total_cash: Decimal,
     pub net_assets: Decimal,
@@ -0,0 +1,17 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::symbol::Symbol;
+use crate::model::common::timestamp::Timestamp;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Candlestick {
+    pub symbol: Symbol,
+    pub timestamp: Timestamp,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub volume: Decimal,
+    pub turnover: Option<Decimal>,
+}
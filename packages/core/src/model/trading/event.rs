@@ -1,15 +1,22 @@
 use serde::{Deserialize, Serialize};
 
-use crate::model::trading::transaction::{
-    CancelOrderRequest, CancelOrderResponse, EditOrderRequest, EditOrderResponse,
-    SubmitOrderRequest, SubmitOrderResponse,
+use crate::{
+    model::{
+        common::timestamp::Timestamp,
+        trading::transaction::{
+            CancelOrderRequest, CancelOrderResponse, EditOrderRequest, EditOrderResponse,
+            SubmitBracketOrderRequest, SubmitBracketOrderResponse, SubmitOcoOrderRequest,
+            SubmitOcoOrderResponse, SubmitOrderRequest, SubmitOrderResponse,
+        },
+    },
+    pod::{shutdown::ShutdownReport, state::PodState},
 };
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct EventContext {
     pub broker_id: String,
     pub pod_id: String,
-    pub timestamp: u64,
+    pub timestamp: Timestamp,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -21,9 +28,12 @@ pub fn from_anyhow_result<T>(result: &Result<T, anyhow::Error>) -> Result<T, Eve
 where
     T: Sized + Clone,
 {
-    result.as_ref().map(|val| val.clone()).map_err(|e| EventError {
-        message: e.to_string(),
-    })
+    result
+        .as_ref()
+        .map(|val| val.clone())
+        .map_err(|e| EventError {
+            message: e.to_string(),
+        })
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -44,4 +54,29 @@ pub enum RabbitTradingEvent {
         request: CancelOrderRequest,
         result: Result<CancelOrderResponse, EventError>,
     },
+    SubmitBracketOrder {
+        context: EventContext,
+        request: SubmitBracketOrderRequest,
+        result: Result<SubmitBracketOrderResponse, EventError>,
+    },
+    SubmitOcoOrder {
+        context: EventContext,
+        request: SubmitOcoOrderRequest,
+        result: Result<SubmitOcoOrderResponse, EventError>,
+    },
+    PodStateChanged {
+        context: EventContext,
+        state: PodState,
+    },
+    PodRestart {
+        context: EventContext,
+        // total number of restarts of the pod, including this one
+        restart_count: u32,
+        reason: String,
+        backoff_seconds: u64,
+    },
+    PodShutdown {
+        context: EventContext,
+        report: ShutdownReport,
+    },
 }
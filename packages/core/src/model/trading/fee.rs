@@ -0,0 +1,51 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::{currency::Currency, symbol::Symbol, transaction::Direction};
+
+// A single fill, fee schedules with minimum charges treat every request as one order
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CalculateFeeRequest {
+    pub symbol: Symbol,
+    pub direction: Direction,
+    pub quantity: Decimal,
+    pub price: Decimal,
+}
+
+impl CalculateFeeRequest {
+    pub fn get_value(&self) -> Decimal {
+        self.quantity * self.price
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum FeeKind {
+    Commission,
+    PlatformFee,
+    ClearingFee,
+    SettlementFee,
+    StampDuty,
+    TradingFee,
+    SfcLevy,
+    AfrcLevy,
+    SecFee,
+    FinraTaf,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FeeItem {
+    pub kind: FeeKind,
+    pub amount: Decimal,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Fee {
+    pub currency: Currency,
+    pub item_list: Vec<FeeItem>,
+}
+
+impl Fee {
+    pub fn get_total(&self) -> Decimal {
+        self.item_list.iter().map(|item| item.amount).sum()
+    }
+}
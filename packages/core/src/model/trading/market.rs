@@ -2,6 +2,8 @@ use anyhow::{anyhow, Error};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
+use super::currency::Currency;
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum Market {
     CN,
@@ -9,6 +11,16 @@ pub enum Market {
     US,
 }
 
+impl Market {
+    pub fn get_currency(&self) -> Currency {
+        match self {
+            Market::CN => Currency::CNY,
+            Market::HK => Currency::HKD,
+            Market::US => Currency::USD,
+        }
+    }
+}
+
 impl std::string::ToString for Market {
     fn to_string(&self) -> String {
         match self {
@@ -1,6 +1,8 @@
 pub mod balance;
+pub mod candlestick;
 pub mod currency;
 pub mod event;
+pub mod fee;
 pub mod market;
 pub mod position;
 pub mod quote;
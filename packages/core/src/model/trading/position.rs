@@ -1,8 +1,11 @@
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
 use super::{currency::Currency, symbol::Symbol};
 
 pub type PositionList = Vec<Position>;
 
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Position {
     pub symbol: Symbol,
     pub currency: Currency,
@@ -3,13 +3,14 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::{currency::Currency, symbol::Symbol};
+use crate::model::common::timestamp::Timestamp;
 
 // todo: prev_close, trading_session, is_trading
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct QuoteRealTimeInfo {
     pub symbol: Symbol,
     pub sequence: u64,
-    pub timestamp: u64,
+    pub timestamp: Timestamp,
     pub current_price: Decimal,
     pub volume: Option<Decimal>,
     pub low_price: Option<Decimal>,
@@ -45,7 +46,7 @@ pub struct Depth {
 pub struct QuoteDepthInfo {
     pub symbol: Symbol,
     pub sequence: u64,
-    pub timestamp: u64,
+    pub timestamp: Timestamp,
     pub ask_list: Vec<Depth>,
     pub bid_list: Vec<Depth>,
 }
@@ -2,6 +2,7 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use super::{currency::Currency, symbol::Symbol};
+use crate::model::common::timestamp::Timestamp;
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum Direction {
@@ -9,6 +10,15 @@ pub enum Direction {
     Sell,
 }
 
+impl Direction {
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Buy => Direction::Sell,
+            Direction::Sell => Direction::Buy,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum RegularTradingTime {
     AllTime,
@@ -61,6 +71,15 @@ pub enum Price {
     TrailingMarketIfTouched {
         trailing: TrailingMarketPrice,
     },
+    // Stop orders trigger once the price moves against the order direction,
+    // i.e. rises to the stop price for buy orders and falls to it for sell orders
+    Stop {
+        stop_price: Decimal,
+    },
+    StopLimit {
+        stop_price: Decimal,
+        limit_price: Decimal,
+    },
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -78,6 +97,46 @@ pub struct SubmitOrderResponse {
     pub order_id: String,
 }
 
+// The take-profit and stop-loss children are placed in the opposite direction once the entry
+// order is filled and cancel each other, like an OCO group
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SubmitBracketOrderRequest {
+    pub entry: SubmitOrderRequest,
+    pub take_profit: Option<Price>,
+    pub stop_loss: Option<Price>,
+}
+
+impl SubmitBracketOrderRequest {
+    pub fn get_child_request(&self, price: Price) -> SubmitOrderRequest {
+        SubmitOrderRequest {
+            symbol: self.entry.symbol.clone(),
+            quantity: self.entry.quantity,
+            direction: self.entry.direction.opposite(),
+            regular_trading_time: self.entry.regular_trading_time.clone(),
+            expire: self.entry.expire.clone(),
+            price,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SubmitBracketOrderResponse {
+    pub entry_order_id: String,
+    pub take_profit_order_id: Option<String>,
+    pub stop_loss_order_id: Option<String>,
+}
+
+// A fill of any order cancels the rest of the group
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SubmitOcoOrderRequest {
+    pub order_list: Vec<SubmitOrderRequest>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SubmitOcoOrderResponse {
+    pub order_id_list: Vec<String>,
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct EstimateMaxBuyingPowerRequest {
     pub symbol: Symbol,
@@ -117,6 +176,31 @@ pub struct OrderDetailRequest {
     pub order_id: String,
 }
 
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum OrderStatus {
+    Pending,
+    Submitted,
+    PartiallyFilled,
+    Filled,
+    Cancelled,
+    Rejected,
+    Expired,
+    Unknown,
+}
+
+impl OrderStatus {
+    pub fn is_open(&self) -> bool {
+        match self {
+            OrderStatus::Pending | OrderStatus::Submitted | OrderStatus::PartiallyFilled => true,
+            OrderStatus::Filled
+            | OrderStatus::Cancelled
+            | OrderStatus::Rejected
+            | OrderStatus::Expired
+            | OrderStatus::Unknown => false,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct OrderDetail {
     pub order_id: String,
@@ -126,10 +210,87 @@ pub struct OrderDetail {
     pub executed_quantity: Decimal,
     pub price: Price,
     pub executed_price: Option<Decimal>,
+    // total fee charged, in the settlement currency of the broker
+    pub fee: Option<Decimal>,
     pub direction: Direction,
     pub regular_trading_time: RegularTradingTime,
     pub expire: Expire,
-    pub created_timestamp: Option<u64>,
-    pub updated_timestamp: Option<u64>,
-    pub triggered_timestamp: Option<u64>,
+    pub status: OrderStatus,
+    pub created_timestamp: Option<Timestamp>,
+    pub updated_timestamp: Option<Timestamp>,
+    pub triggered_timestamp: Option<Timestamp>,
+}
+
+// Both bounds are inclusive, an absent bound or symbol matches everything
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ListOrderHistoryRequest {
+    pub symbol: Option<Symbol>,
+    pub start_timestamp: Option<Timestamp>,
+    pub end_timestamp: Option<Timestamp>,
+}
+
+impl ListOrderHistoryRequest {
+    pub fn is_matched(&self, order_detail: &OrderDetail) -> bool {
+        is_matched(
+            &self.symbol,
+            self.start_timestamp,
+            self.end_timestamp,
+            &order_detail.symbol,
+            order_detail.created_timestamp,
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ListExecutionsRequest {
+    pub symbol: Option<Symbol>,
+    pub start_timestamp: Option<Timestamp>,
+    pub end_timestamp: Option<Timestamp>,
+}
+
+impl ListExecutionsRequest {
+    pub fn is_matched(&self, execution: &Execution) -> bool {
+        is_matched(
+            &self.symbol,
+            self.start_timestamp,
+            self.end_timestamp,
+            &execution.symbol,
+            Option::Some(execution.timestamp),
+        )
+    }
+}
+
+fn is_matched(
+    symbol_filter: &Option<Symbol>,
+    start_timestamp: Option<Timestamp>,
+    end_timestamp: Option<Timestamp>,
+    symbol: &Symbol,
+    timestamp: Option<Timestamp>,
+) -> bool {
+    let is_symbol_matched = symbol_filter
+        .as_ref()
+        .is_none_or(|symbol_filter| symbol_filter == symbol);
+    let is_timestamp_matched = match timestamp {
+        Option::Some(timestamp) => {
+            start_timestamp.is_none_or(|start| start <= timestamp)
+                && end_timestamp.is_none_or(|end| timestamp <= end)
+        }
+        // brokers might omit the time, keep the record unless a range is requested
+        Option::None => start_timestamp.is_none() && end_timestamp.is_none(),
+    };
+    is_symbol_matched && is_timestamp_matched
+}
+
+// A single fill, some brokers only report a subset of the fields
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Execution {
+    pub execution_id: String,
+    pub order_id: Option<String>,
+    pub symbol: Symbol,
+    pub direction: Option<Direction>,
+    pub quantity: Decimal,
+    pub price: Decimal,
+    pub fee: Option<Decimal>,
+    pub currency: Option<Currency>,
+    pub timestamp: Timestamp,
 }
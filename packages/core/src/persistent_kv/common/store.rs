@@ -1,7 +1,7 @@
 use anyhow::Error;
 use async_trait::async_trait;
 
-use crate::model::common::types::ConfigMap;
+use crate::model::common::{config_key::ConfigKeySpec, types::ConfigMap};
 
 pub type BytesArray = Vec<u8>;
 
@@ -10,10 +10,18 @@ pub trait PersistentKVStoreTrait: Send + Sync {
     fn get_identifier() -> String
     where
         Self: Sized;
+    fn get_config_key_list() -> Vec<ConfigKeySpec>
+    where
+        Self: Sized,
+    {
+        vec![]
+    }
 
     async fn new(config_map: ConfigMap) -> Self
     where
         Self: Sized;
+    // `read` fails for a missing key as for any other error
+    async fn exists(&self, key: String) -> Result<bool, Error>;
     async fn read(&self, key: String) -> Result<BytesArray, Error>;
     async fn write(&self, key: String, value: BytesArray) -> Result<usize, Error>;
 }
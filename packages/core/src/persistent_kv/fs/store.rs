@@ -5,7 +5,10 @@ use tempfile::{tempdir, TempDir};
 use tokio::fs;
 
 use crate::{
-    model::common::types::ConfigMap,
+    model::common::{
+        config_key::{ConfigKeySpec, ConfigValueType},
+        types::ConfigMap,
+    },
     persistent_kv::common::store::{BytesArray, PersistentKVStoreTrait},
 };
 
@@ -19,6 +22,8 @@ pub struct FileSystemKVStore {
 }
 
 impl FileSystemKVStore {
+    pub const CONFIG_KEY_BASE_PATH: &'static str = "persistent.fs.base_path";
+
     fn get_file_path_for_key(&self, key: &str) -> PathBuf {
         match &self.backend_path {
             KVStoreBackendPath::UserDefinedPath { base_path } => Path::new(base_path).join(key),
@@ -34,10 +39,17 @@ impl PersistentKVStoreTrait for FileSystemKVStore {
         IDENTIFIER.to_owned()
     }
 
-    async fn new(config_map: ConfigMap) -> Self {
-        const CONFIG_KEY_BASE_PATH: &'static str = "persistent.fs.base_path";
+    fn get_config_key_list() -> Vec<ConfigKeySpec> {
+        vec![ConfigKeySpec::optional(
+            Self::CONFIG_KEY_BASE_PATH,
+            ConfigValueType::String,
+            Option::None,
+            "Directory of the stored files, a temporary directory is used when not set",
+        )]
+    }
 
-        let backend_path = match config_map.get(CONFIG_KEY_BASE_PATH) {
+    async fn new(config_map: ConfigMap) -> Self {
+        let backend_path = match config_map.get(Self::CONFIG_KEY_BASE_PATH) {
             Some(base_path) => KVStoreBackendPath::UserDefinedPath {
                 base_path: base_path.clone(),
             },
@@ -54,6 +66,14 @@ impl PersistentKVStoreTrait for FileSystemKVStore {
         FileSystemKVStore { backend_path }
     }
 
+    async fn exists(&self, key: String) -> Result<bool, Error> {
+        let file_path = self.get_file_path_for_key(key.as_str());
+
+        fs::try_exists(file_path)
+            .await
+            .with_context(|| format!("Error when checking key: {}", key))
+    }
+
     async fn read(&self, key: String) -> Result<BytesArray, Error> {
         let file_path = self.get_file_path_for_key(key.as_str());
 
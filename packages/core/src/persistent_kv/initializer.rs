@@ -1,7 +1,7 @@
 use anyhow::{anyhow, Error};
 
 use super::common::store::PersistentKVStoreTrait;
-use crate::model::common::types::ConfigMap;
+use crate::model::common::{config_key::ConfigKeySpec, types::ConfigMap};
 
 #[cfg(feature = "persistent__fs")]
 use super::fs::store::FileSystemKVStore;
@@ -29,3 +29,31 @@ pub async fn get_persistent_kv_instance(
         )),
     }
 }
+
+pub fn get_persistent_kv_identifier_list() -> Vec<String> {
+    vec![
+        #[cfg(feature = "persistent__fs")]
+        FileSystemKVStore::get_identifier(),
+        #[cfg(feature = "persistent__memory")]
+        MemoryKVStore::get_identifier(),
+    ]
+}
+
+pub fn get_persistent_kv_config_key_list(identifier: &str) -> Result<Vec<ConfigKeySpec>, Error> {
+    match identifier {
+        #[cfg(feature = "persistent__fs")]
+        identifier if identifier == FileSystemKVStore::get_identifier() => {
+            Result::Ok(FileSystemKVStore::get_config_key_list())
+        }
+
+        #[cfg(feature = "persistent__memory")]
+        identifier if identifier == MemoryKVStore::get_identifier() => {
+            Result::Ok(MemoryKVStore::get_config_key_list())
+        }
+
+        _ => Result::Err(anyhow!(
+            "IDENTIFIER_NOT_MATCHED PersistentKV: {}",
+            identifier
+        )),
+    }
+}
@@ -25,6 +25,10 @@ impl PersistentKVStoreTrait for MemoryKVStore {
         }
     }
 
+    async fn exists(&self, key: String) -> Result<bool, Error> {
+        Result::Ok(self.data.read().await.contains_key(key.as_str()))
+    }
+
     async fn read(&self, key: String) -> Result<BytesArray, Error> {
         match self.data.read().await.get(key.as_str()) {
             Option::Some(val) => Result::Ok(val.clone()),
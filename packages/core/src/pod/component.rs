@@ -0,0 +1,137 @@
+use anyhow::{anyhow, Error};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::str::FromStr;
+
+use super::event::listener::initializer::{
+    get_event_listener_config_key_list, get_event_listener_identifier_list,
+};
+use crate::{
+    broker::initializer::{
+        get_broker_config_key_list, get_broker_identifier_list, get_fee_model_config_key_list,
+        get_fee_model_identifier_list,
+    },
+    metrics::initializer::{
+        get_metrics_registry_factory_config_key_list, get_metrics_registry_factory_identifier_list,
+    },
+    model::common::config_key::{get_config_map_json_schema, ConfigKeySpec},
+    persistent_kv::initializer::{
+        get_persistent_kv_config_key_list, get_persistent_kv_identifier_list,
+    },
+    strategy::initializer::{get_strategy_config_key_list, get_strategy_identifier_list},
+    utils::{
+        calendar::initializer::{
+            get_trading_calendar_config_key_list, get_trading_calendar_identifier_list,
+        },
+        clock::initializer::{get_clock_config_key_list, get_clock_identifier_list},
+    },
+};
+
+// Kinds of the pluggable parts of a pod, each picked by an identifier and configured by a
+// `ConfigMap`
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum ComponentKind {
+    Broker,
+    FeeModel,
+    Strategy,
+    EventListener,
+    PersistentKVStore,
+    MetricsRegistryFactory,
+    Clock,
+    TradingCalendar,
+}
+
+impl ComponentKind {
+    pub const ALL: [ComponentKind; 8] = [
+        ComponentKind::Broker,
+        ComponentKind::FeeModel,
+        ComponentKind::Strategy,
+        ComponentKind::EventListener,
+        ComponentKind::PersistentKVStore,
+        ComponentKind::MetricsRegistryFactory,
+        ComponentKind::Clock,
+        ComponentKind::TradingCalendar,
+    ];
+
+    // Identifiers compiled in through cargo features
+    pub fn get_identifier_list(&self) -> Vec<String> {
+        match self {
+            ComponentKind::Broker => get_broker_identifier_list(),
+            ComponentKind::FeeModel => get_fee_model_identifier_list(),
+            ComponentKind::Strategy => get_strategy_identifier_list(),
+            ComponentKind::EventListener => get_event_listener_identifier_list(),
+            ComponentKind::PersistentKVStore => get_persistent_kv_identifier_list(),
+            ComponentKind::MetricsRegistryFactory => get_metrics_registry_factory_identifier_list(),
+            ComponentKind::Clock => get_clock_identifier_list(),
+            ComponentKind::TradingCalendar => get_trading_calendar_identifier_list(),
+        }
+    }
+
+    pub fn get_config_key_list(&self, identifier: &str) -> Result<Vec<ConfigKeySpec>, Error> {
+        match self {
+            ComponentKind::Broker => get_broker_config_key_list(identifier),
+            ComponentKind::FeeModel => get_fee_model_config_key_list(identifier),
+            ComponentKind::Strategy => get_strategy_config_key_list(identifier),
+            ComponentKind::EventListener => get_event_listener_config_key_list(identifier),
+            ComponentKind::PersistentKVStore => get_persistent_kv_config_key_list(identifier),
+            ComponentKind::MetricsRegistryFactory => {
+                get_metrics_registry_factory_config_key_list(identifier)
+            }
+            ComponentKind::Clock => get_clock_config_key_list(identifier),
+            ComponentKind::TradingCalendar => get_trading_calendar_config_key_list(identifier),
+        }
+    }
+}
+
+impl FromStr for ComponentKind {
+    type Err = Error;
+
+    // accepts `EventListener`, `eventlistener` and `event_listener` alike
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.replace('_', "").to_lowercase();
+        ComponentKind::ALL
+            .into_iter()
+            .find(|kind| format!("{:?}", kind).to_lowercase() == normalized)
+            .ok_or(anyhow!(
+                "PARSING_ERROR Error when parsing component kind {}",
+                s
+            ))
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ComponentSchema {
+    pub kind: ComponentKind,
+    pub identifier: String,
+    pub config_key_list: Vec<ConfigKeySpec>,
+    // JSON Schema of the config map of the component
+    pub json_schema: Value,
+}
+
+impl ComponentSchema {
+    pub fn new(kind: ComponentKind, identifier: &str) -> Result<Self, Error> {
+        let config_key_list = kind.get_config_key_list(identifier)?;
+        Result::Ok(ComponentSchema {
+            kind,
+            identifier: identifier.to_owned(),
+            json_schema: get_config_map_json_schema(&config_key_list),
+            config_key_list,
+        })
+    }
+}
+
+pub fn get_component_schema_list(kind: Option<ComponentKind>) -> Vec<ComponentSchema> {
+    ComponentKind::ALL
+        .into_iter()
+        .filter(|component_kind| kind.is_none() || kind == Option::Some(*component_kind))
+        .flat_map(|component_kind| {
+            component_kind
+                .get_identifier_list()
+                .into_iter()
+                // every listed identifier is matched by its initializer
+                .filter_map(move |identifier| {
+                    ComponentSchema::new(component_kind, &identifier).ok()
+                })
+        })
+        .collect()
+}
@@ -1,4 +1,4 @@
-use std::collections::LinkedList;
+use std::{collections::LinkedList, sync::Arc};
 use tokio::sync::{
     broadcast::{self, Receiver, Sender},
     RwLockReadGuard,
@@ -12,7 +12,7 @@ use crate::{
         common::types::ConfigMap,
         trading::event::{EventContext, RabbitTradingEvent},
     },
-    utils::time::get_now_unix_timestamp,
+    utils::clock::common_trait::ClockTrait,
 };
 
 pub struct EventBus {
@@ -20,10 +20,11 @@ pub struct EventBus {
     broker_id: String,
     pod_id: String,
     log_container_event_listener: LogContainerEventListener,
+    clock: Arc<dyn ClockTrait>,
 }
 
 impl EventBus {
-    pub fn new(broker_id: String, pod_id: String) -> Self {
+    pub fn new(broker_id: String, pod_id: String, clock: Arc<dyn ClockTrait>) -> Self {
         let (sender, receiver) = broadcast::channel::<RabbitTradingEvent>(256);
         let log_container_event_listener = LogContainerEventListener::new(ConfigMap::new());
         log_container_event_listener.start(receiver);
@@ -32,6 +33,7 @@ impl EventBus {
             broker_id,
             pod_id,
             log_container_event_listener,
+            clock,
         }
     }
 
@@ -54,7 +56,7 @@ impl EventBus {
         EventContext {
             broker_id: self.broker_id.clone(),
             pod_id: self.pod_id.clone(),
-            timestamp: get_now_unix_timestamp(),
+            timestamp: self.clock.now(),
         }
     }
 
@@ -64,6 +66,7 @@ impl EventBus {
             broker_id: broker_id.unwrap_or(self.broker_id.clone()),
             pod_id: self.pod_id.clone(),
             log_container_event_listener: self.log_container_event_listener.clone(),
+            clock: self.clock.clone(),
         }
     }
 }
@@ -1,7 +1,10 @@
 use anyhow::Error;
 use tokio::sync::broadcast::Receiver;
 
-use crate::model::{common::types::ConfigMap, trading::event::RabbitTradingEvent};
+use crate::model::{
+    common::{config_key::ConfigKeySpec, types::ConfigMap},
+    trading::event::RabbitTradingEvent,
+};
 
 pub trait EventListenerTrait {
     fn new(config_map: ConfigMap) -> Self
@@ -10,6 +13,12 @@ pub trait EventListenerTrait {
     fn get_identifier() -> String
     where
         Self: Sized;
+    fn get_config_key_list() -> Vec<ConfigKeySpec>
+    where
+        Self: Sized,
+    {
+        vec![]
+    }
 
     fn start(&self, receiver: Receiver<RabbitTradingEvent>);
     fn stop(&self) -> Result<(), Error>;
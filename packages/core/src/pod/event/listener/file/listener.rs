@@ -0,0 +1,128 @@
+use anyhow::{Context, Error};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+
+use crate::{
+    model::{
+        common::{
+            config_key::{ConfigKeySpec, ConfigValueType},
+            types::ConfigMap,
+        },
+        trading::event::RabbitTradingEvent,
+    },
+    pod::event::listener::common::listener::EventListenerTrait,
+};
+
+// Appends every event to a JSON Lines file, so that the event log of a pod can be tailed from
+// another process
+pub struct FileEventListener {
+    path: PathBuf,
+    stopped_indicator: Arc<AtomicBool>,
+}
+
+impl FileEventListener {
+    pub const CONFIG_KEY_PATH: &'static str = "event_listener.file.path";
+
+    fn append_event(writer: &mut BufWriter<File>, event: &RabbitTradingEvent) -> Result<(), Error> {
+        serde_json::to_writer(&mut *writer, event)?;
+        writer.write_all(b"\n")?;
+        // flushed on every event for the readers tailing the file
+        writer.flush()?;
+        Result::Ok(())
+    }
+
+    async fn async_write_task(
+        mut receiver: Receiver<RabbitTradingEvent>,
+        path: PathBuf,
+        stopped_indicator: Arc<AtomicBool>,
+    ) {
+        let file = match Self::open(&path) {
+            Result::Ok(file) => file,
+            Result::Err(err) => {
+                log::error!("{:#}", err);
+                return;
+            }
+        };
+        let mut writer = BufWriter::new(file);
+        loop {
+            if stopped_indicator.load(Ordering::Relaxed) {
+                return;
+            }
+
+            match receiver.recv().await {
+                Result::Ok(event) => {
+                    if let Result::Err(err) = Self::append_event(&mut writer, &event) {
+                        log::error!("Error when writing event into {}, {}", path.display(), err);
+                    }
+                }
+                Result::Err(RecvError::Lagged(count)) => {
+                    log::warn!("{} events are not written into {}", count, path.display());
+                }
+                Result::Err(RecvError::Closed) => return,
+            }
+        }
+    }
+
+    fn open(path: &Path) -> Result<File, Error> {
+        if let Option::Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Error when opening event log {}", path.display()))
+    }
+
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl EventListenerTrait for FileEventListener {
+    fn new(config_map: ConfigMap) -> Self {
+        FileEventListener {
+            path: PathBuf::from(
+                config_map
+                    .get(Self::CONFIG_KEY_PATH)
+                    .cloned()
+                    .unwrap_or_default(),
+            ),
+            stopped_indicator: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    fn get_identifier() -> String {
+        const IDENTIFIER: &'static str = "FileEventListener";
+        IDENTIFIER.to_owned()
+    }
+
+    fn get_config_key_list() -> Vec<ConfigKeySpec> {
+        vec![ConfigKeySpec::required(
+            Self::CONFIG_KEY_PATH,
+            ConfigValueType::String,
+            "Path of the JSON Lines file the events are appended to",
+        )]
+    }
+
+    fn start(&self, receiver: Receiver<RabbitTradingEvent>) {
+        tokio::task::spawn(Self::async_write_task(
+            receiver,
+            self.path.clone(),
+            self.stopped_indicator.clone(),
+        ));
+    }
+
+    fn stop(&self) -> Result<(), Error> {
+        self.stopped_indicator.store(true, Ordering::Relaxed);
+        Result::Ok(())
+    }
+}
@@ -2,9 +2,10 @@ use anyhow::{anyhow, Error};
 
 use super::common::listener::EventListenerTrait;
 use crate::{
-    model::common::types::ConfigMap,
+    model::common::{config_key::ConfigKeySpec, types::ConfigMap},
     pod::event::listener::{
-        log::listener::LogEventListener, log_container::listener::LogContainerEventListener,
+        file::listener::FileEventListener, log::listener::LogEventListener,
+        log_container::listener::LogContainerEventListener,
     },
 };
 
@@ -21,6 +22,42 @@ pub fn get_event_listener(
             Result::Ok(Box::new(LogContainerEventListener::new(config_map)))
         }
 
-        _ => Result::Err(anyhow!("IDENTIFIER_NOT_MATCHED Broker: {}", identifier)),
+        identifier if identifier == FileEventListener::get_identifier() => {
+            Result::Ok(Box::new(FileEventListener::new(config_map)))
+        }
+
+        _ => Result::Err(anyhow!(
+            "IDENTIFIER_NOT_MATCHED EventListener: {}",
+            identifier
+        )),
+    }
+}
+
+pub fn get_event_listener_identifier_list() -> Vec<String> {
+    vec![
+        LogEventListener::get_identifier(),
+        LogContainerEventListener::get_identifier(),
+        FileEventListener::get_identifier(),
+    ]
+}
+
+pub fn get_event_listener_config_key_list(identifier: &str) -> Result<Vec<ConfigKeySpec>, Error> {
+    match identifier {
+        identifier if identifier == LogEventListener::get_identifier() => {
+            Result::Ok(LogEventListener::get_config_key_list())
+        }
+
+        identifier if identifier == LogContainerEventListener::get_identifier() => {
+            Result::Ok(LogContainerEventListener::get_config_key_list())
+        }
+
+        identifier if identifier == FileEventListener::get_identifier() => {
+            Result::Ok(FileEventListener::get_config_key_list())
+        }
+
+        _ => Result::Err(anyhow!(
+            "IDENTIFIER_NOT_MATCHED EventListener: {}",
+            identifier
+        )),
     }
 }
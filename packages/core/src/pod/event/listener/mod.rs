@@ -1,5 +1,6 @@
 pub mod common;
 pub mod initializer;
 
+pub mod file;
 pub mod log_container;
 pub mod log;
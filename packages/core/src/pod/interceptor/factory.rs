@@ -1,5 +1,6 @@
 use super::{
-    info::PodInfoInterceptor, subscription::PodSubscriptionInterceptor,
+    info::PodInfoInterceptor,
+    subscription::{PodSubscriptionInterceptor, SubscriptionControllerList},
     transaction::PodTransactionInterceptor,
 };
 use crate::{
@@ -14,16 +15,19 @@ use crate::{
 pub struct PodBrokerInterceptorCollectionFactory {
     event_bus: EventBus,
     metric_registry_factory: Box<dyn MetricRegistryFactoryTrait>,
+    subscription_controller_list: SubscriptionControllerList,
 }
 
 impl PodBrokerInterceptorCollectionFactory {
     pub fn new(
         event_bus: EventBus,
         metric_registry_factory: Box<dyn MetricRegistryFactoryTrait>,
+        subscription_controller_list: SubscriptionControllerList,
     ) -> Self {
         PodBrokerInterceptorCollectionFactory {
             event_bus,
             metric_registry_factory,
+            subscription_controller_list,
         }
     }
 }
@@ -38,8 +42,10 @@ impl BrokerInterceptorFactoryTrait for PodBrokerInterceptorCollectionFactory {
     }
 
     fn create_subscription_interceptor(&self) -> Option<Box<dyn SubscriptionInterceptorTrait>> {
-        let subscription_interceptor =
-            PodSubscriptionInterceptor::new(self.metric_registry_factory.create());
+        let subscription_interceptor = PodSubscriptionInterceptor::new(
+            self.metric_registry_factory.create(),
+            self.subscription_controller_list.clone(),
+        );
         Option::Some(Box::new(subscription_interceptor))
     }
 
@@ -1,20 +1,63 @@
 use anyhow::Error;
 use async_trait::async_trait;
-use std::{collections::HashMap, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use crate::{
-    broker::common::subscription::{SubscriptionData, SubscriptionInterceptorTrait},
+    broker::common::subscription::{
+        SubscriptionController, SubscriptionData, SubscriptionInterceptorTrait,
+    },
     metrics::common::registry::MetricRegistryTrait,
     model::trading::quote::{QueryInfoRequest, QuoteDepthInfo, QuoteRealTimeInfo},
 };
 
+// Controllers of every subscription opened through the pod, stopped when the pod shuts down
+pub type SubscriptionControllerList = Arc<Mutex<Vec<Arc<dyn SubscriptionController>>>>;
+
+struct PodSubscriptionController {
+    controller: Arc<dyn SubscriptionController>,
+}
+
+#[async_trait]
+impl SubscriptionController for PodSubscriptionController {
+    async fn stop(&self) -> Result<(), Error> {
+        self.controller.stop().await
+    }
+}
+
 pub struct PodSubscriptionInterceptor {
     metric_registry: Box<dyn MetricRegistryTrait>,
+    subscription_controller_list: SubscriptionControllerList,
 }
 
 impl PodSubscriptionInterceptor {
-    pub fn new(metric_registry: Box<dyn MetricRegistryTrait>) -> Self {
-        PodSubscriptionInterceptor { metric_registry }
+    pub fn new(
+        metric_registry: Box<dyn MetricRegistryTrait>,
+        subscription_controller_list: SubscriptionControllerList,
+    ) -> Self {
+        PodSubscriptionInterceptor {
+            metric_registry,
+            subscription_controller_list,
+        }
+    }
+
+    fn track_controller<T>(
+        &self,
+        result: Result<SubscriptionData<T>, Error>,
+    ) -> Result<SubscriptionData<T>, Error> {
+        result.map(|(receiver, controller)| {
+            let controller: Arc<dyn SubscriptionController> = Arc::from(controller);
+            self.subscription_controller_list
+                .lock()
+                .unwrap()
+                .push(controller.clone());
+            let controller: Box<dyn SubscriptionController> =
+                Box::new(PodSubscriptionController { controller });
+            (receiver, controller)
+        })
     }
 }
 
@@ -38,7 +81,7 @@ impl SubscriptionInterceptorTrait for PodSubscriptionInterceptor {
             )
             .await;
 
-        result
+        self.track_controller(result)
     }
 
     async fn after_depth_info(
@@ -59,6 +102,6 @@ impl SubscriptionInterceptorTrait for PodSubscriptionInterceptor {
             )
             .await;
 
-        result
+        self.track_controller(result)
     }
 }
@@ -11,7 +11,9 @@ use crate::{
         position::PositionList,
         transaction::{
             BuyingPower, CancelOrderRequest, CancelOrderResponse, EditOrderRequest,
-            EditOrderResponse, EstimateMaxBuyingPowerRequest, OrderDetail, OrderDetailRequest,
+            EditOrderResponse, EstimateMaxBuyingPowerRequest, Execution, ListExecutionsRequest,
+            ListOrderHistoryRequest, OrderDetail, OrderDetailRequest, SubmitBracketOrderRequest,
+            SubmitBracketOrderResponse, SubmitOcoOrderRequest, SubmitOcoOrderResponse,
             SubmitOrderRequest, SubmitOrderResponse,
         },
     },
@@ -118,6 +120,69 @@ impl TransactionInterceptorTrait for PodTransactionInterceptor {
         result
     }
 
+    async fn after_list_open_orders(
+        &self,
+        _request: (),
+        result: Result<Vec<OrderDetail>, Error>,
+        duration: Duration,
+    ) -> Result<Vec<OrderDetail>, Error> {
+        self.metric_registry
+            .timer(
+                "system.pod.counter".to_owned(),
+                HashMap::from([
+                    ("component".to_owned(), "transaction".to_owned()),
+                    ("method".to_owned(), "list_open_orders".to_owned()),
+                    ("is_success".to_owned(), result.is_ok().to_string()),
+                ]),
+                duration,
+            )
+            .await;
+
+        result
+    }
+
+    async fn after_list_order_history(
+        &self,
+        _request: ListOrderHistoryRequest,
+        result: Result<Vec<OrderDetail>, Error>,
+        duration: Duration,
+    ) -> Result<Vec<OrderDetail>, Error> {
+        self.metric_registry
+            .timer(
+                "system.pod.counter".to_owned(),
+                HashMap::from([
+                    ("component".to_owned(), "transaction".to_owned()),
+                    ("method".to_owned(), "list_order_history".to_owned()),
+                    ("is_success".to_owned(), result.is_ok().to_string()),
+                ]),
+                duration,
+            )
+            .await;
+
+        result
+    }
+
+    async fn after_list_executions(
+        &self,
+        _request: ListExecutionsRequest,
+        result: Result<Vec<Execution>, Error>,
+        duration: Duration,
+    ) -> Result<Vec<Execution>, Error> {
+        self.metric_registry
+            .timer(
+                "system.pod.counter".to_owned(),
+                HashMap::from([
+                    ("component".to_owned(), "transaction".to_owned()),
+                    ("method".to_owned(), "list_executions".to_owned()),
+                    ("is_success".to_owned(), result.is_ok().to_string()),
+                ]),
+                duration,
+            )
+            .await;
+
+        result
+    }
+
     async fn after_submit_order(
         &self,
         request: SubmitOrderRequest,
@@ -151,6 +216,72 @@ impl TransactionInterceptorTrait for PodTransactionInterceptor {
         result
     }
 
+    async fn after_submit_bracket_order(
+        &self,
+        request: SubmitBracketOrderRequest,
+        result: Result<SubmitBracketOrderResponse, Error>,
+        duration: Duration,
+    ) -> Result<SubmitBracketOrderResponse, Error> {
+        self.metric_registry
+            .timer(
+                "system.pod.counter".to_owned(),
+                HashMap::from([
+                    ("component".to_owned(), "transaction".to_owned()),
+                    ("method".to_owned(), "submit_bracket_order".to_owned()),
+                    ("is_success".to_owned(), result.is_ok().to_string()),
+                ]),
+                duration,
+            )
+            .await;
+
+        if let Some(err) = self
+            .event_bus
+            .send(RabbitTradingEvent::SubmitBracketOrder {
+                context: self.event_bus.create_event_context(),
+                request,
+                result: from_anyhow_result(&result),
+            })
+            .await
+            .err()
+        {
+            log::error!("Error when sending message into event_bus, {}", err);
+        }
+        result
+    }
+
+    async fn after_submit_oco_order(
+        &self,
+        request: SubmitOcoOrderRequest,
+        result: Result<SubmitOcoOrderResponse, Error>,
+        duration: Duration,
+    ) -> Result<SubmitOcoOrderResponse, Error> {
+        self.metric_registry
+            .timer(
+                "system.pod.counter".to_owned(),
+                HashMap::from([
+                    ("component".to_owned(), "transaction".to_owned()),
+                    ("method".to_owned(), "submit_oco_order".to_owned()),
+                    ("is_success".to_owned(), result.is_ok().to_string()),
+                ]),
+                duration,
+            )
+            .await;
+
+        if let Some(err) = self
+            .event_bus
+            .send(RabbitTradingEvent::SubmitOcoOrder {
+                context: self.event_bus.create_event_context(),
+                request,
+                result: from_anyhow_result(&result),
+            })
+            .await
+            .err()
+        {
+            log::error!("Error when sending message into event_bus, {}", err);
+        }
+        result
+    }
+
     async fn after_edit_order(
         &self,
         request: EditOrderRequest,
@@ -1,4 +1,10 @@
+pub mod component;
 pub mod event;
 pub mod interceptor;
 mod pod;
+pub mod recorder;
+pub mod scheduler;
+pub mod shutdown;
+pub mod state;
+pub mod validator;
 pub use pod::*;
@@ -1,21 +1,35 @@
 use anyhow::{anyhow, Error};
 use std::{
-    collections::LinkedList,
+    collections::{HashMap, LinkedList},
     sync::{
         atomic::{AtomicBool, Ordering},
-        Arc,
+        Arc, Mutex,
     },
+    time::Duration,
+};
+use tokio::{
+    sync::{watch, Mutex as AsyncMutex, RwLockReadGuard},
+    task::{AbortHandle, JoinHandle},
 };
-use tokio::sync::RwLockReadGuard;
 
-use super::event::{event_bus::EventBus, listener::initializer::get_event_listener};
+use super::{
+    event::{event_bus::EventBus, listener::initializer::get_event_listener},
+    interceptor::subscription::SubscriptionControllerList,
+    recorder::market_data_recorder::MarketDataRecorder,
+    shutdown::{cancel_open_orders, flatten_positions, ShutdownReport},
+    state::{PodState, PodStatus},
+};
 use crate::{
     broker::{
-        common::{broker::BrokerTrait, heartbeat::HeartbeatTrait},
+        common::{broker::BrokerTrait, heartbeat::HeartbeatTrait, transaction::TransactionTrait},
         initializer::get_broker_instance,
     },
     metrics::initializer::get_metrics_registry_factory,
-    model::{config::pod::PodConfig, trading::event::RabbitTradingEvent},
+    model::{
+        common::types::ConfigMap,
+        config::{broker::BrokerConfig, pod::PodConfig, shutdown_policy::OpenPositionAction},
+        trading::event::{EventContext, RabbitTradingEvent},
+    },
     persistent_kv::{
         common::store::PersistentKVStoreTrait, initializer::get_persistent_kv_instance,
     },
@@ -24,54 +38,240 @@ use crate::{
         common::strategy::{StrategyContext, StrategyTrait},
         initializer::get_strategy_instance,
     },
+    utils::clock::{
+        common_trait::ClockTrait, initializer::get_clock_instance, system::SystemClock,
+    },
 };
 
+// Shared by the requests made from outside the strategy, one at a time
+pub type SharedTransaction = Arc<AsyncMutex<Box<dyn TransactionTrait>>>;
+
 pub struct Pod {
     pod_config: PodConfig,
     event_bus: EventBus,
-    stopped_indicator: Arc<AtomicBool>,
+    // replaced on every start so that a scheduled pod can be started again after a stop
+    stopped_indicator: Arc<Mutex<Arc<AtomicBool>>>,
+    clock: Arc<dyn ClockTrait>,
+    status: Arc<Mutex<PodStatus>>,
+    // watchers of the strategy and heartbeat tasks of the latest start
+    task_handle_list: Mutex<Vec<JoinHandle<()>>>,
+    // event listeners outlive restarts, they are only started once
+    is_event_listener_started: AtomicBool,
+    subscription_controller_list: SubscriptionControllerList,
+    running_context: Mutex<Option<RunningContext>>,
+}
+
+// What a started pod needs to be shut down, kept until the next stop or start
+struct RunningContext {
+    strategy: Arc<dyn StrategyTrait>,
+    strategy_abort_handle: AbortHandle,
+    // turns true once the result of the strategy task is recorded into the status
+    strategy_finished_receiver: watch::Receiver<bool>,
+    heartbeat_list: Vec<Arc<dyn HeartbeatTrait>>,
+    // transactions lent by `get_transaction`, keyed by broker identifier
+    transaction_map: HashMap<String, SharedTransaction>,
 }
 
 pub struct InitializerContext {
     pub heartbeat_list: Vec<Box<dyn HeartbeatTrait>>,
+    pub recorder_list: Vec<MarketDataRecorder>,
     pub strategy: Box<dyn StrategyTrait>,
 }
 
 impl Pod {
-    pub fn new(pod_config: PodConfig, pod_id: String) -> Self {
+    pub fn new(pod_config: PodConfig, pod_id: String) -> Result<Self, Error> {
+        let clock: Arc<dyn ClockTrait> = match &pod_config.clock {
+            Option::Some(clock_config) => Arc::from(get_clock_instance(
+                clock_config.identifier.clone(),
+                clock_config.config_map.clone(),
+            )?),
+            Option::None => Arc::new(SystemClock::new(ConfigMap::new())),
+        };
+        Result::Ok(Self::new_with_clock(pod_config, pod_id, clock))
+    }
+
+    // `pod_config.clock` is ignored, the pod runs on the given clock
+    pub fn new_with_clock(
+        pod_config: PodConfig,
+        pod_id: String,
+        clock: Arc<dyn ClockTrait>,
+    ) -> Self {
         const EMPTY_BROKER_ID: &'static str = "";
 
         Pod {
             pod_config,
-            event_bus: EventBus::new(EMPTY_BROKER_ID.to_owned(), pod_id),
-            stopped_indicator: Arc::new(AtomicBool::new(false)),
+            event_bus: EventBus::new(EMPTY_BROKER_ID.to_owned(), pod_id, clock.clone()),
+            stopped_indicator: Arc::new(Mutex::new(Arc::new(AtomicBool::new(false)))),
+            clock,
+            status: Arc::new(Mutex::new(PodStatus::new())),
+            task_handle_list: Mutex::new(Vec::new()),
+            is_event_listener_started: AtomicBool::new(false),
+            subscription_controller_list: Arc::new(Mutex::new(Vec::new())),
+            running_context: Mutex::new(Option::None),
         }
     }
 
+    pub fn get_pod_config(&self) -> &PodConfig {
+        &self.pod_config
+    }
+
+    pub fn get_clock(&self) -> Arc<dyn ClockTrait> {
+        self.clock.clone()
+    }
+
+    pub fn get_status(&self) -> PodStatus {
+        self.status.lock().unwrap().clone()
+    }
+
+    fn get_stopped_indicator(&self) -> Arc<AtomicBool> {
+        self.stopped_indicator.lock().unwrap().clone()
+    }
+
+    // Records the result of a spawned task into the pod status, a failing task stops the whole pod
+    // and the end of the strategy task, which carries `finished_sender`, ends the pod
+    fn watch_task(
+        &self,
+        task_name: String,
+        task_handle: JoinHandle<Result<(), Error>>,
+        finished_sender: Option<watch::Sender<bool>>,
+    ) -> JoinHandle<()> {
+        let status = self.status.clone();
+        let event_bus = self.event_bus.shallow_clone(Option::None);
+        let current_stopped_indicator = self.stopped_indicator.clone();
+        let stopped_indicator = self.get_stopped_indicator();
+
+        tokio::task::spawn(async move {
+            let failure_reason = match task_handle.await {
+                Result::Ok(Result::Ok(_)) => Option::None,
+                Result::Ok(Result::Err(err)) => {
+                    Option::Some(format!("{} returned an error, {:#}", task_name, err))
+                }
+                Result::Err(err) => {
+                    Option::Some(format!("{} did not complete, {}", task_name, err))
+                }
+            };
+            // a task left over from a previous start must not override the status of the new one
+            let is_current = Arc::ptr_eq(
+                &current_stopped_indicator.lock().unwrap(),
+                &stopped_indicator,
+            );
+            if (failure_reason.is_some() || finished_sender.is_some()) && is_current {
+                stopped_indicator.store(true, Ordering::Relaxed);
+                let _ = update_status(&status, &event_bus, |status| {
+                    if let PodState::Failed { .. } = status.state {
+                        return Result::Ok(());
+                    }
+                    match failure_reason {
+                        Option::Some(reason) => {
+                            log::error!("Pod failed, {}", reason);
+                            status.fail(reason);
+                        }
+                        Option::None => status.state = PodState::Stopped,
+                    }
+                    Result::Ok(())
+                })
+                .await;
+            }
+            if let Option::Some(finished_sender) = finished_sender {
+                let _ = finished_sender.send(true);
+            }
+        })
+    }
+
+    pub async fn send_event(&self, event: RabbitTradingEvent) {
+        if let Some(err) = self.event_bus.send(event).await.err() {
+            log::error!("Error when sending message into event_bus, {}", err);
+        }
+    }
+
+    pub fn create_event_context(&self) -> EventContext {
+        self.event_bus.create_event_context()
+    }
+
+    fn initialize_broker(
+        &self,
+        broker_config: &BrokerConfig,
+    ) -> Result<Box<dyn BrokerTrait>, Error> {
+        let metrics_registry_factory = get_metrics_registry_factory(
+            self.pod_config.metrics_registry.identifier.clone(),
+            self.pod_config.metrics_registry.config_map.clone(),
+        )?;
+
+        get_broker_instance(
+            broker_config.identifier.clone(),
+            Box::new(PodBrokerInterceptorCollectionFactory::new(
+                self.event_bus
+                    .shallow_clone(Option::Some(broker_config.identifier.clone())),
+                metrics_registry_factory,
+                self.subscription_controller_list.clone(),
+            )),
+            broker_config.config_map.clone(),
+            self.get_stopped_indicator(),
+            self.clock.clone(),
+        )
+    }
+
+    // A broker of the pod wired with the same interceptors as the ones of the strategy, so that
+    // requests made from outside the strategy are recorded as events and metrics of the pod,
+    // the first broker of the pod is picked when no identifier is given
+    pub fn create_broker(
+        &self,
+        broker_identifier: Option<&str>,
+    ) -> Result<Box<dyn BrokerTrait>, Error> {
+        self.initialize_broker(self.get_broker_config(broker_identifier)?)
+    }
+
+    // The transaction of a broker for requests made from outside the strategy, created with the
+    // same interceptors as `create_broker` and kept until the pod stops, so that the state of the
+    // transaction, e.g. the orders held by an order emulator, outlives a single request
+    pub fn get_transaction(
+        &self,
+        broker_identifier: Option<&str>,
+    ) -> Result<SharedTransaction, Error> {
+        let broker_config = self.get_broker_config(broker_identifier)?;
+        let mut running_context = self.running_context.lock().unwrap();
+        let running_context = running_context
+            .as_mut()
+            .ok_or(anyhow!("ILLEGAL_POD_STATE, pod is not started"))?;
+        if let Option::Some(transaction) = running_context
+            .transaction_map
+            .get(&broker_config.identifier)
+        {
+            return Result::Ok(transaction.clone());
+        }
+
+        let transaction: SharedTransaction = Arc::new(AsyncMutex::new(
+            self.initialize_broker(broker_config)?
+                .create_transaction()?,
+        ));
+        running_context
+            .transaction_map
+            .insert(broker_config.identifier.clone(), transaction.clone());
+        Result::Ok(transaction)
+    }
+
+    // the first broker of the pod when no identifier is given
+    fn get_broker_config(&self, broker_identifier: Option<&str>) -> Result<&BrokerConfig, Error> {
+        match broker_identifier {
+            Option::Some(broker_identifier) => self
+                .pod_config
+                .broker_list
+                .iter()
+                .find(|broker_config| broker_config.identifier == broker_identifier),
+            Option::None => self.pod_config.broker_list.first(),
+        }
+        .ok_or(anyhow!(
+            "ILLEGAL_BROKER_ID, broker: {}",
+            broker_identifier.unwrap_or_default()
+        ))
+    }
+
     fn initialize_broker_list(&self) -> Result<Vec<Box<dyn BrokerTrait>>, Error> {
         let broker_list: Vec<Box<dyn BrokerTrait>> = self
             .pod_config
             .broker_list
             .iter()
-            .filter_map(|broker_config| {
-                let metrics_registry_factory = get_metrics_registry_factory(
-                    self.pod_config.metrics_registry.identifier.clone(),
-                    self.pod_config.metrics_registry.config_map.clone(),
-                )
-                .ok()?;
-
-                get_broker_instance(
-                    broker_config.identifier.clone(),
-                    Box::new(PodBrokerInterceptorCollectionFactory::new(
-                        self.event_bus
-                            .shallow_clone(Option::Some(broker_config.identifier.clone())),
-                        metrics_registry_factory,
-                    )),
-                    broker_config.config_map.clone(),
-                    self.stopped_indicator.clone(),
-                )
-                .ok()
-            })
+            .filter_map(|broker_config| self.initialize_broker(broker_config).ok())
             .collect();
 
         if broker_list.len() != self.pod_config.broker_list.len() {
@@ -91,6 +291,38 @@ impl Pod {
         Result::Ok(broker_list)
     }
 
+    // Recorders subscribe through the brokers handed to the strategy rather than brokers of their
+    // own, `broker_list` is in the order of the pod config
+    fn initialize_recorder_list(
+        &self,
+        broker_list: &[Box<dyn BrokerTrait>],
+    ) -> Result<Vec<MarketDataRecorder>, Error> {
+        self.pod_config
+            .recorder_list
+            .iter()
+            .map(|recorder_config| {
+                let broker = self
+                    .pod_config
+                    .broker_list
+                    .iter()
+                    .position(|broker_config| {
+                        broker_config.identifier == recorder_config.broker_identifier
+                    })
+                    .and_then(|index| broker_list.get(index))
+                    .ok_or(anyhow!(
+                        "ILLEGAL_BROKER_ID, recorder: {}",
+                        recorder_config.broker_identifier
+                    ))?;
+
+                MarketDataRecorder::new(
+                    broker.create_subscription(),
+                    recorder_config.config_map.clone(),
+                    self.get_stopped_indicator(),
+                )
+            })
+            .collect()
+    }
+
     async fn initialize_persistent_kv_store(
         &self,
     ) -> Result<Box<dyn PersistentKVStoreTrait>, Error> {
@@ -112,7 +344,8 @@ impl Pod {
                 broker_list,
                 persistent_kv_store,
                 config_map: self.pod_config.strategy.config_map.clone(),
-                stopped_indicator: self.stopped_indicator.clone(),
+                stopped_indicator: self.get_stopped_indicator(),
+                clock: self.clock.clone(),
             },
         )
     }
@@ -142,12 +375,18 @@ impl Pod {
             .map_while(|broker| broker.create_heartbeat())
             .collect();
 
+        let recorder_list = self.initialize_recorder_list(&broker_list)?;
         let persistent_kv_store = self.initialize_persistent_kv_store().await?;
-        self.initialize_event_listeners()?;
+        if !self.is_event_listener_started.load(Ordering::Relaxed) {
+            self.initialize_event_listeners()?;
+            self.is_event_listener_started
+                .store(true, Ordering::Relaxed);
+        }
         let strategy = self.initialize_strategy(broker_list, persistent_kv_store)?;
 
         Result::Ok(InitializerContext {
             heartbeat_list,
+            recorder_list,
             strategy,
         })
     }
@@ -156,21 +395,296 @@ impl Pod {
         self.event_bus.inspect_log().await
     }
 
-    pub async fn start(&self) -> Result<(), Error> {
+    async fn launch(&self) -> Result<(), Error> {
         let InitializerContext {
             heartbeat_list,
+            recorder_list,
             strategy,
         } = self.initialize().await?;
 
-        tokio::task::spawn(async move { strategy.start().await });
-        heartbeat_list.into_iter().for_each(|heartbeat| {
-            tokio::task::spawn(async move { heartbeat.start().await });
+        for recorder in &recorder_list {
+            recorder.start().await?;
+        }
+
+        let mut task_handle_list = Vec::new();
+        let strategy: Arc<dyn StrategyTrait> = Arc::from(strategy);
+        let strategy_task_handle = {
+            let strategy = strategy.clone();
+            tokio::task::spawn(async move { strategy.start().await })
+        };
+        let strategy_abort_handle = strategy_task_handle.abort_handle();
+        let (strategy_finished_sender, strategy_finished_receiver) = watch::channel(false);
+        task_handle_list.push(self.watch_task(
+            "strategy".to_owned(),
+            strategy_task_handle,
+            Option::Some(strategy_finished_sender),
+        ));
+
+        let heartbeat_list: Vec<Arc<dyn HeartbeatTrait>> =
+            heartbeat_list.into_iter().map(Arc::from).collect();
+        heartbeat_list
+            .iter()
+            .enumerate()
+            .for_each(|(index, heartbeat)| {
+                let heartbeat = heartbeat.clone();
+                task_handle_list.push(self.watch_task(
+                    format!("heartbeat {}", index),
+                    tokio::task::spawn(async move { heartbeat.start().await }),
+                    Option::None,
+                ));
+            });
+        *self.task_handle_list.lock().unwrap() = task_handle_list;
+        *self.running_context.lock().unwrap() = Option::Some(RunningContext {
+            strategy,
+            strategy_abort_handle,
+            strategy_finished_receiver,
+            heartbeat_list,
+            transaction_map: HashMap::new(),
         });
         Result::Ok(())
     }
 
-    pub async fn stop(&self) -> Result<(), Error> {
-        self.stopped_indicator.store(true, Ordering::Relaxed);
-        Result::Ok(())
+    pub async fn start(&self) -> Result<(), Error> {
+        update_status(&self.status, &self.event_bus, |status| {
+            if status.state.is_active() {
+                return Result::Err(anyhow!("ILLEGAL_POD_STATE, state: {:?}", status.state));
+            }
+            status.state = PodState::Initializing;
+            Result::Ok(())
+        })
+        .await?;
+        // subscriptions and heartbeats of a failed run are still around when it is restarted
+        let previous_running_context = self.running_context.lock().unwrap().take();
+        self.release(previous_running_context, &mut ShutdownReport::default())
+            .await;
+        *self.stopped_indicator.lock().unwrap() = Arc::new(AtomicBool::new(false));
+
+        let result = self.launch().await;
+        let _ = update_status(&self.status, &self.event_bus, |status| {
+            match &result {
+                Result::Ok(_) => {
+                    // the pod might have been stopped or have failed in the meantime
+                    if status.state == PodState::Initializing {
+                        status.state = PodState::Running;
+                    }
+                }
+                Result::Err(err) => {
+                    self.get_stopped_indicator().store(true, Ordering::Relaxed);
+                    status.fail(format!("initialization failed, {:#}", err));
+                }
+            }
+            Result::Ok(())
+        })
+        .await;
+        result
+    }
+
+    // Asks the strategy to stop and waits for its task, which is aborted after the timeout
+    async fn stop_strategy(&self, running_context: &mut RunningContext) -> bool {
+        let strategy = running_context.strategy.clone();
+        let strategy_finished_receiver = &mut running_context.strategy_finished_receiver;
+        let timeout = Duration::from_secs(
+            self.pod_config
+                .shutdown_policy
+                .strategy_stop_timeout_seconds,
+        );
+
+        let is_stopped = tokio::time::timeout(timeout, async move {
+            // a panicking `stop` must not take the shutdown down with it
+            match tokio::task::spawn(async move { strategy.stop().await }).await {
+                Result::Ok(Result::Ok(_)) => {}
+                Result::Ok(Result::Err(err)) => {
+                    log::error!("Error when stopping the strategy, {:#}", err)
+                }
+                Result::Err(err) => log::error!("Error when stopping the strategy, {}", err),
+            }
+            let _ = strategy_finished_receiver
+                .wait_for(|is_finished| *is_finished)
+                .await;
+        })
+        .await
+        .is_ok();
+
+        if !is_stopped {
+            log::error!(
+                "Strategy did not stop within {} seconds, aborting it",
+                timeout.as_secs()
+            );
+            running_context.strategy_abort_handle.abort();
+            let _ = running_context
+                .strategy_finished_receiver
+                .wait_for(|is_finished| *is_finished)
+                .await;
+        }
+        is_stopped
+    }
+
+    // Stops the subscriptions and heartbeats opened by the pod
+    async fn release(&self, running_context: Option<RunningContext>, report: &mut ShutdownReport) {
+        let subscription_controller_list: Vec<_> = self
+            .subscription_controller_list
+            .lock()
+            .unwrap()
+            .drain(..)
+            .collect();
+        for subscription_controller in subscription_controller_list {
+            match subscription_controller.stop().await {
+                Result::Ok(_) => report.stopped_subscription_count += 1,
+                Result::Err(err) => report
+                    .error_list
+                    .push(format!("Error when stopping a subscription, {:#}", err)),
+            }
+        }
+
+        if let Option::Some(running_context) = running_context {
+            for heartbeat in running_context.heartbeat_list {
+                match heartbeat.stop().await {
+                    Result::Ok(_) => report.stopped_heartbeat_count += 1,
+                    Result::Err(err) => report
+                        .error_list
+                        .push(format!("Error when stopping a heartbeat, {:#}", err)),
+                }
+            }
+        }
+    }
+
+    // The transactions lent by `get_transaction` are reused, so that the orders they hold, e.g.
+    // the ones of an order emulator, are cancelled as well
+    async fn apply_open_position_action(
+        &self,
+        mut transaction_map: HashMap<String, SharedTransaction>,
+        report: &mut ShutdownReport,
+    ) {
+        let shutdown_policy = &self.pod_config.shutdown_policy;
+        let open_position_action = shutdown_policy.open_position_action;
+        if open_position_action == OpenPositionAction::Keep {
+            return;
+        }
+
+        for broker_config in &self.pod_config.broker_list {
+            let transaction = match transaction_map.remove(&broker_config.identifier) {
+                Option::Some(transaction) => Result::Ok(transaction),
+                Option::None => self
+                    .initialize_broker(broker_config)
+                    .and_then(|broker| broker.create_transaction())
+                    .map(|transaction| Arc::new(AsyncMutex::new(transaction))),
+            };
+            let transaction = match transaction {
+                Result::Ok(transaction) => transaction,
+                Result::Err(err) => {
+                    report.error_list.push(format!(
+                        "Error when creating the transaction of broker {}, {:#}",
+                        broker_config.identifier, err
+                    ));
+                    continue;
+                }
+            };
+            let mut transaction = transaction.lock().await;
+            let is_all_cancelled = cancel_open_orders(
+                &mut transaction,
+                Duration::from_secs(shutdown_policy.order_cancel_timeout_seconds),
+                report,
+            )
+            .await;
+            if open_position_action != OpenPositionAction::FlattenPositions {
+                continue;
+            }
+            // positions are only read once the cancels are confirmed, an order filled before its
+            // cancel changes them
+            match is_all_cancelled {
+                true => flatten_positions(&mut transaction, report).await,
+                false => report.error_list.push(format!(
+                    "Positions of broker {} are not flattened, some orders might still be open",
+                    broker_config.identifier
+                )),
+            }
+        }
+    }
+
+    // Signals the strategy to stop and waits for it, then stops subscriptions and heartbeats and
+    // handles open orders and positions according to the shutdown policy
+    pub async fn stop(&self) -> Result<ShutdownReport, Error> {
+        let _ = update_status(&self.status, &self.event_bus, |status| {
+            match status.state {
+                PodState::Initializing | PodState::Running => status.state = PodState::Stopping,
+                PodState::Created => status.state = PodState::Stopped,
+                _ => {}
+            }
+            Result::Ok(())
+        })
+        .await;
+        self.get_stopped_indicator().store(true, Ordering::Relaxed);
+
+        let mut report = ShutdownReport::default();
+        let running_context = self.running_context.lock().unwrap().take();
+        let Option::Some(mut running_context) = running_context else {
+            // never started or already shut down
+            report.is_strategy_stopped = true;
+            return Result::Ok(report);
+        };
+        report.is_strategy_stopped = self.stop_strategy(&mut running_context).await;
+        let transaction_map = std::mem::take(&mut running_context.transaction_map);
+        self.release(Option::Some(running_context), &mut report)
+            .await;
+        self.apply_open_position_action(transaction_map, &mut report)
+            .await;
+
+        let _ = update_status(&self.status, &self.event_bus, |status| {
+            if status.state == PodState::Stopping {
+                status.state = PodState::Stopped;
+            }
+            Result::Ok(())
+        })
+        .await;
+        log::info!("Pod shut down, {:?}", report);
+        self.send_event(RabbitTradingEvent::PodShutdown {
+            context: self.create_event_context(),
+            report: report.clone(),
+        })
+        .await;
+        Result::Ok(report)
+    }
+
+    // Waits until the tasks of the latest start have completed and their results are recorded
+    pub async fn join(&self) {
+        let task_handle_list: Vec<JoinHandle<()>> =
+            self.task_handle_list.lock().unwrap().drain(..).collect();
+        for task_handle in task_handle_list {
+            if let Result::Err(err) = task_handle.await {
+                log::error!("Error when joining the pod task, {}", err);
+            }
+        }
+    }
+}
+
+// Applies `transition` to the status and publishes the new state on the event bus when it changed
+async fn update_status<F>(
+    status: &Mutex<PodStatus>,
+    event_bus: &EventBus,
+    transition: F,
+) -> Result<(), Error>
+where
+    F: FnOnce(&mut PodStatus) -> Result<(), Error>,
+{
+    let state = {
+        let mut status = status.lock().unwrap();
+        let previous_state = status.state.clone();
+        transition(&mut status)?;
+        if status.state == previous_state {
+            return Result::Ok(());
+        }
+        status.state.clone()
+    };
+
+    if let Some(err) = event_bus
+        .send(RabbitTradingEvent::PodStateChanged {
+            context: event_bus.create_event_context(),
+            state,
+        })
+        .await
+        .err()
+    {
+        log::error!("Error when sending message into event_bus, {}", err);
     }
+    Result::Ok(())
 }
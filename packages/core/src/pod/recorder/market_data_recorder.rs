@@ -0,0 +1,206 @@
+use anyhow::{anyhow, Error};
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::{sync::mpsc::Receiver, time::timeout};
+
+use super::{
+    record::RecordTrait,
+    writer::{RecordCompression, RecordFileWriter, RecordFormat},
+};
+use crate::{
+    broker::common::subscription::{SubscriptionController, SubscriptionTrait},
+    model::{
+        common::{
+            config_key::{ConfigKeySpec, ConfigValueType},
+            types::ConfigMap,
+        },
+        trading::{
+            quote::{QueryInfoRequest, QuoteKind},
+            symbol::Symbol,
+        },
+    },
+};
+
+pub struct MarketDataRecorder {
+    subscription: Box<dyn SubscriptionTrait>,
+    symbol_list: Vec<Symbol>,
+    base_path: PathBuf,
+    format: RecordFormat,
+    compression: RecordCompression,
+    record_real_time_info: bool,
+    record_depth_info: bool,
+    stopped_indicator: Arc<AtomicBool>,
+}
+
+impl MarketDataRecorder {
+    pub const CONFIG_KEY_SYMBOLS: &'static str = "recorder.symbols";
+    pub const CONFIG_KEY_BASE_PATH: &'static str = "recorder.base_path";
+    pub const CONFIG_KEY_FORMAT: &'static str = "recorder.format";
+    pub const CONFIG_KEY_COMPRESSION: &'static str = "recorder.compression";
+    pub const CONFIG_KEY_REAL_TIME_INFO: &'static str = "recorder.real_time_info";
+    pub const CONFIG_KEY_DEPTH_INFO: &'static str = "recorder.depth_info";
+    pub const CONFIG_VALUE_DEFAULT_BASE_PATH: &'static str = "./market_data";
+    const IDLE_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+    pub fn get_config_key_list() -> Vec<ConfigKeySpec> {
+        vec![
+            ConfigKeySpec::required(
+                Self::CONFIG_KEY_SYMBOLS,
+                ConfigValueType::SymbolList,
+                "Comma separated symbols to record, like `AAPL.US,0700.HK`",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_BASE_PATH,
+                ConfigValueType::String,
+                Option::Some(Self::CONFIG_VALUE_DEFAULT_BASE_PATH),
+                "Directory of the recorded files",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_FORMAT,
+                ConfigValueType::RecordFormat,
+                Option::Some("jsonl"),
+                "Format of the recorded files",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_COMPRESSION,
+                ConfigValueType::RecordCompression,
+                Option::Some("gzip"),
+                "Compression of the recorded files",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_REAL_TIME_INFO,
+                ConfigValueType::Bool,
+                Option::Some("true"),
+                "Whether quotes are recorded",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_DEPTH_INFO,
+                ConfigValueType::Bool,
+                Option::Some("true"),
+                "Whether order book depths are recorded",
+            ),
+        ]
+    }
+
+    pub fn new(
+        subscription: Box<dyn SubscriptionTrait>,
+        config_map: ConfigMap,
+        stopped_indicator: Arc<AtomicBool>,
+    ) -> Result<Self, Error> {
+        let symbol_list = config_map
+            .get(Self::CONFIG_KEY_SYMBOLS)
+            .ok_or(anyhow!(
+                "MISSING_CONFIG {} is required for recorder",
+                Self::CONFIG_KEY_SYMBOLS
+            ))?
+            .split(',')
+            .map(|symbol| symbol.trim())
+            .filter(|symbol| !symbol.is_empty())
+            .map(|symbol| symbol.parse())
+            .collect::<Result<Vec<Symbol>, Error>>()?;
+        let base_path = config_map
+            .get(Self::CONFIG_KEY_BASE_PATH)
+            .cloned()
+            .unwrap_or(Self::CONFIG_VALUE_DEFAULT_BASE_PATH.to_owned());
+        let format = match config_map.get(Self::CONFIG_KEY_FORMAT) {
+            Option::Some(format) => format.parse()?,
+            Option::None => RecordFormat::JsonLines,
+        };
+        let compression = match config_map.get(Self::CONFIG_KEY_COMPRESSION) {
+            Option::Some(compression) => compression.parse()?,
+            Option::None => RecordCompression::Gzip,
+        };
+        let record_real_time_info = config_map
+            .get(Self::CONFIG_KEY_REAL_TIME_INFO)
+            .map(|value| value == "true")
+            .unwrap_or(true);
+        let record_depth_info = config_map
+            .get(Self::CONFIG_KEY_DEPTH_INFO)
+            .map(|value| value == "true")
+            .unwrap_or(true);
+
+        Result::Ok(MarketDataRecorder {
+            subscription,
+            symbol_list,
+            base_path: PathBuf::from(base_path),
+            format,
+            compression,
+            record_real_time_info,
+            record_depth_info,
+            stopped_indicator,
+        })
+    }
+
+    async fn async_record_task<T: RecordTrait>(
+        mut receiver: Receiver<T>,
+        _controller: Box<dyn SubscriptionController>,
+        mut writer: RecordFileWriter<T>,
+        stopped_indicator: Arc<AtomicBool>,
+    ) {
+        loop {
+            if stopped_indicator.load(Ordering::Relaxed) {
+                break;
+            }
+
+            match timeout(Self::IDLE_FLUSH_INTERVAL, receiver.recv()).await {
+                Result::Ok(Option::Some(record)) => {
+                    if let Result::Err(err) = writer.write(&record) {
+                        log::error!("Error when recording {}, {}", T::get_kind(), err);
+                    }
+                }
+                Result::Ok(Option::None) => {
+                    log::warn!(
+                        "Subscription of {} closed, recorder will exit",
+                        T::get_kind()
+                    );
+                    break;
+                }
+                Result::Err(_) => {
+                    if let Result::Err(err) = writer.flush() {
+                        log::error!("Error when flushing {} records, {}", T::get_kind(), err);
+                    }
+                }
+            }
+        }
+
+        if let Result::Err(err) = writer.close() {
+            log::error!("Error when closing {} records, {}", T::get_kind(), err);
+        }
+    }
+
+    pub async fn start(&self) -> Result<(), Error> {
+        for symbol in &self.symbol_list {
+            let request = QueryInfoRequest {
+                symbol: symbol.clone(),
+                kind: QuoteKind::Stock,
+            };
+
+            if self.record_real_time_info {
+                let (receiver, controller) =
+                    self.subscription.real_time_info(request.clone()).await?;
+                tokio::task::spawn(Self::async_record_task(
+                    receiver,
+                    controller,
+                    RecordFileWriter::new(self.base_path.clone(), self.format, self.compression),
+                    self.stopped_indicator.clone(),
+                ));
+            }
+            if self.record_depth_info {
+                let (receiver, controller) = self.subscription.depth_info(request).await?;
+                tokio::task::spawn(Self::async_record_task(
+                    receiver,
+                    controller,
+                    RecordFileWriter::new(self.base_path.clone(), self.format, self.compression),
+                    self.stopped_indicator.clone(),
+                ));
+            }
+        }
+        Result::Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod market_data_recorder;
+pub mod reader;
+pub mod record;
+pub mod writer;
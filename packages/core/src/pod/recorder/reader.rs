@@ -0,0 +1,86 @@
+use anyhow::{Context, Error};
+use flate2::read::MultiGzDecoder;
+use std::{
+    fs::{self, File},
+    io::{BufRead, BufReader, Read},
+    marker::PhantomData,
+    path::PathBuf,
+};
+
+use super::{
+    record::RecordTrait,
+    writer::{
+        get_record_directory, get_record_file_path, get_record_file_suffix, RecordCompression,
+        RecordFormat,
+    },
+};
+use crate::model::trading::symbol::Symbol;
+
+// Reads back the files written by `RecordFileWriter`
+pub struct RecordFileReader<T: RecordTrait> {
+    base_path: PathBuf,
+    format: RecordFormat,
+    compression: RecordCompression,
+    _record: PhantomData<T>,
+}
+
+impl<T: RecordTrait> RecordFileReader<T> {
+    pub fn new(base_path: PathBuf, format: RecordFormat, compression: RecordCompression) -> Self {
+        RecordFileReader {
+            base_path,
+            format,
+            compression,
+            _record: PhantomData,
+        }
+    }
+
+    pub fn list_date(&self, symbol: &Symbol) -> Result<Vec<String>, Error> {
+        let directory = get_record_directory::<T>(&self.base_path, symbol);
+        if !directory.exists() {
+            return Result::Ok(vec![]);
+        }
+
+        let suffix = get_record_file_suffix(self.format, self.compression);
+        let mut date_list: Vec<String> = fs::read_dir(&directory)
+            .with_context(|| format!("Error when listing directory {}", directory.display()))?
+            .filter_map(|entry| {
+                let file_name = entry.ok()?.file_name().into_string().ok()?;
+                file_name.strip_suffix(suffix.as_str()).map(str::to_owned)
+            })
+            .collect();
+        date_list.sort();
+        Result::Ok(date_list)
+    }
+
+    pub fn read(&self, symbol: &Symbol, date: &str) -> Result<Vec<T>, Error> {
+        let file_path =
+            get_record_file_path::<T>(&self.base_path, self.format, self.compression, symbol, date);
+        let file = File::open(&file_path)
+            .with_context(|| format!("Error when opening file {}", file_path.display()))?;
+        let reader: Box<dyn Read> = match self.compression {
+            RecordCompression::None => Box::new(file),
+            RecordCompression::Gzip => Box::new(MultiGzDecoder::new(file)),
+        };
+
+        match self.format {
+            RecordFormat::JsonLines => BufReader::new(reader)
+                .lines()
+                .filter(|line| !line.as_ref().is_ok_and(|line| line.trim().is_empty()))
+                .map(|line| {
+                    let line = line?;
+                    serde_json::from_str(&line)
+                        .with_context(|| format!("PARSING_ERROR Error when parsing {}", line))
+                })
+                .collect(),
+            RecordFormat::Csv => {
+                let rows = csv::Reader::from_reader(reader)
+                    .records()
+                    .map(|record| {
+                        record.map(|record| record.iter().map(str::to_owned).collect::<Vec<_>>())
+                    })
+                    .collect::<Result<Vec<Vec<String>>, csv::Error>>()?;
+                T::from_csv_rows(rows)
+            }
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use anyhow::{anyhow, Context, Error};
+use rust_decimal::Decimal;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{collections::HashMap, str::FromStr};
+
+use crate::model::{
+    common::timestamp::Timestamp,
+    trading::{
+        quote::{Depth, QuoteDepthInfo, QuoteRealTimeInfo},
+        symbol::Symbol,
+    },
+};
+
+pub trait RecordTrait: Serialize + DeserializeOwned + Send + Sync + 'static {
+    fn get_kind() -> String
+    where
+        Self: Sized;
+    fn get_csv_header() -> Vec<String>
+    where
+        Self: Sized;
+
+    fn get_symbol(&self) -> &Symbol;
+    fn get_timestamp(&self) -> Timestamp;
+    fn to_csv_rows(&self) -> Vec<Vec<String>>;
+    fn from_csv_rows(rows: Vec<Vec<String>>) -> Result<Vec<Self>, Error>
+    where
+        Self: Sized;
+}
+
+const DEPTH_SIDE_ASK: &'static str = "ask";
+const DEPTH_SIDE_BID: &'static str = "bid";
+
+fn optional_decimal_to_string(value: &Option<Decimal>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+fn get_column(row: &[String], index: usize) -> Result<&str, Error> {
+    row.get(index).map(|column| column.as_str()).ok_or(anyhow!(
+        "PARSING_ERROR Missing column {} in {:?}",
+        index,
+        row
+    ))
+}
+
+fn parse_column<T>(row: &[String], index: usize) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    let column = get_column(row, index)?;
+    column
+        .parse()
+        .map_err(Into::into)
+        .with_context(|| format!("PARSING_ERROR Error when parsing column {}", column))
+}
+
+fn parse_optional_decimal_column(row: &[String], index: usize) -> Result<Option<Decimal>, Error> {
+    match get_column(row, index)? {
+        "" => Result::Ok(Option::None),
+        _ => parse_column(row, index).map(Option::Some),
+    }
+}
+
+impl RecordTrait for QuoteRealTimeInfo {
+    fn get_kind() -> String {
+        const KIND: &'static str = "real_time";
+        KIND.to_owned()
+    }
+
+    fn get_csv_header() -> Vec<String> {
+        [
+            "symbol",
+            "sequence",
+            "timestamp",
+            "current_price",
+            "volume",
+            "low_price",
+            "high_price",
+            "open_price",
+            "prev_close",
+            "turnover",
+            "extra",
+        ]
+        .map(|column| column.to_owned())
+        .to_vec()
+    }
+
+    fn get_symbol(&self) -> &Symbol {
+        &self.symbol
+    }
+
+    fn get_timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    fn to_csv_rows(&self) -> Vec<Vec<String>> {
+        let extra = self
+            .extra
+            .as_ref()
+            .and_then(|extra| serde_json::to_string(extra).ok())
+            .unwrap_or_default();
+
+        vec![vec![
+            self.symbol.to_string(),
+            self.sequence.to_string(),
+            self.timestamp.to_string(),
+            self.current_price.to_string(),
+            optional_decimal_to_string(&self.volume),
+            optional_decimal_to_string(&self.low_price),
+            optional_decimal_to_string(&self.high_price),
+            optional_decimal_to_string(&self.open_price),
+            optional_decimal_to_string(&self.prev_close),
+            optional_decimal_to_string(&self.turnover),
+            extra,
+        ]]
+    }
+
+    fn from_csv_rows(rows: Vec<Vec<String>>) -> Result<Vec<Self>, Error> {
+        rows.into_iter()
+            .map(|row| {
+                let extra = match get_column(&row, 10)? {
+                    "" => Option::None,
+                    extra => Option::Some(serde_json::from_str::<HashMap<String, String>>(extra)?),
+                };
+
+                Result::Ok(QuoteRealTimeInfo {
+                    symbol: get_column(&row, 0)?.parse()?,
+                    sequence: parse_column(&row, 1)?,
+                    timestamp: parse_column(&row, 2)?,
+                    current_price: parse_column(&row, 3)?,
+                    volume: parse_optional_decimal_column(&row, 4)?,
+                    low_price: parse_optional_decimal_column(&row, 5)?,
+                    high_price: parse_optional_decimal_column(&row, 6)?,
+                    open_price: parse_optional_decimal_column(&row, 7)?,
+                    prev_close: parse_optional_decimal_column(&row, 8)?,
+                    turnover: parse_optional_decimal_column(&row, 9)?,
+                    extra,
+                })
+            })
+            .collect()
+    }
+}
+
+impl RecordTrait for QuoteDepthInfo {
+    fn get_kind() -> String {
+        const KIND: &'static str = "depth";
+        KIND.to_owned()
+    }
+
+    fn get_csv_header() -> Vec<String> {
+        [
+            "symbol",
+            "sequence",
+            "timestamp",
+            "side",
+            "position",
+            "price",
+            "volume",
+            "order_count",
+        ]
+        .map(|column| column.to_owned())
+        .to_vec()
+    }
+
+    fn get_symbol(&self) -> &Symbol {
+        &self.symbol
+    }
+
+    fn get_timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    // one row per price level, rows sharing the same sequence belong to the same snapshot
+    fn to_csv_rows(&self) -> Vec<Vec<String>> {
+        let to_row = |side: &str, depth: &Depth| {
+            vec![
+                self.symbol.to_string(),
+                self.sequence.to_string(),
+                self.timestamp.to_string(),
+                side.to_owned(),
+                optional_decimal_to_string(&depth.position),
+                depth.price.to_string(),
+                optional_decimal_to_string(&depth.volume),
+                optional_decimal_to_string(&depth.order_count),
+            ]
+        };
+
+        self.ask_list
+            .iter()
+            .map(|depth| to_row(DEPTH_SIDE_ASK, depth))
+            .chain(
+                self.bid_list
+                    .iter()
+                    .map(|depth| to_row(DEPTH_SIDE_BID, depth)),
+            )
+            .collect()
+    }
+
+    // consecutive rows sharing the same sequence and timestamp are merged into one snapshot
+    fn from_csv_rows(rows: Vec<Vec<String>>) -> Result<Vec<Self>, Error> {
+        let mut depth_info_list: Vec<QuoteDepthInfo> = Vec::new();
+        for row in rows {
+            let sequence: u64 = parse_column(&row, 1)?;
+            let timestamp: Timestamp = parse_column(&row, 2)?;
+            let depth = Depth {
+                position: parse_optional_decimal_column(&row, 4)?,
+                price: parse_column(&row, 5)?,
+                volume: parse_optional_decimal_column(&row, 6)?,
+                order_count: parse_optional_decimal_column(&row, 7)?,
+            };
+
+            let is_same_snapshot = depth_info_list.last().is_some_and(|depth_info| {
+                depth_info.sequence == sequence && depth_info.timestamp == timestamp
+            });
+            if !is_same_snapshot {
+                depth_info_list.push(QuoteDepthInfo {
+                    symbol: get_column(&row, 0)?.parse()?,
+                    sequence,
+                    timestamp,
+                    ask_list: vec![],
+                    bid_list: vec![],
+                });
+            }
+
+            let depth_info = depth_info_list.last_mut().unwrap();
+            match get_column(&row, 3)? {
+                DEPTH_SIDE_ASK => depth_info.ask_list.push(depth),
+                DEPTH_SIDE_BID => depth_info.bid_list.push(depth),
+                side => {
+                    return Result::Err(anyhow!(
+                        "PARSING_ERROR Error when parsing depth side {}",
+                        side
+                    ))
+                }
+            }
+        }
+        Result::Ok(depth_info_list)
+    }
+}
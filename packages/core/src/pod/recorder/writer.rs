@@ -0,0 +1,245 @@
+use anyhow::{anyhow, Context, Error};
+use flate2::{write::GzEncoder, Compression};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter, Write},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use time::macros::format_description;
+
+use super::record::RecordTrait;
+use crate::model::{common::timestamp::Timestamp, trading::symbol::Symbol};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RecordFormat {
+    JsonLines,
+    Csv,
+}
+
+impl RecordFormat {
+    fn get_extension(&self) -> &'static str {
+        match self {
+            RecordFormat::JsonLines => "jsonl",
+            RecordFormat::Csv => "csv",
+        }
+    }
+}
+
+impl FromStr for RecordFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "jsonl" => Result::Ok(RecordFormat::JsonLines),
+            "csv" => Result::Ok(RecordFormat::Csv),
+            _ => Result::Err(anyhow!(
+                "PARSING_ERROR Error when parsing record format {}",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RecordCompression {
+    None,
+    Gzip,
+}
+
+impl FromStr for RecordCompression {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Result::Ok(RecordCompression::None),
+            "gzip" => Result::Ok(RecordCompression::Gzip),
+            _ => Result::Err(anyhow!(
+                "PARSING_ERROR Error when parsing record compression {}",
+                s
+            )),
+        }
+    }
+}
+
+pub(crate) fn get_record_file_suffix(
+    format: RecordFormat,
+    compression: RecordCompression,
+) -> String {
+    match compression {
+        RecordCompression::None => format!(".{}", format.get_extension()),
+        RecordCompression::Gzip => format!(".{}.gz", format.get_extension()),
+    }
+}
+
+pub(crate) fn get_record_directory<T: RecordTrait>(base_path: &Path, symbol: &Symbol) -> PathBuf {
+    base_path.join(symbol.to_string()).join(T::get_kind())
+}
+
+pub(crate) fn get_record_file_path<T: RecordTrait>(
+    base_path: &Path,
+    format: RecordFormat,
+    compression: RecordCompression,
+    symbol: &Symbol,
+    date: &str,
+) -> PathBuf {
+    get_record_directory::<T>(base_path, symbol).join(format!(
+        "{}{}",
+        date,
+        get_record_file_suffix(format, compression)
+    ))
+}
+
+enum RecordSink {
+    Plain(BufWriter<File>),
+    Gzip(GzEncoder<BufWriter<File>>),
+}
+
+impl RecordSink {
+    fn finish(self) -> io::Result<()> {
+        match self {
+            RecordSink::Plain(mut writer) => writer.flush(),
+            RecordSink::Gzip(encoder) => encoder.finish()?.flush(),
+        }
+    }
+}
+
+impl Write for RecordSink {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            RecordSink::Plain(writer) => writer.write(buf),
+            RecordSink::Gzip(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            RecordSink::Plain(writer) => writer.flush(),
+            RecordSink::Gzip(encoder) => encoder.flush(),
+        }
+    }
+}
+
+struct RecordFile {
+    date: String,
+    sink: RecordSink,
+}
+
+// Writes one file per symbol per (UTC) day, rotating when a record of the next day arrives.
+// Re-opening an existing file appends to it, gzip files get a new gzip member appended.
+pub struct RecordFileWriter<T: RecordTrait> {
+    base_path: PathBuf,
+    format: RecordFormat,
+    compression: RecordCompression,
+    current_file: Option<RecordFile>,
+    _record: PhantomData<T>,
+}
+
+impl<T: RecordTrait> RecordFileWriter<T> {
+    pub fn new(base_path: PathBuf, format: RecordFormat, compression: RecordCompression) -> Self {
+        RecordFileWriter {
+            base_path,
+            format,
+            compression,
+            current_file: Option::None,
+            _record: PhantomData,
+        }
+    }
+
+    pub fn get_date(timestamp: Timestamp) -> Result<String, Error> {
+        let date_format = format_description!("[year]-[month]-[day]");
+        timestamp
+            .to_offset_date_time()?
+            .format(&date_format)
+            .with_context(|| format!("Error when formatting timestamp {}", timestamp))
+    }
+
+    pub fn get_file_path(&self, symbol: &Symbol, date: &str) -> PathBuf {
+        get_record_file_path::<T>(&self.base_path, self.format, self.compression, symbol, date)
+    }
+
+    fn open(&self, file_path: &Path) -> Result<RecordSink, Error> {
+        if let Option::Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Error when creating directory {}", parent.display()))?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(file_path)
+            .with_context(|| format!("Error when opening file {}", file_path.display()))?;
+        let is_new_file = file.metadata()?.len() == 0;
+
+        let writer = BufWriter::new(file);
+        let mut sink = match self.compression {
+            RecordCompression::None => RecordSink::Plain(writer),
+            RecordCompression::Gzip => {
+                RecordSink::Gzip(GzEncoder::new(writer, Compression::default()))
+            }
+        };
+        if is_new_file && self.format == RecordFormat::Csv {
+            sink.write_all(&Self::to_csv_bytes(vec![T::get_csv_header()])?)?;
+        }
+        Result::Ok(sink)
+    }
+
+    fn to_csv_bytes(rows: Vec<Vec<String>>) -> Result<Vec<u8>, Error> {
+        let mut csv_writer = csv::Writer::from_writer(vec![]);
+        for row in rows {
+            csv_writer.write_record(row)?;
+        }
+        Result::Ok(csv_writer.into_inner()?)
+    }
+
+    pub fn write(&mut self, record: &T) -> Result<(), Error> {
+        let date = Self::get_date(record.get_timestamp())?;
+        let should_rotate = match &self.current_file {
+            Option::Some(current_file) => current_file.date != date,
+            Option::None => true,
+        };
+        if should_rotate {
+            self.close()?;
+            let sink = self.open(&self.get_file_path(record.get_symbol(), &date))?;
+            self.current_file = Option::Some(RecordFile { date, sink });
+        }
+
+        let bytes = match self.format {
+            RecordFormat::JsonLines => {
+                let mut bytes = serde_json::to_vec(record)?;
+                bytes.push(b'\n');
+                bytes
+            }
+            RecordFormat::Csv => Self::to_csv_bytes(record.to_csv_rows())?,
+        };
+        match self.current_file.as_mut() {
+            Option::Some(current_file) => {
+                current_file.sink.write_all(&bytes)?;
+                Result::Ok(())
+            }
+            Option::None => Result::Err(anyhow!("Record file is not opened")),
+        }
+    }
+
+    pub fn flush(&mut self) -> Result<(), Error> {
+        if let Option::Some(current_file) = self.current_file.as_mut() {
+            current_file.sink.flush()?;
+        }
+        Result::Ok(())
+    }
+
+    pub fn close(&mut self) -> Result<(), Error> {
+        if let Option::Some(current_file) = self.current_file.take() {
+            current_file.sink.finish()?;
+        }
+        Result::Ok(())
+    }
+}
+
+impl<T: RecordTrait> Drop for RecordFileWriter<T> {
+    fn drop(&mut self) {
+        if let Result::Err(err) = self.close() {
+            log::error!("Error when closing record file, {}", err);
+        }
+    }
+}
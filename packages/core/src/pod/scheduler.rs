@@ -0,0 +1,64 @@
+use anyhow::Error;
+use std::time::Duration;
+
+use crate::{
+    model::{common::timestamp::Timestamp, config::schedule::ScheduleConfig},
+    utils::calendar::{
+        common_trait::TradingCalendarTrait, initializer::get_trading_calendar_instance,
+        static_calendar::StaticTradingCalendar,
+    },
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ScheduleWindow {
+    pub start_timestamp: Timestamp,
+    pub stop_timestamp: Timestamp,
+}
+
+// Computes the windows in which a pod runs around the trading days of a market
+pub struct PodScheduler {
+    schedule_config: ScheduleConfig,
+    calendar: Box<dyn TradingCalendarTrait>,
+}
+
+impl PodScheduler {
+    pub fn new(schedule_config: ScheduleConfig) -> Result<Self, Error> {
+        let calendar = match &schedule_config.calendar {
+            Option::Some(calendar_config) => get_trading_calendar_instance(
+                calendar_config.identifier.clone(),
+                calendar_config.config_map.clone(),
+            )?,
+            Option::None => get_trading_calendar_instance(
+                StaticTradingCalendar::get_identifier(),
+                Default::default(),
+            )?,
+        };
+        Result::Ok(Self::new_with_calendar(schedule_config, calendar))
+    }
+
+    pub fn new_with_calendar(
+        schedule_config: ScheduleConfig,
+        calendar: Box<dyn TradingCalendarTrait>,
+    ) -> Self {
+        PodScheduler {
+            schedule_config,
+            calendar,
+        }
+    }
+
+    // The window currently open or the next one, a window ends after `timestamp`
+    pub async fn get_next_window(&self, timestamp: Timestamp) -> Result<ScheduleWindow, Error> {
+        let start_before_open =
+            Duration::from_secs(self.schedule_config.start_before_open_minutes * 60);
+        let stop_after_close =
+            Duration::from_secs(self.schedule_config.stop_after_close_minutes * 60);
+        let trading_day = self
+            .calendar
+            .next_trading_day(&self.schedule_config.market, timestamp - stop_after_close)
+            .await?;
+        Result::Ok(ScheduleWindow {
+            start_timestamp: trading_day.open_timestamp - start_before_open,
+            stop_timestamp: trading_day.close_timestamp + stop_after_close,
+        })
+    }
+}
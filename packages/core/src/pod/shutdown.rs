@@ -0,0 +1,141 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::{
+    broker::common::transaction::TransactionTrait,
+    model::trading::transaction::{
+        CancelOrderRequest, Direction, Expire, Price, RegularTradingTime, SubmitOrderRequest,
+    },
+};
+
+// What a pod did while shutting down, errors are collected instead of interrupting the shutdown
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ShutdownReport {
+    // false when the strategy did not return in time and its task was aborted
+    pub is_strategy_stopped: bool,
+    pub stopped_subscription_count: usize,
+    pub stopped_heartbeat_count: usize,
+    pub cancelled_order_id_list: Vec<String>,
+    // market orders submitted to close positions
+    pub flatten_order_id_list: Vec<String>,
+    pub error_list: Vec<String>,
+}
+
+const CANCEL_CHECK_INTERVAL: Duration = Duration::from_millis(200);
+
+// Cancels every open order, then waits up to `timeout` until the cancelled orders are closed at
+// the broker, returns false when some orders might still be open
+pub async fn cancel_open_orders(
+    transaction: &mut Box<dyn TransactionTrait>,
+    timeout: Duration,
+    report: &mut ShutdownReport,
+) -> bool {
+    let open_order_list = match transaction.list_open_orders().await {
+        Result::Ok(open_order_list) => open_order_list,
+        Result::Err(err) => {
+            report
+                .error_list
+                .push(format!("Error when listing open orders, {:#}", err));
+            return false;
+        }
+    };
+
+    let mut is_all_cancelled = true;
+    let mut cancelled_order_id_list = vec![];
+    for order in open_order_list {
+        match transaction
+            .cancel_order(CancelOrderRequest {
+                order_id: order.order_id.clone(),
+            })
+            .await
+        {
+            Result::Ok(_) => cancelled_order_id_list.push(order.order_id),
+            Result::Err(err) => {
+                is_all_cancelled = false;
+                report.error_list.push(format!(
+                    "Error when cancelling order {}, {:#}",
+                    order.order_id, err
+                ))
+            }
+        }
+    }
+    report
+        .cancelled_order_id_list
+        .extend(cancelled_order_id_list.iter().cloned());
+    if cancelled_order_id_list.is_empty() {
+        return is_all_cancelled;
+    }
+
+    // a cancel is only a request, the order might still be filled until the broker confirms it
+    let is_closed = tokio::time::timeout(timeout, async {
+        loop {
+            match transaction.list_open_orders().await {
+                Result::Ok(open_order_list) => {
+                    if !open_order_list
+                        .iter()
+                        .any(|order| cancelled_order_id_list.contains(&order.order_id))
+                    {
+                        return;
+                    }
+                }
+                Result::Err(err) => log::warn!("Error when listing open orders, {:#}", err),
+            }
+            tokio::time::sleep(CANCEL_CHECK_INTERVAL).await;
+        }
+    })
+    .await
+    .is_ok();
+    if !is_closed {
+        report.error_list.push(format!(
+            "Cancelled orders still open after {} seconds",
+            timeout.as_secs_f64()
+        ));
+    }
+    is_all_cancelled && is_closed
+}
+
+pub async fn flatten_positions(
+    transaction: &mut Box<dyn TransactionTrait>,
+    report: &mut ShutdownReport,
+) {
+    let position_list = match transaction.positions().await {
+        Result::Ok(position_list) => position_list,
+        Result::Err(err) => {
+            report
+                .error_list
+                .push(format!("Error when listing positions, {:#}", err));
+            return;
+        }
+    };
+
+    for position in position_list {
+        if position.quantity == Decimal::ZERO {
+            continue;
+        }
+        let direction = if position.quantity > Decimal::ZERO {
+            Direction::Sell
+        } else {
+            Direction::Buy
+        };
+        let symbol = position.symbol.clone();
+        match transaction
+            .submit_order(SubmitOrderRequest {
+                symbol: position.symbol,
+                quantity: position.quantity.abs(),
+                direction,
+                regular_trading_time: RegularTradingTime::AllTime,
+                expire: Expire::Day,
+                price: Price::MarketOrder,
+            })
+            .await
+        {
+            Result::Ok(response) => report.flatten_order_id_list.push(response.order_id),
+            Result::Err(err) => report.error_list.push(format!(
+                "Error when closing the position of {}, {:#}",
+                symbol.to_string(),
+                err
+            )),
+        }
+    }
+}
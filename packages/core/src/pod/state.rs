@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum PodState {
+    Created,
+    Initializing,
+    Running,
+    Stopping,
+    Stopped,
+    Failed { reason: String },
+}
+
+impl PodState {
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self,
+            PodState::Initializing | PodState::Running | PodState::Stopping
+        )
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PodStatus {
+    pub state: PodState,
+    // kept across restarts so that a recovered pod still reports what went wrong
+    pub last_error: Option<String>,
+}
+
+impl PodStatus {
+    pub fn new() -> Self {
+        PodStatus {
+            state: PodState::Created,
+            last_error: Option::None,
+        }
+    }
+
+    pub fn fail(&mut self, reason: String) {
+        self.last_error = Option::Some(reason.clone());
+        self.state = PodState::Failed { reason };
+    }
+}
+
+impl Default for PodStatus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
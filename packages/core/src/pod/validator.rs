@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, fmt};
+
+use super::{component::ComponentKind, recorder::market_data_recorder::MarketDataRecorder};
+use crate::model::{
+    common::{config_key::ConfigKeySpec, types::ConfigMap},
+    config::pod::PodConfig,
+};
+
+#[cfg(feature = "broker__replay")]
+use crate::broker::{common::broker::BrokerTrait, replay::broker::ReplayBroker};
+
+// Every problem found in a pod config, each entry is prefixed with the path of the field
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PodConfigValidationError {
+    pub error_list: Vec<String>,
+}
+
+impl fmt::Display for PodConfigValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "INVALID_POD_CONFIG {}", self.error_list.join("; "))
+    }
+}
+
+impl std::error::Error for PodConfigValidationError {}
+
+fn validate_config_map(
+    error_list: &mut Vec<String>,
+    path: &str,
+    config_map: &ConfigMap,
+    config_key_list: &[ConfigKeySpec],
+) {
+    for config_key in config_key_list {
+        match config_map.get(config_key.key) {
+            Option::Some(value) => {
+                if let Result::Err(err) = config_key.value_type.check(value) {
+                    error_list.push(format!("{}.config_map.{}: {}", path, config_key.key, err));
+                }
+            }
+            Option::None if config_key.is_required => {
+                error_list.push(format!(
+                    "{}.config_map.{}: MISSING_CONFIG",
+                    path, config_key.key
+                ));
+            }
+            Option::None => {}
+        }
+    }
+}
+
+// Checks that the identifier is compiled in, `path` points at the field holding it
+fn validate_identifier(
+    error_list: &mut Vec<String>,
+    path: &str,
+    kind: ComponentKind,
+    identifier: &str,
+) -> bool {
+    let identifier_list = kind.get_identifier_list();
+    let is_known = identifier_list.iter().any(|known| known == identifier);
+    if !is_known {
+        error_list.push(format!(
+            "{}: IDENTIFIER_NOT_MATCHED {:?}: {}, available: [{}]",
+            path,
+            kind,
+            identifier,
+            identifier_list.join(", ")
+        ));
+    }
+    is_known
+}
+
+// The config map is only checked against the keys of the component when the identifier is known
+fn validate_component(
+    error_list: &mut Vec<String>,
+    path: &str,
+    kind: ComponentKind,
+    identifier: &str,
+    config_map: &ConfigMap,
+) {
+    if validate_identifier(
+        error_list,
+        &format!("{}.identifier", path),
+        kind,
+        identifier,
+    ) {
+        let config_key_list = kind.get_config_key_list(identifier).unwrap_or_default();
+        validate_config_map(error_list, path, config_map, &config_key_list);
+    }
+}
+
+// Checks identifiers, required config keys and config values of every component of the pod,
+// so that a broken config is rejected before the pod is created
+pub fn validate_pod_config(pod_config: &PodConfig) -> Result<(), PodConfigValidationError> {
+    let mut error_list = Vec::new();
+
+    if pod_config.name.trim().is_empty() {
+        error_list.push("name: should not be empty".to_owned());
+    }
+
+    let mut broker_identifier_set = HashSet::new();
+    for (index, broker_config) in pod_config.broker_list.iter().enumerate() {
+        let path = format!("broker_list[{}]", index);
+        if !broker_identifier_set.insert(broker_config.identifier.as_str()) {
+            error_list.push(format!(
+                "{}.identifier: DUPLICATED_BROKER_ID {}",
+                path, broker_config.identifier
+            ));
+        }
+        validate_component(
+            &mut error_list,
+            &path,
+            ComponentKind::Broker,
+            &broker_config.identifier,
+            &broker_config.config_map,
+        );
+
+        // the fee model of a replay reads its own keys from the config map of the broker
+        #[cfg(feature = "broker__replay")]
+        if broker_config.identifier == ReplayBroker::get_identifier() {
+            if let Option::Some(fee_model_identifier) = broker_config
+                .config_map
+                .get(ReplayBroker::CONFIG_KEY_FEE_MODEL)
+            {
+                if validate_identifier(
+                    &mut error_list,
+                    &format!("{}.config_map.{}", path, ReplayBroker::CONFIG_KEY_FEE_MODEL),
+                    ComponentKind::FeeModel,
+                    fee_model_identifier,
+                ) {
+                    validate_config_map(
+                        &mut error_list,
+                        &path,
+                        &broker_config.config_map,
+                        &ComponentKind::FeeModel
+                            .get_config_key_list(fee_model_identifier)
+                            .unwrap_or_default(),
+                    );
+                }
+            }
+        }
+    }
+
+    validate_component(
+        &mut error_list,
+        "persistent_kv_store",
+        ComponentKind::PersistentKVStore,
+        &pod_config.persistent_kv_store.identifier,
+        &pod_config.persistent_kv_store.config_map,
+    );
+    validate_component(
+        &mut error_list,
+        "strategy",
+        ComponentKind::Strategy,
+        &pod_config.strategy.identifier,
+        &pod_config.strategy.config_map,
+    );
+    validate_component(
+        &mut error_list,
+        "metrics_registry",
+        ComponentKind::MetricsRegistryFactory,
+        &pod_config.metrics_registry.identifier,
+        &pod_config.metrics_registry.config_map,
+    );
+    for (index, event_listener_config) in pod_config.event_listener_list.iter().enumerate() {
+        validate_component(
+            &mut error_list,
+            &format!("event_listener_list[{}]", index),
+            ComponentKind::EventListener,
+            &event_listener_config.identifier,
+            &event_listener_config.config_map,
+        );
+    }
+    if let Option::Some(clock_config) = &pod_config.clock {
+        validate_component(
+            &mut error_list,
+            "clock",
+            ComponentKind::Clock,
+            &clock_config.identifier,
+            &clock_config.config_map,
+        );
+    }
+
+    for (index, recorder_config) in pod_config.recorder_list.iter().enumerate() {
+        let path = format!("recorder_list[{}]", index);
+        if !broker_identifier_set.contains(recorder_config.broker_identifier.as_str()) {
+            error_list.push(format!(
+                "{}.broker_identifier: ILLEGAL_BROKER_ID {} is not in broker_list",
+                path, recorder_config.broker_identifier
+            ));
+        }
+        validate_config_map(
+            &mut error_list,
+            &path,
+            &recorder_config.config_map,
+            &MarketDataRecorder::get_config_key_list(),
+        );
+    }
+
+    if let Option::Some(calendar_config) = pod_config
+        .schedule
+        .as_ref()
+        .and_then(|schedule_config| schedule_config.calendar.as_ref())
+    {
+        validate_component(
+            &mut error_list,
+            "schedule.calendar",
+            ComponentKind::TradingCalendar,
+            &calendar_config.identifier,
+            &calendar_config.config_map,
+        );
+    }
+
+    let restart_policy = &pod_config.restart_policy;
+    if restart_policy.initial_backoff_seconds > restart_policy.max_backoff_seconds {
+        error_list.push(format!(
+            "restart_policy.initial_backoff_seconds: ILLEGAL_VALUE {} is greater than max_backoff_seconds {}",
+            restart_policy.initial_backoff_seconds, restart_policy.max_backoff_seconds
+        ));
+    }
+    if restart_policy.healthy_seconds == 0 {
+        error_list.push(
+            "restart_policy.healthy_seconds: ILLEGAL_VALUE 0 should be at least 1".to_owned(),
+        );
+    }
+
+    match error_list.is_empty() {
+        true => Result::Ok(()),
+        false => Result::Err(PodConfigValidationError { error_list }),
+    }
+}
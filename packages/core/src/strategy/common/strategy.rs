@@ -3,8 +3,10 @@ use async_trait::async_trait;
 use std::sync::{atomic::AtomicBool, Arc};
 
 use crate::{
-    broker::common::broker::BrokerTrait, model::common::types::ConfigMap,
+    broker::common::broker::BrokerTrait,
+    model::common::{config_key::ConfigKeySpec, types::ConfigMap},
     persistent_kv::common::store::PersistentKVStoreTrait,
+    utils::clock::common_trait::ClockTrait,
 };
 
 pub struct StrategyContext {
@@ -12,6 +14,7 @@ pub struct StrategyContext {
     pub persistent_kv_store: Box<dyn PersistentKVStoreTrait>,
     pub config_map: ConfigMap,
     pub stopped_indicator: Arc<AtomicBool>,
+    pub clock: Arc<dyn ClockTrait>,
 }
 
 #[async_trait]
@@ -22,6 +25,12 @@ pub trait StrategyTrait: Send + Sync {
     fn get_identifier() -> String
     where
         Self: Sized;
+    fn get_config_key_list() -> Vec<ConfigKeySpec>
+    where
+        Self: Sized,
+    {
+        vec![]
+    }
 
     async fn start(&self) -> Result<(), Error>;
     async fn stop(&self) -> Result<(), Error>;
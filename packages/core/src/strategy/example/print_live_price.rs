@@ -1,7 +1,7 @@
 use anyhow::{anyhow, Error};
 use async_trait::async_trait;
 use std::sync::atomic::Ordering;
-use time::{format_description, OffsetDateTime};
+use time::format_description;
 use tokio::select;
 
 use crate::{
@@ -59,7 +59,9 @@ impl StrategyTrait for PrintLivePriceStrategy {
                             log::info!(
                                 "[{}] ({}), Price: {}, Vol: {:?}",
                                 quote_info.symbol.to_string(),
-                                OffsetDateTime::from_unix_timestamp(quote_info.timestamp as i64)
+                                quote_info
+                                    .timestamp
+                                    .to_offset_date_time()
                                     .unwrap()
                                     .format(&format)
                                     .unwrap(),
@@ -0,0 +1,115 @@
+use anyhow::{anyhow, Error};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::model::{
+    common::timestamp::Timestamp,
+    trading::{
+        symbol::Symbol,
+        transaction::{Direction, Expire, Price, RegularTradingTime, SubmitOrderRequest},
+    },
+};
+
+// The order to be sliced, child orders are limit orders when `limit_price` is set and market
+// orders otherwise
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ParentOrder {
+    pub symbol: Symbol,
+    pub direction: Direction,
+    pub quantity: Decimal,
+    pub start_timestamp: Timestamp,
+    pub end_timestamp: Timestamp,
+    pub limit_price: Option<Decimal>,
+    pub lot_size: Decimal,
+    pub regular_trading_time: RegularTradingTime,
+}
+
+impl ParentOrder {
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.quantity <= Decimal::ZERO {
+            return Result::Err(anyhow!("ILLEGAL_QUANTITY quantity should be positive"));
+        }
+        if self.lot_size <= Decimal::ZERO {
+            return Result::Err(anyhow!("ILLEGAL_LOT_SIZE lot_size should be positive"));
+        }
+        if self.start_timestamp >= self.end_timestamp {
+            return Result::Err(anyhow!(
+                "ILLEGAL_TIME_WINDOW start_timestamp should be before end_timestamp"
+            ));
+        }
+        Result::Ok(())
+    }
+
+    pub fn get_child_request(&self, quantity: Decimal) -> SubmitOrderRequest {
+        SubmitOrderRequest {
+            symbol: self.symbol.clone(),
+            quantity,
+            direction: self.direction.clone(),
+            regular_trading_time: self.regular_trading_time.clone(),
+            expire: Expire::Day,
+            price: match self.limit_price {
+                Option::Some(price) => Price::LimitOrder { price },
+                Option::None => Price::MarketOrder,
+            },
+        }
+    }
+
+    // Index of the slice `now` belongs to when the time window is split into `slice_count` equal
+    // slices, clamped into the window
+    pub fn get_slice_index(&self, now: Timestamp, slice_count: usize) -> usize {
+        if now <= self.start_timestamp {
+            return 0;
+        }
+        let elapsed = now.saturating_sub(self.start_timestamp).as_nanos();
+        let window = self
+            .end_timestamp
+            .saturating_sub(self.start_timestamp)
+            .as_nanos();
+        ((elapsed * slice_count as u128 / window) as usize).min(slice_count - 1)
+    }
+
+    pub fn round_to_lot(&self, quantity: Decimal) -> Decimal {
+        (quantity / self.lot_size).floor() * self.lot_size
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ExecutionProgress {
+    pub filled_quantity: Decimal,
+    // submitted but neither filled nor closed yet
+    pub working_quantity: Decimal,
+}
+
+impl ExecutionProgress {
+    pub fn get_remaining_quantity(&self, parent_order: &ParentOrder) -> Decimal {
+        (parent_order.quantity - self.filled_quantity - self.working_quantity).max(Decimal::ZERO)
+    }
+}
+
+pub trait ExecutionAlgorithmTrait: Send + Sync {
+    // Quantity of the child order to submit now, zero to wait
+    fn get_slice_quantity(
+        &self,
+        parent_order: &ParentOrder,
+        progress: &ExecutionProgress,
+        now: Timestamp,
+    ) -> Decimal;
+}
+
+// Submits whatever is behind the cumulative target, so unfilled or cancelled children are
+// caught up by later slices. The last slice is not rounded to lots so the order completes.
+pub(crate) fn get_scheduled_slice_quantity(
+    parent_order: &ParentOrder,
+    progress: &ExecutionProgress,
+    target_ratio: Decimal,
+) -> Decimal {
+    let remaining_quantity = progress.get_remaining_quantity(parent_order);
+    if target_ratio >= Decimal::ONE {
+        return remaining_quantity;
+    }
+    let behind_quantity =
+        parent_order.quantity * target_ratio - progress.filled_quantity - progress.working_quantity;
+    parent_order
+        .round_to_lot(behind_quantity)
+        .clamp(Decimal::ZERO, remaining_quantity)
+}
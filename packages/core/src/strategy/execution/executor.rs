@@ -0,0 +1,234 @@
+use anyhow::Error;
+use rust_decimal::Decimal;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use super::common::algorithm::{ExecutionAlgorithmTrait, ExecutionProgress, ParentOrder};
+use crate::{
+    broker::common::transaction::TransactionTrait,
+    model::trading::transaction::{CancelOrderRequest, OrderDetailRequest, OrderStatus},
+    utils::clock::common_trait::ClockTrait,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChildOrder {
+    pub order_id: String,
+    pub quantity: Decimal,
+    pub executed_quantity: Decimal,
+    pub status: OrderStatus,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExecutionStatus {
+    Waiting,
+    Running,
+    Completed,
+    // the time window is over, the unfilled quantity is left
+    Expired,
+    Cancelled,
+}
+
+impl ExecutionStatus {
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            ExecutionStatus::Completed | ExecutionStatus::Expired | ExecutionStatus::Cancelled
+        )
+    }
+}
+
+// Drives an execution algorithm on top of any transaction, child orders are refreshed and the
+// next slice is submitted on every step.
+pub struct AlgorithmicExecutor {
+    transaction: Box<dyn TransactionTrait>,
+    algorithm: Box<dyn ExecutionAlgorithmTrait>,
+    parent_order: ParentOrder,
+    clock: Arc<dyn ClockTrait>,
+    child_order_list: Vec<ChildOrder>,
+    status: ExecutionStatus,
+}
+
+impl AlgorithmicExecutor {
+    pub fn new(
+        transaction: Box<dyn TransactionTrait>,
+        algorithm: Box<dyn ExecutionAlgorithmTrait>,
+        parent_order: ParentOrder,
+        clock: Arc<dyn ClockTrait>,
+    ) -> Result<Self, Error> {
+        parent_order.validate()?;
+        Result::Ok(AlgorithmicExecutor {
+            transaction,
+            algorithm,
+            parent_order,
+            clock,
+            child_order_list: vec![],
+            status: ExecutionStatus::Waiting,
+        })
+    }
+
+    pub fn get_parent_order(&self) -> &ParentOrder {
+        &self.parent_order
+    }
+
+    pub fn get_child_order_list(&self) -> &Vec<ChildOrder> {
+        &self.child_order_list
+    }
+
+    pub fn get_status(&self) -> ExecutionStatus {
+        self.status.clone()
+    }
+
+    pub fn get_progress(&self) -> ExecutionProgress {
+        self.child_order_list.iter().fold(
+            ExecutionProgress::default(),
+            |mut progress, child_order| {
+                progress.filled_quantity += child_order.executed_quantity;
+                if child_order.status.is_open() {
+                    progress.working_quantity +=
+                        child_order.quantity - child_order.executed_quantity;
+                }
+                progress
+            },
+        )
+    }
+
+    async fn refresh_child_orders(&mut self) {
+        for child_order in self
+            .child_order_list
+            .iter_mut()
+            .filter(|child_order| child_order.status.is_open())
+        {
+            match self
+                .transaction
+                .order_detail(OrderDetailRequest {
+                    order_id: child_order.order_id.clone(),
+                })
+                .await
+            {
+                Result::Ok(order_detail) => {
+                    child_order.executed_quantity = order_detail.executed_quantity;
+                    child_order.status = order_detail.status;
+                }
+                Result::Err(err) => {
+                    log::warn!(
+                        "Error when refreshing child order {}, {}",
+                        child_order.order_id,
+                        err
+                    );
+                }
+            }
+        }
+    }
+
+    async fn cancel_child_orders(&mut self) -> Result<(), Error> {
+        for child_order in self
+            .child_order_list
+            .iter_mut()
+            .filter(|child_order| child_order.status.is_open())
+        {
+            self.transaction
+                .cancel_order(CancelOrderRequest {
+                    order_id: child_order.order_id.clone(),
+                })
+                .await?;
+            // fills that came in before the cancel still count
+            match self
+                .transaction
+                .order_detail(OrderDetailRequest {
+                    order_id: child_order.order_id.clone(),
+                })
+                .await
+            {
+                Result::Ok(order_detail) => {
+                    child_order.executed_quantity = order_detail.executed_quantity;
+                    child_order.status = match order_detail.status.is_open() {
+                        true => OrderStatus::Cancelled,
+                        false => order_detail.status,
+                    };
+                }
+                Result::Err(err) => {
+                    log::warn!(
+                        "Error when refreshing cancelled child order {}, {}",
+                        child_order.order_id,
+                        err
+                    );
+                    child_order.status = OrderStatus::Cancelled;
+                }
+            }
+        }
+        Result::Ok(())
+    }
+
+    pub async fn step(&mut self) -> Result<ExecutionStatus, Error> {
+        if self.status.is_finished() {
+            return Result::Ok(self.get_status());
+        }
+        let now = self.clock.now();
+        if now < self.parent_order.start_timestamp {
+            return Result::Ok(self.get_status());
+        }
+
+        self.refresh_child_orders().await;
+        let progress = self.get_progress();
+        if progress.filled_quantity >= self.parent_order.quantity {
+            self.status = ExecutionStatus::Completed;
+            return Result::Ok(self.get_status());
+        }
+        if now >= self.parent_order.end_timestamp {
+            self.cancel_child_orders().await?;
+            self.status = ExecutionStatus::Expired;
+            return Result::Ok(self.get_status());
+        }
+
+        self.status = ExecutionStatus::Running;
+        let quantity = self
+            .algorithm
+            .get_slice_quantity(&self.parent_order, &progress, now);
+        if quantity > Decimal::ZERO {
+            let response = self
+                .transaction
+                .submit_order(self.parent_order.get_child_request(quantity))
+                .await?;
+            self.child_order_list.push(ChildOrder {
+                order_id: response.order_id,
+                quantity,
+                executed_quantity: Decimal::ZERO,
+                status: OrderStatus::Submitted,
+            });
+        }
+        Result::Ok(self.get_status())
+    }
+
+    pub async fn cancel(&mut self) -> Result<(), Error> {
+        self.cancel_child_orders().await?;
+        self.status = ExecutionStatus::Cancelled;
+        Result::Ok(())
+    }
+
+    // Steps until the execution finishes, working children are cancelled when stopped
+    pub async fn run(
+        &mut self,
+        poll_interval: Duration,
+        stopped_indicator: Arc<AtomicBool>,
+    ) -> Result<ExecutionProgress, Error> {
+        loop {
+            if stopped_indicator.load(Ordering::Relaxed) {
+                self.cancel().await?;
+                break;
+            }
+            if let Result::Err(err) = self.step().await {
+                log::error!("Error when executing the parent order, {}", err);
+            }
+            if self.status.is_finished() {
+                break;
+            }
+            self.clock.sleep(poll_interval).await;
+        }
+        Result::Ok(self.get_progress())
+    }
+}
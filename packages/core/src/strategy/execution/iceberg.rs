@@ -0,0 +1,42 @@
+use anyhow::{anyhow, Error};
+use rust_decimal::Decimal;
+
+use super::common::algorithm::{ExecutionAlgorithmTrait, ExecutionProgress, ParentOrder};
+use crate::model::common::timestamp::Timestamp;
+
+// Shows at most `display_quantity` at a time, the next child is submitted once the previous
+// one is closed
+pub struct Iceberg {
+    display_quantity: Decimal,
+}
+
+impl Iceberg {
+    pub fn new(display_quantity: Decimal) -> Result<Self, Error> {
+        if display_quantity <= Decimal::ZERO {
+            return Result::Err(anyhow!(
+                "ILLEGAL_QUANTITY display_quantity should be positive"
+            ));
+        }
+        Result::Ok(Iceberg { display_quantity })
+    }
+}
+
+impl ExecutionAlgorithmTrait for Iceberg {
+    fn get_slice_quantity(
+        &self,
+        parent_order: &ParentOrder,
+        progress: &ExecutionProgress,
+        _now: Timestamp,
+    ) -> Decimal {
+        if progress.working_quantity > Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        let remaining_quantity = progress.get_remaining_quantity(parent_order);
+        match remaining_quantity > self.display_quantity {
+            true => parent_order
+                .round_to_lot(self.display_quantity)
+                .max(parent_order.lot_size),
+            false => remaining_quantity,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod common;
+pub mod executor;
+pub mod iceberg;
+pub mod twap;
+pub mod vwap;
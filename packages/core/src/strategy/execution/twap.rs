@@ -0,0 +1,35 @@
+use anyhow::{anyhow, Error};
+use rust_decimal::Decimal;
+
+use super::common::algorithm::{
+    get_scheduled_slice_quantity, ExecutionAlgorithmTrait, ExecutionProgress, ParentOrder,
+};
+use crate::model::common::timestamp::Timestamp;
+
+// Splits the time window into `slice_count` equal slices with equal quantity, each slice is
+// released at its start
+pub struct TimeWeightedAveragePrice {
+    slice_count: usize,
+}
+
+impl TimeWeightedAveragePrice {
+    pub fn new(slice_count: usize) -> Result<Self, Error> {
+        if slice_count == 0 {
+            return Result::Err(anyhow!("ILLEGAL_PERIOD slice_count should be positive"));
+        }
+        Result::Ok(TimeWeightedAveragePrice { slice_count })
+    }
+}
+
+impl ExecutionAlgorithmTrait for TimeWeightedAveragePrice {
+    fn get_slice_quantity(
+        &self,
+        parent_order: &ParentOrder,
+        progress: &ExecutionProgress,
+        now: Timestamp,
+    ) -> Decimal {
+        let slice_index = parent_order.get_slice_index(now, self.slice_count);
+        let target_ratio = Decimal::from(slice_index + 1) / Decimal::from(self.slice_count);
+        get_scheduled_slice_quantity(parent_order, progress, target_ratio)
+    }
+}
@@ -0,0 +1,109 @@
+use anyhow::{anyhow, Error};
+use rust_decimal::Decimal;
+
+use super::common::algorithm::{
+    get_scheduled_slice_quantity, ExecutionAlgorithmTrait, ExecutionProgress, ParentOrder,
+};
+use crate::model::{common::timestamp::Timestamp, trading::candlestick::Candlestick};
+
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+// Share of the volume traded in each of the equal buckets of the time window
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VolumeProfile {
+    weight_list: Vec<Decimal>,
+}
+
+impl VolumeProfile {
+    pub fn new(volume_list: Vec<Decimal>) -> Result<Self, Error> {
+        if volume_list.iter().any(|volume| volume.is_sign_negative()) {
+            return Result::Err(anyhow!(
+                "ILLEGAL_VOLUME_PROFILE volume should not be negative"
+            ));
+        }
+        let volume_sum: Decimal = volume_list.iter().sum();
+        if volume_sum.is_zero() {
+            return Result::Err(anyhow!("ILLEGAL_VOLUME_PROFILE volume should not be empty"));
+        }
+        Result::Ok(VolumeProfile {
+            weight_list: volume_list
+                .into_iter()
+                .map(|volume| volume / volume_sum)
+                .collect(),
+        })
+    }
+
+    // Buckets the historical candlesticks by their time of day (UTC) within the time of day of
+    // the window, so candlesticks of several past sessions are averaged into one profile.
+    // The window should not cross midnight UTC.
+    pub fn from_candlestick_list(
+        candlestick_list: &[Candlestick],
+        start_timestamp: Timestamp,
+        end_timestamp: Timestamp,
+        bucket_count: usize,
+    ) -> Result<Self, Error> {
+        if bucket_count == 0 {
+            return Result::Err(anyhow!("ILLEGAL_PERIOD bucket_count should be positive"));
+        }
+        let start_secs = start_timestamp.as_secs() % SECS_PER_DAY;
+        let window_secs = match end_timestamp
+            .as_secs()
+            .checked_sub(start_timestamp.as_secs())
+        {
+            Option::Some(window_secs) => window_secs,
+            Option::None => {
+                return Result::Err(anyhow!(
+                    "ILLEGAL_TIME_WINDOW start_timestamp should be before end_timestamp"
+                ))
+            }
+        };
+        if window_secs == 0 || start_secs + window_secs > SECS_PER_DAY {
+            return Result::Err(anyhow!(
+                "ILLEGAL_TIME_WINDOW window should be within a single day"
+            ));
+        }
+
+        let mut volume_list = vec![Decimal::ZERO; bucket_count];
+        for candlestick in candlestick_list {
+            let secs = candlestick.timestamp.as_secs() % SECS_PER_DAY;
+            if secs < start_secs || secs >= start_secs + window_secs {
+                continue;
+            }
+            let bucket_index = ((secs - start_secs) * bucket_count as u64 / window_secs) as usize;
+            volume_list[bucket_index] += candlestick.volume;
+        }
+        Self::new(volume_list)
+    }
+
+    pub fn get_weight_list(&self) -> &Vec<Decimal> {
+        &self.weight_list
+    }
+}
+
+// Releases the quantity of each bucket at its start in proportion to the historical volume
+pub struct VolumeWeightedAveragePrice {
+    volume_profile: VolumeProfile,
+}
+
+impl VolumeWeightedAveragePrice {
+    pub fn new(volume_profile: VolumeProfile) -> Self {
+        VolumeWeightedAveragePrice { volume_profile }
+    }
+}
+
+impl ExecutionAlgorithmTrait for VolumeWeightedAveragePrice {
+    fn get_slice_quantity(
+        &self,
+        parent_order: &ParentOrder,
+        progress: &ExecutionProgress,
+        now: Timestamp,
+    ) -> Decimal {
+        let weight_list = self.volume_profile.get_weight_list();
+        let bucket_index = parent_order.get_slice_index(now, weight_list.len());
+        let target_ratio = match bucket_index + 1 == weight_list.len() {
+            true => Decimal::ONE,
+            false => weight_list[..=bucket_index].iter().sum(),
+        };
+        get_scheduled_slice_quantity(parent_order, progress, target_ratio)
+    }
+}
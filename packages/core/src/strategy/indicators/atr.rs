@@ -0,0 +1,72 @@
+use anyhow::Error;
+use rust_decimal::Decimal;
+
+use super::common::indicator::{check_period, IndicatorInput, IndicatorTrait};
+
+// Wilder's smoothing over the true range
+pub struct AverageTrueRange {
+    period: usize,
+    previous_close: Option<Decimal>,
+    count: usize,
+    current: Decimal,
+}
+
+impl AverageTrueRange {
+    pub fn new(period: usize) -> Result<Self, Error> {
+        check_period(period)?;
+        Result::Ok(AverageTrueRange {
+            period,
+            previous_close: Option::None,
+            count: 0,
+            current: Decimal::ZERO,
+        })
+    }
+
+    fn true_range(&self, input: &IndicatorInput) -> Decimal {
+        let range = input.high - input.low;
+        match self.previous_close {
+            Option::Some(previous_close) => range
+                .max((input.high - previous_close).abs())
+                .max((input.low - previous_close).abs()),
+            Option::None => range,
+        }
+    }
+
+    fn current(&self) -> Option<Decimal> {
+        if self.count < self.period {
+            return Option::None;
+        }
+        Option::Some(self.current)
+    }
+}
+
+impl IndicatorTrait for AverageTrueRange {
+    type Output = Decimal;
+
+    fn update(&mut self, input: &IndicatorInput) -> Option<Decimal> {
+        let true_range = self.true_range(input);
+        let period = Decimal::from(self.period);
+        self.previous_close = Option::Some(input.close);
+
+        self.count += 1;
+        if self.count <= self.period {
+            self.current += true_range;
+            if self.count == self.period {
+                self.current /= period;
+            }
+        } else {
+            self.current = (self.current * (period - Decimal::ONE) + true_range) / period;
+        }
+        self.current()
+    }
+
+    fn value(&self) -> Option<Decimal> {
+        self.current()
+    }
+
+    fn reset(&mut self) {
+        self.previous_close = Option::None;
+        self.count = 0;
+        self.current = Decimal::ZERO;
+    }
+}
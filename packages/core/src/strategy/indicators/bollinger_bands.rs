@@ -0,0 +1,81 @@
+use anyhow::Error;
+use rust_decimal::{Decimal, MathematicalOps};
+use std::collections::VecDeque;
+
+use super::common::indicator::{check_period, IndicatorInput, IndicatorTrait};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BollingerBandsOutput {
+    pub upper: Decimal,
+    pub middle: Decimal,
+    pub lower: Decimal,
+}
+
+// Uses the population standard deviation of the window
+pub struct BollingerBands {
+    period: usize,
+    multiplier: Decimal,
+    window: VecDeque<Decimal>,
+    sum: Decimal,
+    square_sum: Decimal,
+}
+
+impl BollingerBands {
+    pub fn new(period: usize, multiplier: Decimal) -> Result<Self, Error> {
+        check_period(period)?;
+        Result::Ok(BollingerBands {
+            period,
+            multiplier,
+            window: VecDeque::with_capacity(period),
+            sum: Decimal::ZERO,
+            square_sum: Decimal::ZERO,
+        })
+    }
+
+    pub fn next(&mut self, value: Decimal) -> Option<BollingerBandsOutput> {
+        self.window.push_back(value);
+        self.sum += value;
+        self.square_sum += value * value;
+        if self.window.len() > self.period {
+            if let Some(oldest) = self.window.pop_front() {
+                self.sum -= oldest;
+                self.square_sum -= oldest * oldest;
+            }
+        }
+        self.current()
+    }
+
+    fn current(&self) -> Option<BollingerBandsOutput> {
+        if self.window.len() < self.period {
+            return Option::None;
+        }
+        let count = Decimal::from(self.period);
+        let middle = self.sum / count;
+        let variance = (self.square_sum / count - middle * middle).max(Decimal::ZERO);
+        let deviation = variance.sqrt()? * self.multiplier;
+
+        Option::Some(BollingerBandsOutput {
+            upper: middle + deviation,
+            middle,
+            lower: middle - deviation,
+        })
+    }
+}
+
+impl IndicatorTrait for BollingerBands {
+    type Output = BollingerBandsOutput;
+
+    fn update(&mut self, input: &IndicatorInput) -> Option<Self::Output> {
+        self.next(input.close)
+    }
+
+    fn value(&self) -> Option<Self::Output> {
+        self.current()
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sum = Decimal::ZERO;
+        self.square_sum = Decimal::ZERO;
+    }
+}
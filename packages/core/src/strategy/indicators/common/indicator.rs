@@ -0,0 +1,60 @@
+use anyhow::{anyhow, Error};
+use rust_decimal::Decimal;
+
+use crate::model::trading::{candlestick::Candlestick, quote::QuoteRealTimeInfo};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndicatorInput {
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub volume: Option<Decimal>,
+}
+
+impl From<&Candlestick> for IndicatorInput {
+    fn from(candlestick: &Candlestick) -> Self {
+        IndicatorInput {
+            high: candlestick.high,
+            low: candlestick.low,
+            close: candlestick.close,
+            volume: Option::Some(candlestick.volume),
+        }
+    }
+}
+
+impl From<&QuoteRealTimeInfo> for IndicatorInput {
+    // high_price / low_price of a quote are session-wide values, a single tick only has a price
+    fn from(quote_info: &QuoteRealTimeInfo) -> Self {
+        IndicatorInput {
+            high: quote_info.current_price,
+            low: quote_info.current_price,
+            close: quote_info.current_price,
+            volume: quote_info.volume,
+        }
+    }
+}
+
+pub trait IndicatorTrait: Send + Sync {
+    type Output;
+
+    fn update(&mut self, input: &IndicatorInput) -> Option<Self::Output>;
+    fn update_candlestick(&mut self, candlestick: &Candlestick) -> Option<Self::Output> {
+        self.update(&candlestick.into())
+    }
+    fn update_quote_real_time_info(
+        &mut self,
+        quote_info: &QuoteRealTimeInfo,
+    ) -> Option<Self::Output> {
+        self.update(&quote_info.into())
+    }
+
+    fn value(&self) -> Option<Self::Output>;
+    fn reset(&mut self);
+}
+
+pub(crate) fn check_period(period: usize) -> Result<(), Error> {
+    if period == 0 {
+        return Result::Err(anyhow!("ILLEGAL_PERIOD period should be positive"));
+    }
+    Result::Ok(())
+}
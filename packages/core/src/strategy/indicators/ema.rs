@@ -0,0 +1,62 @@
+use anyhow::Error;
+use rust_decimal::Decimal;
+
+use super::common::indicator::{check_period, IndicatorInput, IndicatorTrait};
+
+// Seeded with the simple average of the first `period` values
+pub struct ExponentialMovingAverage {
+    period: usize,
+    alpha: Decimal,
+    seed_count: usize,
+    seed_sum: Decimal,
+    current: Option<Decimal>,
+}
+
+impl ExponentialMovingAverage {
+    pub fn new(period: usize) -> Result<Self, Error> {
+        check_period(period)?;
+        Result::Ok(ExponentialMovingAverage {
+            period,
+            alpha: Decimal::TWO / Decimal::from(period + 1),
+            seed_count: 0,
+            seed_sum: Decimal::ZERO,
+            current: Option::None,
+        })
+    }
+
+    pub fn next(&mut self, value: Decimal) -> Option<Decimal> {
+        self.current = match self.current {
+            Some(previous) => {
+                Option::Some(self.alpha * value + (Decimal::ONE - self.alpha) * previous)
+            }
+            None => {
+                self.seed_count += 1;
+                self.seed_sum += value;
+                if self.seed_count < self.period {
+                    Option::None
+                } else {
+                    Option::Some(self.seed_sum / Decimal::from(self.period))
+                }
+            }
+        };
+        self.current
+    }
+}
+
+impl IndicatorTrait for ExponentialMovingAverage {
+    type Output = Decimal;
+
+    fn update(&mut self, input: &IndicatorInput) -> Option<Decimal> {
+        self.next(input.close)
+    }
+
+    fn value(&self) -> Option<Decimal> {
+        self.current
+    }
+
+    fn reset(&mut self) {
+        self.seed_count = 0;
+        self.seed_sum = Decimal::ZERO;
+        self.current = Option::None;
+    }
+}
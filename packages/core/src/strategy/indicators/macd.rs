@@ -0,0 +1,76 @@
+use anyhow::{anyhow, Error};
+use rust_decimal::Decimal;
+
+use super::{
+    common::indicator::{IndicatorInput, IndicatorTrait},
+    ema::ExponentialMovingAverage,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MovingAverageConvergenceDivergenceOutput {
+    pub macd: Decimal,
+    pub signal: Decimal,
+    pub histogram: Decimal,
+}
+
+pub struct MovingAverageConvergenceDivergence {
+    fast_ema: ExponentialMovingAverage,
+    slow_ema: ExponentialMovingAverage,
+    signal_ema: ExponentialMovingAverage,
+    current: Option<MovingAverageConvergenceDivergenceOutput>,
+}
+
+impl MovingAverageConvergenceDivergence {
+    pub fn new(
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: usize,
+    ) -> Result<Self, Error> {
+        if fast_period >= slow_period {
+            return Result::Err(anyhow!(
+                "ILLEGAL_PERIOD fast_period {} should be less than slow_period {}",
+                fast_period,
+                slow_period
+            ));
+        }
+        Result::Ok(MovingAverageConvergenceDivergence {
+            fast_ema: ExponentialMovingAverage::new(fast_period)?,
+            slow_ema: ExponentialMovingAverage::new(slow_period)?,
+            signal_ema: ExponentialMovingAverage::new(signal_period)?,
+            current: Option::None,
+        })
+    }
+
+    pub fn next(&mut self, close: Decimal) -> Option<MovingAverageConvergenceDivergenceOutput> {
+        let fast = self.fast_ema.next(close);
+        let slow = self.slow_ema.next(close);
+        let macd = fast? - slow?;
+        let signal = self.signal_ema.next(macd)?;
+
+        self.current = Option::Some(MovingAverageConvergenceDivergenceOutput {
+            macd,
+            signal,
+            histogram: macd - signal,
+        });
+        self.current.clone()
+    }
+}
+
+impl IndicatorTrait for MovingAverageConvergenceDivergence {
+    type Output = MovingAverageConvergenceDivergenceOutput;
+
+    fn update(&mut self, input: &IndicatorInput) -> Option<Self::Output> {
+        self.next(input.close)
+    }
+
+    fn value(&self) -> Option<Self::Output> {
+        self.current.clone()
+    }
+
+    fn reset(&mut self) {
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+        self.signal_ema.reset();
+        self.current = Option::None;
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::Error;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use super::common::indicator::{check_period, IndicatorInput, IndicatorTrait};
+
+// Wilder's smoothing, the first average is the simple average of the first `period` changes
+pub struct RelativeStrengthIndex {
+    period: usize,
+    previous_close: Option<Decimal>,
+    change_count: usize,
+    average_gain: Decimal,
+    average_loss: Decimal,
+}
+
+impl RelativeStrengthIndex {
+    pub fn new(period: usize) -> Result<Self, Error> {
+        check_period(period)?;
+        Result::Ok(RelativeStrengthIndex {
+            period,
+            previous_close: Option::None,
+            change_count: 0,
+            average_gain: Decimal::ZERO,
+            average_loss: Decimal::ZERO,
+        })
+    }
+
+    pub fn next(&mut self, close: Decimal) -> Option<Decimal> {
+        let previous_close = self.previous_close.replace(close)?;
+        let change = close - previous_close;
+        let gain = change.max(Decimal::ZERO);
+        let loss = (-change).max(Decimal::ZERO);
+        let period = Decimal::from(self.period);
+
+        self.change_count += 1;
+        if self.change_count <= self.period {
+            self.average_gain += gain;
+            self.average_loss += loss;
+            if self.change_count == self.period {
+                self.average_gain /= period;
+                self.average_loss /= period;
+            }
+        } else {
+            self.average_gain = (self.average_gain * (period - Decimal::ONE) + gain) / period;
+            self.average_loss = (self.average_loss * (period - Decimal::ONE) + loss) / period;
+        }
+        self.current()
+    }
+
+    fn current(&self) -> Option<Decimal> {
+        if self.change_count < self.period {
+            return Option::None;
+        }
+        if self.average_loss.is_zero() {
+            return Option::Some(dec!(100));
+        }
+        let relative_strength = self.average_gain / self.average_loss;
+        Option::Some(dec!(100) - dec!(100) / (Decimal::ONE + relative_strength))
+    }
+}
+
+impl IndicatorTrait for RelativeStrengthIndex {
+    type Output = Decimal;
+
+    fn update(&mut self, input: &IndicatorInput) -> Option<Decimal> {
+        self.next(input.close)
+    }
+
+    fn value(&self) -> Option<Decimal> {
+        self.current()
+    }
+
+    fn reset(&mut self) {
+        self.previous_close = Option::None;
+        self.change_count = 0;
+        self.average_gain = Decimal::ZERO;
+        self.average_loss = Decimal::ZERO;
+    }
+}
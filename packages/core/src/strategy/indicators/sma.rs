@@ -0,0 +1,57 @@
+use anyhow::Error;
+use rust_decimal::Decimal;
+use std::collections::VecDeque;
+
+use super::common::indicator::{check_period, IndicatorInput, IndicatorTrait};
+
+pub struct SimpleMovingAverage {
+    period: usize,
+    window: VecDeque<Decimal>,
+    sum: Decimal,
+}
+
+impl SimpleMovingAverage {
+    pub fn new(period: usize) -> Result<Self, Error> {
+        check_period(period)?;
+        Result::Ok(SimpleMovingAverage {
+            period,
+            window: VecDeque::with_capacity(period),
+            sum: Decimal::ZERO,
+        })
+    }
+
+    pub fn next(&mut self, value: Decimal) -> Option<Decimal> {
+        self.window.push_back(value);
+        self.sum += value;
+        if self.window.len() > self.period {
+            if let Some(oldest) = self.window.pop_front() {
+                self.sum -= oldest;
+            }
+        }
+        self.current()
+    }
+
+    fn current(&self) -> Option<Decimal> {
+        if self.window.len() < self.period {
+            return Option::None;
+        }
+        Option::Some(self.sum / Decimal::from(self.period))
+    }
+}
+
+impl IndicatorTrait for SimpleMovingAverage {
+    type Output = Decimal;
+
+    fn update(&mut self, input: &IndicatorInput) -> Option<Decimal> {
+        self.next(input.close)
+    }
+
+    fn value(&self) -> Option<Decimal> {
+        self.current()
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sum = Decimal::ZERO;
+    }
+}
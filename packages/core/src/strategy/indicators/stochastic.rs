@@ -0,0 +1,72 @@
+use anyhow::Error;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::collections::VecDeque;
+
+use super::{
+    common::indicator::{check_period, IndicatorInput, IndicatorTrait},
+    sma::SimpleMovingAverage,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StochasticOscillatorOutput {
+    pub k: Decimal,
+    pub d: Decimal,
+}
+
+pub struct StochasticOscillator {
+    k_period: usize,
+    window: VecDeque<(Decimal, Decimal)>,
+    d_sma: SimpleMovingAverage,
+    current: Option<StochasticOscillatorOutput>,
+}
+
+impl StochasticOscillator {
+    pub fn new(k_period: usize, d_period: usize) -> Result<Self, Error> {
+        check_period(k_period)?;
+        Result::Ok(StochasticOscillator {
+            k_period,
+            window: VecDeque::with_capacity(k_period),
+            d_sma: SimpleMovingAverage::new(d_period)?,
+            current: Option::None,
+        })
+    }
+
+    fn next_k(&mut self, input: &IndicatorInput) -> Option<Decimal> {
+        self.window.push_back((input.high, input.low));
+        if self.window.len() > self.k_period {
+            self.window.pop_front();
+        }
+        if self.window.len() < self.k_period {
+            return Option::None;
+        }
+
+        let highest = self.window.iter().map(|(high, _)| *high).max()?;
+        let lowest = self.window.iter().map(|(_, low)| *low).min()?;
+        if highest == lowest {
+            return Option::Some(dec!(50));
+        }
+        Option::Some(dec!(100) * (input.close - lowest) / (highest - lowest))
+    }
+}
+
+impl IndicatorTrait for StochasticOscillator {
+    type Output = StochasticOscillatorOutput;
+
+    fn update(&mut self, input: &IndicatorInput) -> Option<Self::Output> {
+        let k = self.next_k(input)?;
+        let d = self.d_sma.next(k)?;
+        self.current = Option::Some(StochasticOscillatorOutput { k, d });
+        self.current.clone()
+    }
+
+    fn value(&self) -> Option<Self::Output> {
+        self.current.clone()
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.d_sma.reset();
+        self.current = Option::None;
+    }
+}
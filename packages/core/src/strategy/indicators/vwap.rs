@@ -0,0 +1,76 @@
+use rust_decimal::Decimal;
+
+use super::common::indicator::{IndicatorInput, IndicatorTrait};
+use crate::model::trading::quote::QuoteRealTimeInfo;
+
+// Accumulates from the first update until `reset`, call `reset` at every session start
+pub struct VolumeWeightedAveragePrice {
+    price_volume_sum: Decimal,
+    volume_sum: Decimal,
+    last_cumulative_volume: Option<Decimal>,
+}
+
+impl Default for VolumeWeightedAveragePrice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VolumeWeightedAveragePrice {
+    pub fn new() -> Self {
+        VolumeWeightedAveragePrice {
+            price_volume_sum: Decimal::ZERO,
+            volume_sum: Decimal::ZERO,
+            last_cumulative_volume: Option::None,
+        }
+    }
+
+    pub fn next(&mut self, price: Decimal, volume: Decimal) -> Option<Decimal> {
+        if volume.is_sign_positive() {
+            self.price_volume_sum += price * volume;
+            self.volume_sum += volume;
+        }
+        self.current()
+    }
+
+    fn current(&self) -> Option<Decimal> {
+        if self.volume_sum.is_zero() {
+            return Option::None;
+        }
+        Option::Some(self.price_volume_sum / self.volume_sum)
+    }
+}
+
+impl IndicatorTrait for VolumeWeightedAveragePrice {
+    type Output = Decimal;
+
+    fn update(&mut self, input: &IndicatorInput) -> Option<Decimal> {
+        let typical_price = (input.high + input.low + input.close) / Decimal::from(3);
+        self.next(typical_price, input.volume.unwrap_or(Decimal::ZERO))
+    }
+
+    // The volume of a quote is the accumulated volume of the session, only the delta is traded at this price
+    fn update_quote_real_time_info(&mut self, quote_info: &QuoteRealTimeInfo) -> Option<Decimal> {
+        let cumulative_volume = match quote_info.volume {
+            Some(volume) => volume,
+            None => return self.current(),
+        };
+        let volume = match self.last_cumulative_volume.replace(cumulative_volume) {
+            Some(last_cumulative_volume) if cumulative_volume >= last_cumulative_volume => {
+                cumulative_volume - last_cumulative_volume
+            }
+            _ => cumulative_volume,
+        };
+        self.next(quote_info.current_price, volume)
+    }
+
+    fn value(&self) -> Option<Decimal> {
+        self.current()
+    }
+
+    fn reset(&mut self) {
+        self.price_volume_sum = Decimal::ZERO;
+        self.volume_sum = Decimal::ZERO;
+        self.last_cumulative_volume = Option::None;
+    }
+}
@@ -0,0 +1,68 @@
+use anyhow::Error;
+use rust_decimal::Decimal;
+use std::collections::VecDeque;
+
+use super::common::indicator::{check_period, IndicatorInput, IndicatorTrait};
+
+// Linear weights 1..=period, the newest value has the largest weight
+pub struct WeightedMovingAverage {
+    period: usize,
+    window: VecDeque<Decimal>,
+    sum: Decimal,
+    weighted_sum: Decimal,
+}
+
+impl WeightedMovingAverage {
+    pub fn new(period: usize) -> Result<Self, Error> {
+        check_period(period)?;
+        Result::Ok(WeightedMovingAverage {
+            period,
+            window: VecDeque::with_capacity(period),
+            sum: Decimal::ZERO,
+            weighted_sum: Decimal::ZERO,
+        })
+    }
+
+    pub fn next(&mut self, value: Decimal) -> Option<Decimal> {
+        if self.window.len() < self.period {
+            self.window.push_back(value);
+            self.weighted_sum += Decimal::from(self.window.len()) * value;
+            self.sum += value;
+        } else {
+            // every existing weight decreases by one, and the oldest one drops to zero
+            self.weighted_sum += Decimal::from(self.period) * value - self.sum;
+            if let Some(oldest) = self.window.pop_front() {
+                self.sum -= oldest;
+            }
+            self.window.push_back(value);
+            self.sum += value;
+        }
+        self.current()
+    }
+
+    fn current(&self) -> Option<Decimal> {
+        if self.window.len() < self.period {
+            return Option::None;
+        }
+        let denominator = Decimal::from(self.period * (self.period + 1) / 2);
+        Option::Some(self.weighted_sum / denominator)
+    }
+}
+
+impl IndicatorTrait for WeightedMovingAverage {
+    type Output = Decimal;
+
+    fn update(&mut self, input: &IndicatorInput) -> Option<Decimal> {
+        self.next(input.close)
+    }
+
+    fn value(&self) -> Option<Decimal> {
+        self.current()
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sum = Decimal::ZERO;
+        self.weighted_sum = Decimal::ZERO;
+    }
+}
@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Error};
 
 use super::common::strategy::{StrategyContext, StrategyTrait};
+use crate::model::common::config_key::ConfigKeySpec;
 
 #[cfg(feature = "strategy__example")]
 use super::example::{grid_trading::GridTradingStrategy, print_live_price::PrintLivePriceStrategy};
@@ -23,3 +24,28 @@ pub fn get_strategy_instance(
         _ => Result::Err(anyhow!("IDENTIFIER_NOT_MATCHED Strategy: {}", identifier)),
     }
 }
+
+pub fn get_strategy_identifier_list() -> Vec<String> {
+    vec![
+        #[cfg(feature = "strategy__example")]
+        PrintLivePriceStrategy::get_identifier(),
+        #[cfg(feature = "strategy__example")]
+        GridTradingStrategy::get_identifier(),
+    ]
+}
+
+pub fn get_strategy_config_key_list(identifier: &str) -> Result<Vec<ConfigKeySpec>, Error> {
+    match identifier {
+        #[cfg(feature = "strategy__example")]
+        identifier if identifier == PrintLivePriceStrategy::get_identifier() => {
+            Result::Ok(PrintLivePriceStrategy::get_config_key_list())
+        }
+
+        #[cfg(feature = "strategy__example")]
+        identifier if identifier == GridTradingStrategy::get_identifier() => {
+            Result::Ok(GridTradingStrategy::get_config_key_list())
+        }
+
+        _ => Result::Err(anyhow!("IDENTIFIER_NOT_MATCHED Strategy: {}", identifier)),
+    }
+}
@@ -1,4 +1,6 @@
 pub mod common;
+pub mod execution;
+pub mod indicators;
 pub mod initializer;
 
 #[cfg(feature = "strategy__example")]
@@ -0,0 +1,94 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::collections::HashMap;
+
+use crate::{
+    broker::common::fee::{
+        get_hk_market_fee_item_list, get_us_regulatory_fee_item_list, FeeModelTrait, NoFeeModel,
+    },
+    model::trading::{
+        currency::Currency,
+        fee::{CalculateFeeRequest, FeeKind},
+        market::Market,
+        symbol::Symbol,
+        transaction::Direction,
+    },
+};
+
+pub fn get_calculate_fee_request(
+    market: Market,
+    direction: Direction,
+    quantity: Decimal,
+    price: Decimal,
+) -> CalculateFeeRequest {
+    CalculateFeeRequest {
+        symbol: Symbol {
+            market,
+            identifier: "ABNB".to_owned(),
+        },
+        direction,
+        quantity,
+        price,
+    }
+}
+
+#[test]
+fn test_no_fee_model() {
+    let fee = NoFeeModel::new(HashMap::new())
+        .calculate_fee(&get_calculate_fee_request(
+            Market::HK,
+            Direction::Buy,
+            dec!(100),
+            dec!(300),
+        ))
+        .unwrap();
+    assert_eq!(Currency::HKD, fee.currency);
+    assert_eq!(Decimal::ZERO, fee.get_total());
+}
+
+#[test]
+fn test_get_hk_market_fee_item_list() {
+    let item_list = get_hk_market_fee_item_list(&get_calculate_fee_request(
+        Market::HK,
+        Direction::Sell,
+        dec!(100),
+        dec!(300),
+    ));
+    let amount_list: Vec<(FeeKind, Decimal)> = item_list
+        .into_iter()
+        .map(|item| (item.kind, item.amount))
+        .collect();
+    assert_eq!(
+        vec![
+            (FeeKind::StampDuty, dec!(30)),
+            (FeeKind::TradingFee, dec!(1.70)),
+            (FeeKind::SfcLevy, dec!(0.81)),
+            (FeeKind::AfrcLevy, dec!(0.05)),
+            (FeeKind::SettlementFee, dec!(2)),
+        ],
+        amount_list
+    );
+}
+
+#[test]
+fn test_get_us_regulatory_fee_item_list() {
+    assert!(get_us_regulatory_fee_item_list(&get_calculate_fee_request(
+        Market::US,
+        Direction::Buy,
+        dec!(100),
+        dec!(150),
+    ))
+    .is_empty());
+
+    let item_list = get_us_regulatory_fee_item_list(&get_calculate_fee_request(
+        Market::US,
+        Direction::Sell,
+        dec!(100_000),
+        dec!(150),
+    ));
+    assert_eq!(FeeKind::SecFee, item_list[0].kind);
+    assert_eq!(dec!(417), item_list[0].amount);
+    // capped per trade
+    assert_eq!(FeeKind::FinraTaf, item_list[1].kind);
+    assert_eq!(dec!(8.30), item_list[1].amount);
+}
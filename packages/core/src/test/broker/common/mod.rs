@@ -0,0 +1,3 @@
+pub mod fee;
+pub mod order_emulator;
+pub mod test_helper;
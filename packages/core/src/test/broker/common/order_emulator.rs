@@ -0,0 +1,292 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::{
+    collections::HashMap,
+    sync::{atomic::AtomicBool, Arc, Mutex},
+};
+
+use super::test_helper::{MockInfo, MockTransaction};
+use crate::{
+    broker::common::{order_emulator::OrderEmulatorTransaction, transaction::TransactionTrait},
+    model::{
+        common::{timestamp::Timestamp, types::ConfigMap},
+        trading::{
+            market::Market,
+            symbol::Symbol,
+            transaction::{
+                CancelOrderRequest, Direction, Expire, OrderDetail, OrderDetailRequest,
+                OrderStatus, Price, RegularTradingTime, SubmitBracketOrderRequest,
+                SubmitOcoOrderRequest, SubmitOrderRequest,
+            },
+        },
+    },
+    utils::clock::manual::ManualClock,
+};
+
+type OrderMap = Arc<Mutex<HashMap<String, OrderDetail>>>;
+
+fn get_unquoted_symbol() -> Symbol {
+    Symbol {
+        market: Market::US,
+        identifier: "UNQUOTED".to_owned(),
+    }
+}
+
+// The manual clock is never advanced, so the background polling never runs
+fn get_order_emulator_transaction() -> (OrderEmulatorTransaction, OrderMap, Arc<Mutex<Decimal>>) {
+    let order_map: OrderMap = Arc::new(Mutex::new(HashMap::new()));
+    let current_price = Arc::new(Mutex::new(dec!(100)));
+    let order_emulator_transaction = OrderEmulatorTransaction::new(
+        Box::new(MockTransaction {
+            order_map: order_map.clone(),
+            is_cancel_delayed: false,
+        }),
+        Box::new(MockInfo {
+            current_price: current_price.clone(),
+            unquoted_symbol: Option::Some(get_unquoted_symbol()),
+        }),
+        &ConfigMap::new(),
+        Arc::new(AtomicBool::new(false)),
+        Arc::new(ManualClock::new_with_timestamp(Timestamp::UNIX_EPOCH)),
+    );
+    (order_emulator_transaction, order_map, current_price)
+}
+
+fn get_submit_order_request(direction: Direction, price: Price) -> SubmitOrderRequest {
+    SubmitOrderRequest {
+        symbol: Symbol {
+            market: Market::US,
+            identifier: "ABNB".to_owned(),
+        },
+        quantity: dec!(100),
+        direction,
+        regular_trading_time: RegularTradingTime::AllTime,
+        expire: Expire::Day,
+        price,
+    }
+}
+
+async fn get_status(transaction: &OrderEmulatorTransaction, order_id: &str) -> OrderStatus {
+    transaction
+        .order_detail(OrderDetailRequest {
+            order_id: order_id.to_owned(),
+        })
+        .await
+        .unwrap()
+        .status
+}
+
+#[test]
+fn test_is_triggered() {
+    let stop = Price::Stop {
+        stop_price: dec!(95),
+    };
+    assert!(OrderEmulatorTransaction::is_triggered(
+        &Direction::Sell,
+        &stop,
+        dec!(95)
+    ));
+    assert!(!OrderEmulatorTransaction::is_triggered(
+        &Direction::Sell,
+        &stop,
+        dec!(96)
+    ));
+    assert!(OrderEmulatorTransaction::is_triggered(
+        &Direction::Buy,
+        &stop,
+        dec!(96)
+    ));
+    assert!(!OrderEmulatorTransaction::is_triggered(
+        &Direction::Buy,
+        &Price::LimitOrder { price: dec!(95) },
+        dec!(96)
+    ));
+    assert_eq!(
+        Price::LimitOrder { price: dec!(94) },
+        OrderEmulatorTransaction::get_triggered_price(&Price::StopLimit {
+            stop_price: dec!(95),
+            limit_price: dec!(94),
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_stop_order() {
+    let (mut transaction, order_map, current_price) = get_order_emulator_transaction();
+    let order_id = transaction
+        .submit_order(get_submit_order_request(
+            Direction::Sell,
+            Price::Stop {
+                stop_price: dec!(95),
+            },
+        ))
+        .await
+        .unwrap()
+        .order_id;
+    assert!(OrderEmulatorTransaction::is_local_order_id(&order_id));
+    assert_eq!(
+        OrderStatus::Pending,
+        get_status(&transaction, &order_id).await
+    );
+    assert_eq!(1, transaction.list_open_orders().await.unwrap().len());
+
+    transaction.poll().await.unwrap();
+    assert!(order_map.lock().unwrap().is_empty());
+
+    *current_price.lock().unwrap() = dec!(94.5);
+    transaction.poll().await.unwrap();
+    let order_detail = transaction
+        .order_detail(OrderDetailRequest {
+            order_id: order_id.clone(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(order_id, order_detail.order_id);
+    assert_eq!(OrderStatus::Submitted, order_detail.status);
+    assert_eq!(Price::MarketOrder, order_detail.price);
+}
+
+#[tokio::test]
+async fn test_bracket_order() {
+    let (mut transaction, order_map, current_price) = get_order_emulator_transaction();
+    let response = transaction
+        .submit_bracket_order(SubmitBracketOrderRequest {
+            entry: get_submit_order_request(Direction::Buy, Price::LimitOrder { price: dec!(100) }),
+            take_profit: Option::Some(Price::LimitOrder { price: dec!(110) }),
+            stop_loss: Option::Some(Price::Stop {
+                stop_price: dec!(90),
+            }),
+        })
+        .await
+        .unwrap();
+    let take_profit_order_id = response.take_profit_order_id.unwrap();
+    let stop_loss_order_id = response.stop_loss_order_id.unwrap();
+    assert_eq!(1, order_map.lock().unwrap().len());
+
+    // children wait for the entry order
+    transaction.poll().await.unwrap();
+    assert_eq!(1, order_map.lock().unwrap().len());
+
+    MockTransaction::fill(&order_map, "mock_1");
+    transaction.poll().await.unwrap();
+    assert_eq!(2, order_map.lock().unwrap().len());
+    assert_eq!(
+        Direction::Sell,
+        order_map.lock().unwrap()["mock_2"].direction
+    );
+    assert_eq!(
+        OrderStatus::Submitted,
+        get_status(&transaction, &take_profit_order_id).await
+    );
+    assert_eq!(
+        OrderStatus::Pending,
+        get_status(&transaction, &stop_loss_order_id).await
+    );
+
+    // the take profit fill cancels the stop loss before it is triggered
+    MockTransaction::fill(&order_map, "mock_2");
+    *current_price.lock().unwrap() = dec!(80);
+    transaction.poll().await.unwrap();
+    assert_eq!(2, order_map.lock().unwrap().len());
+    assert_eq!(
+        OrderStatus::Cancelled,
+        get_status(&transaction, &stop_loss_order_id).await
+    );
+}
+
+#[tokio::test]
+async fn test_bracket_order_entry_cancelled() {
+    let (mut transaction, order_map, _) = get_order_emulator_transaction();
+    let response = transaction
+        .submit_bracket_order(SubmitBracketOrderRequest {
+            entry: get_submit_order_request(Direction::Buy, Price::LimitOrder { price: dec!(100) }),
+            take_profit: Option::Some(Price::LimitOrder { price: dec!(110) }),
+            stop_loss: Option::None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(Option::None, response.stop_loss_order_id);
+
+    transaction
+        .cancel_order(CancelOrderRequest {
+            order_id: response.entry_order_id.clone(),
+        })
+        .await
+        .unwrap();
+    transaction.poll().await.unwrap();
+    assert_eq!(1, order_map.lock().unwrap().len());
+    assert_eq!(
+        OrderStatus::Cancelled,
+        get_status(&transaction, &response.take_profit_order_id.unwrap()).await
+    );
+}
+
+#[tokio::test]
+async fn test_oco_order() {
+    let (mut transaction, order_map, _) = get_order_emulator_transaction();
+    let response = transaction
+        .submit_oco_order(SubmitOcoOrderRequest {
+            order_list: vec![
+                get_submit_order_request(Direction::Buy, Price::LimitOrder { price: dec!(90) }),
+                get_submit_order_request(Direction::Buy, Price::LimitOrder { price: dec!(95) }),
+            ],
+        })
+        .await
+        .unwrap();
+    assert_eq!(2, response.order_id_list.len());
+    assert_eq!(2, order_map.lock().unwrap().len());
+
+    MockTransaction::fill(&order_map, "mock_2");
+    transaction.poll().await.unwrap();
+    assert_eq!(
+        OrderStatus::Cancelled,
+        order_map.lock().unwrap()["mock_1"].status
+    );
+    assert_eq!(
+        OrderStatus::Filled,
+        get_status(&transaction, &response.order_id_list[1]).await
+    );
+}
+
+#[tokio::test]
+async fn test_poll_with_failed_order() {
+    let (mut transaction, order_map, current_price) = get_order_emulator_transaction();
+    let unquoted_order_id = transaction
+        .submit_order(SubmitOrderRequest {
+            symbol: get_unquoted_symbol(),
+            ..get_submit_order_request(
+                Direction::Sell,
+                Price::Stop {
+                    stop_price: dec!(95),
+                },
+            )
+        })
+        .await
+        .unwrap()
+        .order_id;
+    let order_id = transaction
+        .submit_order(get_submit_order_request(
+            Direction::Sell,
+            Price::Stop {
+                stop_price: dec!(95),
+            },
+        ))
+        .await
+        .unwrap()
+        .order_id;
+
+    // the order without quote is reported, the other one is still triggered
+    *current_price.lock().unwrap() = dec!(94.5);
+    let err = transaction.poll().await.unwrap_err();
+    assert!(err.to_string().starts_with("EMULATION_ERROR"));
+    assert!(err.to_string().contains(&unquoted_order_id));
+    assert_eq!(1, order_map.lock().unwrap().len());
+    assert_eq!(
+        OrderStatus::Submitted,
+        get_status(&transaction, &order_id).await
+    );
+    assert_eq!(
+        OrderStatus::Pending,
+        get_status(&transaction, &unquoted_order_id).await
+    );
+}
@@ -0,0 +1,246 @@
+use anyhow::{anyhow, Error};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    broker::common::{info::InfoTrait, transaction::TransactionTrait},
+    model::{
+        common::{timestamp::Timestamp, types::ConfigMap},
+        trading::{
+            balance::BalanceHashMap,
+            currency::Currency,
+            position::{Position, PositionList},
+            quote::{QueryInfoRequest, QuoteBasicInfo, QuoteDepthInfo, QuoteRealTimeInfo},
+            symbol::Symbol,
+            transaction::{
+                BuyingPower, CancelOrderRequest, CancelOrderResponse, Direction, EditOrderRequest,
+                EditOrderResponse, EstimateMaxBuyingPowerRequest, Execution, ListExecutionsRequest,
+                ListOrderHistoryRequest, OrderDetail, OrderDetailRequest, OrderStatus,
+                SubmitBracketOrderRequest, SubmitBracketOrderResponse, SubmitOcoOrderRequest,
+                SubmitOcoOrderResponse, SubmitOrderRequest, SubmitOrderResponse,
+            },
+        },
+    },
+    utils::clock::common_trait::ClockTrait,
+};
+
+// Keeps orders in memory, tests fill them through the shared order map
+pub struct MockTransaction {
+    pub order_map: Arc<Mutex<HashMap<String, OrderDetail>>>,
+    // cancels are accepted but leave the orders open, tests close them through the order map
+    pub is_cancel_delayed: bool,
+}
+
+impl MockTransaction {
+    pub fn fill(order_map: &Arc<Mutex<HashMap<String, OrderDetail>>>, order_id: &str) {
+        let mut order_map = order_map.lock().unwrap();
+        let order_detail = order_map.get_mut(order_id).unwrap();
+        order_detail.executed_quantity = order_detail.quantity;
+        order_detail.status = OrderStatus::Filled;
+    }
+}
+
+#[async_trait]
+impl TransactionTrait for MockTransaction {
+    fn new(_config_map: ConfigMap, _clock: Arc<dyn ClockTrait>) -> Self {
+        MockTransaction {
+            order_map: Arc::new(Mutex::new(HashMap::new())),
+            is_cancel_delayed: false,
+        }
+    }
+
+    async fn account_balance(&self) -> Result<BalanceHashMap, Error> {
+        Result::Ok(BalanceHashMap::new())
+    }
+
+    // nets the executed quantity of every order by symbol
+    async fn positions(&self) -> Result<PositionList, Error> {
+        let mut quantity_map: HashMap<_, Decimal> = HashMap::new();
+        for order_detail in self.order_map.lock().unwrap().values() {
+            let quantity = match order_detail.direction {
+                Direction::Buy => order_detail.executed_quantity,
+                Direction::Sell => -order_detail.executed_quantity,
+            };
+            *quantity_map.entry(order_detail.symbol.clone()).or_default() += quantity;
+        }
+        Result::Ok(
+            quantity_map
+                .into_iter()
+                .map(|(symbol, quantity)| Position {
+                    symbol,
+                    currency: Currency::USD,
+                    cost_price: Decimal::ZERO,
+                    quantity,
+                })
+                .collect(),
+        )
+    }
+
+    async fn estimate_max_buying_power(
+        &self,
+        request: EstimateMaxBuyingPowerRequest,
+    ) -> Result<BuyingPower, Error> {
+        Result::Err(anyhow!("Not supported by the mock, {:?}", request))
+    }
+
+    async fn order_detail(&self, request: OrderDetailRequest) -> Result<OrderDetail, Error> {
+        self.order_map
+            .lock()
+            .unwrap()
+            .get(&request.order_id)
+            .cloned()
+            .ok_or_else(|| anyhow!("Order not found, {:?}", request))
+    }
+
+    async fn list_open_orders(&self) -> Result<Vec<OrderDetail>, Error> {
+        Result::Ok(
+            self.order_map
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|order_detail| order_detail.status.is_open())
+                .cloned()
+                .collect(),
+        )
+    }
+
+    async fn list_order_history(
+        &self,
+        request: ListOrderHistoryRequest,
+    ) -> Result<Vec<OrderDetail>, Error> {
+        Result::Ok(
+            self.order_map
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|order_detail| request.is_matched(order_detail))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    async fn list_executions(
+        &self,
+        _request: ListExecutionsRequest,
+    ) -> Result<Vec<Execution>, Error> {
+        Result::Ok(vec![])
+    }
+
+    async fn submit_order(
+        &mut self,
+        request: SubmitOrderRequest,
+    ) -> Result<SubmitOrderResponse, Error> {
+        let mut order_map = self.order_map.lock().unwrap();
+        let order_id = format!("mock_{}", order_map.len() + 1);
+        order_map.insert(
+            order_id.clone(),
+            OrderDetail {
+                order_id: order_id.clone(),
+                symbol: request.symbol,
+                currency: Currency::USD,
+                quantity: request.quantity,
+                executed_quantity: Decimal::ZERO,
+                price: request.price,
+                executed_price: Option::None,
+                fee: Option::None,
+                direction: request.direction,
+                regular_trading_time: request.regular_trading_time,
+                expire: request.expire,
+                status: OrderStatus::Submitted,
+                created_timestamp: Option::None,
+                updated_timestamp: Option::None,
+                triggered_timestamp: Option::None,
+            },
+        );
+        Result::Ok(SubmitOrderResponse { order_id })
+    }
+
+    async fn submit_bracket_order(
+        &mut self,
+        request: SubmitBracketOrderRequest,
+    ) -> Result<SubmitBracketOrderResponse, Error> {
+        Result::Err(anyhow!("Not supported by the mock, {:?}", request))
+    }
+
+    async fn submit_oco_order(
+        &mut self,
+        request: SubmitOcoOrderRequest,
+    ) -> Result<SubmitOcoOrderResponse, Error> {
+        Result::Err(anyhow!("Not supported by the mock, {:?}", request))
+    }
+
+    async fn edit_order(&mut self, request: EditOrderRequest) -> Result<EditOrderResponse, Error> {
+        let mut order_map = self.order_map.lock().unwrap();
+        let order_detail = order_map
+            .get_mut(&request.order_id)
+            .ok_or_else(|| anyhow!("Order not found, {:?}", request))?;
+        order_detail.quantity = request.quantity;
+        order_detail.price = request.price;
+        Result::Ok(EditOrderResponse {})
+    }
+
+    async fn cancel_order(
+        &mut self,
+        request: CancelOrderRequest,
+    ) -> Result<CancelOrderResponse, Error> {
+        let mut order_map = self.order_map.lock().unwrap();
+        let order_detail = order_map
+            .get_mut(&request.order_id)
+            .ok_or_else(|| anyhow!("Order not found, {:?}", request))?;
+        if !self.is_cancel_delayed {
+            order_detail.status = OrderStatus::Cancelled;
+        }
+        Result::Ok(CancelOrderResponse {})
+    }
+}
+
+// Quotes every symbol at the shared current price
+// Quotes every symbol at the shared current price, except the unquoted one
+pub struct MockInfo {
+    pub current_price: Arc<Mutex<Decimal>>,
+    pub unquoted_symbol: Option<Symbol>,
+}
+
+#[async_trait]
+impl InfoTrait for MockInfo {
+    fn new(_config_map: ConfigMap, _clock: Arc<dyn ClockTrait>) -> Self {
+        MockInfo {
+            current_price: Arc::new(Mutex::new(Decimal::ZERO)),
+            unquoted_symbol: Option::None,
+        }
+    }
+
+    async fn query_basic_info(&self, request: QueryInfoRequest) -> Result<QuoteBasicInfo, Error> {
+        Result::Err(anyhow!("Not supported by the mock, {:?}", request))
+    }
+
+    async fn query_real_time_info(
+        &self,
+        request: QueryInfoRequest,
+    ) -> Result<QuoteRealTimeInfo, Error> {
+        if self.unquoted_symbol.as_ref() == Option::Some(&request.symbol) {
+            return Result::Err(anyhow!("QUOTE_NOT_FOUND {:?}", request.symbol));
+        }
+        Result::Ok(QuoteRealTimeInfo {
+            symbol: request.symbol,
+            sequence: 0,
+            timestamp: Timestamp::UNIX_EPOCH,
+            current_price: *self.current_price.lock().unwrap(),
+            volume: Option::None,
+            low_price: Option::None,
+            high_price: Option::None,
+            open_price: Option::None,
+            prev_close: Option::None,
+            turnover: Option::None,
+            extra: Option::None,
+        })
+    }
+
+    async fn query_depth(&self, request: QueryInfoRequest) -> Result<QuoteDepthInfo, Error> {
+        Result::Err(anyhow!("Not supported by the mock, {:?}", request))
+    }
+}
@@ -3,14 +3,19 @@ use std::{
     sync::{atomic::AtomicBool, Arc},
 };
 
-use crate::broker::{
-    common::broker::EmptyBrokerInterceptorFactory, initializer::get_broker_instance,
+use crate::{
+    broker::{
+        common::broker::EmptyBrokerInterceptorFactory,
+        initializer::{get_broker_instance, get_fee_model_instance},
+    },
+    utils::clock::{common_trait::ClockTrait, system::SystemClock},
 };
 
 #[test]
 fn test_get_broker_instance() {
     const LONGBRIDGE_IDENTIFIER: &'static str = "longbridge";
     const YAHOO_FINANCE_IDENTIFIER: &'static str = "yahoo_finance";
+    const REPLAY_IDENTIFIER: &'static str = "replay";
 
     assert_eq!(
         cfg!(feature = "broker__longbridge"),
@@ -19,6 +24,7 @@ fn test_get_broker_instance() {
             Box::new(EmptyBrokerInterceptorFactory::new()),
             HashMap::new(),
             Arc::new(AtomicBool::new(false)),
+            Arc::new(SystemClock::new(HashMap::new())),
         )
         .is_ok()
     );
@@ -29,7 +35,33 @@ fn test_get_broker_instance() {
             Box::new(EmptyBrokerInterceptorFactory::new()),
             HashMap::new(),
             Arc::new(AtomicBool::new(false)),
+            Arc::new(SystemClock::new(HashMap::new())),
         )
         .is_ok()
     );
+    assert_eq!(
+        cfg!(feature = "broker__replay"),
+        get_broker_instance(
+            REPLAY_IDENTIFIER.to_owned(),
+            Box::new(EmptyBrokerInterceptorFactory::new()),
+            HashMap::new(),
+            Arc::new(AtomicBool::new(false)),
+            Arc::new(SystemClock::new(HashMap::new())),
+        )
+        .is_ok()
+    );
+}
+
+#[test]
+fn test_get_fee_model_instance() {
+    assert!(get_fee_model_instance("NoFeeModel".to_owned(), HashMap::new()).is_ok());
+    assert_eq!(
+        cfg!(feature = "broker__longbridge"),
+        get_fee_model_instance("LongBridgeFeeModel".to_owned(), HashMap::new()).is_ok()
+    );
+    assert_eq!(
+        cfg!(feature = "broker__interactive_brokers"),
+        get_fee_model_instance("InteractiveBrokersFeeModel".to_owned(), HashMap::new()).is_ok()
+    );
+    assert!(get_fee_model_instance("Unknown".to_owned(), HashMap::new()).is_err());
 }
@@ -0,0 +1,68 @@
+use ibkr_client_portal::model::contract::{TradingSchedule, TradingSession, TradingTime};
+use time::macros::{date, time};
+
+use crate::{
+    broker::interactive_brokers::calendar::InteractiveBrokersTradingCalendar,
+    utils::calendar::{common_trait::TradingSessionKind, schedule::SessionTime},
+};
+
+fn get_trading_schedule(date: &str, session_list: Vec<(&str, &str)>) -> TradingSchedule {
+    TradingSchedule {
+        clearing_cycle_end_time: Option::None,
+        trading_schedule_date: Option::Some(date.to_owned()),
+        sessions: Option::Some(
+            session_list
+                .into_iter()
+                .map(|(opening_time, closing_time)| TradingSession {
+                    opening_time: Option::Some(opening_time.to_owned()),
+                    closing_time: Option::Some(closing_time.to_owned()),
+                    prop: Option::None,
+                })
+                .collect(),
+        ),
+        trading_times: Option::Some(TradingTime {
+            opening_time: Option::Some("0900".to_owned()),
+            closing_time: Option::Some("1610".to_owned()),
+            cancel_day_orders: Option::None,
+        }),
+    }
+}
+
+#[test]
+fn test_trading_schedule_to_session_list() {
+    let trading_schedule =
+        get_trading_schedule("20240702", vec![("1300", "1600"), ("0930", "1200")]);
+    assert_eq!(
+        Option::Some(date!(2024 - 07 - 02)),
+        InteractiveBrokersTradingCalendar::parse_schedule_date(&trading_schedule)
+    );
+    assert_eq!(
+        vec![
+            SessionTime::new(TradingSessionKind::PreMarket, time!(09:00), time!(09:30)),
+            SessionTime::new(TradingSessionKind::Regular, time!(09:30), time!(12:00)),
+            SessionTime::new(TradingSessionKind::LunchBreak, time!(12:00), time!(13:00)),
+            SessionTime::new(TradingSessionKind::Regular, time!(13:00), time!(16:00)),
+            SessionTime::new(TradingSessionKind::AfterHours, time!(16:00), time!(16:10)),
+        ],
+        InteractiveBrokersTradingCalendar::trading_schedule_to_session_list(&trading_schedule)
+            .unwrap()
+    );
+
+    // weekday templates are skipped and days without liquid hours are closed
+    let trading_schedule = get_trading_schedule("20000103", vec![]);
+    assert_eq!(
+        Option::None,
+        InteractiveBrokersTradingCalendar::parse_schedule_date(&trading_schedule)
+    );
+    assert!(
+        InteractiveBrokersTradingCalendar::trading_schedule_to_session_list(&trading_schedule)
+            .unwrap()
+            .is_empty()
+    );
+
+    let trading_schedule = get_trading_schedule("20240702", vec![("0930", "noon")]);
+    assert!(
+        InteractiveBrokersTradingCalendar::trading_schedule_to_session_list(&trading_schedule)
+            .is_err()
+    );
+}
@@ -0,0 +1,117 @@
+use rust_decimal_macros::dec;
+use std::collections::HashMap;
+
+use crate::{
+    broker::{
+        common::fee::FeeModelTrait,
+        interactive_brokers::fee::{InteractiveBrokersFeeModel, InteractiveBrokersPricingPlan},
+    },
+    model::trading::{market::Market, transaction::Direction},
+    test::broker::common::fee::get_calculate_fee_request,
+};
+
+#[test]
+fn test_interactive_brokers_fee_model_fixed() {
+    let fee_model = InteractiveBrokersFeeModel::new(HashMap::new());
+    let fee = fee_model
+        .calculate_fee(&get_calculate_fee_request(
+            Market::US,
+            Direction::Sell,
+            dec!(100),
+            dec!(150),
+        ))
+        .unwrap();
+    assert_eq!(dec!(1.44), fee.get_total());
+
+    // capped at 1% of the trade value
+    let fee = fee_model
+        .calculate_fee(&get_calculate_fee_request(
+            Market::US,
+            Direction::Buy,
+            dec!(1000),
+            dec!(0.2),
+        ))
+        .unwrap();
+    assert_eq!(dec!(2), fee.get_total());
+
+    let fee = fee_model
+        .calculate_fee(&get_calculate_fee_request(
+            Market::HK,
+            Direction::Buy,
+            dec!(100),
+            dec!(300),
+        ))
+        .unwrap();
+    assert_eq!(dec!(58.56), fee.get_total());
+
+    assert!(fee_model
+        .calculate_fee(&get_calculate_fee_request(
+            Market::CN,
+            Direction::Buy,
+            dec!(100),
+            dec!(10),
+        ))
+        .is_err());
+}
+
+#[test]
+fn test_interactive_brokers_fee_model_tiered() {
+    let fee_model = InteractiveBrokersFeeModel::new(HashMap::from([
+        (
+            InteractiveBrokersFeeModel::CONFIG_KEY_PRICING_PLAN.to_owned(),
+            InteractiveBrokersFeeModel::CONFIG_VALUE_PRICING_PLAN_TIERED.to_owned(),
+        ),
+        (
+            InteractiveBrokersFeeModel::CONFIG_KEY_MONTHLY_VOLUME.to_owned(),
+            "1000".to_owned(),
+        ),
+    ]));
+    let fee = fee_model
+        .calculate_fee(&get_calculate_fee_request(
+            Market::US,
+            Direction::Sell,
+            dec!(100),
+            dec!(150),
+        ))
+        .unwrap();
+    assert_eq!(dec!(0.81), fee.get_total());
+
+    let fee_model =
+        InteractiveBrokersFeeModel::new_with_pricing_plan(InteractiveBrokersPricingPlan::Tiered {
+            monthly_volume: dec!(5_000_000),
+        });
+    let fee = fee_model
+        .calculate_fee(&get_calculate_fee_request(
+            Market::US,
+            Direction::Buy,
+            dec!(1000),
+            dec!(150),
+        ))
+        .unwrap();
+    assert_eq!(dec!(1.70), fee.get_total());
+}
+
+#[test]
+fn test_interactive_brokers_fee_model_invalid_config() {
+    assert!(InteractiveBrokersFeeModel::try_new(HashMap::from([(
+        InteractiveBrokersFeeModel::CONFIG_KEY_PRICING_PLAN.to_owned(),
+        "unknown".to_owned(),
+    )]))
+    .is_err());
+    assert!(InteractiveBrokersFeeModel::try_new(HashMap::from([
+        (
+            InteractiveBrokersFeeModel::CONFIG_KEY_PRICING_PLAN.to_owned(),
+            InteractiveBrokersFeeModel::CONFIG_VALUE_PRICING_PLAN_TIERED.to_owned(),
+        ),
+        (
+            InteractiveBrokersFeeModel::CONFIG_KEY_MONTHLY_VOLUME.to_owned(),
+            "many".to_owned(),
+        ),
+    ]))
+    .is_err());
+    assert!(InteractiveBrokersFeeModel::try_new(HashMap::from([(
+        InteractiveBrokersFeeModel::CONFIG_KEY_PRICING_PLAN.to_owned(),
+        InteractiveBrokersFeeModel::CONFIG_VALUE_PRICING_PLAN_FIXED.to_owned(),
+    )]))
+    .is_ok());
+}
@@ -1,4 +1,6 @@
+pub mod calendar;
 pub mod config;
+pub mod fee;
 pub mod symbol;
 pub mod test_helper;
 pub mod transaction;
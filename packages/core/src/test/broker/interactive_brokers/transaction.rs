@@ -1,26 +1,111 @@
 use rust_decimal_macros::dec;
+use std::sync::Arc;
 
 use crate::{
     broker::{
         common::transaction::TransactionTrait,
         interactive_brokers::transaction::InteractiveBrokersTransaction,
     },
-    model::trading::{
-        market::Market,
-        symbol::Symbol,
-        transaction::{
-            CancelOrderRequest, Direction, EditOrderRequest, Expire, OrderDetailRequest, Price,
-            RegularTradingTime, SubmitOrderRequest,
+    model::{
+        common::{timestamp::Timestamp, types::ConfigMap},
+        trading::{
+            market::Market,
+            symbol::Symbol,
+            transaction::{
+                CancelOrderRequest, Direction, EditOrderRequest, Expire, ListExecutionsRequest,
+                OrderDetailRequest, OrderStatus, Price, RegularTradingTime, SubmitOrderRequest,
+            },
         },
     },
     test::broker::interactive_brokers::test_helper::get_config_map,
+    utils::{
+        clock::{common_trait::ClockTrait, manual::ManualClock, system::SystemClock},
+        time::parse_utc_offset,
+    },
 };
 
+#[test]
+fn test_ib_order_status_to_core_order_status() {
+    assert_eq!(
+        OrderStatus::Pending,
+        InteractiveBrokersTransaction::ib_order_status_to_core_order_status(
+            "PreSubmitted",
+            dec!(0)
+        )
+    );
+    assert_eq!(
+        OrderStatus::Submitted,
+        InteractiveBrokersTransaction::ib_order_status_to_core_order_status("Submitted", dec!(0))
+    );
+    assert_eq!(
+        OrderStatus::PartiallyFilled,
+        InteractiveBrokersTransaction::ib_order_status_to_core_order_status("Submitted", dec!(10))
+    );
+    assert_eq!(
+        OrderStatus::Cancelled,
+        InteractiveBrokersTransaction::ib_order_status_to_core_order_status("Cancelled", dec!(10))
+    );
+    assert_eq!(
+        OrderStatus::Unknown,
+        InteractiveBrokersTransaction::ib_order_status_to_core_order_status("", dec!(0))
+    );
+}
+
+#[test]
+fn test_parse_order_time() {
+    // in daylight saving time in the US and standard time once it ends
+    assert_eq!(
+        Timestamp::from_secs(1697117400),
+        InteractiveBrokersTransaction::parse_order_time("231012093000", Option::None, &Market::US)
+            .unwrap()
+    );
+    assert_eq!(
+        Timestamp::from_secs(1702391400),
+        InteractiveBrokersTransaction::parse_order_time("231212093000", Option::None, &Market::US)
+            .unwrap()
+    );
+    assert_eq!(
+        Timestamp::from_secs(1697074200),
+        InteractiveBrokersTransaction::parse_order_time("231012093000", Option::None, &Market::HK)
+            .unwrap()
+    );
+    assert_eq!(
+        Timestamp::from_secs(1697103000),
+        InteractiveBrokersTransaction::parse_order_time(
+            "231012093000",
+            Option::Some(parse_utc_offset("+00:00").unwrap()),
+            &Market::US
+        )
+        .unwrap()
+    );
+    assert!(InteractiveBrokersTransaction::parse_order_time(
+        "1697117400",
+        Option::None,
+        &Market::US
+    )
+    .is_err());
+}
+
+#[test]
+fn test_generate_customer_order_id() {
+    // a clock that does not move between the calls
+    let clock = ManualClock::new_with_timestamp(Timestamp::from_secs(1718000000));
+    let customer_order_id = InteractiveBrokersTransaction::generate_customer_order_id(&clock);
+    assert!(customer_order_id.starts_with("rt_1718000000000000000_"));
+    assert_ne!(
+        customer_order_id,
+        InteractiveBrokersTransaction::generate_customer_order_id(&clock)
+    );
+}
+
 #[tokio::test]
 #[cfg_attr(feature = "ci", ignore)]
 async fn test_interactive_brokers_transaction() {
     let config_map = get_config_map();
-    let mut transaction = InteractiveBrokersTransaction::new(config_map);
+    let mut transaction = InteractiveBrokersTransaction::new(
+        config_map,
+        Arc::new(SystemClock::new(ConfigMap::new())),
+    );
 
     let account_balance_result = transaction.account_balance().await;
     assert!(account_balance_result.is_ok());
@@ -58,6 +143,13 @@ async fn test_interactive_brokers_transaction() {
     );
     assert_eq!(Price::LimitOrder { price: dec!(88.88) }, order_detail.price);
 
+    let open_orders_result = transaction.list_open_orders().await;
+    assert!(open_orders_result.is_ok());
+    assert!(open_orders_result
+        .unwrap()
+        .iter()
+        .any(|order_detail| order_detail.order_id == order_id));
+
     let edit_order_result = transaction
         .edit_order(EditOrderRequest {
             order_id: order_id.clone(),
@@ -96,4 +188,9 @@ async fn test_interactive_brokers_transaction() {
         })
         .await;
     assert!(cancel_order_result.is_ok());
+
+    let executions_result = transaction
+        .list_executions(ListExecutionsRequest::default())
+        .await;
+    assert!(executions_result.is_ok());
 }
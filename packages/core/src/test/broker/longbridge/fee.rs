@@ -0,0 +1,60 @@
+use rust_decimal_macros::dec;
+use std::collections::HashMap;
+
+use crate::{
+    broker::{common::fee::FeeModelTrait, longbridge::fee::LongBridgeFeeModel},
+    model::trading::{
+        currency::Currency,
+        fee::{FeeItem, FeeKind},
+        market::Market,
+        transaction::Direction,
+    },
+    test::broker::common::fee::get_calculate_fee_request,
+};
+
+#[test]
+fn test_longbridge_fee_model() {
+    let fee_model = LongBridgeFeeModel::new(HashMap::new());
+    let fee = fee_model
+        .calculate_fee(&get_calculate_fee_request(
+            Market::US,
+            Direction::Sell,
+            dec!(100),
+            dec!(150),
+        ))
+        .unwrap();
+    assert_eq!(Currency::USD, fee.currency);
+    assert_eq!(dec!(2.73), fee.get_total());
+
+    let fee = fee_model
+        .calculate_fee(&get_calculate_fee_request(
+            Market::HK,
+            Direction::Buy,
+            dec!(100),
+            dec!(300),
+        ))
+        .unwrap();
+    assert_eq!(Currency::HKD, fee.currency);
+    assert_eq!(dec!(58.56), fee.get_total());
+    assert!(fee.item_list.contains(&FeeItem {
+        kind: FeeKind::PlatformFee,
+        amount: dec!(15),
+    }));
+}
+
+#[test]
+fn test_longbridge_fee_model_commission_free() {
+    let fee_model = LongBridgeFeeModel::new(HashMap::from([(
+        LongBridgeFeeModel::CONFIG_KEY_COMMISSION_FREE.to_owned(),
+        "true".to_owned(),
+    )]));
+    let fee = fee_model
+        .calculate_fee(&get_calculate_fee_request(
+            Market::US,
+            Direction::Sell,
+            dec!(100),
+            dec!(150),
+        ))
+        .unwrap();
+    assert_eq!(dec!(1.74), fee.get_total());
+}
@@ -1,10 +1,11 @@
 use log;
 use rust_decimal_macros::dec;
+use std::sync::Arc;
 
 use crate::{
     broker::{common::info::InfoTrait, longbridge::info::LongBridgeInfo},
     model::{
-        common::types::ConfigMap,
+        common::{timestamp::Timestamp, types::ConfigMap},
         trading::{
             currency::Currency,
             market::Market,
@@ -12,12 +13,16 @@ use crate::{
             symbol::Symbol,
         },
     },
+    utils::clock::{common_trait::ClockTrait, system::SystemClock},
 };
 
 #[tokio::test]
 #[cfg_attr(feature = "ci", ignore)]
 async fn test_query_basic_info() {
-    let longbridge_info = LongBridgeInfo::new(ConfigMap::new());
+    let longbridge_info = LongBridgeInfo::new(
+        ConfigMap::new(),
+        Arc::new(SystemClock::new(ConfigMap::new())),
+    );
     let quote_basic_info_result = longbridge_info
         .query_basic_info(QueryInfoRequest {
             symbol: Symbol {
@@ -43,7 +48,10 @@ async fn test_query_basic_info() {
 #[tokio::test]
 #[cfg_attr(feature = "ci", ignore)]
 async fn test_query_real_time_info() {
-    let longbridge_info = LongBridgeInfo::new(ConfigMap::new());
+    let longbridge_info = LongBridgeInfo::new(
+        ConfigMap::new(),
+        Arc::new(SystemClock::new(ConfigMap::new())),
+    );
     let quote_real_time_info_result = longbridge_info
         .query_real_time_info(QueryInfoRequest {
             symbol: Symbol {
@@ -63,13 +71,16 @@ async fn test_query_real_time_info() {
     assert!(quote_real_time_info.open_price.unwrap() > dec!(0.0));
     assert!(quote_real_time_info.prev_close.unwrap() > dec!(0.0));
     assert!(quote_real_time_info.turnover.unwrap() > dec!(0.0));
-    assert!(quote_real_time_info.timestamp > 0);
+    assert!(quote_real_time_info.timestamp > Timestamp::UNIX_EPOCH);
 }
 
 #[tokio::test]
 #[cfg_attr(feature = "ci", ignore)]
 async fn test_query_depth() {
-    let longbridge_info = LongBridgeInfo::new(ConfigMap::new());
+    let longbridge_info = LongBridgeInfo::new(
+        ConfigMap::new(),
+        Arc::new(SystemClock::new(ConfigMap::new())),
+    );
     let quote_depth_info_result = longbridge_info
         .query_depth(QueryInfoRequest {
             symbol: Symbol {
@@ -1,3 +1,4 @@
+pub mod fee;
 pub mod info;
 pub mod subscription;
 pub mod transaction;
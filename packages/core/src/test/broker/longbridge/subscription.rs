@@ -8,20 +8,24 @@ use crate::{
         common::subscription::SubscriptionTrait, longbridge::subscription::LongBridgeSubscription,
     },
     model::{
-        common::types::ConfigMap,
+        common::{timestamp::Timestamp, types::ConfigMap},
         trading::{
             market::Market,
             quote::{QueryInfoRequest, QuoteKind},
             symbol::Symbol,
         },
     },
+    utils::clock::{common_trait::ClockTrait, system::SystemClock},
 };
 
 #[tokio::test]
 #[cfg_attr(not(feature = "flaky_test_cases"), ignore)]
 async fn test_quote_real_time_info() {
-    let longbridge_subscription =
-        LongBridgeSubscription::new(ConfigMap::new(), Arc::new(AtomicBool::new(false)));
+    let longbridge_subscription = LongBridgeSubscription::new(
+        ConfigMap::new(),
+        Arc::new(AtomicBool::new(false)),
+        Arc::new(SystemClock::new(ConfigMap::new())),
+    );
     let (mut receiver, _) = longbridge_subscription
         .real_time_info(QueryInfoRequest {
             symbol: Symbol {
@@ -40,7 +44,7 @@ async fn test_quote_real_time_info() {
             assert_eq!("0700.HK", quote_info.symbol.to_string());
             assert!(quote_info.current_price > dec!(0.0));
             assert!(quote_info.volume.unwrap() > dec!(0.0));
-            assert!(quote_info.timestamp > 0u64);
+            assert!(quote_info.timestamp > Timestamp::UNIX_EPOCH);
         },
         _ = sleep(Duration::from_millis(3000))=> {
             panic!("loop not working!");
@@ -51,8 +55,11 @@ async fn test_quote_real_time_info() {
 #[tokio::test]
 #[cfg_attr(not(feature = "flaky_test_cases"), ignore)]
 async fn test_quote_depth_info() {
-    let longbridge_subscription =
-        LongBridgeSubscription::new(ConfigMap::new(), Arc::new(AtomicBool::new(false)));
+    let longbridge_subscription = LongBridgeSubscription::new(
+        ConfigMap::new(),
+        Arc::new(AtomicBool::new(false)),
+        Arc::new(SystemClock::new(ConfigMap::new())),
+    );
     let (mut receiver, _) = longbridge_subscription
         .depth_info(QueryInfoRequest {
             symbol: Symbol {
@@ -1,16 +1,27 @@
 use rust_decimal_macros::dec;
+use std::sync::Arc;
 
 use crate::{
     broker::{
         common::transaction::TransactionTrait, longbridge::transaction::LongBridgeTransaction,
     },
-    model::{common::types::ConfigMap, trading::currency::Currency},
+    model::{
+        common::types::ConfigMap,
+        trading::{
+            currency::Currency,
+            transaction::{ListExecutionsRequest, ListOrderHistoryRequest},
+        },
+    },
+    utils::clock::{common_trait::ClockTrait, system::SystemClock},
 };
 
 #[tokio::test]
 #[cfg_attr(feature = "ci", ignore)]
 async fn test_account_balance() {
-    let longbridge_transaction = LongBridgeTransaction::new(ConfigMap::new());
+    let longbridge_transaction = LongBridgeTransaction::new(
+        ConfigMap::new(),
+        Arc::new(SystemClock::new(ConfigMap::new())),
+    );
 
     let account_balance_result = longbridge_transaction.account_balance().await;
     assert!(account_balance_result.is_ok());
@@ -28,7 +39,10 @@ async fn test_account_balance() {
 #[tokio::test]
 #[cfg_attr(feature = "ci", ignore)]
 async fn test_positions() {
-    let longbridge_transaction = LongBridgeTransaction::new(ConfigMap::new());
+    let longbridge_transaction = LongBridgeTransaction::new(
+        ConfigMap::new(),
+        Arc::new(SystemClock::new(ConfigMap::new())),
+    );
 
     let positions_result = longbridge_transaction.positions().await;
     assert!(positions_result.is_ok());
@@ -37,3 +51,29 @@ async fn test_positions() {
         assert!(position.quantity >= dec!(0));
     });
 }
+
+#[tokio::test]
+#[cfg_attr(feature = "ci", ignore)]
+async fn test_list_orders() {
+    let longbridge_transaction = LongBridgeTransaction::new(
+        ConfigMap::new(),
+        Arc::new(SystemClock::new(ConfigMap::new())),
+    );
+
+    let open_orders_result = longbridge_transaction.list_open_orders().await;
+    assert!(open_orders_result.is_ok());
+    open_orders_result
+        .unwrap()
+        .iter()
+        .for_each(|order_detail| assert!(order_detail.status.is_open()));
+
+    let order_history_result = longbridge_transaction
+        .list_order_history(ListOrderHistoryRequest::default())
+        .await;
+    assert!(order_history_result.is_ok());
+
+    let executions_result = longbridge_transaction
+        .list_executions(ListExecutionsRequest::default())
+        .await;
+    assert!(executions_result.is_ok());
+}
@@ -1,8 +1,11 @@
+#[cfg(feature = "broker__interactive_brokers")]
+pub mod interactive_brokers;
 #[cfg(feature = "broker__longbridge")]
 pub mod longbridge;
+#[cfg(feature = "broker__replay")]
+pub mod replay;
 #[cfg(feature = "broker__yahoo_finance")]
 pub mod yahoo_finance;
-#[cfg(feature = "broker__interactive_brokers")]
-pub mod interactive_brokers;
 
+pub mod common;
 pub mod initializer;
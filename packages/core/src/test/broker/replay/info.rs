@@ -0,0 +1,45 @@
+use rust_decimal_macros::dec;
+use std::{
+    sync::{atomic::AtomicBool, Arc},
+    time::Duration,
+};
+use tempfile::tempdir;
+
+use super::test_helper::{get_config_map, get_symbol, write_quote_real_time_info, TIMESTAMP};
+use crate::{
+    broker::{
+        common::broker::{BrokerTrait, EmptyBrokerInterceptorFactory},
+        replay::broker::ReplayBroker,
+    },
+    model::trading::quote::{QueryInfoRequest, QuoteKind},
+    utils::clock::simulated::{SimulatedClock, SimulationSpeed},
+};
+
+#[tokio::test]
+async fn test_query_real_time_info() {
+    let temp_dir = tempdir().unwrap();
+    write_quote_real_time_info(&temp_dir, 5);
+    let clock = SimulatedClock::new_with_speed(SimulationSpeed::Max, Option::None);
+    let broker = ReplayBroker::new(
+        Box::new(EmptyBrokerInterceptorFactory::new()),
+        get_config_map(&temp_dir),
+        Arc::new(AtomicBool::new(false)),
+        Arc::new(clock.clone()),
+    );
+    let info = broker.create_info();
+    let request = QueryInfoRequest {
+        symbol: get_symbol(),
+        kind: QuoteKind::Stock,
+    };
+
+    assert!(info.query_real_time_info(request.clone()).await.is_err());
+    assert!(info.query_basic_info(request.clone()).await.is_err());
+
+    clock.seek(TIMESTAMP + Duration::from_secs(2));
+    let quote_info = info.query_real_time_info(request.clone()).await.unwrap();
+    assert_eq!(2, quote_info.sequence);
+    assert_eq!(dec!(2), quote_info.current_price);
+
+    clock.seek(TIMESTAMP - Duration::from_secs(1));
+    assert!(info.query_real_time_info(request).await.is_err());
+}
@@ -0,0 +1,5 @@
+pub mod info;
+pub mod subscription;
+pub mod test_helper;
+pub mod transaction;
+pub mod worker;
@@ -0,0 +1,123 @@
+use std::{
+    sync::{atomic::AtomicBool, Arc},
+    time::Duration,
+};
+use tempfile::tempdir;
+use tokio::time::Instant;
+
+use super::test_helper::{
+    get_config_map, get_symbol, write_quote_depth_info, write_quote_real_time_info, TIMESTAMP,
+};
+use crate::{
+    broker::{
+        common::broker::{BrokerTrait, EmptyBrokerInterceptorFactory},
+        replay::broker::ReplayBroker,
+    },
+    model::trading::quote::{QueryInfoRequest, QuoteKind},
+    utils::clock::simulated::{SimulatedClock, SimulationSpeed},
+};
+
+#[tokio::test]
+async fn test_replay_real_time_info() {
+    let temp_dir = tempdir().unwrap();
+    let quote_info_list = write_quote_real_time_info(&temp_dir, 5);
+    let clock = SimulatedClock::new_with_speed(SimulationSpeed::Max, Option::None);
+    let broker = ReplayBroker::new(
+        Box::new(EmptyBrokerInterceptorFactory::new()),
+        get_config_map(&temp_dir),
+        Arc::new(AtomicBool::new(false)),
+        Arc::new(clock.clone()),
+    );
+
+    let (mut receiver, _) = broker
+        .create_subscription()
+        .real_time_info(QueryInfoRequest {
+            symbol: get_symbol(),
+            kind: QuoteKind::Stock,
+        })
+        .await
+        .unwrap();
+    for quote_info in quote_info_list {
+        assert_eq!(Option::Some(quote_info), receiver.recv().await);
+    }
+    assert_eq!(Option::None, receiver.recv().await);
+    assert_eq!(
+        Option::Some(TIMESTAMP + Duration::from_secs(4)),
+        clock.try_now()
+    );
+}
+
+#[tokio::test]
+async fn test_replay_real_time_and_depth_info() {
+    let temp_dir = tempdir().unwrap();
+    let quote_info_list = write_quote_real_time_info(&temp_dir, 5);
+    let depth_info_list = write_quote_depth_info(&temp_dir, 3, 1);
+    let clock = SimulatedClock::new_with_speed(SimulationSpeed::Max, Option::None);
+    let broker = ReplayBroker::new(
+        Box::new(EmptyBrokerInterceptorFactory::new()),
+        get_config_map(&temp_dir),
+        Arc::new(AtomicBool::new(false)),
+        Arc::new(clock.clone()),
+    );
+    let request = QueryInfoRequest {
+        symbol: get_symbol(),
+        kind: QuoteKind::Stock,
+    };
+
+    let subscription = broker.create_subscription();
+    let (mut quote_receiver, _) = subscription.real_time_info(request.clone()).await.unwrap();
+    let (mut depth_receiver, _) = subscription.depth_info(request).await.unwrap();
+    for depth_info in depth_info_list {
+        assert_eq!(Option::Some(depth_info), depth_receiver.recv().await);
+    }
+    assert_eq!(Option::None, depth_receiver.recv().await);
+    for quote_info in quote_info_list {
+        assert_eq!(Option::Some(quote_info), quote_receiver.recv().await);
+    }
+    assert_eq!(Option::None, quote_receiver.recv().await);
+    assert_eq!(
+        Option::Some(TIMESTAMP + Duration::from_secs(5)),
+        clock.try_now()
+    );
+}
+
+#[tokio::test]
+async fn test_replay_with_speed_and_range() {
+    let temp_dir = tempdir().unwrap();
+    write_quote_real_time_info(&temp_dir, 10);
+    let mut config_map = get_config_map(&temp_dir);
+    config_map.insert(
+        ReplayBroker::CONFIG_KEY_START.to_owned(),
+        (TIMESTAMP + Duration::from_secs(2)).to_string(),
+    );
+    config_map.insert(
+        ReplayBroker::CONFIG_KEY_END.to_owned(),
+        (TIMESTAMP + Duration::from_secs(4)).to_string(),
+    );
+    let broker = ReplayBroker::new(
+        Box::new(EmptyBrokerInterceptorFactory::new()),
+        config_map,
+        Arc::new(AtomicBool::new(false)),
+        Arc::new(SimulatedClock::new_with_speed(
+            SimulationSpeed::Multiplier(20.0),
+            Option::None,
+        )),
+    );
+
+    let instant = Instant::now();
+    let (mut receiver, _) = broker
+        .create_subscription()
+        .real_time_info(QueryInfoRequest {
+            symbol: get_symbol(),
+            kind: QuoteKind::Stock,
+        })
+        .await
+        .unwrap();
+    let mut sequence_list = vec![];
+    while let Option::Some(quote_info) = receiver.recv().await {
+        sequence_list.push(quote_info.sequence);
+    }
+    assert_eq!(vec![2, 3, 4], sequence_list);
+    // 2 seconds of market data at 20x
+    assert!(instant.elapsed() >= Duration::from_millis(90));
+}
@@ -0,0 +1,96 @@
+use rust_decimal::Decimal;
+use std::time::Duration;
+use tempfile::TempDir;
+
+use crate::{
+    broker::replay::broker::ReplayBroker,
+    model::{
+        common::{timestamp::Timestamp, types::ConfigMap},
+        trading::{
+            market::Market,
+            quote::{Depth, QuoteDepthInfo, QuoteRealTimeInfo},
+            symbol::Symbol,
+        },
+    },
+    pod::recorder::writer::{RecordCompression, RecordFileWriter, RecordFormat},
+};
+
+// 2024-06-10 06:13:20 UTC
+pub(super) const TIMESTAMP: Timestamp = Timestamp::from_secs(1718000000);
+
+pub(super) fn get_symbol() -> Symbol {
+    Symbol {
+        market: Market::US,
+        identifier: "ABNB".to_owned(),
+    }
+}
+
+// one quote per second, the price equals to the sequence
+pub(super) fn write_quote_real_time_info(temp_dir: &TempDir, count: u64) -> Vec<QuoteRealTimeInfo> {
+    let quote_info_list: Vec<QuoteRealTimeInfo> = (0..count)
+        .map(|sequence| QuoteRealTimeInfo {
+            symbol: get_symbol(),
+            sequence,
+            timestamp: TIMESTAMP + Duration::from_secs(sequence),
+            current_price: Decimal::from(sequence),
+            volume: Option::None,
+            low_price: Option::None,
+            high_price: Option::None,
+            open_price: Option::None,
+            prev_close: Option::None,
+            turnover: Option::None,
+            extra: Option::None,
+        })
+        .collect();
+
+    let mut writer = RecordFileWriter::new(
+        temp_dir.path().to_path_buf(),
+        RecordFormat::JsonLines,
+        RecordCompression::Gzip,
+    );
+    quote_info_list
+        .iter()
+        .for_each(|quote_info| writer.write(quote_info).unwrap());
+    writer.close().unwrap();
+    quote_info_list
+}
+
+// one depth every other second from `offset_seconds` on, the price equals to the sequence
+pub(super) fn write_quote_depth_info(
+    temp_dir: &TempDir,
+    count: u64,
+    offset_seconds: u64,
+) -> Vec<QuoteDepthInfo> {
+    let depth_info_list: Vec<QuoteDepthInfo> = (0..count)
+        .map(|sequence| QuoteDepthInfo {
+            symbol: get_symbol(),
+            sequence,
+            timestamp: TIMESTAMP + Duration::from_secs(offset_seconds + sequence * 2),
+            ask_list: vec![Depth {
+                position: Option::None,
+                price: Decimal::from(sequence),
+                volume: Option::None,
+                order_count: Option::None,
+            }],
+            bid_list: vec![],
+        })
+        .collect();
+
+    let mut writer = RecordFileWriter::new(
+        temp_dir.path().to_path_buf(),
+        RecordFormat::JsonLines,
+        RecordCompression::Gzip,
+    );
+    depth_info_list
+        .iter()
+        .for_each(|depth_info| writer.write(depth_info).unwrap());
+    writer.close().unwrap();
+    depth_info_list
+}
+
+pub(super) fn get_config_map(temp_dir: &TempDir) -> ConfigMap {
+    ConfigMap::from([(
+        ReplayBroker::CONFIG_KEY_BASE_PATH.to_owned(),
+        temp_dir.path().to_str().unwrap().to_owned(),
+    )])
+}
@@ -0,0 +1,143 @@
+use rust_decimal_macros::dec;
+use std::{
+    sync::{atomic::AtomicBool, Arc},
+    time::Duration,
+};
+use tempfile::tempdir;
+
+use super::test_helper::{get_config_map, get_symbol, write_quote_real_time_info, TIMESTAMP};
+use crate::{
+    broker::{
+        common::{
+            broker::{BrokerTrait, EmptyBrokerInterceptorFactory},
+            fee::FeeModelTrait,
+            transaction::TransactionTrait,
+        },
+        interactive_brokers::fee::InteractiveBrokersFeeModel,
+        replay::broker::ReplayBroker,
+    },
+    model::{
+        common::types::ConfigMap,
+        trading::{
+            fee::CalculateFeeRequest,
+            transaction::{
+                Direction, Expire, OrderDetailRequest, OrderStatus, Price, RegularTradingTime,
+                SubmitOrderRequest,
+            },
+        },
+    },
+    utils::clock::simulated::{SimulatedClock, SimulationSpeed},
+};
+
+fn get_submit_order_request(direction: Direction, price: Price) -> SubmitOrderRequest {
+    SubmitOrderRequest {
+        symbol: get_symbol(),
+        quantity: dec!(100),
+        direction,
+        regular_trading_time: RegularTradingTime::AllTime,
+        expire: Expire::Day,
+        price,
+    }
+}
+
+async fn get_order_status(transaction: &dyn TransactionTrait, order_id: &str) -> OrderStatus {
+    transaction
+        .order_detail(OrderDetailRequest {
+            order_id: order_id.to_owned(),
+        })
+        .await
+        .unwrap()
+        .status
+}
+
+#[tokio::test]
+async fn test_replay_transaction() {
+    let temp_dir = tempdir().unwrap();
+    write_quote_real_time_info(&temp_dir, 5);
+    let clock = SimulatedClock::new_with_speed(SimulationSpeed::Max, Option::None);
+    let mut config_map = get_config_map(&temp_dir);
+    config_map.insert(
+        ReplayBroker::CONFIG_KEY_FEE_MODEL.to_owned(),
+        InteractiveBrokersFeeModel::get_identifier(),
+    );
+    let broker = ReplayBroker::new(
+        Box::new(EmptyBrokerInterceptorFactory::new()),
+        config_map,
+        Arc::new(AtomicBool::new(false)),
+        Arc::new(clock.clone()),
+    );
+    let mut transaction = broker.create_transaction().unwrap();
+    clock.seek(TIMESTAMP + Duration::from_secs(1));
+
+    // the market order fills at once at the replayed price and is charged by the fee model
+    let order_id = transaction
+        .submit_order(get_submit_order_request(Direction::Buy, Price::MarketOrder))
+        .await
+        .unwrap()
+        .order_id;
+    let order_detail = transaction
+        .order_detail(OrderDetailRequest {
+            order_id: order_id.clone(),
+        })
+        .await
+        .unwrap();
+    let fee = InteractiveBrokersFeeModel::new(ConfigMap::new())
+        .calculate_fee(&CalculateFeeRequest {
+            symbol: get_symbol(),
+            direction: Direction::Buy,
+            quantity: dec!(100),
+            price: dec!(1),
+        })
+        .unwrap()
+        .get_total();
+    assert!(fee > dec!(0));
+    assert_eq!(OrderStatus::Filled, order_detail.status);
+    assert_eq!(Option::Some(dec!(1)), order_detail.executed_price);
+    assert_eq!(Option::Some(fee), order_detail.fee);
+
+    // the limit order stays open until the replayed price reaches it
+    let order_id = transaction
+        .submit_order(get_submit_order_request(
+            Direction::Sell,
+            Price::LimitOrder { price: dec!(3) },
+        ))
+        .await
+        .unwrap()
+        .order_id;
+    assert_eq!(
+        OrderStatus::Submitted,
+        get_order_status(transaction.as_ref(), &order_id).await
+    );
+    clock.seek(TIMESTAMP + Duration::from_secs(2));
+    assert_eq!(
+        OrderStatus::Submitted,
+        get_order_status(transaction.as_ref(), &order_id).await
+    );
+    clock.seek(TIMESTAMP + Duration::from_secs(3));
+    assert_eq!(
+        OrderStatus::Filled,
+        get_order_status(transaction.as_ref(), &order_id).await
+    );
+    assert!(transaction.positions().await.unwrap().is_empty());
+}
+
+#[test]
+fn test_replay_transaction_unknown_fee_model() {
+    let temp_dir = tempdir().unwrap();
+    let mut config_map = get_config_map(&temp_dir);
+    config_map.insert(
+        ReplayBroker::CONFIG_KEY_FEE_MODEL.to_owned(),
+        "Unknown".to_owned(),
+    );
+    let broker = ReplayBroker::new(
+        Box::new(EmptyBrokerInterceptorFactory::new()),
+        config_map,
+        Arc::new(AtomicBool::new(false)),
+        Arc::new(SimulatedClock::new_with_speed(
+            SimulationSpeed::Max,
+            Option::None,
+        )),
+    );
+    assert!(broker.create_fee_model().is_err());
+    assert!(broker.create_transaction().is_err());
+}
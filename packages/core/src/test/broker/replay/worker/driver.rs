@@ -0,0 +1,103 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use std::{
+    collections::VecDeque,
+    sync::{atomic::AtomicBool, Arc, Mutex},
+    time::Duration,
+};
+
+use crate::{
+    broker::replay::worker::driver::{ReplayDriver, ReplayFeedTrait},
+    model::common::timestamp::Timestamp,
+    utils::clock::{
+        common_trait::ClockTrait,
+        simulated::{SimulatedClock, SimulationSpeed},
+    },
+};
+
+const TIMESTAMP: Timestamp = Timestamp::from_secs(1718000000);
+
+// name of the feed, timestamp of the record and the clock when it got sent
+type SendLog = Arc<Mutex<Vec<(&'static str, Timestamp, Timestamp)>>>;
+
+// Logs the clock at the time each record is sent
+struct TestFeed {
+    name: &'static str,
+    timestamp_list: VecDeque<Timestamp>,
+    clock: Arc<SimulatedClock>,
+    log: SendLog,
+}
+
+#[async_trait]
+impl ReplayFeedTrait for TestFeed {
+    fn is_stopped(&self) -> bool {
+        false
+    }
+
+    fn peek_timestamp(&mut self) -> Result<Option<Timestamp>, Error> {
+        Result::Ok(self.timestamp_list.front().cloned())
+    }
+
+    async fn send_next(&mut self) -> bool {
+        let timestamp = self.timestamp_list.pop_front().unwrap();
+        self.log
+            .lock()
+            .unwrap()
+            .push((self.name, timestamp, self.clock.now()));
+        true
+    }
+}
+
+#[tokio::test]
+async fn test_replay_driver_merges_feeds() {
+    let clock = Arc::new(SimulatedClock::new_with_speed(
+        SimulationSpeed::Max,
+        Option::None,
+    ));
+    let driver = Arc::new(ReplayDriver::new(
+        clock.clone(),
+        Arc::new(AtomicBool::new(false)),
+    ));
+    let log = Arc::new(Mutex::new(vec![]));
+    let create_feed = |name, second_list: &[u64]| {
+        Box::new(TestFeed {
+            name,
+            timestamp_list: second_list
+                .iter()
+                .map(|second| TIMESTAMP + Duration::from_secs(*second))
+                .collect(),
+            clock: clock.clone(),
+            log: log.clone(),
+        })
+    };
+
+    driver.register(create_feed("real_time", &[0, 2, 4, 6]));
+    driver.register(create_feed("depth", &[1, 3, 5]));
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let log = log.lock().unwrap().clone();
+    assert_eq!(
+        vec![
+            "real_time",
+            "depth",
+            "real_time",
+            "depth",
+            "real_time",
+            "depth",
+            "real_time"
+        ],
+        log.iter().map(|(name, _, _)| *name).collect::<Vec<_>>()
+    );
+    // the clock never ran ahead of a record
+    for (_, timestamp, now) in log {
+        assert_eq!(timestamp, now);
+    }
+
+    // the task ended with the feeds, a new feed starts another one
+    driver.register(create_feed("late", &[7]));
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(
+        Option::Some(TIMESTAMP + Duration::from_secs(7)),
+        clock.try_now()
+    );
+}
@@ -1,22 +1,27 @@
 use log;
 use rust_decimal_macros::dec;
+use std::sync::Arc;
 
 use crate::{
     broker::{common::info::InfoTrait, yahoo_finance::info::YahooFinanceInfo},
     model::{
-        common::types::ConfigMap,
+        common::{timestamp::Timestamp, types::ConfigMap},
         trading::{
             market::Market,
             quote::{QueryInfoRequest, QuoteKind},
             symbol::Symbol,
         },
     },
+    utils::clock::{common_trait::ClockTrait, system::SystemClock},
 };
 
 #[tokio::test]
 #[cfg_attr(feature = "ci", ignore)]
 async fn test_query_quote_info() {
-    let yahoo_finance_info = YahooFinanceInfo::new(ConfigMap::new());
+    let yahoo_finance_info = YahooFinanceInfo::new(
+        ConfigMap::new(),
+        Arc::new(SystemClock::new(ConfigMap::new())),
+    );
 
     let quote_info_result = yahoo_finance_info
         .query_real_time_info(QueryInfoRequest {
@@ -33,5 +38,5 @@ async fn test_query_quote_info() {
     assert_eq!("ABNB.US", quote_info.symbol.to_string());
     assert!(quote_info.current_price > dec!(0.0));
     assert!(quote_info.volume.unwrap() > dec!(0.0));
-    assert!(quote_info.timestamp > 0u64);
+    assert!(quote_info.timestamp > Timestamp::UNIX_EPOCH);
 }
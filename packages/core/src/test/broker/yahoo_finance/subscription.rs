@@ -9,20 +9,24 @@ use crate::{
         yahoo_finance::subscription::YahooFinanceSubscription,
     },
     model::{
-        common::types::ConfigMap,
+        common::{timestamp::Timestamp, types::ConfigMap},
         trading::{
             market::Market,
             quote::{QueryInfoRequest, QuoteKind},
             symbol::Symbol,
         },
     },
+    utils::clock::{common_trait::ClockTrait, system::SystemClock},
 };
 
 #[tokio::test]
 #[cfg_attr(feature = "ci", ignore)]
 async fn test_subscribe_quote_real_time_info() {
-    let yahoo_finance_subscription =
-        YahooFinanceSubscription::new(ConfigMap::new(), Arc::new(AtomicBool::new(false)));
+    let yahoo_finance_subscription = YahooFinanceSubscription::new(
+        ConfigMap::new(),
+        Arc::new(AtomicBool::new(false)),
+        Arc::new(SystemClock::new(ConfigMap::new())),
+    );
     let subscription_instance_result = yahoo_finance_subscription
         .real_time_info(QueryInfoRequest {
             symbol: Symbol {
@@ -42,7 +46,7 @@ async fn test_subscribe_quote_real_time_info() {
             assert_eq!("ABNB.US", quote_info.symbol.to_string());
             assert!(quote_info.current_price > dec!(0.0));
             assert!(quote_info.volume.unwrap() > dec!(0.0));
-            assert!(quote_info.timestamp > 0u64);
+            assert!(quote_info.timestamp > Timestamp::UNIX_EPOCH);
         },
         _ = sleep(Duration::from_millis(5000))=> {
             panic!("loop not working!");
@@ -2,4 +2,5 @@ pub mod broker;
 pub mod model;
 pub mod persistent_kv;
 pub mod pod;
+pub mod strategy;
 pub mod utils;
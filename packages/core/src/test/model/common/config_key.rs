@@ -0,0 +1,51 @@
+use serde_json::json;
+
+use crate::model::common::config_key::{
+    get_config_map_json_schema, ConfigKeySpec, ConfigValueType,
+};
+
+#[test]
+fn test_check() {
+    assert!(ConfigValueType::Bool.check("true").is_ok());
+    assert!(ConfigValueType::Bool.check("yes").is_err());
+    assert!(ConfigValueType::Integer.check("-3").is_ok());
+    assert!(ConfigValueType::Integer.check("3.5").is_err());
+    assert!(ConfigValueType::Decimal.check("3.5").is_ok());
+    assert!(ConfigValueType::SymbolList
+        .check("AAPL.US, 0700.HK")
+        .is_ok());
+    assert!(ConfigValueType::SymbolList.check("AAPL").is_err());
+    assert!(ConfigValueType::OneOf(&["a", "b"]).check("b").is_ok());
+    assert_eq!(
+        Result::Err("ILLEGAL_VALUE c, expected OneOf([\"a\", \"b\"])".to_owned()),
+        ConfigValueType::OneOf(&["a", "b"]).check("c")
+    );
+}
+
+#[test]
+fn test_get_config_map_json_schema() {
+    let json_schema = get_config_map_json_schema(&[
+        ConfigKeySpec::required("account", ConfigValueType::String, "Account id"),
+        ConfigKeySpec::optional(
+            "ssl",
+            ConfigValueType::Bool,
+            Option::Some("true"),
+            "Whether to use SSL",
+        ),
+    ]);
+    assert_eq!(json!("object"), json_schema["type"]);
+    assert_eq!(json!(["account"]), json_schema["required"]);
+    assert_eq!(
+        json!({"type": "string", "description": "Account id"}),
+        json_schema["properties"]["account"]
+    );
+    assert_eq!(
+        json!({
+            "type": "string",
+            "enum": ["true", "false"],
+            "default": "true",
+            "description": "Whether to use SSL",
+        }),
+        json_schema["properties"]["ssl"]
+    );
+}
@@ -0,0 +1,2 @@
+pub mod config_key;
+pub mod timestamp;
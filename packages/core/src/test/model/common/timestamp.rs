@@ -0,0 +1,104 @@
+use rust_decimal_macros::dec;
+use std::time::Duration;
+
+use crate::model::{
+    common::timestamp::Timestamp,
+    trading::{event::EventContext, market::Market, quote::QuoteRealTimeInfo, symbol::Symbol},
+};
+
+#[test]
+fn test_unit_conversion() {
+    let timestamp = Timestamp::from_nanos(1_718_000_000_123_456_789);
+    assert_eq!(1_718_000_000, timestamp.as_secs());
+    assert_eq!(1_718_000_000_123, timestamp.as_millis());
+    assert_eq!(1_718_000_000_123_456, timestamp.as_micros());
+    assert_eq!(
+        Timestamp::from_millis(1_718_000_000_123),
+        Timestamp::from_secs(1_718_000_000) + Duration::from_millis(123)
+    );
+    assert_eq!(
+        Duration::from_millis(123),
+        Timestamp::from_millis(1_718_000_000_123)
+            .saturating_sub(Timestamp::from_secs(1_718_000_000))
+    );
+    assert_eq!(
+        Duration::ZERO,
+        Timestamp::UNIX_EPOCH.saturating_sub(Timestamp::from_secs(1))
+    );
+    // saturates instead of overflowing
+    assert_eq!(
+        Timestamp::from_nanos(u64::MAX),
+        Timestamp::from_secs(1_718_000_000) + Duration::MAX
+    );
+    assert_eq!(
+        Timestamp::UNIX_EPOCH,
+        Timestamp::from_secs(1_718_000_000) - Duration::MAX
+    );
+    assert_eq!(
+        Timestamp::from_nanos(u64::MAX),
+        Timestamp::from(Duration::MAX)
+    );
+}
+
+#[test]
+fn test_from_epoch() {
+    let timestamp = Timestamp::from_secs(1_718_000_000);
+    assert_eq!(timestamp, Timestamp::from_epoch(1_718_000_000));
+    assert_eq!(timestamp, Timestamp::from_epoch(1_718_000_000_000));
+    assert_eq!(timestamp, Timestamp::from_epoch(1_718_000_000_000_000));
+    assert_eq!(timestamp, Timestamp::from_epoch(1_718_000_000_000_000_000));
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!(
+        Timestamp::from_secs(1_718_000_000),
+        "1718000000".parse().unwrap()
+    );
+    assert_eq!(
+        Timestamp::from_millis(1_718_000_000_500),
+        "1718000000.5".parse().unwrap()
+    );
+    assert_eq!(
+        Timestamp::from_nanos(1_718_000_000_123_456_789),
+        Timestamp::from_nanos(1_718_000_000_123_456_789)
+            .to_string()
+            .parse()
+            .unwrap()
+    );
+    assert!("yesterday".parse::<Timestamp>().is_err());
+    assert!("-1".parse::<Timestamp>().is_err());
+}
+
+#[test]
+fn test_serde_backward_compatibility() {
+    let event_context = EventContext {
+        broker_id: "broker_id".to_owned(),
+        pod_id: "pod_id".to_owned(),
+        timestamp: Timestamp::from_millis(1_718_000_000_123),
+    };
+    let json = serde_json::to_string(&event_context).unwrap();
+    assert_eq!(
+        r#"{"broker_id":"broker_id","pod_id":"pod_id","timestamp":1718000000123000000}"#,
+        json
+    );
+    assert_eq!(
+        event_context,
+        serde_json::from_str::<EventContext>(&json).unwrap()
+    );
+
+    // written when timestamps were seconds
+    let quote_info: QuoteRealTimeInfo = serde_json::from_str(
+        r#"{"symbol":{"market":"US","identifier":"ABNB"},"sequence":1,"timestamp":1718000000,"current_price":"150.5","volume":null,"low_price":null,"high_price":null,"open_price":null,"prev_close":null,"turnover":null,"extra":null}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        Symbol {
+            market: Market::US,
+            identifier: "ABNB".to_owned()
+        },
+        quote_info.symbol
+    );
+    assert_eq!(dec!(150.5), quote_info.current_price);
+    assert_eq!(Timestamp::from_secs(1_718_000_000), quote_info.timestamp);
+}
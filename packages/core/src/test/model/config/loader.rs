@@ -0,0 +1,138 @@
+use std::{collections::HashMap, io::Write, path::Path};
+
+use crate::model::config::loader::{
+    interpolate_env_var, load_pod_config, parse_pod_config, PodConfigFormat,
+};
+
+const YAML_POD_CONFIG: &'static str = r#"
+# name: ${COMMENTED_OUT}
+name: ${POD_NAME}
+broker_list:
+  - identifier: interactive_brokers
+    config_map:
+      ibkr.cp.account: ${IBKR_ACCOUNT}
+      ibkr.cp.max.reply.count: "${IBKR_MAX_REPLY_COUNT:-3}"
+persistent_kv_store:
+  identifier: MemoryKVStore
+  config_map: {}
+strategy:
+  identifier: ExamplePrintLivePriceStrategy
+  config_map: {}
+metrics_registry:
+  identifier: NoOpMetricRegistryFactory
+  config_map: {}
+event_listener_list: []
+"#;
+
+const TOML_POD_CONFIG: &'static str = r#"
+name = "test_pod"
+event_listener_list = []
+
+[[broker_list]]
+identifier = "yahoo_finance"
+config_map = {}
+
+[persistent_kv_store]
+identifier = "MemoryKVStore"
+config_map = {}
+
+[strategy]
+identifier = "ExamplePrintLivePriceStrategy"
+config_map = {}
+
+[metrics_registry]
+identifier = "NoOpMetricRegistryFactory"
+config_map = { "metrics.statsd.prefix" = "rabbit." }
+
+# name = "${COMMENTED_OUT}"
+[restart_policy]
+policy = "OnFailure"
+"#;
+
+#[test]
+fn test_pod_config_format() {
+    assert_eq!(
+        PodConfigFormat::Yaml,
+        PodConfigFormat::from_path(Path::new("./pod.yml")).unwrap()
+    );
+    assert_eq!(
+        PodConfigFormat::Toml,
+        PodConfigFormat::from_path(Path::new("./pod.TOML")).unwrap()
+    );
+    assert!(PodConfigFormat::from_path(Path::new("./pod")).is_err());
+    assert!(PodConfigFormat::from_path(Path::new("./pod.ini")).is_err());
+}
+
+#[test]
+fn test_interpolate_env_var() {
+    let env_var_map = HashMap::from([("USER", "rabbit"), ("EMPTY", "")]);
+    let get_env_var = |name: &str| env_var_map.get(name).map(|value| value.to_string());
+
+    assert_eq!(
+        "user: rabbit, empty: , default: 1, price: $5, literal: ${USER}",
+        interpolate_env_var(
+            "user: ${USER}, empty: ${EMPTY:-1}, default: ${MISSING:-1}, price: $5, literal: $${USER}",
+            get_env_var
+        )
+        .unwrap()
+    );
+
+    let err = interpolate_env_var("${A} ${B} ${A} ${USER}", get_env_var).unwrap_err();
+    assert_eq!("MISSING_ENV_VAR A, B", err.to_string());
+    assert!(interpolate_env_var("${USER", get_env_var).is_err());
+}
+
+#[test]
+fn test_parse_pod_config() {
+    // the values are not valid YAML on their own
+    let env_var_map = HashMap::from([
+        ("POD_NAME", "ibkr_pod # not a comment"),
+        ("IBKR_ACCOUNT", "U1234567: \"quoted\""),
+    ]);
+    let get_env_var = |name: &str| env_var_map.get(name).map(|value| value.to_string());
+    let pod_config = parse_pod_config(YAML_POD_CONFIG, PodConfigFormat::Yaml, get_env_var).unwrap();
+    assert_eq!("ibkr_pod # not a comment", pod_config.name);
+    assert_eq!(
+        "U1234567: \"quoted\"",
+        pod_config.broker_list[0].config_map["ibkr.cp.account"]
+    );
+    assert_eq!(
+        "3",
+        pod_config.broker_list[0].config_map["ibkr.cp.max.reply.count"]
+    );
+
+    // every missing variable of the document is reported at once
+    let err =
+        parse_pod_config(YAML_POD_CONFIG, PodConfigFormat::Yaml, |_| Option::None).unwrap_err();
+    assert_eq!("MISSING_ENV_VAR IBKR_ACCOUNT, POD_NAME", err.to_string());
+
+    let get_env_var = |name: &str| (name == "PREFIX").then(|| "\"rabbit\n".to_owned());
+    let pod_config = parse_pod_config(
+        &TOML_POD_CONFIG.replace("rabbit.", "${PREFIX}"),
+        PodConfigFormat::Toml,
+        get_env_var,
+    )
+    .unwrap();
+    assert_eq!("yahoo_finance", pod_config.broker_list[0].identifier);
+    assert_eq!(
+        "\"rabbit\n",
+        pod_config.metrics_registry.config_map["metrics.statsd.prefix"]
+    );
+
+    let json = serde_json::to_string(&pod_config).unwrap();
+    assert_eq!(
+        pod_config,
+        parse_pod_config(&json, PodConfigFormat::Json, |_| Option::None).unwrap()
+    );
+    assert!(parse_pod_config(TOML_POD_CONFIG, PodConfigFormat::Yaml, |_| Option::None).is_err());
+}
+
+#[test]
+fn test_load_pod_config() {
+    let mut file = tempfile::Builder::new().suffix(".toml").tempfile().unwrap();
+    file.write_all(TOML_POD_CONFIG.as_bytes()).unwrap();
+    let pod_config = load_pod_config(file.path()).unwrap();
+    assert_eq!("test_pod", pod_config.name);
+
+    assert!(load_pod_config(Path::new("./not_existing_pod.yaml")).is_err());
+}
@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use crate::{
+    model::config::restart_policy::{RestartPolicy, RestartPolicyConfig},
+    pod::state::PodState,
+};
+
+#[test]
+fn test_should_restart() {
+    let failed = PodState::Failed {
+        reason: "EMPTY_MESSAGE_RECEIVED".to_owned(),
+    };
+    let never = RestartPolicyConfig::default();
+    assert!(!never.should_restart(&failed, 0));
+
+    let on_failure = RestartPolicyConfig {
+        policy: RestartPolicy::OnFailure,
+        max_retries: Option::Some(2),
+        ..Default::default()
+    };
+    assert!(on_failure.should_restart(&failed, 0));
+    assert!(on_failure.should_restart(&failed, 1));
+    assert!(!on_failure.should_restart(&failed, 2));
+    assert!(!on_failure.should_restart(&PodState::Stopped, 0));
+
+    let always = RestartPolicyConfig {
+        policy: RestartPolicy::Always,
+        ..Default::default()
+    };
+    assert!(always.should_restart(&failed, 100));
+    assert!(always.should_restart(&PodState::Stopped, 0));
+    assert!(!always.should_restart(&PodState::Running, 0));
+}
+
+#[test]
+fn test_get_backoff() {
+    let restart_policy = RestartPolicyConfig {
+        policy: RestartPolicy::OnFailure,
+        max_retries: Option::None,
+        initial_backoff_seconds: 2,
+        max_backoff_seconds: 60,
+        healthy_seconds: 60,
+    };
+    assert_eq!(Duration::from_secs(2), restart_policy.get_backoff(0));
+    assert_eq!(Duration::from_secs(4), restart_policy.get_backoff(1));
+    assert_eq!(Duration::from_secs(32), restart_policy.get_backoff(4));
+    assert_eq!(Duration::from_secs(60), restart_policy.get_backoff(5));
+    assert_eq!(Duration::from_secs(60), restart_policy.get_backoff(100));
+}
+
+#[test]
+fn test_deserialize_restart_policy_config() {
+    let restart_policy: RestartPolicyConfig =
+        serde_json::from_str(r#"{"policy": "OnFailure", "max_retries": 3}"#).unwrap();
+    assert_eq!(
+        RestartPolicyConfig {
+            policy: RestartPolicy::OnFailure,
+            max_retries: Option::Some(3),
+            initial_backoff_seconds: 1,
+            max_backoff_seconds: 300,
+            healthy_seconds: 60,
+        },
+        restart_policy
+    );
+}
+
+#[test]
+fn test_is_healthy_run() {
+    let restart_policy = RestartPolicyConfig {
+        healthy_seconds: 30,
+        ..Default::default()
+    };
+    assert!(!restart_policy.is_healthy_run(Duration::from_secs(29)));
+    assert!(restart_policy.is_healthy_run(Duration::from_secs(30)));
+
+    // a threshold of zero still requires the pod to run a while
+    let restart_policy = RestartPolicyConfig {
+        healthy_seconds: 0,
+        ..Default::default()
+    };
+    assert!(!restart_policy.is_healthy_run(Duration::ZERO));
+    assert!(restart_policy.is_healthy_run(Duration::from_secs(1)));
+}
@@ -1 +1,3 @@
+pub mod common;
+pub mod config;
 pub mod trading;
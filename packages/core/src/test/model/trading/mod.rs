@@ -1,3 +1,4 @@
 pub mod currency;
 pub mod market;
 pub mod symbol;
+pub mod transaction;
@@ -0,0 +1,94 @@
+use rust_decimal_macros::dec;
+
+use crate::model::{
+    common::timestamp::Timestamp,
+    trading::{
+        market::Market,
+        symbol::Symbol,
+        transaction::{
+            Direction, Execution, Expire, ListExecutionsRequest, ListOrderHistoryRequest,
+            OrderStatus, Price, RegularTradingTime, SubmitBracketOrderRequest, SubmitOrderRequest,
+        },
+    },
+};
+
+fn get_execution(identifier: &str, timestamp: Timestamp) -> Execution {
+    Execution {
+        execution_id: "execution_id".to_owned(),
+        order_id: Option::None,
+        symbol: Symbol {
+            market: Market::US,
+            identifier: identifier.to_owned(),
+        },
+        direction: Option::None,
+        quantity: dec!(100),
+        price: dec!(150.5),
+        fee: Option::None,
+        currency: Option::None,
+        timestamp,
+    }
+}
+
+#[test]
+fn test_order_status_is_open() {
+    assert!(OrderStatus::Pending.is_open());
+    assert!(OrderStatus::PartiallyFilled.is_open());
+    assert!(!OrderStatus::Filled.is_open());
+    assert!(!OrderStatus::Cancelled.is_open());
+    assert!(!OrderStatus::Unknown.is_open());
+}
+
+#[test]
+fn test_list_executions_request_is_matched() {
+    let execution = get_execution("ABNB", Timestamp::from_secs(1_718_000_000));
+    assert!(ListExecutionsRequest::default().is_matched(&execution));
+
+    let request = ListExecutionsRequest {
+        symbol: Option::Some(execution.symbol.clone()),
+        start_timestamp: Option::Some(Timestamp::from_secs(1_718_000_000)),
+        end_timestamp: Option::Some(Timestamp::from_secs(1_718_000_000)),
+    };
+    assert!(request.is_matched(&execution));
+    assert!(!request.is_matched(&get_execution("META", execution.timestamp)));
+    assert!(!request.is_matched(&get_execution("ABNB", Timestamp::from_secs(1_718_000_001))));
+}
+
+#[test]
+fn test_list_order_history_request_default() {
+    let request = ListOrderHistoryRequest::default();
+    assert_eq!(Option::None, request.symbol);
+    assert_eq!(Option::None, request.start_timestamp);
+    assert_eq!(Option::None, request.end_timestamp);
+}
+
+#[test]
+fn test_bracket_child_request() {
+    let entry = SubmitOrderRequest {
+        symbol: Symbol {
+            market: Market::US,
+            identifier: "ABNB".to_owned(),
+        },
+        quantity: dec!(100),
+        direction: Direction::Buy,
+        regular_trading_time: RegularTradingTime::OnlyRegularTradingTime,
+        expire: Expire::GoodTillCancelled,
+        price: Price::LimitOrder { price: dec!(150) },
+    };
+    let request = SubmitBracketOrderRequest {
+        entry: entry.clone(),
+        take_profit: Option::None,
+        stop_loss: Option::None,
+    };
+    let child_request = request.get_child_request(Price::Stop {
+        stop_price: dec!(140),
+    });
+    assert_eq!(Direction::Sell, child_request.direction);
+    assert_eq!(entry.quantity, child_request.quantity);
+    assert_eq!(entry.expire, child_request.expire);
+    assert_eq!(
+        Price::Stop {
+            stop_price: dec!(140)
+        },
+        child_request.price
+    );
+}
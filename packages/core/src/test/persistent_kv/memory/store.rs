@@ -13,10 +13,13 @@ async fn test_operations_on_memory_kv_store() {
 
     assert!(kv_store.read(MAP_KEY_1.to_owned()).await.is_err());
     assert!(kv_store.read(MAP_KEY_2.to_owned()).await.is_err());
+    assert!(!kv_store.exists(MAP_KEY_1.to_owned()).await.unwrap());
     assert!(kv_store
         .write(MAP_KEY_1.to_owned(), MAP_VALUE_1.as_bytes().to_owned())
         .await
         .is_ok());
     assert!(kv_store.read(MAP_KEY_1.to_owned()).await.is_ok());
     assert!(kv_store.read(MAP_KEY_2.to_owned()).await.is_err());
+    assert!(kv_store.exists(MAP_KEY_1.to_owned()).await.unwrap());
+    assert!(!kv_store.exists(MAP_KEY_2.to_owned()).await.unwrap());
 }
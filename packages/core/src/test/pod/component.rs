@@ -0,0 +1,49 @@
+use serde_json::json;
+
+use crate::pod::component::{get_component_schema_list, ComponentKind, ComponentSchema};
+
+#[test]
+fn test_get_component_schema_list() {
+    let component_schema_list = get_component_schema_list(Option::None);
+    for kind in ComponentKind::ALL {
+        // every compiled in identifier has a schema
+        assert_eq!(
+            kind.get_identifier_list().len(),
+            component_schema_list
+                .iter()
+                .filter(|component_schema| component_schema.kind == kind)
+                .count()
+        );
+    }
+
+    let broker_schema_list = get_component_schema_list(Option::Some(ComponentKind::Broker));
+    assert!(broker_schema_list
+        .iter()
+        .all(|component_schema| component_schema.kind == ComponentKind::Broker));
+}
+
+#[test]
+#[cfg(feature = "broker__interactive_brokers")]
+fn test_component_schema() {
+    let component_schema =
+        ComponentSchema::new(ComponentKind::Broker, "interactive_brokers").unwrap();
+    assert_eq!(
+        json!(["ibkr.cp.account", "ibkr.cp.max.reply.count"]),
+        component_schema.json_schema["required"]
+    );
+
+    assert!(ComponentSchema::new(ComponentKind::Strategy, "interactive_brokers").is_err());
+}
+
+#[test]
+fn test_parse_component_kind() {
+    assert_eq!(
+        ComponentKind::EventListener,
+        "event_listener".parse::<ComponentKind>().unwrap()
+    );
+    assert_eq!(
+        ComponentKind::PersistentKVStore,
+        "PersistentKVStore".parse::<ComponentKind>().unwrap()
+    );
+    assert!("listener".parse::<ComponentKind>().is_err());
+}
@@ -0,0 +1,52 @@
+use std::{collections::HashMap, fs, time::Duration};
+use tokio::{sync::broadcast, time::sleep};
+
+use crate::{
+    model::{
+        common::timestamp::Timestamp,
+        trading::event::{EventContext, RabbitTradingEvent},
+    },
+    pod::{
+        event::listener::{
+            common::listener::EventListenerTrait, file::listener::FileEventListener,
+        },
+        state::PodState,
+    },
+};
+
+#[tokio::test]
+async fn test_append_event() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("event").join("pod.jsonl");
+    let file_event_listener = FileEventListener::new(HashMap::from([(
+        FileEventListener::CONFIG_KEY_PATH.to_owned(),
+        path.to_str().unwrap().to_owned(),
+    )]));
+    assert_eq!(path.as_path(), file_event_listener.get_path());
+
+    let (sender, receiver) = broadcast::channel::<RabbitTradingEvent>(256);
+    file_event_listener.start(receiver);
+    let event_list: Vec<RabbitTradingEvent> = [PodState::Initializing, PodState::Running]
+        .into_iter()
+        .map(|state| RabbitTradingEvent::PodStateChanged {
+            context: EventContext {
+                broker_id: "".to_owned(),
+                pod_id: "test_pod_1".to_owned(),
+                timestamp: Timestamp::now(),
+            },
+            state,
+        })
+        .collect();
+    for event in &event_list {
+        sender.send(event.clone()).unwrap();
+    }
+    sleep(Duration::from_millis(500)).await;
+
+    let written_event_list: Vec<RabbitTradingEvent> = fs::read_to_string(&path)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(event_list, written_event_list);
+    file_event_listener.stop().unwrap();
+}
@@ -4,7 +4,7 @@ use tokio::{sync::broadcast, time::sleep};
 
 use crate::{
     model::{
-        common::types::ConfigMap,
+        common::{timestamp::Timestamp, types::ConfigMap},
         trading::{
             event::{EventContext, RabbitTradingEvent},
             market::Market,
@@ -18,7 +18,6 @@ use crate::{
     pod::event::listener::{
         common::listener::EventListenerTrait, log_container::listener::LogContainerEventListener,
     },
-    utils::time::get_now_unix_timestamp,
 };
 
 #[tokio::test]
@@ -35,7 +34,7 @@ async fn test_whole_lifecycle() {
         context: EventContext {
             broker_id: BROKER_ID.to_owned(),
             pod_id: POD_ID.to_owned(),
-            timestamp: get_now_unix_timestamp(),
+            timestamp: Timestamp::now(),
         },
         request: SubmitOrderRequest {
             symbol: Symbol {
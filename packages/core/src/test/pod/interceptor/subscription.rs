@@ -0,0 +1,71 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::sync::mpsc;
+
+use crate::{
+    broker::common::subscription::{SubscriptionController, SubscriptionInterceptorTrait},
+    metrics::noops::registry::NoOpMetricRegistry,
+    model::trading::{
+        market::Market,
+        quote::{QueryInfoRequest, QuoteKind, QuoteRealTimeInfo},
+        symbol::Symbol,
+    },
+    pod::interceptor::subscription::PodSubscriptionInterceptor,
+};
+
+struct MockSubscriptionController {
+    is_stopped: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl SubscriptionController for MockSubscriptionController {
+    async fn stop(&self) -> Result<(), Error> {
+        self.is_stopped.store(true, Ordering::Relaxed);
+        Result::Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_subscription_controller_tracking() {
+    let subscription_controller_list = Arc::new(Mutex::new(Vec::new()));
+    let interceptor = PodSubscriptionInterceptor::new(
+        Box::new(NoOpMetricRegistry {}),
+        subscription_controller_list.clone(),
+    );
+    let is_stopped = Arc::new(AtomicBool::new(false));
+    let (_, receiver) = mpsc::channel::<QuoteRealTimeInfo>(1);
+    let controller: Box<dyn SubscriptionController> = Box::new(MockSubscriptionController {
+        is_stopped: is_stopped.clone(),
+    });
+
+    let (_, controller) = interceptor
+        .after_real_time_info(
+            QueryInfoRequest {
+                symbol: Symbol {
+                    market: Market::US,
+                    identifier: "ABNB".to_owned(),
+                },
+                kind: QuoteKind::Stock,
+            },
+            Result::Ok((receiver, controller)),
+            Duration::ZERO,
+        )
+        .await
+        .unwrap();
+    assert_eq!(1, subscription_controller_list.lock().unwrap().len());
+
+    // the tracked handle and the one given back stop the same subscription
+    let tracked_controller = subscription_controller_list.lock().unwrap()[0].clone();
+    tracked_controller.stop().await.unwrap();
+    assert!(is_stopped.load(Ordering::Relaxed));
+    is_stopped.store(false, Ordering::Relaxed);
+    controller.stop().await.unwrap();
+    assert!(is_stopped.load(Ordering::Relaxed));
+}
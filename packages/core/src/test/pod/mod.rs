@@ -1 +1,8 @@
+pub mod component;
 pub mod event;
+pub mod interceptor;
+pub mod pod;
+pub mod recorder;
+pub mod scheduler;
+pub mod shutdown;
+pub mod validator;
@@ -0,0 +1,185 @@
+use rust_decimal_macros::dec;
+use std::collections::HashMap;
+use tempfile::tempdir;
+
+use crate::{
+    model::config::{
+        broker::BrokerConfig,
+        metrics_registry::MetricsRegistryConfig,
+        persistent_kv_store::PersistentKVStoreConfig,
+        pod::PodConfig,
+        shutdown_policy::{OpenPositionAction, ShutdownPolicyConfig},
+        strategy::StrategyConfig,
+    },
+    model::trading::{
+        event::RabbitTradingEvent,
+        market::Market,
+        symbol::Symbol,
+        transaction::{Direction, Expire, Price, RegularTradingTime, SubmitOrderRequest},
+    },
+    pod::{
+        state::{PodState, PodStatus},
+        Pod,
+    },
+};
+
+fn get_pod_config(strategy_identifier: &str) -> PodConfig {
+    PodConfig {
+        name: "test_pod".to_owned(),
+        broker_list: vec![],
+        persistent_kv_store: PersistentKVStoreConfig {
+            identifier: "MemoryKVStore".to_owned(),
+            config_map: HashMap::new(),
+        },
+        strategy: StrategyConfig {
+            identifier: strategy_identifier.to_owned(),
+            config_map: HashMap::new(),
+        },
+        metrics_registry: MetricsRegistryConfig {
+            identifier: "NoOpMetricRegistryFactory".to_owned(),
+            config_map: HashMap::new(),
+        },
+        event_listener_list: vec![],
+        clock: Option::None,
+        recorder_list: vec![],
+        schedule: Option::None,
+        restart_policy: Default::default(),
+        shutdown_policy: Default::default(),
+    }
+}
+
+fn get_pod(strategy_identifier: &str) -> Pod {
+    Pod::new(
+        get_pod_config(strategy_identifier),
+        "test_pod_id".to_owned(),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_pod_initialization_failure() {
+    let pod = get_pod("UnknownStrategy");
+    assert_eq!(PodStatus::new(), pod.get_status());
+
+    assert!(pod.start().await.is_err());
+    let status = pod.get_status();
+    assert!(matches!(status.state, PodState::Failed { .. }));
+    assert!(status
+        .last_error
+        .unwrap()
+        .contains("IDENTIFIER_NOT_MATCHED"));
+
+    // a failed pod can be stopped and started again
+    pod.stop().await.unwrap();
+    assert!(pod.start().await.is_err());
+}
+
+#[tokio::test]
+async fn test_pod_state_changed_event() {
+    let pod = get_pod("UnknownStrategy");
+    assert!(pod.start().await.is_err());
+    // events are consumed asynchronously by the log container
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    let state_list: Vec<PodState> = pod
+        .inspect_log()
+        .await
+        .iter()
+        .filter_map(|event| match event {
+            RabbitTradingEvent::PodStateChanged { context, state } => {
+                assert_eq!("test_pod_id", context.pod_id);
+                Option::Some(state.clone())
+            }
+            _ => Option::None,
+        })
+        .collect();
+    assert_eq!(2, state_list.len());
+    assert_eq!(PodState::Initializing, state_list[0]);
+    assert!(matches!(state_list[1], PodState::Failed { .. }));
+}
+
+#[tokio::test]
+async fn test_pod_stop_before_start() {
+    let pod = get_pod("UnknownStrategy");
+    pod.stop().await.unwrap();
+    assert_eq!(PodState::Stopped, pod.get_status().state);
+    assert_eq!(Option::None, pod.get_status().last_error);
+}
+
+#[tokio::test]
+async fn test_pod_strategy_panic() {
+    // the example grid trading strategy is not implemented yet and panics on start
+    let pod = get_pod("ExampleGridTradingStrategy");
+    pod.start().await.unwrap();
+    pod.join().await;
+
+    let status = pod.get_status();
+    assert!(matches!(status.state, PodState::Failed { .. }));
+    assert!(status.last_error.unwrap().contains("strategy"));
+
+    // nothing is left to stop, the pod keeps its failure
+    let shutdown_report = pod.stop().await.unwrap();
+    assert!(shutdown_report.is_strategy_stopped);
+    assert!(shutdown_report.error_list.is_empty());
+    assert!(matches!(pod.get_status().state, PodState::Failed { .. }));
+}
+
+#[tokio::test]
+async fn test_pod_create_broker_with_illegal_id() {
+    let pod = get_pod("UnknownStrategy");
+
+    for broker_identifier in [Option::None, Option::Some("UnknownBroker")] {
+        let err = pod.create_broker(broker_identifier).err().unwrap();
+        assert!(err.to_string().contains("ILLEGAL_BROKER_ID"));
+    }
+}
+
+#[tokio::test]
+async fn test_pod_stop_cancels_orders_of_lent_transaction() {
+    let temp_dir = tempdir().unwrap();
+    let pod = Pod::new(
+        PodConfig {
+            broker_list: vec![BrokerConfig {
+                identifier: "replay".to_owned(),
+                config_map: HashMap::from([(
+                    "replay.base_path".to_owned(),
+                    temp_dir.path().to_str().unwrap().to_owned(),
+                )]),
+            }],
+            shutdown_policy: ShutdownPolicyConfig {
+                open_position_action: OpenPositionAction::CancelOpenOrders,
+                ..Default::default()
+            },
+            ..get_pod_config("ExampleGridTradingStrategy")
+        },
+        "test_pod_id".to_owned(),
+    )
+    .unwrap();
+    pod.start().await.unwrap();
+    pod.join().await;
+
+    // nothing is replayed, so the order stays open in the replay transaction that lent it
+    let order_id = pod
+        .get_transaction(Option::None)
+        .unwrap()
+        .lock()
+        .await
+        .submit_order(SubmitOrderRequest {
+            symbol: Symbol {
+                market: Market::US,
+                identifier: "ABNB".to_owned(),
+            },
+            quantity: dec!(100),
+            direction: Direction::Buy,
+            regular_trading_time: RegularTradingTime::AllTime,
+            expire: Expire::Day,
+            price: Price::LimitOrder { price: dec!(1) },
+        })
+        .await
+        .unwrap()
+        .order_id;
+
+    let shutdown_report = pod.stop().await.unwrap();
+    assert_eq!(vec![order_id], shutdown_report.cancelled_order_id_list);
+    assert!(shutdown_report.error_list.is_empty());
+}
@@ -0,0 +1,151 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use flate2::read::MultiGzDecoder;
+use rust_decimal_macros::dec;
+use std::{
+    fs,
+    io::Read,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tempfile::tempdir;
+use tokio::{sync::mpsc, time::sleep};
+
+use crate::{
+    broker::common::subscription::{SubscriptionController, SubscriptionData, SubscriptionTrait},
+    model::{
+        common::{timestamp::Timestamp, types::ConfigMap},
+        trading::quote::{QueryInfoRequest, QuoteDepthInfo, QuoteRealTimeInfo},
+    },
+    pod::recorder::market_data_recorder::MarketDataRecorder,
+    utils::clock::{common_trait::ClockTrait, system::SystemClock},
+};
+
+struct MockSubscriptionController {}
+
+#[async_trait]
+impl SubscriptionController for MockSubscriptionController {
+    async fn stop(&self) -> Result<(), Error> {
+        Result::Ok(())
+    }
+}
+
+struct MockSubscription {}
+
+#[async_trait]
+impl SubscriptionTrait for MockSubscription {
+    fn new(
+        _config_map: ConfigMap,
+        _global_stopped_indicator: Arc<AtomicBool>,
+        _clock: Arc<dyn ClockTrait>,
+    ) -> Self {
+        MockSubscription {}
+    }
+
+    async fn real_time_info(
+        &self,
+        request: QueryInfoRequest,
+    ) -> Result<SubscriptionData<QuoteRealTimeInfo>, Error> {
+        let (sender, receiver) = mpsc::channel(64);
+        for sequence in 1..=3 {
+            sender
+                .send(QuoteRealTimeInfo {
+                    symbol: request.symbol.clone(),
+                    sequence,
+                    timestamp: Timestamp::from_secs(1718000000 + sequence),
+                    current_price: dec!(10),
+                    volume: Option::None,
+                    low_price: Option::None,
+                    high_price: Option::None,
+                    open_price: Option::None,
+                    prev_close: Option::None,
+                    turnover: Option::None,
+                    extra: Option::None,
+                })
+                .await?;
+        }
+        Result::Ok((receiver, Box::new(MockSubscriptionController {})))
+    }
+
+    async fn depth_info(
+        &self,
+        _request: QueryInfoRequest,
+    ) -> Result<SubscriptionData<QuoteDepthInfo>, Error> {
+        let (_sender, receiver) = mpsc::channel(64);
+        Result::Ok((receiver, Box::new(MockSubscriptionController {})))
+    }
+}
+
+#[test]
+fn test_missing_symbols() {
+    let recorder = MarketDataRecorder::new(
+        Box::new(MockSubscription::new(
+            ConfigMap::new(),
+            Arc::new(AtomicBool::new(false)),
+            Arc::new(SystemClock::new(ConfigMap::new())),
+        )),
+        ConfigMap::new(),
+        Arc::new(AtomicBool::new(false)),
+    );
+    assert!(recorder.is_err());
+}
+
+#[tokio::test]
+async fn test_record_real_time_info() {
+    let temp_dir = tempdir().unwrap();
+    let stopped_indicator = Arc::new(AtomicBool::new(false));
+    let config_map = ConfigMap::from([
+        (
+            MarketDataRecorder::CONFIG_KEY_SYMBOLS.to_owned(),
+            "ABNB.US, 700.HK".to_owned(),
+        ),
+        (
+            MarketDataRecorder::CONFIG_KEY_BASE_PATH.to_owned(),
+            temp_dir.path().to_str().unwrap().to_owned(),
+        ),
+        (
+            MarketDataRecorder::CONFIG_KEY_DEPTH_INFO.to_owned(),
+            "false".to_owned(),
+        ),
+    ]);
+    let recorder = MarketDataRecorder::new(
+        Box::new(MockSubscription::new(
+            ConfigMap::new(),
+            stopped_indicator.clone(),
+            Arc::new(SystemClock::new(ConfigMap::new())),
+        )),
+        config_map,
+        stopped_indicator.clone(),
+    )
+    .unwrap();
+    recorder.start().await.unwrap();
+    sleep(Duration::from_millis(100)).await;
+    stopped_indicator.store(true, Ordering::Relaxed);
+    sleep(Duration::from_millis(1500)).await;
+
+    for symbol in ["ABNB.US", "700.HK"] {
+        let mut content = String::new();
+        MultiGzDecoder::new(
+            fs::File::open(
+                temp_dir
+                    .path()
+                    .join(symbol)
+                    .join("real_time")
+                    .join("2024-06-10.jsonl.gz"),
+            )
+            .unwrap(),
+        )
+        .read_to_string(&mut content)
+        .unwrap();
+        let sequence_list: Vec<u64> = content
+            .lines()
+            .map(|line| serde_json::from_str::<QuoteRealTimeInfo>(line).unwrap())
+            .map(|quote_info| quote_info.sequence)
+            .collect();
+        assert_eq!(vec![1, 2, 3], sequence_list);
+    }
+    assert!(!temp_dir.path().join("ABNB.US").join("depth").exists());
+}
@@ -0,0 +1,3 @@
+pub mod market_data_recorder;
+pub mod reader;
+pub mod writer;
@@ -0,0 +1,131 @@
+use rust_decimal_macros::dec;
+use std::{collections::HashMap, time::Duration};
+use tempfile::tempdir;
+
+use crate::{
+    model::{
+        common::timestamp::Timestamp,
+        trading::{
+            market::Market,
+            quote::{Depth, QuoteDepthInfo, QuoteRealTimeInfo},
+            symbol::Symbol,
+        },
+    },
+    pod::recorder::{
+        reader::RecordFileReader,
+        writer::{RecordCompression, RecordFileWriter, RecordFormat},
+    },
+};
+
+// 2024-06-10 06:13:20 UTC
+const TIMESTAMP: Timestamp = Timestamp::from_secs(1718000000);
+const ONE_DAY: Duration = Duration::from_secs(86400);
+
+fn get_symbol() -> Symbol {
+    Symbol {
+        market: Market::HK,
+        identifier: "700".to_owned(),
+    }
+}
+
+#[test]
+fn test_read_csv_real_time_info() {
+    let temp_dir = tempdir().unwrap();
+    let quote_info_list: Vec<QuoteRealTimeInfo> = [TIMESTAMP, TIMESTAMP + ONE_DAY]
+        .into_iter()
+        .enumerate()
+        .map(|(index, timestamp)| QuoteRealTimeInfo {
+            symbol: get_symbol(),
+            sequence: index as u64,
+            timestamp,
+            current_price: dec!(380.2),
+            volume: Option::Some(dec!(1000)),
+            low_price: Option::Some(dec!(375)),
+            high_price: Option::None,
+            open_price: Option::None,
+            prev_close: Option::None,
+            turnover: Option::None,
+            extra: Option::Some(HashMap::from([(
+                "note".to_owned(),
+                "a, \"quoted\" value".to_owned(),
+            )])),
+        })
+        .collect();
+
+    let mut writer = RecordFileWriter::new(
+        temp_dir.path().to_path_buf(),
+        RecordFormat::Csv,
+        RecordCompression::Gzip,
+    );
+    quote_info_list
+        .iter()
+        .for_each(|quote_info| writer.write(quote_info).unwrap());
+    writer.close().unwrap();
+
+    let reader = RecordFileReader::<QuoteRealTimeInfo>::new(
+        temp_dir.path().to_path_buf(),
+        RecordFormat::Csv,
+        RecordCompression::Gzip,
+    );
+    let date_list = reader.list_date(&get_symbol()).unwrap();
+    assert_eq!(vec!["2024-06-10", "2024-06-11"], date_list);
+    let read_list: Vec<QuoteRealTimeInfo> = date_list
+        .iter()
+        .flat_map(|date| reader.read(&get_symbol(), date).unwrap())
+        .collect();
+    assert_eq!(quote_info_list, read_list);
+}
+
+#[test]
+fn test_read_csv_depth_info() {
+    let temp_dir = tempdir().unwrap();
+    let depth = Depth {
+        position: Option::Some(dec!(1)),
+        price: dec!(380.2),
+        volume: Option::Some(dec!(500)),
+        order_count: Option::None,
+    };
+    let depth_info_list = vec![
+        QuoteDepthInfo {
+            symbol: get_symbol(),
+            sequence: 1,
+            timestamp: TIMESTAMP,
+            ask_list: vec![depth.clone(), depth.clone()],
+            bid_list: vec![depth.clone()],
+        },
+        QuoteDepthInfo {
+            symbol: get_symbol(),
+            sequence: 2,
+            timestamp: TIMESTAMP + Duration::from_secs(1),
+            ask_list: vec![],
+            bid_list: vec![depth],
+        },
+    ];
+
+    let mut writer = RecordFileWriter::new(
+        temp_dir.path().to_path_buf(),
+        RecordFormat::Csv,
+        RecordCompression::None,
+    );
+    depth_info_list
+        .iter()
+        .for_each(|depth_info| writer.write(depth_info).unwrap());
+    writer.close().unwrap();
+
+    let reader = RecordFileReader::<QuoteDepthInfo>::new(
+        temp_dir.path().to_path_buf(),
+        RecordFormat::Csv,
+        RecordCompression::None,
+    );
+    assert_eq!(
+        depth_info_list,
+        reader.read(&get_symbol(), "2024-06-10").unwrap()
+    );
+    assert!(reader
+        .list_date(&Symbol {
+            market: Market::US,
+            identifier: "QQQ".to_owned(),
+        })
+        .unwrap()
+        .is_empty());
+}
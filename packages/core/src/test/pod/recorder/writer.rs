@@ -0,0 +1,168 @@
+use flate2::read::MultiGzDecoder;
+use rust_decimal_macros::dec;
+use std::{fs, io::Read, path::PathBuf, time::Duration};
+use tempfile::tempdir;
+
+use crate::{
+    model::{
+        common::timestamp::Timestamp,
+        trading::{
+            market::Market,
+            quote::{Depth, QuoteDepthInfo, QuoteRealTimeInfo},
+            symbol::Symbol,
+        },
+    },
+    pod::recorder::writer::{RecordCompression, RecordFileWriter, RecordFormat},
+};
+
+// 2024-06-10 06:13:20 UTC
+const TIMESTAMP: Timestamp = Timestamp::from_secs(1718000000);
+const ONE_SECOND: Duration = Duration::from_secs(1);
+const ONE_DAY: Duration = Duration::from_secs(86400);
+
+fn get_symbol() -> Symbol {
+    Symbol {
+        market: Market::US,
+        identifier: "ABNB".to_owned(),
+    }
+}
+
+fn get_quote_real_time_info(sequence: u64, timestamp: Timestamp) -> QuoteRealTimeInfo {
+    QuoteRealTimeInfo {
+        symbol: get_symbol(),
+        sequence,
+        timestamp,
+        current_price: dec!(150.5),
+        volume: Option::Some(dec!(100)),
+        low_price: Option::None,
+        high_price: Option::None,
+        open_price: Option::None,
+        prev_close: Option::None,
+        turnover: Option::None,
+        extra: Option::None,
+    }
+}
+
+fn read_gzip(path: PathBuf) -> String {
+    let mut content = String::new();
+    MultiGzDecoder::new(fs::File::open(path).unwrap())
+        .read_to_string(&mut content)
+        .unwrap();
+    content
+}
+
+#[test]
+fn test_get_date() {
+    assert_eq!(
+        "2024-06-10",
+        RecordFileWriter::<QuoteRealTimeInfo>::get_date(TIMESTAMP).unwrap()
+    );
+}
+
+#[test]
+fn test_json_lines_gzip_rotation() {
+    let temp_dir = tempdir().unwrap();
+    let mut writer = RecordFileWriter::<QuoteRealTimeInfo>::new(
+        temp_dir.path().to_path_buf(),
+        RecordFormat::JsonLines,
+        RecordCompression::Gzip,
+    );
+    let quote_info_list = [
+        get_quote_real_time_info(1, TIMESTAMP),
+        get_quote_real_time_info(2, TIMESTAMP + ONE_SECOND),
+        get_quote_real_time_info(3, TIMESTAMP + ONE_DAY),
+    ];
+    quote_info_list
+        .iter()
+        .for_each(|quote_info| writer.write(quote_info).unwrap());
+    writer.close().unwrap();
+
+    let first_day_path = writer.get_file_path(&get_symbol(), "2024-06-10");
+    assert_eq!(
+        temp_dir
+            .path()
+            .join("ABNB.US")
+            .join("real_time")
+            .join("2024-06-10.jsonl.gz"),
+        first_day_path
+    );
+    let first_day: Vec<QuoteRealTimeInfo> = read_gzip(first_day_path)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(quote_info_list[0..2].to_vec(), first_day);
+
+    // re-opening the file of the same day appends a new gzip member
+    writer
+        .write(&get_quote_real_time_info(
+            4,
+            TIMESTAMP + ONE_DAY + ONE_SECOND,
+        ))
+        .unwrap();
+    writer.close().unwrap();
+    let second_day: Vec<QuoteRealTimeInfo> =
+        read_gzip(writer.get_file_path(&get_symbol(), "2024-06-11"))
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+    assert_eq!(
+        vec![3, 4],
+        second_day
+            .iter()
+            .map(|quote_info| quote_info.sequence)
+            .collect::<Vec<u64>>()
+    );
+}
+
+#[test]
+fn test_csv_depth_info() {
+    let temp_dir = tempdir().unwrap();
+    let mut writer = RecordFileWriter::<QuoteDepthInfo>::new(
+        temp_dir.path().to_path_buf(),
+        RecordFormat::Csv,
+        RecordCompression::None,
+    );
+    let depth_info = QuoteDepthInfo {
+        symbol: get_symbol(),
+        sequence: 7,
+        timestamp: TIMESTAMP,
+        ask_list: vec![Depth {
+            position: Option::Some(dec!(1)),
+            price: dec!(150.6),
+            volume: Option::Some(dec!(200)),
+            order_count: Option::None,
+        }],
+        bid_list: vec![Depth {
+            position: Option::Some(dec!(1)),
+            price: dec!(150.4),
+            volume: Option::Some(dec!(300)),
+            order_count: Option::Some(dec!(2)),
+        }],
+    };
+    writer.write(&depth_info).unwrap();
+    writer.write(&depth_info).unwrap();
+    writer.close().unwrap();
+
+    let content = fs::read_to_string(
+        temp_dir
+            .path()
+            .join("ABNB.US")
+            .join("depth")
+            .join("2024-06-10.csv"),
+    )
+    .unwrap();
+    let line_list: Vec<&str> = content.lines().collect();
+    assert_eq!(5, line_list.len());
+    assert_eq!(
+        "symbol,sequence,timestamp,side,position,price,volume,order_count",
+        line_list[0]
+    );
+    assert_eq!(
+        "ABNB.US,7,1718000000000000000,ask,1,150.6,200,",
+        line_list[1]
+    );
+    assert_eq!(
+        "ABNB.US,7,1718000000000000000,bid,1,150.4,300,2",
+        line_list[2]
+    );
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+use time::macros::datetime;
+
+use crate::{
+    model::{
+        common::timestamp::Timestamp, config::schedule::ScheduleConfig, trading::market::Market,
+    },
+    pod::scheduler::{PodScheduler, ScheduleWindow},
+    utils::calendar::{
+        schedule::{EarlyClose, HolidayTable},
+        static_calendar::StaticTradingCalendar,
+    },
+};
+
+fn get_pod_scheduler() -> PodScheduler {
+    let calendar = StaticTradingCalendar::new_with_holiday_table_map(&HashMap::from([(
+        Market::HK,
+        HolidayTable {
+            holiday_list: vec!["2024-07-01".to_owned()],
+            early_close_list: vec![EarlyClose {
+                date: "2024-12-24".to_owned(),
+                close_time: "12:00".to_owned(),
+            }],
+        },
+    )]))
+    .unwrap();
+    PodScheduler::new_with_calendar(
+        ScheduleConfig {
+            market: Market::HK,
+            start_before_open_minutes: 5,
+            stop_after_close_minutes: 10,
+            calendar: Option::None,
+        },
+        Box::new(calendar),
+    )
+}
+
+#[tokio::test]
+async fn test_get_next_window() {
+    let pod_scheduler = get_pod_scheduler();
+    let window = ScheduleWindow {
+        start_timestamp: Timestamp::from(datetime!(2024-07-02 01:25 UTC)),
+        stop_timestamp: Timestamp::from(datetime!(2024-07-02 08:10 UTC)),
+    };
+    // skips the weekend and the holiday
+    assert_eq!(
+        window,
+        pod_scheduler
+            .get_next_window(Timestamp::from(datetime!(2024-06-29 00:00 UTC)))
+            .await
+            .unwrap()
+    );
+    // the ongoing window is kept until the stop time
+    assert_eq!(
+        window,
+        pod_scheduler
+            .get_next_window(Timestamp::from(datetime!(2024-07-02 08:05 UTC)))
+            .await
+            .unwrap()
+    );
+    assert_eq!(
+        ScheduleWindow {
+            start_timestamp: Timestamp::from(datetime!(2024-07-03 01:25 UTC)),
+            stop_timestamp: Timestamp::from(datetime!(2024-07-03 08:10 UTC)),
+        },
+        pod_scheduler
+            .get_next_window(Timestamp::from(datetime!(2024-07-02 08:10 UTC)))
+            .await
+            .unwrap()
+    );
+    // half-day
+    assert_eq!(
+        ScheduleWindow {
+            start_timestamp: Timestamp::from(datetime!(2024-12-24 01:25 UTC)),
+            stop_timestamp: Timestamp::from(datetime!(2024-12-24 04:10 UTC)),
+        },
+        pod_scheduler
+            .get_next_window(Timestamp::from(datetime!(2024-12-24 00:00 UTC)))
+            .await
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_new_pod_scheduler() {
+    assert!(PodScheduler::new(ScheduleConfig {
+        market: Market::US,
+        start_before_open_minutes: 0,
+        stop_after_close_minutes: 0,
+        calendar: Option::None,
+    })
+    .is_ok());
+}
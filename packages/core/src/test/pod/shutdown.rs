@@ -0,0 +1,137 @@
+use rust_decimal_macros::dec;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::{
+    broker::common::transaction::TransactionTrait,
+    model::trading::{
+        market::Market,
+        symbol::Symbol,
+        transaction::{
+            Direction, Expire, OrderDetailRequest, OrderStatus, Price, RegularTradingTime,
+            SubmitOrderRequest,
+        },
+    },
+    pod::shutdown::{cancel_open_orders, flatten_positions, ShutdownReport},
+    test::broker::common::test_helper::MockTransaction,
+};
+
+fn get_submit_order_request(direction: Direction) -> SubmitOrderRequest {
+    SubmitOrderRequest {
+        symbol: Symbol {
+            market: Market::US,
+            identifier: "ABNB".to_owned(),
+        },
+        quantity: dec!(100),
+        direction,
+        regular_trading_time: RegularTradingTime::AllTime,
+        expire: Expire::Day,
+        price: Price::LimitOrder { price: dec!(100) },
+    }
+}
+
+#[tokio::test]
+async fn test_cancel_open_orders() {
+    let order_map = Arc::new(Mutex::new(HashMap::new()));
+    let mut transaction: Box<dyn TransactionTrait> = Box::new(MockTransaction {
+        order_map: order_map.clone(),
+        is_cancel_delayed: false,
+    });
+    transaction
+        .submit_order(get_submit_order_request(Direction::Buy))
+        .await
+        .unwrap();
+    transaction
+        .submit_order(get_submit_order_request(Direction::Buy))
+        .await
+        .unwrap();
+    MockTransaction::fill(&order_map, "mock_1");
+
+    let mut report = ShutdownReport::default();
+    assert!(cancel_open_orders(&mut transaction, Duration::from_secs(1), &mut report).await);
+    assert_eq!(vec!["mock_2".to_owned()], report.cancelled_order_id_list);
+    assert!(report.error_list.is_empty());
+    assert_eq!(
+        OrderStatus::Cancelled,
+        transaction
+            .order_detail(OrderDetailRequest {
+                order_id: "mock_2".to_owned(),
+            })
+            .await
+            .unwrap()
+            .status
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_open_orders_delayed() {
+    let order_map = Arc::new(Mutex::new(HashMap::new()));
+    let mut transaction: Box<dyn TransactionTrait> = Box::new(MockTransaction {
+        order_map: order_map.clone(),
+        is_cancel_delayed: true,
+    });
+    transaction
+        .submit_order(get_submit_order_request(Direction::Buy))
+        .await
+        .unwrap();
+
+    // the broker confirms the cancel a while later
+    let handle = tokio::spawn({
+        let order_map = order_map.clone();
+        async move {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            order_map.lock().unwrap().get_mut("mock_1").unwrap().status = OrderStatus::Cancelled;
+        }
+    });
+    let mut report = ShutdownReport::default();
+    assert!(cancel_open_orders(&mut transaction, Duration::from_secs(5), &mut report).await);
+    assert_eq!(vec!["mock_1".to_owned()], report.cancelled_order_id_list);
+    assert!(report.error_list.is_empty());
+    handle.await.unwrap();
+
+    // never confirmed
+    transaction
+        .submit_order(get_submit_order_request(Direction::Buy))
+        .await
+        .unwrap();
+    let mut report = ShutdownReport::default();
+    assert!(!cancel_open_orders(&mut transaction, Duration::from_millis(300), &mut report).await);
+    assert_eq!(vec!["mock_2".to_owned()], report.cancelled_order_id_list);
+    assert_eq!(1, report.error_list.len());
+}
+
+#[tokio::test]
+async fn test_flatten_positions() {
+    let order_map = Arc::new(Mutex::new(HashMap::new()));
+    let mut transaction: Box<dyn TransactionTrait> = Box::new(MockTransaction {
+        order_map: order_map.clone(),
+        is_cancel_delayed: false,
+    });
+    transaction
+        .submit_order(get_submit_order_request(Direction::Buy))
+        .await
+        .unwrap();
+    MockTransaction::fill(&order_map, "mock_1");
+
+    let mut report = ShutdownReport::default();
+    flatten_positions(&mut transaction, &mut report).await;
+    assert_eq!(vec!["mock_2".to_owned()], report.flatten_order_id_list);
+    let order_detail = transaction
+        .order_detail(OrderDetailRequest {
+            order_id: "mock_2".to_owned(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(Direction::Sell, order_detail.direction);
+    assert_eq!(dec!(100), order_detail.quantity);
+    assert_eq!(Price::MarketOrder, order_detail.price);
+
+    // nothing left to close once the closing order is filled
+    MockTransaction::fill(&order_map, "mock_2");
+    let mut report = ShutdownReport::default();
+    flatten_positions(&mut transaction, &mut report).await;
+    assert!(report.flatten_order_id_list.is_empty());
+}
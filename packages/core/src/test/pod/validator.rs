@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use crate::{
+    model::config::{
+        broker::BrokerConfig, clock::ClockConfig, event_listener::EventListenerConfig,
+        metrics_registry::MetricsRegistryConfig, persistent_kv_store::PersistentKVStoreConfig,
+        pod::PodConfig, recorder::RecorderConfig, restart_policy::RestartPolicyConfig,
+        strategy::StrategyConfig,
+    },
+    pod::validator::validate_pod_config,
+};
+
+fn get_pod_config() -> PodConfig {
+    PodConfig {
+        name: "test_pod".to_owned(),
+        broker_list: vec![BrokerConfig {
+            identifier: "interactive_brokers".to_owned(),
+            config_map: HashMap::from([
+                ("ibkr.cp.account".to_owned(), "U1234567".to_owned()),
+                ("ibkr.cp.max.reply.count".to_owned(), "3".to_owned()),
+            ]),
+        }],
+        persistent_kv_store: PersistentKVStoreConfig {
+            identifier: "MemoryKVStore".to_owned(),
+            config_map: HashMap::new(),
+        },
+        strategy: StrategyConfig {
+            identifier: "ExamplePrintLivePriceStrategy".to_owned(),
+            config_map: HashMap::new(),
+        },
+        metrics_registry: MetricsRegistryConfig {
+            identifier: "NoOpMetricRegistryFactory".to_owned(),
+            config_map: HashMap::new(),
+        },
+        event_listener_list: vec![EventListenerConfig {
+            identifier: "LogEventListener".to_owned(),
+            config_map: HashMap::new(),
+        }],
+        clock: Option::None,
+        recorder_list: vec![RecorderConfig {
+            broker_identifier: "interactive_brokers".to_owned(),
+            config_map: HashMap::from([(
+                "recorder.symbols".to_owned(),
+                "AAPL.US,0700.HK".to_owned(),
+            )]),
+        }],
+        schedule: Option::None,
+        restart_policy: Default::default(),
+        shutdown_policy: Default::default(),
+    }
+}
+
+#[test]
+fn test_validate_pod_config() {
+    assert_eq!(Result::Ok(()), validate_pod_config(&get_pod_config()));
+
+    let mut pod_config = get_pod_config();
+    pod_config.broker_list[0]
+        .config_map
+        .remove("ibkr.cp.account");
+    pod_config.broker_list[0]
+        .config_map
+        .insert("ibkr.cp.ssl".to_owned(), "yes".to_owned());
+    pod_config.broker_list.push(BrokerConfig {
+        identifier: "unknown_broker".to_owned(),
+        config_map: HashMap::new(),
+    });
+    pod_config.strategy.identifier = "UnknownStrategy".to_owned();
+    // every key of StatsD has a default
+    pod_config.metrics_registry.identifier = "StatsDMetricRegistryFactory".to_owned();
+    pod_config.clock = Option::Some(ClockConfig {
+        identifier: "SimulatedClock".to_owned(),
+        config_map: HashMap::from([("clock.simulated.speed".to_owned(), "-1x".to_owned())]),
+    });
+    pod_config.recorder_list[0].broker_identifier = "yahoo_finance".to_owned();
+    pod_config.recorder_list[0]
+        .config_map
+        .insert("recorder.format".to_owned(), "parquet".to_owned());
+    pod_config.restart_policy = RestartPolicyConfig {
+        initial_backoff_seconds: 10,
+        max_backoff_seconds: 1,
+        healthy_seconds: 0,
+        ..Default::default()
+    };
+
+    // every problem is reported at once
+    let error_list = validate_pod_config(&pod_config).unwrap_err().error_list;
+    let expected_prefix_list = [
+        "broker_list[0].config_map.ibkr.cp.account: MISSING_CONFIG",
+        "broker_list[0].config_map.ibkr.cp.ssl: ILLEGAL_VALUE yes",
+        "broker_list[1].identifier: IDENTIFIER_NOT_MATCHED Broker: unknown_broker",
+        "strategy.identifier: IDENTIFIER_NOT_MATCHED Strategy: UnknownStrategy",
+        "clock.config_map.clock.simulated.speed: ILLEGAL_VALUE -1x",
+        "recorder_list[0].broker_identifier: ILLEGAL_BROKER_ID yahoo_finance",
+        "recorder_list[0].config_map.recorder.format: ILLEGAL_VALUE parquet",
+        "restart_policy.initial_backoff_seconds: ILLEGAL_VALUE 10",
+        "restart_policy.healthy_seconds: ILLEGAL_VALUE 0",
+    ];
+    assert_eq!(
+        expected_prefix_list.len(),
+        error_list.len(),
+        "{:?}",
+        error_list
+    );
+    for expected_prefix in expected_prefix_list {
+        assert!(
+            error_list
+                .iter()
+                .any(|error| error.starts_with(expected_prefix)),
+            "{} not in {:?}",
+            expected_prefix,
+            error_list
+        );
+    }
+}
+
+#[test]
+fn test_validate_duplicated_broker() {
+    let mut pod_config = get_pod_config();
+    pod_config
+        .broker_list
+        .push(pod_config.broker_list[0].clone());
+    pod_config.recorder_list[0]
+        .config_map
+        .insert("recorder.symbols".to_owned(), "AAPL".to_owned());
+
+    let error_list = validate_pod_config(&pod_config).unwrap_err().error_list;
+    assert_eq!(2, error_list.len(), "{:?}", error_list);
+    assert!(error_list[0].starts_with("broker_list[1].identifier: DUPLICATED_BROKER_ID"));
+    assert!(error_list[1].starts_with("recorder_list[0].config_map.recorder.symbols"));
+}
@@ -0,0 +1,129 @@
+use rust_decimal_macros::dec;
+use std::{
+    collections::HashMap,
+    sync::{atomic::AtomicBool, Arc, Mutex},
+    time::Duration,
+};
+
+use super::twap::get_parent_order;
+use crate::{
+    model::{
+        common::timestamp::Timestamp,
+        trading::transaction::{OrderStatus, Price},
+    },
+    strategy::execution::{
+        common::algorithm::ExecutionProgress,
+        executor::{AlgorithmicExecutor, ExecutionStatus},
+        twap::TimeWeightedAveragePrice,
+    },
+    test::broker::common::test_helper::MockTransaction,
+    utils::clock::manual::ManualClock,
+};
+
+#[tokio::test]
+async fn test_algorithmic_executor() {
+    let order_map = Arc::new(Mutex::new(HashMap::new()));
+    let clock = Arc::new(ManualClock::new_with_timestamp(Timestamp::from_secs(900)));
+    let mut parent_order = get_parent_order(dec!(400), dec!(100));
+    parent_order.limit_price = Option::Some(dec!(300));
+    let mut executor = AlgorithmicExecutor::new(
+        Box::new(MockTransaction {
+            order_map: order_map.clone(),
+            is_cancel_delayed: false,
+        }),
+        Box::new(TimeWeightedAveragePrice::new(4).unwrap()),
+        parent_order,
+        clock.clone(),
+    )
+    .unwrap();
+
+    assert_eq!(ExecutionStatus::Waiting, executor.step().await.unwrap());
+    assert!(order_map.lock().unwrap().is_empty());
+
+    clock.set(Timestamp::from_secs(1_000));
+    assert_eq!(ExecutionStatus::Running, executor.step().await.unwrap());
+    assert_eq!(
+        Price::LimitOrder { price: dec!(300) },
+        order_map.lock().unwrap()["mock_1"].price
+    );
+    MockTransaction::fill(&order_map, "mock_1");
+
+    clock.set(Timestamp::from_secs(1_500));
+    executor.step().await.unwrap();
+    assert_eq!(dec!(200), order_map.lock().unwrap()["mock_2"].quantity);
+    assert_eq!(
+        ExecutionProgress {
+            filled_quantity: dec!(100),
+            working_quantity: dec!(200),
+        },
+        executor.get_progress()
+    );
+
+    // working children are cancelled once the window is over
+    clock.set(Timestamp::from_secs(2_000));
+    assert_eq!(ExecutionStatus::Expired, executor.step().await.unwrap());
+    assert_eq!(
+        OrderStatus::Cancelled,
+        order_map.lock().unwrap()["mock_2"].status
+    );
+    assert_eq!(dec!(0), executor.get_progress().working_quantity);
+}
+
+#[tokio::test]
+async fn test_algorithmic_executor_cancel() {
+    let order_map = Arc::new(Mutex::new(HashMap::new()));
+    let clock = Arc::new(ManualClock::new_with_timestamp(Timestamp::from_secs(1_000)));
+    let mut executor = AlgorithmicExecutor::new(
+        Box::new(MockTransaction {
+            order_map: order_map.clone(),
+            is_cancel_delayed: false,
+        }),
+        Box::new(TimeWeightedAveragePrice::new(1).unwrap()),
+        get_parent_order(dec!(400), dec!(100)),
+        clock.clone(),
+    )
+    .unwrap();
+    executor.step().await.unwrap();
+    // partially filled after the last refresh
+    order_map
+        .lock()
+        .unwrap()
+        .get_mut("mock_1")
+        .unwrap()
+        .executed_quantity = dec!(150);
+
+    executor.cancel().await.unwrap();
+    assert_eq!(ExecutionStatus::Cancelled, executor.get_status());
+    assert_eq!(
+        ExecutionProgress {
+            filled_quantity: dec!(150),
+            working_quantity: dec!(0),
+        },
+        executor.get_progress()
+    );
+}
+
+#[tokio::test]
+async fn test_algorithmic_executor_run() {
+    let order_map = Arc::new(Mutex::new(HashMap::new()));
+    let clock = Arc::new(ManualClock::new_with_timestamp(Timestamp::from_secs(1_000)));
+    let mut executor = AlgorithmicExecutor::new(
+        Box::new(MockTransaction {
+            order_map: order_map.clone(),
+            is_cancel_delayed: false,
+        }),
+        Box::new(TimeWeightedAveragePrice::new(1).unwrap()),
+        get_parent_order(dec!(400), dec!(100)),
+        clock.clone(),
+    )
+    .unwrap();
+
+    let stopped_indicator = Arc::new(AtomicBool::new(true));
+    let progress = executor
+        .run(Duration::from_secs(1), stopped_indicator)
+        .await
+        .unwrap();
+    assert_eq!(ExecutionStatus::Cancelled, executor.get_status());
+    assert_eq!(ExecutionProgress::default(), progress);
+    assert!(order_map.lock().unwrap().is_empty());
+}
@@ -0,0 +1,35 @@
+use rust_decimal_macros::dec;
+
+use super::twap::get_parent_order;
+use crate::{
+    model::common::timestamp::Timestamp,
+    strategy::execution::{
+        common::algorithm::{ExecutionAlgorithmTrait, ExecutionProgress},
+        iceberg::Iceberg,
+    },
+};
+
+#[test]
+fn test_iceberg() {
+    assert!(Iceberg::new(dec!(0)).is_err());
+
+    let iceberg = Iceberg::new(dec!(250)).unwrap();
+    let parent_order = get_parent_order(dec!(1000), dec!(100));
+    let now = Timestamp::from_secs(1_000);
+    let mut progress = ExecutionProgress::default();
+    assert_eq!(
+        dec!(200),
+        iceberg.get_slice_quantity(&parent_order, &progress, now)
+    );
+    progress.working_quantity = dec!(200);
+    assert_eq!(
+        dec!(0),
+        iceberg.get_slice_quantity(&parent_order, &progress, now)
+    );
+    progress.working_quantity = dec!(0);
+    progress.filled_quantity = dec!(800);
+    assert_eq!(
+        dec!(200),
+        iceberg.get_slice_quantity(&parent_order, &progress, now)
+    );
+}
@@ -0,0 +1,4 @@
+pub mod executor;
+pub mod iceberg;
+pub mod twap;
+pub mod vwap;
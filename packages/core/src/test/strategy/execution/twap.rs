@@ -0,0 +1,70 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::{
+    model::{
+        common::timestamp::Timestamp,
+        trading::{
+            market::Market,
+            symbol::Symbol,
+            transaction::{Direction, RegularTradingTime},
+        },
+    },
+    strategy::execution::{
+        common::algorithm::{ExecutionAlgorithmTrait, ExecutionProgress, ParentOrder},
+        twap::TimeWeightedAveragePrice,
+    },
+};
+
+pub fn get_parent_order(quantity: Decimal, lot_size: Decimal) -> ParentOrder {
+    ParentOrder {
+        symbol: Symbol {
+            market: Market::HK,
+            identifier: "0700".to_owned(),
+        },
+        direction: Direction::Buy,
+        quantity,
+        start_timestamp: Timestamp::from_secs(1_000),
+        end_timestamp: Timestamp::from_secs(2_000),
+        limit_price: Option::None,
+        lot_size,
+        regular_trading_time: RegularTradingTime::OnlyRegularTradingTime,
+    }
+}
+
+#[test]
+fn test_time_weighted_average_price() {
+    assert!(TimeWeightedAveragePrice::new(0).is_err());
+
+    let twap = TimeWeightedAveragePrice::new(4).unwrap();
+    let parent_order = get_parent_order(dec!(1000), dec!(100));
+    let mut progress = ExecutionProgress::default();
+    // 250 is rounded down to lots
+    assert_eq!(
+        dec!(200),
+        twap.get_slice_quantity(&parent_order, &progress, Timestamp::from_secs(1_000))
+    );
+    progress.working_quantity = dec!(200);
+    assert_eq!(
+        dec!(0),
+        twap.get_slice_quantity(&parent_order, &progress, Timestamp::from_secs(1_200))
+    );
+    assert_eq!(
+        dec!(300),
+        twap.get_slice_quantity(&parent_order, &progress, Timestamp::from_secs(1_250))
+    );
+
+    // unfilled quantity is caught up in the later slices
+    progress.working_quantity = dec!(0);
+    progress.filled_quantity = dec!(100);
+    assert_eq!(
+        dec!(600),
+        twap.get_slice_quantity(&parent_order, &progress, Timestamp::from_secs(1_700))
+    );
+    // the last slice submits the whole remaining quantity regardless of lots
+    let parent_order = get_parent_order(dec!(1050), dec!(100));
+    assert_eq!(
+        dec!(950),
+        twap.get_slice_quantity(&parent_order, &progress, Timestamp::from_secs(1_999))
+    );
+}
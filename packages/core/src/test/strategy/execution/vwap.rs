@@ -0,0 +1,96 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use super::twap::get_parent_order;
+use crate::{
+    model::{
+        common::timestamp::Timestamp,
+        trading::{candlestick::Candlestick, market::Market, symbol::Symbol},
+    },
+    strategy::execution::{
+        common::algorithm::{ExecutionAlgorithmTrait, ExecutionProgress},
+        vwap::{VolumeProfile, VolumeWeightedAveragePrice},
+    },
+};
+
+fn get_candlestick(timestamp: Timestamp, volume: Decimal) -> Candlestick {
+    Candlestick {
+        symbol: Symbol {
+            market: Market::HK,
+            identifier: "0700".to_owned(),
+        },
+        timestamp,
+        open: dec!(300),
+        high: dec!(300),
+        low: dec!(300),
+        close: dec!(300),
+        volume,
+        turnover: Option::None,
+    }
+}
+
+#[test]
+fn test_volume_profile() {
+    assert!(VolumeProfile::new(vec![]).is_err());
+    assert!(VolumeProfile::new(vec![dec!(1), dec!(-1)]).is_err());
+
+    const SECS_PER_DAY: u64 = 24 * 60 * 60;
+    let candlestick_list = vec![
+        get_candlestick(Timestamp::from_secs(1_000), dec!(300)),
+        get_candlestick(Timestamp::from_secs(SECS_PER_DAY + 1_100), dec!(100)),
+        get_candlestick(Timestamp::from_secs(SECS_PER_DAY + 1_600), dec!(200)),
+        get_candlestick(Timestamp::from_secs(2 * SECS_PER_DAY + 1_999), dec!(400)),
+        // outside of the window
+        get_candlestick(Timestamp::from_secs(2_000), dec!(10_000)),
+    ];
+    let volume_profile = VolumeProfile::from_candlestick_list(
+        &candlestick_list,
+        Timestamp::from_secs(3 * SECS_PER_DAY + 1_000),
+        Timestamp::from_secs(3 * SECS_PER_DAY + 2_000),
+        2,
+    )
+    .unwrap();
+    assert_eq!(
+        &vec![dec!(0.4), dec!(0.6)],
+        volume_profile.get_weight_list()
+    );
+}
+
+#[test]
+fn test_volume_profile_illegal_time_window() {
+    let err = VolumeProfile::from_candlestick_list(
+        &[],
+        Timestamp::from_secs(2_000),
+        Timestamp::from_secs(1_000),
+        2,
+    )
+    .unwrap_err();
+    assert!(err.to_string().starts_with("ILLEGAL_TIME_WINDOW"));
+}
+
+#[test]
+fn test_volume_weighted_average_price() {
+    let vwap = VolumeWeightedAveragePrice::new(
+        VolumeProfile::new(vec![dec!(1), dec!(3), dec!(0), dec!(1)]).unwrap(),
+    );
+    let parent_order = get_parent_order(dec!(1000), dec!(1));
+    let mut progress = ExecutionProgress::default();
+    assert_eq!(
+        dec!(200),
+        vwap.get_slice_quantity(&parent_order, &progress, Timestamp::from_secs(1_000))
+    );
+    progress.filled_quantity = dec!(200);
+    assert_eq!(
+        dec!(600),
+        vwap.get_slice_quantity(&parent_order, &progress, Timestamp::from_secs(1_300))
+    );
+    progress.filled_quantity = dec!(800);
+    assert_eq!(
+        dec!(0),
+        vwap.get_slice_quantity(&parent_order, &progress, Timestamp::from_secs(1_600))
+    );
+    assert_eq!(
+        dec!(200),
+        vwap.get_slice_quantity(&parent_order, &progress, Timestamp::from_secs(1_800))
+    );
+}
@@ -0,0 +1,33 @@
+use rust_decimal_macros::dec;
+
+use crate::strategy::indicators::{
+    atr::AverageTrueRange,
+    common::indicator::{IndicatorInput, IndicatorTrait},
+};
+
+#[test]
+fn test_average_true_range() {
+    assert!(AverageTrueRange::new(0).is_err());
+
+    let mut atr = AverageTrueRange::new(3).unwrap();
+    let inputs = [
+        (dec!(10), dec!(8), dec!(9)),
+        (dec!(11), dec!(9), dec!(10)),
+        (dec!(12), dec!(10), dec!(11)),
+        (dec!(15), dec!(12), dec!(14)),
+    ]
+    .map(|(high, low, close)| IndicatorInput {
+        high,
+        low,
+        close,
+        volume: Option::None,
+    });
+
+    assert_eq!(Option::None, atr.update(&inputs[0]));
+    assert_eq!(Option::None, atr.update(&inputs[1]));
+    assert_eq!(Option::Some(dec!(2)), atr.update(&inputs[2]));
+    assert_eq!(dec!(2.666667), atr.update(&inputs[3]).unwrap().round_dp(6));
+
+    atr.reset();
+    assert_eq!(Option::None, atr.value());
+}
@@ -0,0 +1,33 @@
+use rust_decimal_macros::dec;
+
+use crate::strategy::indicators::{
+    bollinger_bands::{BollingerBands, BollingerBandsOutput},
+    common::indicator::IndicatorTrait,
+};
+
+#[test]
+fn test_bollinger_bands() {
+    assert!(BollingerBands::new(0, dec!(2)).is_err());
+
+    let mut bollinger_bands = BollingerBands::new(3, dec!(2)).unwrap();
+    assert_eq!(Option::None, bollinger_bands.next(dec!(1)));
+    assert_eq!(Option::None, bollinger_bands.next(dec!(2)));
+    let output = bollinger_bands.next(dec!(3)).unwrap();
+    assert_eq!(dec!(2), output.middle);
+    assert_eq!(dec!(3.6330), output.upper.round_dp(4));
+    assert_eq!(dec!(0.3670), output.lower.round_dp(4));
+
+    let mut flat_bollinger_bands = BollingerBands::new(2, dec!(2)).unwrap();
+    flat_bollinger_bands.next(dec!(5));
+    assert_eq!(
+        Option::Some(BollingerBandsOutput {
+            upper: dec!(5),
+            middle: dec!(5),
+            lower: dec!(5),
+        }),
+        flat_bollinger_bands.next(dec!(5))
+    );
+
+    bollinger_bands.reset();
+    assert_eq!(Option::None, bollinger_bands.value());
+}
@@ -0,0 +1,20 @@
+use rust_decimal_macros::dec;
+
+use crate::strategy::indicators::{
+    common::indicator::IndicatorTrait, ema::ExponentialMovingAverage,
+};
+
+#[test]
+fn test_exponential_moving_average() {
+    assert!(ExponentialMovingAverage::new(0).is_err());
+
+    let mut ema = ExponentialMovingAverage::new(3).unwrap();
+    assert_eq!(Option::None, ema.next(dec!(1)));
+    assert_eq!(Option::None, ema.next(dec!(2)));
+    assert_eq!(Option::Some(dec!(2)), ema.next(dec!(3)));
+    assert_eq!(Option::Some(dec!(3)), ema.next(dec!(4)));
+    assert_eq!(Option::Some(dec!(4)), ema.next(dec!(5)));
+
+    ema.reset();
+    assert_eq!(Option::None, ema.value());
+}
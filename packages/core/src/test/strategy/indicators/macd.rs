@@ -0,0 +1,34 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::strategy::indicators::{
+    common::indicator::IndicatorTrait,
+    macd::{MovingAverageConvergenceDivergence, MovingAverageConvergenceDivergenceOutput},
+};
+
+#[test]
+fn test_moving_average_convergence_divergence() {
+    assert!(MovingAverageConvergenceDivergence::new(3, 2, 2).is_err());
+
+    let mut macd = MovingAverageConvergenceDivergence::new(2, 3, 2).unwrap();
+    assert_eq!(Option::None, macd.next(dec!(1)));
+    assert_eq!(Option::None, macd.next(dec!(2)));
+    assert_eq!(Option::None, macd.next(dec!(3)));
+    // the fast and slow EMA lag a linear series by a constant
+    assert_eq!(
+        Option::Some(MovingAverageConvergenceDivergenceOutput {
+            macd: dec!(0.5),
+            signal: dec!(0.5),
+            histogram: Decimal::ZERO,
+        }),
+        macd.next(dec!(4))
+            .map(|output| MovingAverageConvergenceDivergenceOutput {
+                macd: output.macd.round_dp(6),
+                signal: output.signal.round_dp(6),
+                histogram: output.histogram.round_dp(6),
+            })
+    );
+
+    macd.reset();
+    assert_eq!(Option::None, macd.value());
+}
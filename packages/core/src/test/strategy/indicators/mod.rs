@@ -0,0 +1,9 @@
+pub mod atr;
+pub mod bollinger_bands;
+pub mod ema;
+pub mod macd;
+pub mod rsi;
+pub mod sma;
+pub mod stochastic;
+pub mod vwap;
+pub mod wma;
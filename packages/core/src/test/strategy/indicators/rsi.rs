@@ -0,0 +1,20 @@
+use rust_decimal_macros::dec;
+
+use crate::strategy::indicators::rsi::RelativeStrengthIndex;
+
+#[test]
+fn test_relative_strength_index() {
+    assert!(RelativeStrengthIndex::new(0).is_err());
+
+    let mut rsi = RelativeStrengthIndex::new(3).unwrap();
+    assert_eq!(Option::None, rsi.next(dec!(1)));
+    assert_eq!(Option::None, rsi.next(dec!(2)));
+    assert_eq!(Option::None, rsi.next(dec!(3)));
+    assert_eq!(dec!(66.6667), rsi.next(dec!(2)).unwrap().round_dp(4));
+    assert_eq!(dec!(77.7778), rsi.next(dec!(3)).unwrap().round_dp(4));
+
+    let mut rising_rsi = RelativeStrengthIndex::new(2).unwrap();
+    rising_rsi.next(dec!(1));
+    rising_rsi.next(dec!(2));
+    assert_eq!(Option::Some(dec!(100)), rising_rsi.next(dec!(3)));
+}
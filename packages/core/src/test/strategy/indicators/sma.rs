@@ -0,0 +1,19 @@
+use rust_decimal_macros::dec;
+
+use crate::strategy::indicators::{common::indicator::IndicatorTrait, sma::SimpleMovingAverage};
+
+#[test]
+fn test_simple_moving_average() {
+    assert!(SimpleMovingAverage::new(0).is_err());
+
+    let mut sma = SimpleMovingAverage::new(3).unwrap();
+    assert_eq!(Option::None, sma.next(dec!(1)));
+    assert_eq!(Option::None, sma.next(dec!(2)));
+    assert_eq!(Option::Some(dec!(2)), sma.next(dec!(3)));
+    assert_eq!(Option::Some(dec!(3)), sma.next(dec!(4)));
+    assert_eq!(Option::Some(dec!(4)), sma.next(dec!(5)));
+    assert_eq!(Option::Some(dec!(4)), sma.value());
+
+    sma.reset();
+    assert_eq!(Option::None, sma.value());
+}
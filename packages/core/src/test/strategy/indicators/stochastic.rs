@@ -0,0 +1,35 @@
+use rust_decimal_macros::dec;
+
+use crate::strategy::indicators::{
+    common::indicator::{IndicatorInput, IndicatorTrait},
+    stochastic::StochasticOscillator,
+};
+
+#[test]
+fn test_stochastic_oscillator() {
+    assert!(StochasticOscillator::new(0, 3).is_err());
+
+    let mut stochastic = StochasticOscillator::new(3, 2).unwrap();
+    let inputs = [
+        (dec!(10), dec!(8), dec!(9)),
+        (dec!(12), dec!(9), dec!(11)),
+        (dec!(11), dec!(7), dec!(8)),
+        (dec!(13), dec!(10), dec!(12)),
+    ]
+    .map(|(high, low, close)| IndicatorInput {
+        high,
+        low,
+        close,
+        volume: Option::None,
+    });
+
+    assert_eq!(Option::None, stochastic.update(&inputs[0]));
+    assert_eq!(Option::None, stochastic.update(&inputs[1]));
+    assert_eq!(Option::None, stochastic.update(&inputs[2]));
+    let output = stochastic.update(&inputs[3]).unwrap();
+    assert_eq!(dec!(83.3333), output.k.round_dp(4));
+    assert_eq!(dec!(51.6667), output.d.round_dp(4));
+
+    stochastic.reset();
+    assert_eq!(Option::None, stochastic.value());
+}
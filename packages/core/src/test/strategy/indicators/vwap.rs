@@ -0,0 +1,84 @@
+use rust_decimal_macros::dec;
+
+use crate::{
+    model::{
+        common::timestamp::Timestamp,
+        trading::{
+            candlestick::Candlestick, market::Market, quote::QuoteRealTimeInfo, symbol::Symbol,
+        },
+    },
+    strategy::indicators::{common::indicator::IndicatorTrait, vwap::VolumeWeightedAveragePrice},
+};
+
+fn get_symbol() -> Symbol {
+    Symbol {
+        market: Market::US,
+        identifier: "ABNB".to_owned(),
+    }
+}
+
+#[test]
+fn test_volume_weighted_average_price_with_candlestick() {
+    let mut vwap = VolumeWeightedAveragePrice::new();
+    assert_eq!(Option::None, vwap.value());
+
+    let candlestick = Candlestick {
+        symbol: get_symbol(),
+        timestamp: Timestamp::from_secs(0),
+        open: dec!(2),
+        high: dec!(3),
+        low: dec!(1),
+        close: dec!(2),
+        volume: dec!(100),
+        turnover: Option::None,
+    };
+    assert_eq!(Option::Some(dec!(2)), vwap.update_candlestick(&candlestick));
+    assert_eq!(
+        Option::Some(dec!(4.25)),
+        vwap.update_candlestick(&Candlestick {
+            high: dec!(6),
+            low: dec!(4),
+            close: dec!(5),
+            volume: dec!(300),
+            ..candlestick
+        })
+    );
+
+    vwap.reset();
+    assert_eq!(Option::None, vwap.value());
+}
+
+#[test]
+fn test_volume_weighted_average_price_with_quote_real_time_info() {
+    let mut vwap = VolumeWeightedAveragePrice::new();
+    let quote_info = QuoteRealTimeInfo {
+        symbol: get_symbol(),
+        sequence: 1,
+        timestamp: Timestamp::from_secs(1),
+        current_price: dec!(10),
+        volume: Option::Some(dec!(100)),
+        low_price: Option::None,
+        high_price: Option::None,
+        open_price: Option::None,
+        prev_close: Option::None,
+        turnover: Option::None,
+        extra: Option::None,
+    };
+
+    assert_eq!(
+        Option::Some(dec!(10)),
+        vwap.update_quote_real_time_info(&quote_info)
+    );
+    // only the 200 newly traded shares are counted at the new price
+    assert_eq!(
+        dec!(16.666667),
+        vwap.update_quote_real_time_info(&QuoteRealTimeInfo {
+            sequence: 2,
+            current_price: dec!(20),
+            volume: Option::Some(dec!(300)),
+            ..quote_info
+        })
+        .unwrap()
+        .round_dp(6)
+    );
+}
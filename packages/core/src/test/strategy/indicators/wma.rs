@@ -0,0 +1,18 @@
+use rust_decimal_macros::dec;
+
+use crate::strategy::indicators::{common::indicator::IndicatorTrait, wma::WeightedMovingAverage};
+
+#[test]
+fn test_weighted_moving_average() {
+    assert!(WeightedMovingAverage::new(0).is_err());
+
+    let mut wma = WeightedMovingAverage::new(3).unwrap();
+    assert_eq!(Option::None, wma.next(dec!(1)));
+    assert_eq!(Option::None, wma.next(dec!(2)));
+    assert_eq!(dec!(2.333333), wma.next(dec!(3)).unwrap().round_dp(6));
+    assert_eq!(dec!(3.333333), wma.next(dec!(4)).unwrap().round_dp(6));
+    assert_eq!(dec!(4.333333), wma.next(dec!(5)).unwrap().round_dp(6));
+
+    wma.reset();
+    assert_eq!(Option::None, wma.value());
+}
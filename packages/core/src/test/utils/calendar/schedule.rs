@@ -0,0 +1,161 @@
+use time::{
+    macros::{date, datetime, time},
+    UtcOffset,
+};
+
+use crate::{
+    model::{common::timestamp::Timestamp, trading::market::Market},
+    utils::calendar::{
+        common_trait::{TradingDay, TradingSessionKind},
+        schedule::{EarlyClose, HolidayTable, MarketSchedule, SessionTime},
+    },
+};
+
+#[test]
+fn test_get_utc_offset() {
+    let get_hours =
+        |market: Market, date| MarketSchedule::get_utc_offset(&market, date).whole_hours();
+    assert_eq!(8, get_hours(Market::HK, date!(2024 - 07 - 01)));
+    assert_eq!(8, get_hours(Market::CN, date!(2024 - 01 - 01)));
+    assert_eq!(-5, get_hours(Market::US, date!(2024 - 03 - 09)));
+    assert_eq!(-4, get_hours(Market::US, date!(2024 - 03 - 10)));
+    assert_eq!(-4, get_hours(Market::US, date!(2024 - 11 - 02)));
+    assert_eq!(-5, get_hours(Market::US, date!(2024 - 11 - 03)));
+    // March 2026 starts on a Sunday
+    assert_eq!(-5, get_hours(Market::US, date!(2026 - 03 - 07)));
+    assert_eq!(-4, get_hours(Market::US, date!(2026 - 03 - 08)));
+    assert_eq!(
+        UtcOffset::from_hms(-5, 0, 0).unwrap(),
+        MarketSchedule::get_standard_utc_offset(&Market::US)
+    );
+}
+
+#[test]
+fn test_get_session_kind() {
+    let us_schedule = MarketSchedule::new(Market::US);
+    assert_eq!(
+        Option::Some(TradingSessionKind::Regular),
+        us_schedule
+            .get_session_kind(Timestamp::from(datetime!(2024-07-01 13:30 UTC)))
+            .unwrap()
+    );
+    assert_eq!(
+        Option::Some(TradingSessionKind::PreMarket),
+        us_schedule
+            .get_session_kind(Timestamp::from(datetime!(2024-01-08 14:29 UTC)))
+            .unwrap()
+    );
+    assert_eq!(
+        Option::Some(TradingSessionKind::AfterHours),
+        us_schedule
+            .get_session_kind(Timestamp::from(datetime!(2024-07-01 20:00 UTC)))
+            .unwrap()
+    );
+    // Saturday
+    assert_eq!(
+        Option::None,
+        us_schedule
+            .get_session_kind(Timestamp::from(datetime!(2024-07-06 15:00 UTC)))
+            .unwrap()
+    );
+
+    let hk_schedule = MarketSchedule::new(Market::HK);
+    assert_eq!(
+        Option::Some(TradingSessionKind::LunchBreak),
+        hk_schedule
+            .get_session_kind(Timestamp::from(datetime!(2024-07-02 04:30 UTC)))
+            .unwrap()
+    );
+    assert_eq!(
+        Timestamp::from(datetime!(2024-07-02 05:00 UTC)),
+        hk_schedule
+            .next_open(Timestamp::from(datetime!(2024-07-02 04:30 UTC)))
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_holiday_and_early_close() {
+    let mut us_schedule = MarketSchedule::new(Market::US);
+    us_schedule
+        .load_holiday_table(&HolidayTable {
+            holiday_list: vec!["2024-07-04".to_owned()],
+            early_close_list: vec![EarlyClose {
+                date: "2024-07-03".to_owned(),
+                close_time: "13:00".to_owned(),
+            }],
+        })
+        .unwrap();
+    assert!(us_schedule
+        .get_day_session_list(date!(2024 - 07 - 04))
+        .is_empty());
+    assert_eq!(
+        vec![
+            SessionTime::new(TradingSessionKind::PreMarket, time!(04:00), time!(09:30)),
+            SessionTime::new(TradingSessionKind::Regular, time!(09:30), time!(13:00)),
+        ],
+        us_schedule.get_day_session_list(date!(2024 - 07 - 03))
+    );
+    assert_eq!(
+        Timestamp::from(datetime!(2024-07-03 17:00 UTC)),
+        us_schedule
+            .next_close(Timestamp::from(datetime!(2024-07-03 14:00 UTC)))
+            .unwrap()
+    );
+    assert_eq!(
+        Option::None,
+        us_schedule
+            .get_session_kind(Timestamp::from(datetime!(2024-07-03 18:00 UTC)))
+            .unwrap()
+    );
+    assert_eq!(
+        Timestamp::from(datetime!(2024-07-05 13:30 UTC)),
+        us_schedule
+            .next_open(Timestamp::from(datetime!(2024-07-03 18:00 UTC)))
+            .unwrap()
+    );
+
+    us_schedule.set_day_session_list(date!(2024 - 07 - 05), vec![]);
+    assert_eq!(
+        Timestamp::from(datetime!(2024-07-08 13:30 UTC)),
+        us_schedule
+            .next_open(Timestamp::from(datetime!(2024-07-03 18:00 UTC)))
+            .unwrap()
+    );
+
+    assert!(us_schedule
+        .load_holiday_table(&HolidayTable {
+            holiday_list: vec!["2024/07/04".to_owned()],
+            early_close_list: vec![],
+        })
+        .is_err());
+}
+
+#[test]
+fn test_next_trading_day() {
+    let hk_schedule = MarketSchedule::new(Market::HK);
+    let trading_day = TradingDay {
+        open_timestamp: Timestamp::from(datetime!(2024-07-02 01:30 UTC)),
+        close_timestamp: Timestamp::from(datetime!(2024-07-02 08:00 UTC)),
+    };
+    // the lunch break is part of the trading day
+    assert_eq!(
+        trading_day,
+        hk_schedule
+            .next_trading_day(Timestamp::from(datetime!(2024-07-02 04:30 UTC)))
+            .unwrap()
+    );
+    assert_eq!(
+        trading_day,
+        hk_schedule
+            .next_trading_day(Timestamp::from(datetime!(2024-07-01 08:00 UTC)))
+            .unwrap()
+    );
+    assert_eq!(
+        Timestamp::from(datetime!(2024-07-03 01:30 UTC)),
+        hk_schedule
+            .next_trading_day(Timestamp::from(datetime!(2024-07-02 08:00 UTC)))
+            .unwrap()
+            .open_timestamp
+    );
+}
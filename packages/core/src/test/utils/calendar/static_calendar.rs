@@ -0,0 +1,90 @@
+use std::{collections::HashMap, io::Write};
+use tempfile::NamedTempFile;
+use time::macros::datetime;
+
+use crate::{
+    model::{common::timestamp::Timestamp, trading::market::Market},
+    utils::calendar::{
+        common_trait::TradingCalendarTrait, initializer::get_trading_calendar_instance,
+        static_calendar::StaticTradingCalendar,
+    },
+};
+
+#[tokio::test]
+async fn test_static_trading_calendar() {
+    let mut holiday_file = NamedTempFile::new().unwrap();
+    holiday_file
+        .write_all(
+            b"HK:\n  holiday_list: [\"2024-07-01\"]\nUS:\n  early_close_list: [{date: \"2024-07-03\", close_time: \"13:00\"}]\n",
+        )
+        .unwrap();
+    let calendar = StaticTradingCalendar::new(HashMap::from([(
+        StaticTradingCalendar::CONFIG_KEY_HOLIDAY_PATH.to_owned(),
+        holiday_file.path().to_str().unwrap().to_owned(),
+    )]));
+
+    assert!(!calendar
+        .is_open(
+            &Market::HK,
+            Timestamp::from(datetime!(2024-07-01 02:00 UTC))
+        )
+        .await
+        .unwrap());
+    assert!(calendar
+        .is_open(
+            &Market::HK,
+            Timestamp::from(datetime!(2024-07-02 02:00 UTC))
+        )
+        .await
+        .unwrap());
+    assert_eq!(
+        Timestamp::from(datetime!(2024-07-02 01:30 UTC)),
+        calendar
+            .next_open(
+                &Market::HK,
+                Timestamp::from(datetime!(2024-06-30 12:00 UTC))
+            )
+            .await
+            .unwrap()
+    );
+    assert_eq!(
+        Timestamp::from(datetime!(2024-07-03 17:00 UTC)),
+        calendar
+            .next_close(
+                &Market::US,
+                Timestamp::from(datetime!(2024-07-03 15:00 UTC))
+            )
+            .await
+            .unwrap()
+    );
+    assert!(calendar
+        .is_open(
+            &Market::CN,
+            Timestamp::from(datetime!(2024-07-02 06:00 UTC))
+        )
+        .await
+        .unwrap());
+}
+
+#[test]
+fn test_get_trading_calendar_instance() {
+    assert!(
+        get_trading_calendar_instance("StaticTradingCalendar".to_owned(), HashMap::new()).is_ok()
+    );
+    assert!(get_trading_calendar_instance("Unknown".to_owned(), HashMap::new()).is_err());
+
+    // a broken calendar config is an error rather than a panic
+    assert!(get_trading_calendar_instance(
+        "StaticTradingCalendar".to_owned(),
+        HashMap::from([(
+            StaticTradingCalendar::CONFIG_KEY_HOLIDAY_PATH.to_owned(),
+            "/not/existing/holiday.yaml".to_owned(),
+        )]),
+    )
+    .is_err());
+    assert!(get_trading_calendar_instance(
+        "InteractiveBrokersTradingCalendar".to_owned(),
+        HashMap::new()
+    )
+    .is_err());
+}
@@ -0,0 +1,15 @@
+use crate::{
+    model::common::types::ConfigMap,
+    utils::clock::{
+        common_trait::ClockTrait, initializer::get_clock_instance, manual::ManualClock,
+        simulated::SimulatedClock, system::SystemClock,
+    },
+};
+
+#[test]
+fn test_get_clock_instance() {
+    assert!(get_clock_instance(SystemClock::get_identifier(), ConfigMap::new()).is_ok());
+    assert!(get_clock_instance(SimulatedClock::get_identifier(), ConfigMap::new()).is_ok());
+    assert!(get_clock_instance(ManualClock::get_identifier(), ConfigMap::new()).is_ok());
+    assert!(get_clock_instance("UnknownClock".to_owned(), ConfigMap::new()).is_err());
+}
@@ -0,0 +1,47 @@
+use std::{sync::Arc, time::Duration};
+use tokio::time::timeout;
+
+use crate::{
+    model::common::{timestamp::Timestamp, types::ConfigMap},
+    utils::clock::{common_trait::ClockTrait, manual::ManualClock},
+};
+
+#[test]
+fn test_new_from_config_map() {
+    let clock = ManualClock::new(ConfigMap::from([(
+        ManualClock::CONFIG_KEY_START.to_owned(),
+        "1718000000".to_owned(),
+    )]));
+    assert_eq!(Timestamp::from_secs(1718000000), clock.now());
+
+    clock.advance(Duration::from_secs(10));
+    assert_eq!(Timestamp::from_secs(1718000010), clock.now());
+    clock.set(Timestamp::from_secs(1000));
+    assert_eq!(Timestamp::from_secs(1000), clock.now());
+}
+
+#[tokio::test]
+async fn test_sleep() {
+    let clock = Arc::new(ManualClock::new_with_timestamp(Timestamp::from_secs(1000)));
+
+    // does not move without being advanced
+    assert!(timeout(
+        Duration::from_millis(50),
+        clock.sleep(Duration::from_secs(1))
+    )
+    .await
+    .is_err());
+    assert_eq!(Timestamp::from_secs(1000), clock.now());
+
+    let sleeping_clock = clock.clone();
+    let handle = tokio::task::spawn(async move {
+        sleeping_clock.sleep_until(Timestamp::from_secs(1060)).await;
+        sleeping_clock.now()
+    });
+    clock.advance(Duration::from_secs(30));
+    clock.advance(Duration::from_secs(30));
+    assert_eq!(Timestamp::from_secs(1060), handle.await.unwrap());
+
+    // already passed
+    clock.sleep_until(Timestamp::from_secs(500)).await;
+}
@@ -0,0 +1,3 @@
+pub mod initializer;
+pub mod manual;
+pub mod simulated;
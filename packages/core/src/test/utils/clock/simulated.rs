@@ -0,0 +1,79 @@
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+
+use crate::{
+    model::common::{timestamp::Timestamp, types::ConfigMap},
+    utils::clock::{
+        common_trait::ClockTrait,
+        simulated::{SimulatedClock, SimulationSpeed},
+    },
+};
+
+#[test]
+fn test_parse_simulation_speed() {
+    assert_eq!(SimulationSpeed::Max, "max".parse().unwrap());
+    assert_eq!(
+        SimulationSpeed::Multiplier(1.0),
+        "realtime".parse().unwrap()
+    );
+    assert_eq!(SimulationSpeed::Multiplier(2.5), "2.5x".parse().unwrap());
+    assert!("0".parse::<SimulationSpeed>().is_err());
+    assert!("fast".parse::<SimulationSpeed>().is_err());
+}
+
+#[test]
+fn test_new_from_config_map() {
+    let clock = SimulatedClock::new(ConfigMap::from([
+        (
+            SimulatedClock::CONFIG_KEY_SPEED.to_owned(),
+            "max".to_owned(),
+        ),
+        (
+            SimulatedClock::CONFIG_KEY_START.to_owned(),
+            "1718000000".to_owned(),
+        ),
+    ]));
+    assert_eq!(SimulationSpeed::Max, clock.get_speed());
+    assert_eq!(Timestamp::from_secs(1718000000), clock.now());
+}
+
+#[tokio::test]
+async fn test_max_speed() {
+    let clock = SimulatedClock::new_with_speed(SimulationSpeed::Max, Option::None);
+    assert_eq!(Option::None, clock.try_now());
+    assert_eq!(Timestamp::UNIX_EPOCH, clock.now());
+
+    clock.sleep_until(Timestamp::from_secs(100)).await;
+    assert_eq!(Option::Some(Timestamp::from_secs(100)), clock.try_now());
+    clock.sleep_until(Timestamp::from_secs(10000)).await;
+    assert_eq!(Timestamp::from_secs(10000), clock.now());
+    // never goes backwards
+    clock.sleep_until(Timestamp::from_secs(5000)).await;
+    assert_eq!(Timestamp::from_secs(10000), clock.now());
+    clock.sleep(Duration::from_secs(60)).await;
+    assert_eq!(Timestamp::from_secs(10060), clock.now());
+}
+
+#[tokio::test]
+async fn test_multiplier_speed_and_pause() {
+    let clock = SimulatedClock::new_with_speed(SimulationSpeed::Multiplier(20.0), Option::None);
+    clock.seek(Timestamp::from_secs(100));
+
+    let instant = Instant::now();
+    clock.sleep_until(Timestamp::from_secs(102)).await;
+    assert!(instant.elapsed() >= Duration::from_millis(90));
+    assert!(clock.now() >= Timestamp::from_secs(102));
+
+    clock.pause();
+    assert!(clock.is_paused());
+    let paused_at = clock.now();
+    sleep(Duration::from_millis(200)).await;
+    assert_eq!(paused_at, clock.now());
+
+    clock.resume();
+    clock.set_speed(SimulationSpeed::Max);
+    clock
+        .sleep_until(paused_at + Duration::from_secs(3600))
+        .await;
+    assert_eq!(paused_at + Duration::from_secs(3600), clock.now());
+}
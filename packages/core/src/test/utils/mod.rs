@@ -1 +1,3 @@
+pub mod calendar;
+pub mod clock;
 pub mod time;
@@ -1,7 +1,23 @@
-use crate::utils::time::get_now_unix_timestamp;
+use time::UtcOffset;
+
+use crate::utils::time::{get_now_unix_timestamp, parse_utc_offset};
 
 #[test]
 fn test_get_now_unix_timestamp() {
     assert!(get_now_unix_timestamp() > 0u64);
     assert!(get_now_unix_timestamp() > 1_700_000_000u64);
 }
+
+#[test]
+fn test_parse_utc_offset() {
+    assert_eq!(
+        UtcOffset::from_hms(8, 0, 0).unwrap(),
+        parse_utc_offset("+08:00").unwrap()
+    );
+    assert_eq!(
+        UtcOffset::from_hms(-5, -30, 0).unwrap(),
+        parse_utc_offset("-05:30").unwrap()
+    );
+    assert!(parse_utc_offset("08:00").is_err());
+    assert!(parse_utc_offset("UTC").is_err());
+}
@@ -0,0 +1,63 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::model::{
+    common::{config_key::ConfigKeySpec, timestamp::Timestamp, types::ConfigMap},
+    trading::market::Market,
+};
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum TradingSessionKind {
+    PreMarket,
+    Regular,
+    LunchBreak,
+    AfterHours,
+}
+
+// First regular open and last regular close of a day, lunch breaks are within the range
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TradingDay {
+    pub open_timestamp: Timestamp,
+    pub close_timestamp: Timestamp,
+}
+
+#[async_trait]
+pub trait TradingCalendarTrait: Send + Sync {
+    fn new(config_map: ConfigMap) -> Self
+    where
+        Self: Sized;
+    fn get_identifier() -> String
+    where
+        Self: Sized;
+    fn get_config_key_list() -> Vec<ConfigKeySpec>
+    where
+        Self: Sized,
+    {
+        vec![]
+    }
+
+    // `Option::None` when the market is closed for the day or outside of any session
+    async fn get_session_kind(
+        &self,
+        market: &Market,
+        timestamp: Timestamp,
+    ) -> Result<Option<TradingSessionKind>, Error>;
+    // Start of the first regular session after `timestamp`
+    async fn next_open(&self, market: &Market, timestamp: Timestamp) -> Result<Timestamp, Error>;
+    // End of the first regular session after `timestamp`
+    async fn next_close(&self, market: &Market, timestamp: Timestamp) -> Result<Timestamp, Error>;
+    // The first trading day closing after `timestamp`, which might be the ongoing one
+    async fn next_trading_day(
+        &self,
+        market: &Market,
+        timestamp: Timestamp,
+    ) -> Result<TradingDay, Error>;
+
+    async fn is_open(&self, market: &Market, timestamp: Timestamp) -> Result<bool, Error> {
+        Result::Ok(
+            self.get_session_kind(market, timestamp).await?
+                == Option::Some(TradingSessionKind::Regular),
+        )
+    }
+}
@@ -0,0 +1,56 @@
+use anyhow::{anyhow, Error};
+
+use super::{common_trait::TradingCalendarTrait, static_calendar::StaticTradingCalendar};
+use crate::model::common::{config_key::ConfigKeySpec, types::ConfigMap};
+
+#[cfg(feature = "broker__interactive_brokers")]
+use crate::broker::interactive_brokers::calendar::InteractiveBrokersTradingCalendar;
+
+pub fn get_trading_calendar_instance(
+    identifier: String,
+    config_map: ConfigMap,
+) -> Result<Box<dyn TradingCalendarTrait>, Error> {
+    match identifier {
+        identifier if identifier == StaticTradingCalendar::get_identifier() => {
+            Result::Ok(Box::new(StaticTradingCalendar::try_new(config_map)?))
+        }
+
+        #[cfg(feature = "broker__interactive_brokers")]
+        identifier if identifier == InteractiveBrokersTradingCalendar::get_identifier() => {
+            Result::Ok(Box::new(InteractiveBrokersTradingCalendar::try_new(
+                config_map,
+            )?))
+        }
+
+        _ => Result::Err(anyhow!(
+            "IDENTIFIER_NOT_MATCHED TradingCalendar: {}",
+            identifier
+        )),
+    }
+}
+
+pub fn get_trading_calendar_identifier_list() -> Vec<String> {
+    vec![
+        StaticTradingCalendar::get_identifier(),
+        #[cfg(feature = "broker__interactive_brokers")]
+        InteractiveBrokersTradingCalendar::get_identifier(),
+    ]
+}
+
+pub fn get_trading_calendar_config_key_list(identifier: &str) -> Result<Vec<ConfigKeySpec>, Error> {
+    match identifier {
+        identifier if identifier == StaticTradingCalendar::get_identifier() => {
+            Result::Ok(StaticTradingCalendar::get_config_key_list())
+        }
+
+        #[cfg(feature = "broker__interactive_brokers")]
+        identifier if identifier == InteractiveBrokersTradingCalendar::get_identifier() => {
+            Result::Ok(InteractiveBrokersTradingCalendar::get_config_key_list())
+        }
+
+        _ => Result::Err(anyhow!(
+            "IDENTIFIER_NOT_MATCHED TradingCalendar: {}",
+            identifier
+        )),
+    }
+}
@@ -0,0 +1,4 @@
+pub mod common_trait;
+pub mod initializer;
+pub mod schedule;
+pub mod static_calendar;
@@ -0,0 +1,287 @@
+use anyhow::{anyhow, Context, Error};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use time::{
+    macros::{format_description, time},
+    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday,
+};
+
+use super::common_trait::{TradingDay, TradingSessionKind};
+use crate::model::{common::timestamp::Timestamp, trading::market::Market};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionTime {
+    pub kind: TradingSessionKind,
+    // local time of the exchange
+    pub start: Time,
+    pub end: Time,
+}
+
+impl SessionTime {
+    pub fn new(kind: TradingSessionKind, start: Time, end: Time) -> Self {
+        SessionTime { kind, start, end }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct EarlyClose {
+    // YYYY-MM-DD
+    pub date: String,
+    // HH:MM, local time of the exchange
+    pub close_time: String,
+}
+
+// Holidays of a market as stored in the holiday file, keyed by market:
+//
+// US:
+//   holiday_list: ["2024-12-25"]
+//   early_close_list: [{date: "2024-12-24", close_time: "13:00"}]
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct HolidayTable {
+    #[serde(default)]
+    pub holiday_list: Vec<String>,
+    #[serde(default)]
+    pub early_close_list: Vec<EarlyClose>,
+}
+
+pub type HolidayTableMap = HashMap<Market, HolidayTable>;
+
+// Weekly sessions of a market with holidays, early closes and per day overrides applied on top
+#[derive(Clone, Debug)]
+pub struct MarketSchedule {
+    market: Market,
+    session_list: Vec<SessionTime>,
+    holiday_set: HashSet<Date>,
+    early_close_map: HashMap<Date, Time>,
+    override_map: HashMap<Date, Vec<SessionTime>>,
+}
+
+impl MarketSchedule {
+    pub const MAX_LOOKAHEAD_DAYS: i64 = 30;
+
+    pub fn new(market: Market) -> Self {
+        MarketSchedule {
+            market,
+            session_list: Self::get_default_session_list(&market),
+            holiday_set: HashSet::new(),
+            early_close_map: HashMap::new(),
+            override_map: HashMap::new(),
+        }
+    }
+
+    fn get_default_session_list(market: &Market) -> Vec<SessionTime> {
+        match market {
+            Market::US => vec![
+                SessionTime::new(TradingSessionKind::PreMarket, time!(04:00), time!(09:30)),
+                SessionTime::new(TradingSessionKind::Regular, time!(09:30), time!(16:00)),
+                SessionTime::new(TradingSessionKind::AfterHours, time!(16:00), time!(20:00)),
+            ],
+            // the pre-opening and closing auctions are reported as pre-market and after-hours
+            Market::HK => vec![
+                SessionTime::new(TradingSessionKind::PreMarket, time!(09:00), time!(09:30)),
+                SessionTime::new(TradingSessionKind::Regular, time!(09:30), time!(12:00)),
+                SessionTime::new(TradingSessionKind::LunchBreak, time!(12:00), time!(13:00)),
+                SessionTime::new(TradingSessionKind::Regular, time!(13:00), time!(16:00)),
+                SessionTime::new(TradingSessionKind::AfterHours, time!(16:00), time!(16:10)),
+            ],
+            Market::CN => vec![
+                SessionTime::new(TradingSessionKind::PreMarket, time!(09:15), time!(09:30)),
+                SessionTime::new(TradingSessionKind::Regular, time!(09:30), time!(11:30)),
+                SessionTime::new(TradingSessionKind::LunchBreak, time!(11:30), time!(13:00)),
+                SessionTime::new(TradingSessionKind::Regular, time!(13:00), time!(15:00)),
+            ],
+        }
+    }
+
+    pub fn get_standard_utc_offset(market: &Market) -> UtcOffset {
+        match market {
+            Market::CN | Market::HK => UtcOffset::from_hms(8, 0, 0).unwrap(),
+            Market::US => UtcOffset::from_hms(-5, 0, 0).unwrap(),
+        }
+    }
+
+    // US markets follow the Eastern Time daylight saving rule, from the second Sunday of
+    // March to the first Sunday of November
+    pub fn get_utc_offset(market: &Market, date: Date) -> UtcOffset {
+        match market {
+            Market::CN | Market::HK => Self::get_standard_utc_offset(market),
+            Market::US => {
+                let get_nth_sunday = |month: Month, nth: u8| {
+                    // counted strictly after the day before the first day of the month
+                    Date::from_calendar_date(date.year(), month, 1)
+                        .unwrap()
+                        .previous_day()
+                        .unwrap()
+                        .nth_next_occurrence(Weekday::Sunday, nth)
+                };
+                match get_nth_sunday(Month::March, 2) <= date
+                    && date < get_nth_sunday(Month::November, 1)
+                {
+                    true => UtcOffset::from_hms(-4, 0, 0).unwrap(),
+                    false => Self::get_standard_utc_offset(market),
+                }
+            }
+        }
+    }
+
+    pub fn get_market(&self) -> Market {
+        self.market
+    }
+
+    pub fn add_holiday(&mut self, date: Date) {
+        self.holiday_set.insert(date);
+    }
+
+    pub fn add_early_close(&mut self, date: Date, close_time: Time) {
+        self.early_close_map.insert(date, close_time);
+    }
+
+    // Replaces the sessions of a single day, an empty list closes the market for the day
+    pub fn set_day_session_list(&mut self, date: Date, session_list: Vec<SessionTime>) {
+        self.override_map.insert(date, session_list);
+    }
+
+    pub fn load_holiday_table(&mut self, holiday_table: &HolidayTable) -> Result<(), Error> {
+        for holiday in holiday_table.holiday_list.iter() {
+            self.add_holiday(parse_date(holiday)?);
+        }
+        for early_close in holiday_table.early_close_list.iter() {
+            self.add_early_close(
+                parse_date(&early_close.date)?,
+                Time::parse(
+                    &early_close.close_time,
+                    format_description!("[hour]:[minute]"),
+                )
+                .with_context(|| {
+                    format!("PARSING_ERROR Error when parsing time {:?}", early_close)
+                })?,
+            );
+        }
+        Result::Ok(())
+    }
+
+    // Sessions starting at or after an early close are dropped, the one spanning it is cut
+    pub fn get_day_session_list(&self, date: Date) -> Vec<SessionTime> {
+        if let Option::Some(session_list) = self.override_map.get(&date) {
+            return session_list.clone();
+        }
+        if matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday)
+            || self.holiday_set.contains(&date)
+        {
+            return vec![];
+        }
+        match self.early_close_map.get(&date) {
+            Option::Some(close_time) => self
+                .session_list
+                .iter()
+                .filter(|session| session.start < *close_time)
+                .map(|session| SessionTime {
+                    end: session.end.min(*close_time),
+                    ..session.clone()
+                })
+                .collect(),
+            Option::None => self.session_list.clone(),
+        }
+    }
+
+    fn to_local_date_time(&self, timestamp: Timestamp) -> Result<PrimitiveDateTime, Error> {
+        let utc_date_time = timestamp.to_offset_date_time()?;
+        // the offset never changes during a session, so the date in standard time is enough
+        let standard_date = utc_date_time
+            .to_offset(Self::get_standard_utc_offset(&self.market))
+            .date();
+        let local_date_time =
+            utc_date_time.to_offset(Self::get_utc_offset(&self.market, standard_date));
+        Result::Ok(PrimitiveDateTime::new(
+            local_date_time.date(),
+            local_date_time.time(),
+        ))
+    }
+
+    fn to_timestamp(&self, date: Date, time: Time) -> Timestamp {
+        let offset = Self::get_utc_offset(&self.market, date);
+        Timestamp::from(OffsetDateTime::new_in_offset(date, time, offset))
+    }
+
+    pub fn get_session_kind(
+        &self,
+        timestamp: Timestamp,
+    ) -> Result<Option<TradingSessionKind>, Error> {
+        let local_date_time = self.to_local_date_time(timestamp)?;
+        let time = local_date_time.time();
+        Result::Ok(
+            self.get_day_session_list(local_date_time.date())
+                .into_iter()
+                .find(|session| session.start <= time && time < session.end)
+                .map(|session| session.kind),
+        )
+    }
+
+    fn find_regular_boundary(
+        &self,
+        timestamp: Timestamp,
+        get_boundary: fn(&SessionTime) -> Time,
+    ) -> Result<Timestamp, Error> {
+        let local_date = self.to_local_date_time(timestamp)?.date();
+        for day in 0..=Self::MAX_LOOKAHEAD_DAYS {
+            let date = local_date + Duration::days(day);
+            let boundary = self
+                .get_day_session_list(date)
+                .iter()
+                .filter(|session| session.kind == TradingSessionKind::Regular)
+                .map(|session| self.to_timestamp(date, get_boundary(session)))
+                .find(|boundary| *boundary > timestamp);
+            if let Option::Some(boundary) = boundary {
+                return Result::Ok(boundary);
+            }
+        }
+        Result::Err(anyhow!(
+            "MARKET_CLOSED No regular session of {:?} within {} days after {}",
+            self.market,
+            Self::MAX_LOOKAHEAD_DAYS,
+            timestamp
+        ))
+    }
+
+    pub fn next_open(&self, timestamp: Timestamp) -> Result<Timestamp, Error> {
+        self.find_regular_boundary(timestamp, |session| session.start)
+    }
+
+    pub fn next_close(&self, timestamp: Timestamp) -> Result<Timestamp, Error> {
+        self.find_regular_boundary(timestamp, |session| session.end)
+    }
+
+    pub fn next_trading_day(&self, timestamp: Timestamp) -> Result<TradingDay, Error> {
+        let local_date = self.to_local_date_time(timestamp)?.date();
+        for day in 0..=Self::MAX_LOOKAHEAD_DAYS {
+            let date = local_date + Duration::days(day);
+            let regular_list: Vec<SessionTime> = self
+                .get_day_session_list(date)
+                .into_iter()
+                .filter(|session| session.kind == TradingSessionKind::Regular)
+                .collect();
+            let (first, last) = match (regular_list.first(), regular_list.last()) {
+                (Option::Some(first), Option::Some(last)) => (first, last),
+                _ => continue,
+            };
+            let close_timestamp = self.to_timestamp(date, last.end);
+            if close_timestamp > timestamp {
+                return Result::Ok(TradingDay {
+                    open_timestamp: self.to_timestamp(date, first.start),
+                    close_timestamp,
+                });
+            }
+        }
+        Result::Err(anyhow!(
+            "MARKET_CLOSED No trading day of {:?} within {} days after {}",
+            self.market,
+            Self::MAX_LOOKAHEAD_DAYS,
+            timestamp
+        ))
+    }
+}
+
+pub fn parse_date(date: &str) -> Result<Date, Error> {
+    Date::parse(date, format_description!("[year]-[month]-[day]"))
+        .with_context(|| format!("PARSING_ERROR Error when parsing date {}", date))
+}
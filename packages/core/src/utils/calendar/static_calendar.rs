@@ -0,0 +1,105 @@
+use anyhow::{Context, Error};
+use async_trait::async_trait;
+use std::{collections::HashMap, fs::File};
+
+use super::{
+    common_trait::{TradingCalendarTrait, TradingDay, TradingSessionKind},
+    schedule::{HolidayTableMap, MarketSchedule},
+};
+use crate::model::{
+    common::{
+        config_key::{ConfigKeySpec, ConfigValueType},
+        timestamp::Timestamp,
+        types::ConfigMap,
+    },
+    trading::market::Market,
+};
+
+// Built-in weekly sessions, holidays are only known when a holiday file is configured
+pub struct StaticTradingCalendar {
+    market_schedule_map: HashMap<Market, MarketSchedule>,
+}
+
+impl StaticTradingCalendar {
+    pub const CONFIG_KEY_HOLIDAY_PATH: &'static str = "calendar.holiday_path";
+
+    pub fn new_with_holiday_table_map(holiday_table_map: &HolidayTableMap) -> Result<Self, Error> {
+        let mut market_schedule_map = HashMap::new();
+        for market in [Market::CN, Market::HK, Market::US] {
+            let mut market_schedule = MarketSchedule::new(market);
+            if let Option::Some(holiday_table) = holiday_table_map.get(&market) {
+                market_schedule.load_holiday_table(holiday_table)?;
+            }
+            market_schedule_map.insert(market, market_schedule);
+        }
+        Result::Ok(StaticTradingCalendar {
+            market_schedule_map,
+        })
+    }
+
+    // fails when the configured holiday file cannot be read
+    pub fn try_new(config_map: ConfigMap) -> Result<Self, Error> {
+        let holiday_table_map = match config_map.get(Self::CONFIG_KEY_HOLIDAY_PATH) {
+            Option::Some(path) => Self::load_holiday_table_map(path)?,
+            Option::None => HolidayTableMap::new(),
+        };
+        Self::new_with_holiday_table_map(&holiday_table_map)
+    }
+
+    pub fn load_holiday_table_map(path: &str) -> Result<HolidayTableMap, Error> {
+        let file = File::open(path)
+            .with_context(|| format!("Error when opening holiday file {}", path))?;
+        serde_yaml_ng::from_reader(file)
+            .with_context(|| format!("Error when reading holidays from path {}", path))
+    }
+
+    pub fn get_market_schedule(&self, market: &Market) -> &MarketSchedule {
+        // every market is inserted on creation
+        &self.market_schedule_map[market]
+    }
+}
+
+#[async_trait]
+impl TradingCalendarTrait for StaticTradingCalendar {
+    fn new(config_map: ConfigMap) -> Self {
+        Self::try_new(config_map).unwrap()
+    }
+
+    fn get_identifier() -> String {
+        const IDENTIFIER: &'static str = "StaticTradingCalendar";
+        IDENTIFIER.to_owned()
+    }
+
+    fn get_config_key_list() -> Vec<ConfigKeySpec> {
+        vec![ConfigKeySpec::optional(
+            Self::CONFIG_KEY_HOLIDAY_PATH,
+            ConfigValueType::String,
+            Option::None,
+            "YAML file of holidays and early closes per market, only weekends are closed when not set",
+        )]
+    }
+
+    async fn get_session_kind(
+        &self,
+        market: &Market,
+        timestamp: Timestamp,
+    ) -> Result<Option<TradingSessionKind>, Error> {
+        self.get_market_schedule(market).get_session_kind(timestamp)
+    }
+
+    async fn next_open(&self, market: &Market, timestamp: Timestamp) -> Result<Timestamp, Error> {
+        self.get_market_schedule(market).next_open(timestamp)
+    }
+
+    async fn next_close(&self, market: &Market, timestamp: Timestamp) -> Result<Timestamp, Error> {
+        self.get_market_schedule(market).next_close(timestamp)
+    }
+
+    async fn next_trading_day(
+        &self,
+        market: &Market,
+        timestamp: Timestamp,
+    ) -> Result<TradingDay, Error> {
+        self.get_market_schedule(market).next_trading_day(timestamp)
+    }
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use std::time::Duration;
+
+use crate::model::common::{config_key::ConfigKeySpec, timestamp::Timestamp, types::ConfigMap};
+
+#[async_trait]
+pub trait ClockTrait: Send + Sync {
+    fn new(config_map: ConfigMap) -> Self
+    where
+        Self: Sized;
+    fn get_identifier() -> String
+    where
+        Self: Sized;
+    fn get_config_key_list() -> Vec<ConfigKeySpec>
+    where
+        Self: Sized,
+    {
+        vec![]
+    }
+
+    fn now(&self) -> Timestamp;
+    async fn sleep(&self, duration: Duration);
+    async fn sleep_until(&self, timestamp: Timestamp);
+}
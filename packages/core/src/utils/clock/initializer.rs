@@ -0,0 +1,53 @@
+use anyhow::{anyhow, Error};
+
+use super::{
+    common_trait::ClockTrait, manual::ManualClock, simulated::SimulatedClock, system::SystemClock,
+};
+use crate::model::common::{config_key::ConfigKeySpec, types::ConfigMap};
+
+pub fn get_clock_instance(
+    identifier: String,
+    config_map: ConfigMap,
+) -> Result<Box<dyn ClockTrait>, Error> {
+    match identifier {
+        identifier if identifier == SystemClock::get_identifier() => {
+            Result::Ok(Box::new(SystemClock::new(config_map)))
+        }
+
+        identifier if identifier == SimulatedClock::get_identifier() => {
+            Result::Ok(Box::new(SimulatedClock::new(config_map)))
+        }
+
+        identifier if identifier == ManualClock::get_identifier() => {
+            Result::Ok(Box::new(ManualClock::new(config_map)))
+        }
+
+        _ => Result::Err(anyhow!("IDENTIFIER_NOT_MATCHED Clock: {}", identifier)),
+    }
+}
+
+pub fn get_clock_identifier_list() -> Vec<String> {
+    vec![
+        SystemClock::get_identifier(),
+        SimulatedClock::get_identifier(),
+        ManualClock::get_identifier(),
+    ]
+}
+
+pub fn get_clock_config_key_list(identifier: &str) -> Result<Vec<ConfigKeySpec>, Error> {
+    match identifier {
+        identifier if identifier == SystemClock::get_identifier() => {
+            Result::Ok(SystemClock::get_config_key_list())
+        }
+
+        identifier if identifier == SimulatedClock::get_identifier() => {
+            Result::Ok(SimulatedClock::get_config_key_list())
+        }
+
+        identifier if identifier == ManualClock::get_identifier() => {
+            Result::Ok(ManualClock::get_config_key_list())
+        }
+
+        _ => Result::Err(anyhow!("IDENTIFIER_NOT_MATCHED Clock: {}", identifier)),
+    }
+}
@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use std::time::Duration;
+use tokio::sync::watch;
+
+use super::common_trait::ClockTrait;
+use crate::model::common::{
+    config_key::{ConfigKeySpec, ConfigValueType},
+    timestamp::Timestamp,
+    types::ConfigMap,
+};
+
+// Only moves when `advance` or `set` is called, sleepers are woken up once the time is reached
+pub struct ManualClock {
+    sender: watch::Sender<Timestamp>,
+}
+
+impl ManualClock {
+    pub const CONFIG_KEY_START: &'static str = "clock.manual.start";
+
+    pub fn new_with_timestamp(timestamp: Timestamp) -> Self {
+        let (sender, _) = watch::channel(timestamp);
+        ManualClock { sender }
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        self.sender.send_modify(|now| *now = *now + duration);
+    }
+
+    pub fn set(&self, timestamp: Timestamp) {
+        self.sender.send_replace(timestamp);
+    }
+
+    async fn wait_until(&self, target: Timestamp) {
+        let mut receiver = self.sender.subscribe();
+        // the sender lives as long as self, so this cannot fail
+        let _ = receiver.wait_for(|now| *now >= target).await;
+    }
+}
+
+#[async_trait]
+impl ClockTrait for ManualClock {
+    fn new(config_map: ConfigMap) -> Self {
+        let timestamp = config_map
+            .get(Self::CONFIG_KEY_START)
+            .and_then(|timestamp| timestamp.parse().ok())
+            .unwrap_or_default();
+        ManualClock::new_with_timestamp(timestamp)
+    }
+
+    fn get_identifier() -> String {
+        const IDENTIFIER: &'static str = "ManualClock";
+        IDENTIFIER.to_owned()
+    }
+
+    fn get_config_key_list() -> Vec<ConfigKeySpec> {
+        vec![ConfigKeySpec::optional(
+            Self::CONFIG_KEY_START,
+            ConfigValueType::Timestamp,
+            Option::Some("0"),
+            "Initial time of the clock",
+        )]
+    }
+
+    fn now(&self) -> Timestamp {
+        *self.sender.borrow()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        let target = *self.sender.borrow() + duration;
+        self.wait_until(target).await
+    }
+
+    async fn sleep_until(&self, timestamp: Timestamp) {
+        self.wait_until(timestamp).await
+    }
+}
@@ -0,0 +1,5 @@
+pub mod common_trait;
+pub mod initializer;
+pub mod manual;
+pub mod simulated;
+pub mod system;
@@ -0,0 +1,213 @@
+use anyhow::{anyhow, Error};
+use async_trait::async_trait;
+use std::{
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::{sleep, Instant};
+
+use super::common_trait::ClockTrait;
+use crate::model::common::{
+    config_key::{ConfigKeySpec, ConfigValueType},
+    timestamp::Timestamp,
+    types::ConfigMap,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SimulationSpeed {
+    Max,
+    Multiplier(f64),
+}
+
+impl FromStr for SimulationSpeed {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "max" => Result::Ok(SimulationSpeed::Max),
+            "realtime" => Result::Ok(SimulationSpeed::Multiplier(1.0)),
+            multiplier => match multiplier.trim_end_matches('x').parse::<f64>() {
+                Result::Ok(multiplier) if multiplier > 0.0 => {
+                    Result::Ok(SimulationSpeed::Multiplier(multiplier))
+                }
+                _ => Result::Err(anyhow!(
+                    "PARSING_ERROR Error when parsing simulation speed {}",
+                    s
+                )),
+            },
+        }
+    }
+}
+
+struct SimulatedClockState {
+    speed: SimulationSpeed,
+    paused: bool,
+    anchor: Option<Timestamp>,
+    anchor_instant: Instant,
+}
+
+impl SimulatedClockState {
+    fn now(&self) -> Option<Timestamp> {
+        let anchor = self.anchor?;
+        match self.speed {
+            _ if self.paused => Option::Some(anchor),
+            SimulationSpeed::Max => Option::Some(anchor),
+            SimulationSpeed::Multiplier(multiplier) => {
+                Option::Some(anchor + self.anchor_instant.elapsed().mul_f64(multiplier))
+            }
+        }
+    }
+
+    fn reanchor(&mut self, anchor: Option<Timestamp>) {
+        self.anchor = anchor;
+        self.anchor_instant = Instant::now();
+    }
+}
+
+// Backtest clock, runs at N times the real speed or jumps straight to whatever is awaited.
+// Unless a start is given, the clock starts at the first `sleep_until`, `now` is 0 before that.
+#[derive(Clone)]
+pub struct SimulatedClock {
+    state: Arc<Mutex<SimulatedClockState>>,
+}
+
+impl SimulatedClock {
+    pub const CONFIG_KEY_SPEED: &'static str = "clock.simulated.speed";
+    pub const CONFIG_KEY_START: &'static str = "clock.simulated.start";
+    const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+    pub fn new_with_speed(speed: SimulationSpeed, start: Option<Timestamp>) -> Self {
+        SimulatedClock {
+            state: Arc::new(Mutex::new(SimulatedClockState {
+                speed,
+                paused: false,
+                anchor: start,
+                anchor_instant: Instant::now(),
+            })),
+        }
+    }
+
+    pub fn try_now(&self) -> Option<Timestamp> {
+        self.state.lock().unwrap().now()
+    }
+
+    pub fn get_speed(&self) -> SimulationSpeed {
+        self.state.lock().unwrap().speed
+    }
+
+    pub fn set_speed(&self, speed: SimulationSpeed) {
+        let mut state = self.state.lock().unwrap();
+        let now = state.now();
+        state.reanchor(now);
+        state.speed = speed;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.state.lock().unwrap().paused
+    }
+
+    pub fn pause(&self) {
+        let mut state = self.state.lock().unwrap();
+        let now = state.now();
+        state.reanchor(now);
+        state.paused = true;
+    }
+
+    pub fn resume(&self) {
+        let mut state = self.state.lock().unwrap();
+        let now = state.now();
+        state.reanchor(now);
+        state.paused = false;
+    }
+
+    pub fn seek(&self, timestamp: Timestamp) {
+        self.state.lock().unwrap().reanchor(Option::Some(timestamp));
+    }
+
+    async fn wait_until(&self, target: Timestamp) {
+        loop {
+            let remaining = {
+                let mut state = self.state.lock().unwrap();
+                match (state.now(), state.speed) {
+                    (Option::None, _) => {
+                        state.reanchor(Option::Some(target));
+                        return;
+                    }
+                    (Option::Some(now), _) if now >= target => return,
+                    (Option::Some(_), _) if state.paused => Self::POLL_INTERVAL,
+                    (Option::Some(_), SimulationSpeed::Max) => {
+                        state.reanchor(Option::Some(target));
+                        return;
+                    }
+                    (Option::Some(now), SimulationSpeed::Multiplier(multiplier)) => {
+                        target.saturating_sub(now).div_f64(multiplier)
+                    }
+                }
+            };
+            sleep(remaining.min(Self::POLL_INTERVAL)).await;
+        }
+    }
+}
+
+#[async_trait]
+impl ClockTrait for SimulatedClock {
+    fn new(config_map: ConfigMap) -> Self {
+        let speed = config_map
+            .get(Self::CONFIG_KEY_SPEED)
+            .and_then(|speed| {
+                speed
+                    .parse()
+                    .inspect_err(|err| log::error!("{}, fallback to realtime", err))
+                    .ok()
+            })
+            .unwrap_or(SimulationSpeed::Multiplier(1.0));
+        let start = config_map
+            .get(Self::CONFIG_KEY_START)
+            .and_then(|start| start.parse().ok());
+        SimulatedClock::new_with_speed(speed, start)
+    }
+
+    fn get_identifier() -> String {
+        const IDENTIFIER: &'static str = "SimulatedClock";
+        IDENTIFIER.to_owned()
+    }
+
+    fn get_config_key_list() -> Vec<ConfigKeySpec> {
+        vec![
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_SPEED,
+                ConfigValueType::SimulationSpeed,
+                Option::Some("realtime"),
+                "Speed of the simulated time, `max`, `realtime` or a multiplier like `10x`",
+            ),
+            ConfigKeySpec::optional(
+                Self::CONFIG_KEY_START,
+                ConfigValueType::Timestamp,
+                Option::None,
+                "Start of the simulated time, the first `sleep_until` when not set",
+            ),
+        ]
+    }
+
+    fn now(&self) -> Timestamp {
+        self.try_now().unwrap_or_default()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        let now = self.state.lock().unwrap().now();
+        match now {
+            Option::Some(now) => self.wait_until(now + duration).await,
+            Option::None => match self.get_speed() {
+                SimulationSpeed::Max => {}
+                SimulationSpeed::Multiplier(multiplier) => {
+                    sleep(duration.div_f64(multiplier)).await
+                }
+            },
+        }
+    }
+
+    async fn sleep_until(&self, timestamp: Timestamp) {
+        self.wait_until(timestamp).await
+    }
+}
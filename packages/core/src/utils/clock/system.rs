@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use std::time::Duration;
+use tokio::time::sleep;
+
+use super::common_trait::ClockTrait;
+use crate::model::common::{timestamp::Timestamp, types::ConfigMap};
+
+pub struct SystemClock {}
+
+#[async_trait]
+impl ClockTrait for SystemClock {
+    fn new(_config_map: ConfigMap) -> Self {
+        SystemClock {}
+    }
+
+    fn get_identifier() -> String {
+        const IDENTIFIER: &'static str = "SystemClock";
+        IDENTIFIER.to_owned()
+    }
+
+    fn now(&self) -> Timestamp {
+        Timestamp::now()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        sleep(duration).await
+    }
+
+    async fn sleep_until(&self, timestamp: Timestamp) {
+        sleep(timestamp.saturating_sub(Timestamp::now())).await
+    }
+}
@@ -1,2 +1,4 @@
+pub mod calendar;
+pub mod clock;
 pub mod error;
 pub mod time;
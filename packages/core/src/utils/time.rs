@@ -1,4 +1,6 @@
+use anyhow::{Context, Error};
 use std::time::SystemTime;
+use time::{macros::format_description, UtcOffset};
 
 pub fn get_now_unix_timestamp() -> u64 {
     SystemTime::now()
@@ -6,3 +8,12 @@ pub fn get_now_unix_timestamp() -> u64 {
         .unwrap()
         .as_secs() as u64
 }
+
+// e.g. "+08:00" or "-05:00"
+pub fn parse_utc_offset(value: &str) -> Result<UtcOffset, Error> {
+    UtcOffset::parse(
+        value,
+        format_description!("[offset_hour sign:mandatory]:[offset_minute]"),
+    )
+    .with_context(|| format!("PARSING_ERROR Error when parsing UTC offset {}", value))
+}
@@ -188,12 +188,12 @@ pub struct LiveOrder {
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct GetLiveOrderResponse {
     #[serde(rename = "filters")]
-    filters: Option<Vec<String>>,
+    pub filters: Option<Vec<String>>,
     #[serde(rename = "orders")]
-    orders: Vec<LiveOrder>,
+    pub orders: Vec<LiveOrder>,
     /// If live order update is a snapshot
     #[serde(rename = "snapshot")]
-    snapshot: bool,
+    pub snapshot: bool,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -400,6 +400,9 @@ pub struct OrderRequest {
     /// set to true if you want to place a single group orders(OCA)
     #[serde(rename = "isSingleGroup", skip_serializing_if = "Option::is_none")]
     pub is_single_group: Option<bool>,
+    /// One-Cancels-All group name, orders sharing the same group are cancelled once one of them is filled
+    #[serde(rename = "ocaGroup", skip_serializing_if = "Option::is_none")]
+    pub oca_group: Option<String>,
     /// set to true if the order can be executed outside regular trading hours.
     #[serde(rename = "outsideRTH")]
     pub outside_regular_trading_hours: bool,
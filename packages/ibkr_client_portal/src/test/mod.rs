@@ -6,6 +6,7 @@ pub mod market_data;
 pub mod order;
 pub mod portfolio;
 pub mod portfolio_analyst;
+pub mod reply;
 pub mod scanner;
 pub mod session;
 pub mod utils;
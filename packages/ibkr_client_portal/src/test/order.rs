@@ -56,6 +56,7 @@ async fn test_preview_order() {
                 limit_offset: Option::None,
                 listing_exchange: Option::None,
                 is_single_group: Option::None,
+                oca_group: Option::None,
                 outside_regular_trading_hours: false,
                 price: Option::Some(dec!(168.88)),
                 aux_price: Option::None,
@@ -112,6 +113,7 @@ async fn test_order_operations() {
                 listing_exchange: Option::None,
                 limit_offset: Option::None,
                 is_single_group: Option::None,
+                oca_group: Option::None,
                 outside_regular_trading_hours: false,
                 price: Option::Some(dec!(99.99)),
                 aux_price: Option::None,
@@ -0,0 +1,39 @@
+use crate::{
+    model::order::{PlaceOrModifyOrderInfo, PlaceOrModifyOrderQuestion, PlaceOrModifyOrderResult},
+    utils::reply::collect_order_id_list,
+};
+
+fn get_success(order_id: &str) -> PlaceOrModifyOrderResult {
+    PlaceOrModifyOrderResult::Success(PlaceOrModifyOrderInfo {
+        order_id: order_id.to_owned(),
+        local_order_id: Option::None,
+        order_status: Option::None,
+        parent_order_id: Option::None,
+        encrypt_message: Option::None,
+    })
+}
+
+fn get_question(id: &str) -> PlaceOrModifyOrderResult {
+    PlaceOrModifyOrderResult::Question(PlaceOrModifyOrderQuestion {
+        id: id.to_owned(),
+        message: vec![],
+        is_suppressed: Option::None,
+        message_ids: Option::None,
+    })
+}
+
+#[test]
+fn test_collect_order_id_list() {
+    let mut order_id_list = vec![];
+    // the parent got placed, the take profit order needs a confirmation
+    let question = collect_order_id_list(
+        vec![get_success("1"), get_question("reply_1")],
+        &mut order_id_list,
+    );
+    assert_eq!("reply_1", question.unwrap().id);
+    // the reply only holds the orders after the question
+    let question =
+        collect_order_id_list(vec![get_success("2"), get_success("3")], &mut order_id_list);
+    assert!(question.is_none());
+    assert_eq!(vec!["1", "2", "3"], order_id_list);
+}
@@ -2,7 +2,10 @@ use anyhow::{anyhow, Error};
 
 use crate::{
     client::IBClientPortal,
-    model::order::{PlaceOrModifyOrderResult, PlaceOrderReplyRequest, PlaceOrdersResponse},
+    model::order::{
+        PlaceOrModifyOrderQuestion, PlaceOrModifyOrderResult, PlaceOrderReplyRequest,
+        PlaceOrdersResponse,
+    },
 };
 
 pub async fn handle_reply_order_requests(
@@ -53,3 +56,61 @@ pub async fn handle_reply_order_requests(
         return Result::Err(anyhow!("Place order failed {:?}", place_order_response));
     }
 }
+
+// Moves the order ids of the successful results into `order_id_list`, returns the first question
+// left to be replied
+pub fn collect_order_id_list(
+    result_list: Vec<PlaceOrModifyOrderResult>,
+    order_id_list: &mut Vec<String>,
+) -> Option<PlaceOrModifyOrderQuestion> {
+    let mut question_option = Option::None;
+    for result in result_list {
+        match result {
+            PlaceOrModifyOrderResult::Success(detail) => order_id_list.push(detail.order_id),
+            PlaceOrModifyOrderResult::Question(question) => {
+                question_option = question_option.or(Option::Some(question))
+            }
+        }
+    }
+    question_option
+}
+
+// Same as handle_reply_order_requests but for requests placing several orders at once
+// (e.g. bracket orders). The results placed before a question are not repeated in its reply, so
+// the order ids are accumulated across replies.
+pub async fn handle_reply_multiple_order_requests(
+    client: IBClientPortal,
+    place_order_response: PlaceOrdersResponse,
+    max_retry_count: i32,
+) -> Result<Vec<String>, Error> {
+    let mut arr = match place_order_response {
+        PlaceOrdersResponse::Ok(arr) => arr,
+        PlaceOrdersResponse::Error(_) => {
+            return Result::Err(anyhow!("Place order failed {:?}", place_order_response));
+        }
+    };
+    let mut order_id_list = vec![];
+    for _ in 0..max_retry_count {
+        let question = match collect_order_id_list(arr, &mut order_id_list) {
+            Option::Some(question) => question,
+            Option::None => return Result::Ok(order_id_list),
+        };
+
+        let place_order_reply_response = client
+            .place_order_reply(PlaceOrderReplyRequest {
+                reply_id: question.id.clone(),
+                confirmed: true,
+            })
+            .await?;
+        match place_order_reply_response {
+            PlaceOrdersResponse::Ok(next_arr) => arr = next_arr,
+            PlaceOrdersResponse::Error(_) => {
+                return Result::Err(anyhow!("Place order failed when replying {:?}", question));
+            }
+        }
+    }
+    Result::Err(anyhow!(
+        "Place order failed after {} times retry",
+        max_retry_count
+    ))
+}